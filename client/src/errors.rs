@@ -0,0 +1,23 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+error_chain! {
+	types {
+		Error, ErrorKind, ResultExt, Result;
+	}
+	foreign_links {
+		Io(std::io::Error);
+		Grpc(grpcio::Error);
+		Serde(serde_json::Error);
+		Oci(oci::serialize::SerializeError);
+		Parse(std::num::ParseIntError);
+	}
+	errors {
+		InvalidArgument(t: String) {
+			description("Invalid Argument")
+			display("Invalid Argument: '{}'", t)
+		}
+	}
+}
@@ -0,0 +1,203 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Host side client of the agent API, the building block of kata-agent-ctl
+// and of scripted integration tests.
+
+#[macro_use]
+extern crate error_chain;
+extern crate grpcio;
+extern crate oci;
+extern crate protobuf;
+extern crate protocols;
+extern crate rustjail;
+extern crate serde_json;
+extern crate sha2;
+
+pub mod errors;
+
+use futures::Stream;
+use grpcio::{ChannelBuilder, EnvBuilder};
+use protobuf::RepeatedField;
+use protocols::agent::{CopyFileRequest, DirEntry, MemoryPressureEvent, ReadFileRequest, SubscribeMemoryPressureRequest};
+use protocols::agent_grpc::AgentServiceClient;
+use protocols::health_grpc::HealthClient;
+use protocols::oci::Spec;
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::os::unix::fs::{FileExt, MetadataExt};
+use std::path::Path;
+use std::sync::Arc;
+
+use errors::*;
+
+pub const DEFAULT_ADDR: &'static str = "unix:///run/kata-agent.sock";
+// what the agent accepts in one CopyFile call
+const COPY_CHUNK_SIZE: usize = 1024 * 1024;
+
+pub struct Client {
+	pub agent: AgentServiceClient,
+	pub health: HealthClient,
+}
+
+// the grpc target of an agent address, the same forms the agent
+// listens on: vsock://<cid>:<port>, unix://<path> or tcp://<host>:<port>
+fn target(addr: &str) -> Result<String> {
+	if addr.starts_with("vsock://") || addr.starts_with("unix://") {
+		return Ok(addr.to_string());
+	}
+
+	if addr.starts_with("tcp://") {
+		return Ok(addr["tcp://".len()..].to_string());
+	}
+
+	Err(ErrorKind::InvalidArgument(format!(
+		"unsupported agent address {}, want vsock://, unix:// or tcp://", addr)).into())
+}
+
+impl Client {
+	pub fn connect(addr: &str) -> Result<Client> {
+		let env = Arc::new(EnvBuilder::new().cq_count(1).build());
+		let channel = ChannelBuilder::new(env).connect(target(addr)?.as_str());
+
+		Ok(Client {
+			agent: AgentServiceClient::new(channel.clone()),
+			health: HealthClient::new(channel),
+		})
+	}
+
+	// copies a local file to path in the guest, in chunks, from where an
+	// earlier attempt stopped; the agent checks the digest at the end
+	pub fn copy_file(&self, src: &str, path: &str) -> Result<()> {
+		let mut f = File::open(src).chain_err(|| format!("cannot open {}", src))?;
+		let meta = f.metadata()?;
+
+		let mut req = CopyFileRequest::new();
+		req.path = path.to_string();
+		req.file_size = meta.len() as i64;
+		req.file_mode = meta.mode();
+		req.dir_mode = 0o750;
+		req.uid = meta.uid() as i32;
+		req.gid = meta.gid() as i32;
+		req.sha256 = sha256(&mut f)?;
+
+		// without data, this only asks how far the guest got
+		let resp = self.agent.copy_file(&req)?;
+		if resp.done {
+			return Ok(());
+		}
+
+		let mut buf = vec![0u8; COPY_CHUNK_SIZE];
+		let mut offset = resp.offset;
+
+		loop {
+			let n = f.read_at(buf.as_mut_slice(), offset as u64)?;
+			if n == 0 {
+				return Err(ErrorKind::InvalidArgument(format!(
+					"{} changed while being copied", src)).into());
+			}
+
+			req.offset = offset;
+			req.data = buf[..n].to_vec();
+			let resp = self.agent.copy_file(&req)?;
+			if resp.done {
+				return Ok(());
+			}
+
+			offset = resp.offset;
+		}
+	}
+
+	// a guest file into w, up to its end
+	pub fn read_file<W: Write>(&self, path: &str, w: &mut W) -> Result<()> {
+		let mut req = ReadFileRequest::new();
+		req.path = path.to_string();
+
+		loop {
+			let resp = self.agent.read_file(&req)?;
+			if resp.dir {
+				return Err(ErrorKind::InvalidArgument(format!("{} is a directory", path)).into());
+			}
+			if resp.data.is_empty() {
+				return Ok(());
+			}
+
+			w.write_all(resp.data.as_slice())?;
+			req.offset += resp.data.len() as i64;
+		}
+	}
+
+	pub fn list_dir(&self, path: &str) -> Result<Vec<DirEntry>> {
+		let mut req = ReadFileRequest::new();
+		req.path = path.to_string();
+
+		let resp = self.agent.read_file(&req)?;
+		if !resp.dir {
+			return Err(ErrorKind::InvalidArgument(format!("{} is not a directory", path)).into());
+		}
+
+		Ok(resp.entries.into_vec())
+	}
+
+	// f gets the events of cids, or of all the containers when empty, for
+	// as long as the agent keeps the stream open
+	pub fn watch_memory_pressure<F: FnMut(MemoryPressureEvent)>(&self, cids: Vec<String>, mut f: F) -> Result<()> {
+		let mut req = SubscribeMemoryPressureRequest::new();
+		req.container_ids = RepeatedField::from_vec(cids);
+
+		for ev in self.agent.subscribe_memory_pressure(&req)?.wait() {
+			f(ev?);
+		}
+
+		Ok(())
+	}
+}
+
+fn sha256(f: &mut File) -> Result<Vec<u8>> {
+	let mut hasher = Sha256::new();
+	let mut buf = vec![0u8; COPY_CHUNK_SIZE];
+
+	loop {
+		let n = f.read(buf.as_mut_slice())?;
+		if n == 0 {
+			return Ok(hasher.result().to_vec());
+		}
+		hasher.input(&buf[..n]);
+	}
+}
+
+// the spec of an OCI bundle, with the root path made absolute since the
+// agent does not know where the bundle is
+pub fn bundle_spec(bundle: &str) -> Result<Spec> {
+	let config = Path::new(bundle).join("config.json");
+	let oci = oci::Spec::load(config.to_str().unwrap_or_default())
+		.chain_err(|| format!("cannot load {}", config.display()))?;
+
+	let mut spec = rustjail::oci_to_grpc(&oci);
+
+	if let Some(root) = spec.Root.as_mut() {
+		if !root.Path.starts_with('/') {
+			let path = Path::new(bundle).join(root.Path.as_str()).canonicalize()
+				.chain_err(|| format!("bad root path {} in {}", root.Path, bundle))?;
+			root.Path = path.to_string_lossy().to_string();
+		}
+	}
+
+	Ok(spec)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_target() {
+		assert_eq!(target("vsock://3:1024").unwrap(), "vsock://3:1024");
+		assert_eq!(target("unix:///run/kata-agent.sock").unwrap(), "unix:///run/kata-agent.sock");
+		assert_eq!(target("tcp://127.0.0.1:5000").unwrap(), "127.0.0.1:5000");
+		assert!(target("127.0.0.1:5000").is_err());
+	}
+}
@@ -0,0 +1,438 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// kata-agent-ctl, runs one agent RPC and prints its response.
+//
+//   kata-agent-ctl [--addr <address>] <command> [--<param> <value>]... [-- <args>]
+//
+// The parameters can also come from a JSON object, --json <file> or - for
+// stdin, the flags overriding it. Lists are given by repeating a flag or as
+// JSON arrays.
+
+extern crate agent_client;
+extern crate protobuf;
+extern crate protocols;
+extern crate serde_json;
+
+use agent_client::errors::*;
+use agent_client::{bundle_spec, Client, DEFAULT_ADDR};
+use protobuf::{Message, RepeatedField, SingularPtrField};
+use protocols::agent::*;
+use protocols::health::CheckRequest;
+use protocols::oci::{Process, User};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+
+const ADDR_ENV: &'static str = "KATA_AGENT_ADDR";
+// flags taking no value
+const SWITCHES: &'static [&'static str] = &["tty", "sandbox-pidns", "stderr", "help"];
+const DEFAULT_PATH: &'static str = "PATH=/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin";
+const DEFAULT_READ_LEN: u32 = 4096;
+
+const USAGE: &'static str = "usage: kata-agent-ctl [--addr <address>] <command> [options]
+
+address: vsock://<cid>:<port>, unix://<path> or tcp://<host>:<port>,
+         $KATA_AGENT_ADDR or unix:///run/kata-agent.sock by default
+
+commands:
+  check              [--service sandbox|storage|uevent|reaper]
+  create-sandbox     [--id <sandbox>] [--hostname <name>] [--dns <entry>]... [--sandbox-pidns]
+  destroy-sandbox
+  guest-details
+  metrics
+  create-container   --id <container> --bundle <dir> [--exec-id <id>] [--sandbox-pidns]
+                     [--memory-pressure <low|medium|critical>[:<stall us>:<window us>]]...
+  start-container    --id <container>
+  remove-container   --id <container> [--timeout <seconds>]
+  pause-container    --id <container>
+  resume-container   --id <container>
+  stats-container    --id <container>
+  exec               --id <container> --exec-id <id> [--tty] [--cwd <dir>] [--env <k=v>]... -- <args>
+  wait               --id <container> --exec-id <id>
+  oom-events         [--count <n>]
+  memory-pressure    [--id <container>]...
+  signal             --id <container> [--exec-id <id>] --signal <number>
+  list-processes     --id <container> [--format table|json] [-- <ps args>]
+  read-stdout        --id <container> --exec-id <id> [--len <bytes>]
+  read-stderr        --id <container> --exec-id <id> [--len <bytes>]
+  read-output        --id <container> --exec-id <id> [--stderr] [--offset <n>] [--len <bytes>]
+  write-stdin        --id <container> --exec-id <id> --data <string>
+  close-stdin        --id <container> --exec-id <id>
+  list-interfaces
+  list-routes
+  update-dns         --dns <entry>...
+  copy-file          --src <local file> --dest <guest path>
+  read-file          --path <guest path> [--dest <local file>]
+  list-dir           --path <guest path>
+
+every command also takes --json <file|->";
+
+struct Params {
+	values: HashMap<String, Vec<String>>,
+	// what follows --
+	rest: Vec<String>,
+}
+
+impl Params {
+	fn get(&self, key: &str) -> Option<&str> {
+		self.values.get(key).and_then(|v| v.last()).map(|s| s.as_str())
+	}
+
+	fn required(&self, key: &str) -> Result<&str> {
+		self.get(key).ok_or_else(|| ErrorKind::InvalidArgument(
+			format!("--{} is required", key)).into())
+	}
+
+	fn list(&self, key: &str) -> Vec<String> {
+		self.values.get(key).cloned().unwrap_or_default()
+	}
+
+	fn flag(&self, key: &str) -> bool {
+		self.get(key).map_or(false, |v| v == "true" || v == "1")
+	}
+
+	fn number<T: std::str::FromStr<Err = std::num::ParseIntError>>(&self, key: &str, default: T) -> Result<T> {
+		match self.get(key) {
+			Some(v) => v.parse::<T>().chain_err(|| format!("bad --{} {}", key, v)),
+			None => Ok(default),
+		}
+	}
+
+	fn id(&self) -> Result<String> {
+		Ok(self.required("id")?.to_string())
+	}
+
+	fn exec_id(&self) -> Result<String> {
+		Ok(self.required("exec-id")?.to_string())
+	}
+
+	// the JSON values sit below the flags
+	fn merge_json(&mut self, v: Value) -> Result<()> {
+		let map = match v {
+			Value::Object(m) => m,
+			_ => return Err(ErrorKind::InvalidArgument("the JSON input must be an object".to_string()).into()),
+		};
+
+		for (key, value) in map {
+			let key = key.replace('_', "-");
+			if self.values.contains_key(&key) {
+				continue;
+			}
+
+			let values = match value {
+				Value::Array(a) => a.into_iter().map(json_string).collect(),
+				v => vec![json_string(v)],
+			};
+
+			if key == "args" {
+				if self.rest.is_empty() {
+					self.rest = values;
+				}
+			} else {
+				self.values.insert(key, values);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+fn json_string(v: Value) -> String {
+	match v {
+		Value::String(s) => s,
+		v => v.to_string(),
+	}
+}
+
+fn parse_params(args: &[String]) -> Result<Params> {
+	let mut p = Params { values: HashMap::new(), rest: Vec::new() };
+	let mut i = 0;
+
+	while i < args.len() {
+		let arg = args[i].as_str();
+		i += 1;
+
+		if arg == "--" {
+			p.rest = args[i..].to_vec();
+			break;
+		}
+
+		if !arg.starts_with("--") {
+			return Err(ErrorKind::InvalidArgument(format!("unexpected argument {}", arg)).into());
+		}
+
+		let arg = &arg[2..];
+		let (key, value) = match arg.find('=') {
+			Some(n) => (arg[..n].to_string(), arg[n + 1..].to_string()),
+			None if SWITCHES.contains(&arg) => (arg.to_string(), "true".to_string()),
+			None if i < args.len() => {
+				i += 1;
+				(arg.to_string(), args[i - 1].clone())
+			}
+			None => return Err(ErrorKind::InvalidArgument(format!("missing value for --{}", arg)).into()),
+		};
+
+		p.values.entry(key).or_insert_with(Vec::new).push(value);
+	}
+
+	if let Some(src) = p.get("json").map(|s| s.to_string()) {
+		let content = if src == "-" {
+			let mut s = String::new();
+			io::stdin().read_to_string(&mut s)?;
+			s
+		} else {
+			fs::read_to_string(src.as_str()).chain_err(|| format!("cannot read {}", src))?
+		};
+		p.merge_json(serde_json::from_str(content.as_str())?)?;
+	}
+
+	Ok(p)
+}
+
+fn print_message<M: Message>(m: &M) {
+	println!("{}", protobuf::text_format::print_to_string(m));
+}
+
+fn exec_process(p: &Params) -> Result<Process> {
+	if p.rest.is_empty() {
+		return Err(ErrorKind::InvalidArgument("no command to run, give it after --".to_string()).into());
+	}
+
+	let mut env = p.list("env");
+	if !env.iter().any(|e| e.starts_with("PATH=")) {
+		env.push(DEFAULT_PATH.to_string());
+	}
+
+	let mut user = User::new();
+	user.UID = p.number("uid", 0u32)?;
+	user.GID = p.number("gid", 0u32)?;
+
+	let mut process = Process::new();
+	process.Terminal = p.flag("tty");
+	process.User = SingularPtrField::some(user);
+	process.Args = RepeatedField::from_vec(p.rest.clone());
+	process.Env = RepeatedField::from_vec(env);
+	process.Cwd = p.get("cwd").unwrap_or("/").to_string();
+
+	Ok(process)
+}
+
+// level[:stall_us:window_us]
+fn pressure_threshold(s: &str) -> Result<MemoryPressureThreshold> {
+	let f: Vec<&str> = s.split(':').collect();
+	let mut t = MemoryPressureThreshold::new();
+	t.level = f[0].to_string();
+
+	match f.len() {
+		1 => {}
+		3 => {
+			t.stall_us = f[1].parse()?;
+			t.window_us = f[2].parse()?;
+		}
+		_ => return Err(ErrorKind::InvalidArgument(format!(
+			"bad memory pressure threshold {}, want level[:stall_us:window_us]", s)).into()),
+	}
+
+	Ok(t)
+}
+
+fn run(client: &Client, cmd: &str, p: &Params) -> Result<()> {
+	let agent = &client.agent;
+
+	match cmd {
+		"check" => {
+			let mut req = CheckRequest::new();
+			req.service = p.get("service").unwrap_or_default().to_string();
+			print_message(&client.health.check(&req)?);
+		}
+		"create-sandbox" => {
+			let mut req = CreateSandboxRequest::new();
+			req.sandbox_id = p.get("id").unwrap_or_default().to_string();
+			req.hostname = p.get("hostname").unwrap_or_default().to_string();
+			req.dns = RepeatedField::from_vec(p.list("dns"));
+			req.sandbox_pidns = p.flag("sandbox-pidns");
+			agent.create_sandbox(&req)?;
+		}
+		"destroy-sandbox" => {
+			agent.destroy_sandbox(&DestroySandboxRequest::new())?;
+		}
+		"guest-details" => {
+			let mut req = GuestDetailsRequest::new();
+			req.mem_block_size = true;
+			req.mem_hotplug_probe = true;
+			print_message(&agent.get_guest_details(&req)?);
+		}
+		"metrics" => print!("{}", agent.get_metrics(&GetMetricsRequest::new())?.metrics),
+		"create-container" => {
+			let mut req = CreateContainerRequest::new();
+			req.container_id = p.id()?;
+			// the init process is known by the container id unless told otherwise
+			req.exec_id = p.get("exec-id").map(|s| s.to_string()).unwrap_or(p.id()?);
+			req.OCI = SingularPtrField::some(bundle_spec(p.required("bundle")?)?);
+			req.sandbox_pidns = p.flag("sandbox-pidns");
+			req.memory_pressure = RepeatedField::from_vec(p.list("memory-pressure").iter()
+				.map(|t| pressure_threshold(t))
+				.collect::<Result<Vec<_>>>()?);
+			agent.create_container(&req)?;
+		}
+		"start-container" => {
+			let mut req = StartContainerRequest::new();
+			req.container_id = p.id()?;
+			agent.start_container(&req)?;
+		}
+		"remove-container" => {
+			let mut req = RemoveContainerRequest::new();
+			req.container_id = p.id()?;
+			req.timeout = p.number("timeout", 0u32)?;
+			agent.remove_container(&req)?;
+		}
+		"pause-container" => {
+			let mut req = PauseContainerRequest::new();
+			req.container_id = p.id()?;
+			agent.pause_container(&req)?;
+		}
+		"resume-container" => {
+			let mut req = ResumeContainerRequest::new();
+			req.container_id = p.id()?;
+			agent.resume_container(&req)?;
+		}
+		"stats-container" => {
+			let mut req = StatsContainerRequest::new();
+			req.container_id = p.id()?;
+			print_message(&agent.stats_container(&req)?);
+		}
+		"exec" => {
+			let mut req = ExecProcessRequest::new();
+			req.container_id = p.id()?;
+			req.exec_id = p.exec_id()?;
+			req.process = SingularPtrField::some(exec_process(p)?);
+			agent.exec_process(&req)?;
+		}
+		"wait" => {
+			let mut req = WaitProcessRequest::new();
+			req.container_id = p.id()?;
+			req.exec_id = p.exec_id()?;
+			print_message(&agent.wait_process(&req)?);
+		}
+		"oom-events" => {
+			// 0 for ever
+			let count = p.number("count", 0u64)?;
+			let mut n = 0;
+			while count == 0 || n < count {
+				println!("{}", agent.get_oom_event(&GetOOMEventRequest::new())?.container_id);
+				n += 1;
+			}
+		}
+		"memory-pressure" => {
+			client.watch_memory_pressure(p.list("id"), |ev| println!("{} {}", ev.container_id, ev.level))?;
+		}
+		"signal" => {
+			let mut req = SignalProcessRequest::new();
+			req.container_id = p.id()?;
+			req.exec_id = p.get("exec-id").unwrap_or_default().to_string();
+			req.signal = p.number("signal", 15u32)?;
+			agent.signal_process(&req)?;
+		}
+		"list-processes" => {
+			let mut req = ListProcessesRequest::new();
+			req.container_id = p.id()?;
+			req.format = p.get("format").unwrap_or("table").to_string();
+			req.args = RepeatedField::from_vec(p.rest.clone());
+			let resp = agent.list_processes(&req)?;
+			io::stdout().write_all(resp.process_list.as_slice())?;
+		}
+		"read-stdout" | "read-stderr" => {
+			let mut req = ReadStreamRequest::new();
+			req.container_id = p.id()?;
+			req.exec_id = p.exec_id()?;
+			req.len = p.number("len", DEFAULT_READ_LEN)?;
+			let resp = if cmd == "read-stdout" {
+				agent.read_stdout(&req)?
+			} else {
+				agent.read_stderr(&req)?
+			};
+			io::stdout().write_all(resp.data.as_slice())?;
+		}
+		"read-output" => {
+			let mut req = ReadOutputRequest::new();
+			req.container_id = p.id()?;
+			req.exec_id = p.exec_id()?;
+			req.stderr = p.flag("stderr");
+			req.offset = p.number("offset", 0u64)?;
+			req.len = p.number("len", DEFAULT_READ_LEN)?;
+			print_message(&agent.read_output(&req)?);
+		}
+		"write-stdin" => {
+			let mut req = WriteStreamRequest::new();
+			req.container_id = p.id()?;
+			req.exec_id = p.exec_id()?;
+			req.data = p.required("data")?.as_bytes().to_vec();
+			print_message(&agent.write_stdin(&req)?);
+		}
+		"close-stdin" => {
+			let mut req = CloseStdinRequest::new();
+			req.container_id = p.id()?;
+			req.exec_id = p.exec_id()?;
+			agent.close_stdin(&req)?;
+		}
+		"list-interfaces" => print_message(&agent.list_interfaces(&ListInterfacesRequest::new())?),
+		"list-routes" => print_message(&agent.list_routes(&ListRoutesRequest::new())?),
+		"update-dns" => {
+			let mut req = UpdateDNSRequest::new();
+			req.dns = RepeatedField::from_vec(p.list("dns"));
+			agent.update_dns(&req)?;
+		}
+		"copy-file" => client.copy_file(p.required("src")?, p.required("dest")?)?,
+		"read-file" => {
+			let path = p.required("path")?;
+			match p.get("dest") {
+				Some(dest) => {
+					let mut f = fs::File::create(dest).chain_err(|| format!("cannot create {}", dest))?;
+					client.read_file(path, &mut f)?;
+				}
+				None => client.read_file(path, &mut io::stdout())?,
+			}
+		}
+		"list-dir" => {
+			for e in client.list_dir(p.required("path")?)? {
+				println!("{:06o} {:>12} {:>12} {}{}", e.mode, e.size, e.mtime, e.name,
+					if e.dir { "/" } else { "" });
+			}
+		}
+		_ => return Err(ErrorKind::InvalidArgument(format!("unknown command {}\n\n{}", cmd, USAGE)).into()),
+	}
+
+	Ok(())
+}
+
+fn main() {
+	let mut args: Vec<String> = env::args().skip(1).collect();
+	let mut addr = env::var(ADDR_ENV).unwrap_or(DEFAULT_ADDR.to_string());
+
+	if args.len() >= 2 && args[0] == "--addr" {
+		addr = args[1].clone();
+		args.drain(..2);
+	}
+
+	if args.is_empty() || args[0] == "--help" || args[0] == "-h" {
+		println!("{}", USAGE);
+		return;
+	}
+
+	let cmd = args.remove(0);
+	let r = parse_params(&args)
+		.and_then(|p| Ok((Client::connect(addr.as_str())?, p)))
+		.and_then(|(client, p)| run(&client, cmd.as_str(), &p));
+
+	if let Err(e) = r {
+		let msg: Vec<String> = e.iter().map(|e| e.to_string()).collect();
+		eprintln!("kata-agent-ctl {}: {}", cmd, msg.join(": "));
+		process::exit(1);
+	}
+}
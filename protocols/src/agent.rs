@@ -31,6 +31,8 @@ pub struct CreateContainerRequest {
     pub storages: ::protobuf::RepeatedField<Storage>,
     pub OCI: ::protobuf::SingularPtrField<super::oci::Spec>,
     pub sandbox_pidns: bool,
+    pub output_buffer_size: u32,
+    pub memory_pressure: ::protobuf::RepeatedField<MemoryPressureThreshold>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -229,6 +231,46 @@ impl CreateContainerRequest {
     pub fn set_sandbox_pidns(&mut self, v: bool) {
         self.sandbox_pidns = v;
     }
+
+    // uint32 output_buffer_size = 8;
+
+
+    pub fn get_output_buffer_size(&self) -> u32 {
+        self.output_buffer_size
+    }
+    pub fn clear_output_buffer_size(&mut self) {
+        self.output_buffer_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_output_buffer_size(&mut self, v: u32) {
+        self.output_buffer_size = v;
+    }
+
+    // repeated .grpc.MemoryPressureThreshold memory_pressure = 9;
+
+
+    pub fn get_memory_pressure(&self) -> &[MemoryPressureThreshold] {
+        &self.memory_pressure
+    }
+    pub fn clear_memory_pressure(&mut self) {
+        self.memory_pressure.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_pressure(&mut self, v: ::protobuf::RepeatedField<MemoryPressureThreshold>) {
+        self.memory_pressure = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_memory_pressure(&mut self) -> &mut ::protobuf::RepeatedField<MemoryPressureThreshold> {
+        &mut self.memory_pressure
+    }
+
+    // Take field
+    pub fn take_memory_pressure(&mut self) -> ::protobuf::RepeatedField<MemoryPressureThreshold> {
+        ::std::mem::replace(&mut self.memory_pressure, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for CreateContainerRequest {
@@ -253,6 +295,11 @@ impl ::protobuf::Message for CreateContainerRequest {
                 return false;
             }
         };
+        for v in &self.memory_pressure {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -285,6 +332,16 @@ impl ::protobuf::Message for CreateContainerRequest {
                     let tmp = is.read_bool()?;
                     self.sandbox_pidns = tmp;
                 },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.output_buffer_size = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.memory_pressure)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -322,6 +379,13 @@ impl ::protobuf::Message for CreateContainerRequest {
         if self.sandbox_pidns != false {
             my_size += 2;
         }
+        if self.output_buffer_size != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.output_buffer_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.memory_pressure {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -357,6 +421,14 @@ impl ::protobuf::Message for CreateContainerRequest {
         if self.sandbox_pidns != false {
             os.write_bool(7, self.sandbox_pidns)?;
         }
+        if self.output_buffer_size != 0 {
+            os.write_uint32(8, self.output_buffer_size)?;
+        }
+        for v in &self.memory_pressure {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -434,6 +506,16 @@ impl ::protobuf::Message for CreateContainerRequest {
                     |m: &CreateContainerRequest| { &m.sandbox_pidns },
                     |m: &mut CreateContainerRequest| { &mut m.sandbox_pidns },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "output_buffer_size",
+                    |m: &CreateContainerRequest| { &m.output_buffer_size },
+                    |m: &mut CreateContainerRequest| { &mut m.output_buffer_size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MemoryPressureThreshold>>(
+                    "memory_pressure",
+                    |m: &CreateContainerRequest| { &m.memory_pressure },
+                    |m: &mut CreateContainerRequest| { &mut m.memory_pressure },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<CreateContainerRequest>(
                     "CreateContainerRequest",
                     fields,
@@ -463,6 +545,8 @@ impl ::protobuf::Clear for CreateContainerRequest {
         self.storages.clear();
         self.OCI.clear();
         self.sandbox_pidns = false;
+        self.output_buffer_size = 0;
+        self.memory_pressure.clear();
         self.unknown_fields.clear();
     }
 }
@@ -479,6 +563,245 @@ impl ::protobuf::reflect::ProtobufValue for CreateContainerRequest {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct MemoryPressureThreshold {
+    // message fields
+    pub level: ::std::string::String,
+    pub stall_us: u64,
+    pub window_us: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MemoryPressureThreshold {
+    fn default() -> &'a MemoryPressureThreshold {
+        <MemoryPressureThreshold as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MemoryPressureThreshold {
+    pub fn new() -> MemoryPressureThreshold {
+        ::std::default::Default::default()
+    }
+
+    // string level = 1;
+
+
+    pub fn get_level(&self) -> &str {
+        &self.level
+    }
+    pub fn clear_level(&mut self) {
+        self.level.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_level(&mut self, v: ::std::string::String) {
+        self.level = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_level(&mut self) -> &mut ::std::string::String {
+        &mut self.level
+    }
+
+    // Take field
+    pub fn take_level(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.level, ::std::string::String::new())
+    }
+
+    // uint64 stall_us = 2;
+
+
+    pub fn get_stall_us(&self) -> u64 {
+        self.stall_us
+    }
+    pub fn clear_stall_us(&mut self) {
+        self.stall_us = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stall_us(&mut self, v: u64) {
+        self.stall_us = v;
+    }
+
+    // uint64 window_us = 3;
+
+
+    pub fn get_window_us(&self) -> u64 {
+        self.window_us
+    }
+    pub fn clear_window_us(&mut self) {
+        self.window_us = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_window_us(&mut self, v: u64) {
+        self.window_us = v;
+    }
+}
+
+impl ::protobuf::Message for MemoryPressureThreshold {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.level)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stall_us = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.window_us = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.level.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.level);
+        }
+        if self.stall_us != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.stall_us, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.window_us != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.window_us, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.level.is_empty() {
+            os.write_string(1, &self.level)?;
+        }
+        if self.stall_us != 0 {
+            os.write_uint64(2, self.stall_us)?;
+        }
+        if self.window_us != 0 {
+            os.write_uint64(3, self.window_us)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MemoryPressureThreshold {
+        MemoryPressureThreshold::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "level",
+                    |m: &MemoryPressureThreshold| { &m.level },
+                    |m: &mut MemoryPressureThreshold| { &mut m.level },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stall_us",
+                    |m: &MemoryPressureThreshold| { &m.stall_us },
+                    |m: &mut MemoryPressureThreshold| { &mut m.stall_us },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "window_us",
+                    |m: &MemoryPressureThreshold| { &m.window_us },
+                    |m: &mut MemoryPressureThreshold| { &mut m.window_us },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MemoryPressureThreshold>(
+                    "MemoryPressureThreshold",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MemoryPressureThreshold {
+        static mut instance: ::protobuf::lazy::Lazy<MemoryPressureThreshold> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MemoryPressureThreshold,
+        };
+        unsafe {
+            instance.get(MemoryPressureThreshold::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MemoryPressureThreshold {
+    fn clear(&mut self) {
+        self.level.clear();
+        self.stall_us = 0;
+        self.window_us = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MemoryPressureThreshold {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MemoryPressureThreshold {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct StartContainerRequest {
     // message fields
@@ -859,6 +1182,7 @@ pub struct ExecProcessRequest {
     pub exec_id: ::std::string::String,
     pub string_user: ::protobuf::SingularPtrField<StringUser>,
     pub process: ::protobuf::SingularPtrField<super::oci::Process>,
+    pub output_buffer_size: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -992,6 +1316,21 @@ impl ExecProcessRequest {
     pub fn take_process(&mut self) -> super::oci::Process {
         self.process.take().unwrap_or_else(|| super::oci::Process::new())
     }
+
+    // uint32 output_buffer_size = 5;
+
+
+    pub fn get_output_buffer_size(&self) -> u32 {
+        self.output_buffer_size
+    }
+    pub fn clear_output_buffer_size(&mut self) {
+        self.output_buffer_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_output_buffer_size(&mut self, v: u32) {
+        self.output_buffer_size = v;
+    }
 }
 
 impl ::protobuf::Message for ExecProcessRequest {
@@ -1025,6 +1364,13 @@ impl ::protobuf::Message for ExecProcessRequest {
                 4 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.process)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.output_buffer_size = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1051,6 +1397,9 @@ impl ::protobuf::Message for ExecProcessRequest {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if self.output_buffer_size != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.output_buffer_size, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1073,6 +1422,9 @@ impl ::protobuf::Message for ExecProcessRequest {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if self.output_buffer_size != 0 {
+            os.write_uint32(5, self.output_buffer_size)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1135,6 +1487,11 @@ impl ::protobuf::Message for ExecProcessRequest {
                     |m: &ExecProcessRequest| { &m.process },
                     |m: &mut ExecProcessRequest| { &mut m.process },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "output_buffer_size",
+                    |m: &ExecProcessRequest| { &m.output_buffer_size },
+                    |m: &mut ExecProcessRequest| { &mut m.output_buffer_size },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ExecProcessRequest>(
                     "ExecProcessRequest",
                     fields,
@@ -1161,6 +1518,7 @@ impl ::protobuf::Clear for ExecProcessRequest {
         self.exec_id.clear();
         self.string_user.clear();
         self.process.clear();
+        self.output_buffer_size = 0;
         self.unknown_fields.clear();
     }
 }
@@ -1638,6 +1996,8 @@ impl ::protobuf::reflect::ProtobufValue for WaitProcessRequest {
 pub struct WaitProcessResponse {
     // message fields
     pub status: i32,
+    pub core_dumped: bool,
+    pub oom_killed: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1668,6 +2028,36 @@ impl WaitProcessResponse {
     pub fn set_status(&mut self, v: i32) {
         self.status = v;
     }
+
+    // bool core_dumped = 2;
+
+
+    pub fn get_core_dumped(&self) -> bool {
+        self.core_dumped
+    }
+    pub fn clear_core_dumped(&mut self) {
+        self.core_dumped = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_core_dumped(&mut self, v: bool) {
+        self.core_dumped = v;
+    }
+
+    // bool oom_killed = 3;
+
+
+    pub fn get_oom_killed(&self) -> bool {
+        self.oom_killed
+    }
+    pub fn clear_oom_killed(&mut self) {
+        self.oom_killed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_oom_killed(&mut self, v: bool) {
+        self.oom_killed = v;
+    }
 }
 
 impl ::protobuf::Message for WaitProcessResponse {
@@ -1686,13 +2076,27 @@ impl ::protobuf::Message for WaitProcessResponse {
                     let tmp = is.read_int32()?;
                     self.status = tmp;
                 },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.core_dumped = tmp;
                 },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.oom_killed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
 
     // Compute sizes of nested messages
     #[allow(unused_variables)]
@@ -1701,6 +2105,12 @@ impl ::protobuf::Message for WaitProcessResponse {
         if self.status != 0 {
             my_size += ::protobuf::rt::value_size(1, self.status, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.core_dumped != false {
+            my_size += 2;
+        }
+        if self.oom_killed != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1710,6 +2120,12 @@ impl ::protobuf::Message for WaitProcessResponse {
         if self.status != 0 {
             os.write_int32(1, self.status)?;
         }
+        if self.core_dumped != false {
+            os.write_bool(2, self.core_dumped)?;
+        }
+        if self.oom_killed != false {
+            os.write_bool(3, self.oom_killed)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1757,6 +2173,16 @@ impl ::protobuf::Message for WaitProcessResponse {
                     |m: &WaitProcessResponse| { &m.status },
                     |m: &mut WaitProcessResponse| { &mut m.status },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "core_dumped",
+                    |m: &WaitProcessResponse| { &m.core_dumped },
+                    |m: &mut WaitProcessResponse| { &mut m.core_dumped },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "oom_killed",
+                    |m: &WaitProcessResponse| { &m.oom_killed },
+                    |m: &mut WaitProcessResponse| { &mut m.oom_killed },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<WaitProcessResponse>(
                     "WaitProcessResponse",
                     fields,
@@ -1780,6 +2206,8 @@ impl ::protobuf::Message for WaitProcessResponse {
 impl ::protobuf::Clear for WaitProcessResponse {
     fn clear(&mut self) {
         self.status = 0;
+        self.core_dumped = false;
+        self.oom_killed = false;
         self.unknown_fields.clear();
     }
 }
@@ -7987,181 +8415,124 @@ impl ::protobuf::reflect::ProtobufValue for TtyWinResizeRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CreateSandboxRequest {
+pub struct StreamIORequest {
     // message fields
-    pub hostname: ::std::string::String,
-    pub dns: ::protobuf::RepeatedField<::std::string::String>,
-    pub storages: ::protobuf::RepeatedField<Storage>,
-    pub sandbox_pidns: bool,
-    pub sandbox_id: ::std::string::String,
-    pub guest_hook_path: ::std::string::String,
+    pub container_id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    pub data: ::std::vec::Vec<u8>,
+    pub close_stdin: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CreateSandboxRequest {
-    fn default() -> &'a CreateSandboxRequest {
-        <CreateSandboxRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a StreamIORequest {
+    fn default() -> &'a StreamIORequest {
+        <StreamIORequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CreateSandboxRequest {
-    pub fn new() -> CreateSandboxRequest {
+impl StreamIORequest {
+    pub fn new() -> StreamIORequest {
         ::std::default::Default::default()
     }
 
-    // string hostname = 1;
+    // string container_id = 1;
 
 
-    pub fn get_hostname(&self) -> &str {
-        &self.hostname
+    pub fn get_container_id(&self) -> &str {
+        &self.container_id
     }
-    pub fn clear_hostname(&mut self) {
-        self.hostname.clear();
+    pub fn clear_container_id(&mut self) {
+        self.container_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_hostname(&mut self, v: ::std::string::String) {
-        self.hostname = v;
+    pub fn set_container_id(&mut self, v: ::std::string::String) {
+        self.container_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
-        &mut self.hostname
-    }
-
-    // Take field
-    pub fn take_hostname(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.hostname, ::std::string::String::new())
-    }
-
-    // repeated string dns = 2;
-
-
-    pub fn get_dns(&self) -> &[::std::string::String] {
-        &self.dns
-    }
-    pub fn clear_dns(&mut self) {
-        self.dns.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_dns(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.dns = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_dns(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.dns
+    pub fn mut_container_id(&mut self) -> &mut ::std::string::String {
+        &mut self.container_id
     }
 
     // Take field
-    pub fn take_dns(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.dns, ::protobuf::RepeatedField::new())
+    pub fn take_container_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.container_id, ::std::string::String::new())
     }
 
-    // repeated .grpc.Storage storages = 3;
+    // string exec_id = 2;
 
 
-    pub fn get_storages(&self) -> &[Storage] {
-        &self.storages
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
     }
-    pub fn clear_storages(&mut self) {
-        self.storages.clear();
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_storages(&mut self, v: ::protobuf::RepeatedField<Storage>) {
-        self.storages = v;
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_storages(&mut self) -> &mut ::protobuf::RepeatedField<Storage> {
-        &mut self.storages
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
     }
 
     // Take field
-    pub fn take_storages(&mut self) -> ::protobuf::RepeatedField<Storage> {
-        ::std::mem::replace(&mut self.storages, ::protobuf::RepeatedField::new())
-    }
-
-    // bool sandbox_pidns = 4;
-
-
-    pub fn get_sandbox_pidns(&self) -> bool {
-        self.sandbox_pidns
-    }
-    pub fn clear_sandbox_pidns(&mut self) {
-        self.sandbox_pidns = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_sandbox_pidns(&mut self, v: bool) {
-        self.sandbox_pidns = v;
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
     }
 
-    // string sandbox_id = 5;
+    // bytes data = 3;
 
 
-    pub fn get_sandbox_id(&self) -> &str {
-        &self.sandbox_id
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
     }
-    pub fn clear_sandbox_id(&mut self) {
-        self.sandbox_id.clear();
+    pub fn clear_data(&mut self) {
+        self.data.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
-        self.sandbox_id = v;
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
-        &mut self.sandbox_id
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
     }
 
     // Take field
-    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
     }
 
-    // string guest_hook_path = 6;
+    // bool close_stdin = 4;
 
 
-    pub fn get_guest_hook_path(&self) -> &str {
-        &self.guest_hook_path
+    pub fn get_close_stdin(&self) -> bool {
+        self.close_stdin
     }
-    pub fn clear_guest_hook_path(&mut self) {
-        self.guest_hook_path.clear();
+    pub fn clear_close_stdin(&mut self) {
+        self.close_stdin = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_guest_hook_path(&mut self, v: ::std::string::String) {
-        self.guest_hook_path = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_guest_hook_path(&mut self) -> &mut ::std::string::String {
-        &mut self.guest_hook_path
-    }
-
-    // Take field
-    pub fn take_guest_hook_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.guest_hook_path, ::std::string::String::new())
+    pub fn set_close_stdin(&mut self, v: bool) {
+        self.close_stdin = v;
     }
 }
 
-impl ::protobuf::Message for CreateSandboxRequest {
+impl ::protobuf::Message for StreamIORequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.storages {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -8170,26 +8541,20 @@ impl ::protobuf::Message for CreateSandboxRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.hostname)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.dns)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.storages)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
                 },
                 4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_bool()?;
-                    self.sandbox_pidns = tmp;
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.guest_hook_path)?;
+                    self.close_stdin = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -8203,24 +8568,17 @@ impl ::protobuf::Message for CreateSandboxRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.hostname.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.hostname);
+        if !self.container_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.container_id);
         }
-        for value in &self.dns {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        for value in &self.storages {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if self.sandbox_pidns != false {
-            my_size += 2;
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
         }
-        if !self.sandbox_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(5, &self.sandbox_id);
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
         }
-        if !self.guest_hook_path.is_empty() {
-            my_size += ::protobuf::rt::string_size(6, &self.guest_hook_path);
+        if self.close_stdin != false {
+            my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -8228,25 +8586,17 @@ impl ::protobuf::Message for CreateSandboxRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.hostname.is_empty() {
-            os.write_string(1, &self.hostname)?;
+        if !self.container_id.is_empty() {
+            os.write_string(1, &self.container_id)?;
         }
-        for v in &self.dns {
-            os.write_string(2, &v)?;
-        };
-        for v in &self.storages {
-            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if self.sandbox_pidns != false {
-            os.write_bool(4, self.sandbox_pidns)?;
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
         }
-        if !self.sandbox_id.is_empty() {
-            os.write_string(5, &self.sandbox_id)?;
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
         }
-        if !self.guest_hook_path.is_empty() {
-            os.write_string(6, &self.guest_hook_path)?;
+        if self.close_stdin != false {
+            os.write_bool(4, self.close_stdin)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -8278,8 +8628,8 @@ impl ::protobuf::Message for CreateSandboxRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> CreateSandboxRequest {
-        CreateSandboxRequest::new()
+    fn new() -> StreamIORequest {
+        StreamIORequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -8291,37 +8641,27 @@ impl ::protobuf::Message for CreateSandboxRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "hostname",
-                    |m: &CreateSandboxRequest| { &m.hostname },
-                    |m: &mut CreateSandboxRequest| { &mut m.hostname },
+                    "container_id",
+                    |m: &StreamIORequest| { &m.container_id },
+                    |m: &mut StreamIORequest| { &mut m.container_id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "dns",
-                    |m: &CreateSandboxRequest| { &m.dns },
-                    |m: &mut CreateSandboxRequest| { &mut m.dns },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "exec_id",
+                    |m: &StreamIORequest| { &m.exec_id },
+                    |m: &mut StreamIORequest| { &mut m.exec_id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Storage>>(
-                    "storages",
-                    |m: &CreateSandboxRequest| { &m.storages },
-                    |m: &mut CreateSandboxRequest| { &mut m.storages },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &StreamIORequest| { &m.data },
+                    |m: &mut StreamIORequest| { &mut m.data },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "sandbox_pidns",
-                    |m: &CreateSandboxRequest| { &m.sandbox_pidns },
-                    |m: &mut CreateSandboxRequest| { &mut m.sandbox_pidns },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "sandbox_id",
-                    |m: &CreateSandboxRequest| { &m.sandbox_id },
-                    |m: &mut CreateSandboxRequest| { &mut m.sandbox_id },
+                    "close_stdin",
+                    |m: &StreamIORequest| { &m.close_stdin },
+                    |m: &mut StreamIORequest| { &mut m.close_stdin },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "guest_hook_path",
-                    |m: &CreateSandboxRequest| { &m.guest_hook_path },
-                    |m: &mut CreateSandboxRequest| { &mut m.guest_hook_path },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CreateSandboxRequest>(
-                    "CreateSandboxRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<StreamIORequest>(
+                    "StreamIORequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -8329,220 +8669,147 @@ impl ::protobuf::Message for CreateSandboxRequest {
         }
     }
 
-    fn default_instance() -> &'static CreateSandboxRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CreateSandboxRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static StreamIORequest {
+        static mut instance: ::protobuf::lazy::Lazy<StreamIORequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CreateSandboxRequest,
+            ptr: 0 as *const StreamIORequest,
         };
         unsafe {
-            instance.get(CreateSandboxRequest::new)
+            instance.get(StreamIORequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CreateSandboxRequest {
+impl ::protobuf::Clear for StreamIORequest {
     fn clear(&mut self) {
-        self.hostname.clear();
-        self.dns.clear();
-        self.storages.clear();
-        self.sandbox_pidns = false;
-        self.sandbox_id.clear();
-        self.guest_hook_path.clear();
+        self.container_id.clear();
+        self.exec_id.clear();
+        self.data.clear();
+        self.close_stdin = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CreateSandboxRequest {
+impl ::std::fmt::Debug for StreamIORequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CreateSandboxRequest {
+impl ::protobuf::reflect::ProtobufValue for StreamIORequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct DestroySandboxRequest {
+pub struct StreamIOResponse {
+    // message fields
+    pub stdout: ::std::vec::Vec<u8>,
+    pub stderr: ::std::vec::Vec<u8>,
+    pub exited: bool,
+    pub status: i32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DestroySandboxRequest {
-    fn default() -> &'a DestroySandboxRequest {
-        <DestroySandboxRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a StreamIOResponse {
+    fn default() -> &'a StreamIOResponse {
+        <StreamIOResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DestroySandboxRequest {
-    pub fn new() -> DestroySandboxRequest {
+impl StreamIOResponse {
+    pub fn new() -> StreamIOResponse {
         ::std::default::Default::default()
     }
-}
 
-impl ::protobuf::Message for DestroySandboxRequest {
-    fn is_initialized(&self) -> bool {
-        true
-    }
+    // bytes stdout = 1;
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    pub fn get_stdout(&self) -> &[u8] {
+        &self.stdout
     }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+    pub fn clear_stdout(&mut self) {
+        self.stdout.clear();
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Param is passed by value, moved
+    pub fn set_stdout(&mut self, v: ::std::vec::Vec<u8>) {
+        self.stdout = v;
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stdout(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.stdout
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    // Take field
+    pub fn take_stdout(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.stdout, ::std::vec::Vec::new())
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
-    }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
-    }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
-        self
-    }
+    // bytes stderr = 2;
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
-    }
 
-    fn new() -> DestroySandboxRequest {
-        DestroySandboxRequest::new()
+    pub fn get_stderr(&self) -> &[u8] {
+        &self.stderr
     }
-
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<DestroySandboxRequest>(
-                    "DestroySandboxRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    pub fn clear_stderr(&mut self) {
+        self.stderr.clear();
     }
 
-    fn default_instance() -> &'static DestroySandboxRequest {
-        static mut instance: ::protobuf::lazy::Lazy<DestroySandboxRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const DestroySandboxRequest,
-        };
-        unsafe {
-            instance.get(DestroySandboxRequest::new)
-        }
+    // Param is passed by value, moved
+    pub fn set_stderr(&mut self, v: ::std::vec::Vec<u8>) {
+        self.stderr = v;
     }
-}
 
-impl ::protobuf::Clear for DestroySandboxRequest {
-    fn clear(&mut self) {
-        self.unknown_fields.clear();
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_stderr(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.stderr
     }
-}
 
-impl ::std::fmt::Debug for DestroySandboxRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    // Take field
+    pub fn take_stderr(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.stderr, ::std::vec::Vec::new())
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for DestroySandboxRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
-    }
-}
+    // bool exited = 3;
 
-#[derive(PartialEq,Clone,Default)]
-pub struct Interfaces {
-    // message fields
-    pub Interfaces: ::protobuf::RepeatedField<super::types::Interface>,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
 
-impl<'a> ::std::default::Default for &'a Interfaces {
-    fn default() -> &'a Interfaces {
-        <Interfaces as ::protobuf::Message>::default_instance()
+    pub fn get_exited(&self) -> bool {
+        self.exited
+    }
+    pub fn clear_exited(&mut self) {
+        self.exited = false;
     }
-}
 
-impl Interfaces {
-    pub fn new() -> Interfaces {
-        ::std::default::Default::default()
+    // Param is passed by value, moved
+    pub fn set_exited(&mut self, v: bool) {
+        self.exited = v;
     }
 
-    // repeated .types.Interface Interfaces = 1;
+    // int32 status = 4;
 
 
-    pub fn get_Interfaces(&self) -> &[super::types::Interface] {
-        &self.Interfaces
+    pub fn get_status(&self) -> i32 {
+        self.status
     }
-    pub fn clear_Interfaces(&mut self) {
-        self.Interfaces.clear();
+    pub fn clear_status(&mut self) {
+        self.status = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_Interfaces(&mut self, v: ::protobuf::RepeatedField<super::types::Interface>) {
-        self.Interfaces = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_Interfaces(&mut self) -> &mut ::protobuf::RepeatedField<super::types::Interface> {
-        &mut self.Interfaces
-    }
-
-    // Take field
-    pub fn take_Interfaces(&mut self) -> ::protobuf::RepeatedField<super::types::Interface> {
-        ::std::mem::replace(&mut self.Interfaces, ::protobuf::RepeatedField::new())
+    pub fn set_status(&mut self, v: i32) {
+        self.status = v;
     }
 }
 
-impl ::protobuf::Message for Interfaces {
+impl ::protobuf::Message for StreamIOResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.Interfaces {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -8551,7 +8818,24 @@ impl ::protobuf::Message for Interfaces {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.Interfaces)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.stdout)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.stderr)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exited = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.status = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -8565,21 +8849,36 @@ impl ::protobuf::Message for Interfaces {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.Interfaces {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        if !self.stdout.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.stdout);
+        }
+        if !self.stderr.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.stderr);
+        }
+        if self.exited != false {
+            my_size += 2;
+        }
+        if self.status != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.status, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.Interfaces {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
+        if !self.stdout.is_empty() {
+            os.write_bytes(1, &self.stdout)?;
+        }
+        if !self.stderr.is_empty() {
+            os.write_bytes(2, &self.stderr)?;
+        }
+        if self.exited != false {
+            os.write_bool(3, self.exited)?;
+        }
+        if self.status != 0 {
+            os.write_int32(4, self.status)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -8610,8 +8909,8 @@ impl ::protobuf::Message for Interfaces {
         Self::descriptor_static()
     }
 
-    fn new() -> Interfaces {
-        Interfaces::new()
+    fn new() -> StreamIOResponse {
+        StreamIOResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -8622,13 +8921,28 @@ impl ::protobuf::Message for Interfaces {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Interface>>(
-                    "Interfaces",
-                    |m: &Interfaces| { &m.Interfaces },
-                    |m: &mut Interfaces| { &mut m.Interfaces },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "stdout",
+                    |m: &StreamIOResponse| { &m.stdout },
+                    |m: &mut StreamIOResponse| { &mut m.stdout },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Interfaces>(
-                    "Interfaces",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "stderr",
+                    |m: &StreamIOResponse| { &m.stderr },
+                    |m: &mut StreamIOResponse| { &mut m.stderr },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exited",
+                    |m: &StreamIOResponse| { &m.exited },
+                    |m: &mut StreamIOResponse| { &mut m.exited },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "status",
+                    |m: &StreamIOResponse| { &m.status },
+                    |m: &mut StreamIOResponse| { &mut m.status },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StreamIOResponse>(
+                    "StreamIOResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -8636,89 +8950,163 @@ impl ::protobuf::Message for Interfaces {
         }
     }
 
-    fn default_instance() -> &'static Interfaces {
-        static mut instance: ::protobuf::lazy::Lazy<Interfaces> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static StreamIOResponse {
+        static mut instance: ::protobuf::lazy::Lazy<StreamIOResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Interfaces,
+            ptr: 0 as *const StreamIOResponse,
         };
         unsafe {
-            instance.get(Interfaces::new)
+            instance.get(StreamIOResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Interfaces {
+impl ::protobuf::Clear for StreamIOResponse {
     fn clear(&mut self) {
-        self.Interfaces.clear();
+        self.stdout.clear();
+        self.stderr.clear();
+        self.exited = false;
+        self.status = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Interfaces {
+impl ::std::fmt::Debug for StreamIOResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Interfaces {
+impl ::protobuf::reflect::ProtobufValue for StreamIOResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Routes {
+pub struct ReadOutputRequest {
     // message fields
-    pub Routes: ::protobuf::RepeatedField<super::types::Route>,
+    pub container_id: ::std::string::String,
+    pub exec_id: ::std::string::String,
+    pub stderr: bool,
+    pub offset: u64,
+    pub len: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Routes {
-    fn default() -> &'a Routes {
-        <Routes as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ReadOutputRequest {
+    fn default() -> &'a ReadOutputRequest {
+        <ReadOutputRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Routes {
-    pub fn new() -> Routes {
+impl ReadOutputRequest {
+    pub fn new() -> ReadOutputRequest {
         ::std::default::Default::default()
     }
 
-    // repeated .types.Route Routes = 1;
+    // string container_id = 1;
 
 
-    pub fn get_Routes(&self) -> &[super::types::Route] {
-        &self.Routes
+    pub fn get_container_id(&self) -> &str {
+        &self.container_id
     }
-    pub fn clear_Routes(&mut self) {
-        self.Routes.clear();
+    pub fn clear_container_id(&mut self) {
+        self.container_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_Routes(&mut self, v: ::protobuf::RepeatedField<super::types::Route>) {
-        self.Routes = v;
+    pub fn set_container_id(&mut self, v: ::std::string::String) {
+        self.container_id = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_Routes(&mut self) -> &mut ::protobuf::RepeatedField<super::types::Route> {
-        &mut self.Routes
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_container_id(&mut self) -> &mut ::std::string::String {
+        &mut self.container_id
     }
 
     // Take field
-    pub fn take_Routes(&mut self) -> ::protobuf::RepeatedField<super::types::Route> {
-        ::std::mem::replace(&mut self.Routes, ::protobuf::RepeatedField::new())
+    pub fn take_container_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.container_id, ::std::string::String::new())
+    }
+
+    // string exec_id = 2;
+
+
+    pub fn get_exec_id(&self) -> &str {
+        &self.exec_id
+    }
+    pub fn clear_exec_id(&mut self) {
+        self.exec_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exec_id(&mut self, v: ::std::string::String) {
+        self.exec_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exec_id(&mut self) -> &mut ::std::string::String {
+        &mut self.exec_id
+    }
+
+    // Take field
+    pub fn take_exec_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exec_id, ::std::string::String::new())
+    }
+
+    // bool stderr = 3;
+
+
+    pub fn get_stderr(&self) -> bool {
+        self.stderr
+    }
+    pub fn clear_stderr(&mut self) {
+        self.stderr = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stderr(&mut self, v: bool) {
+        self.stderr = v;
+    }
+
+    // uint64 offset = 4;
+
+
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u64) {
+        self.offset = v;
+    }
+
+    // uint32 len = 5;
+
+
+    pub fn get_len(&self) -> u32 {
+        self.len
+    }
+    pub fn clear_len(&mut self) {
+        self.len = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_len(&mut self, v: u32) {
+        self.len = v;
     }
 }
 
-impl ::protobuf::Message for Routes {
+impl ::protobuf::Message for ReadOutputRequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.Routes {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -8727,7 +9115,31 @@ impl ::protobuf::Message for Routes {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.Routes)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exec_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.stderr = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.offset = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.len = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -8741,21 +9153,42 @@ impl ::protobuf::Message for Routes {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.Routes {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
+        if !self.container_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.container_id);
+        }
+        if !self.exec_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.exec_id);
+        }
+        if self.stderr != false {
+            my_size += 2;
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.len != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.len, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.Routes {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
+        if !self.container_id.is_empty() {
+            os.write_string(1, &self.container_id)?;
+        }
+        if !self.exec_id.is_empty() {
+            os.write_string(2, &self.exec_id)?;
+        }
+        if self.stderr != false {
+            os.write_bool(3, self.stderr)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(4, self.offset)?;
+        }
+        if self.len != 0 {
+            os.write_uint32(5, self.len)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -8786,8 +9219,8 @@ impl ::protobuf::Message for Routes {
         Self::descriptor_static()
     }
 
-    fn new() -> Routes {
-        Routes::new()
+    fn new() -> ReadOutputRequest {
+        ReadOutputRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -8798,13 +9231,33 @@ impl ::protobuf::Message for Routes {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Route>>(
-                    "Routes",
-                    |m: &Routes| { &m.Routes },
-                    |m: &mut Routes| { &mut m.Routes },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "container_id",
+                    |m: &ReadOutputRequest| { &m.container_id },
+                    |m: &mut ReadOutputRequest| { &mut m.container_id },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Routes>(
-                    "Routes",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "exec_id",
+                    |m: &ReadOutputRequest| { &m.exec_id },
+                    |m: &mut ReadOutputRequest| { &mut m.exec_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "stderr",
+                    |m: &ReadOutputRequest| { &m.stderr },
+                    |m: &mut ReadOutputRequest| { &mut m.stderr },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "offset",
+                    |m: &ReadOutputRequest| { &m.offset },
+                    |m: &mut ReadOutputRequest| { &mut m.offset },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "len",
+                    |m: &ReadOutputRequest| { &m.len },
+                    |m: &mut ReadOutputRequest| { &mut m.len },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReadOutputRequest>(
+                    "ReadOutputRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -8812,97 +9265,137 @@ impl ::protobuf::Message for Routes {
         }
     }
 
-    fn default_instance() -> &'static Routes {
-        static mut instance: ::protobuf::lazy::Lazy<Routes> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ReadOutputRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ReadOutputRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Routes,
+            ptr: 0 as *const ReadOutputRequest,
         };
         unsafe {
-            instance.get(Routes::new)
+            instance.get(ReadOutputRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Routes {
+impl ::protobuf::Clear for ReadOutputRequest {
     fn clear(&mut self) {
-        self.Routes.clear();
+        self.container_id.clear();
+        self.exec_id.clear();
+        self.stderr = false;
+        self.offset = 0;
+        self.len = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Routes {
+impl ::std::fmt::Debug for ReadOutputRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Routes {
+impl ::protobuf::reflect::ProtobufValue for ReadOutputRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct UpdateInterfaceRequest {
+pub struct ReadOutputResponse {
     // message fields
-    pub interface: ::protobuf::SingularPtrField<super::types::Interface>,
+    pub data: ::std::vec::Vec<u8>,
+    pub offset: u64,
+    pub dropped: u64,
+    pub eof: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a UpdateInterfaceRequest {
-    fn default() -> &'a UpdateInterfaceRequest {
-        <UpdateInterfaceRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ReadOutputResponse {
+    fn default() -> &'a ReadOutputResponse {
+        <ReadOutputResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl UpdateInterfaceRequest {
-    pub fn new() -> UpdateInterfaceRequest {
+impl ReadOutputResponse {
+    pub fn new() -> ReadOutputResponse {
         ::std::default::Default::default()
     }
 
-    // .types.Interface interface = 1;
+    // bytes data = 1;
 
 
-    pub fn get_interface(&self) -> &super::types::Interface {
-        self.interface.as_ref().unwrap_or_else(|| super::types::Interface::default_instance())
-    }
-    pub fn clear_interface(&mut self) {
-        self.interface.clear();
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
     }
-
-    pub fn has_interface(&self) -> bool {
-        self.interface.is_some()
+    pub fn clear_data(&mut self) {
+        self.data.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_interface(&mut self, v: super::types::Interface) {
-        self.interface = ::protobuf::SingularPtrField::some(v);
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_interface(&mut self) -> &mut super::types::Interface {
-        if self.interface.is_none() {
-            self.interface.set_default();
-        }
-        self.interface.as_mut().unwrap()
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
     }
 
     // Take field
-    pub fn take_interface(&mut self) -> super::types::Interface {
-        self.interface.take().unwrap_or_else(|| super::types::Interface::new())
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // uint64 offset = 2;
+
+
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: u64) {
+        self.offset = v;
+    }
+
+    // uint64 dropped = 3;
+
+
+    pub fn get_dropped(&self) -> u64 {
+        self.dropped
+    }
+    pub fn clear_dropped(&mut self) {
+        self.dropped = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dropped(&mut self, v: u64) {
+        self.dropped = v;
+    }
+
+    // bool eof = 4;
+
+
+    pub fn get_eof(&self) -> bool {
+        self.eof
+    }
+    pub fn clear_eof(&mut self) {
+        self.eof = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eof(&mut self, v: bool) {
+        self.eof = v;
     }
 }
 
-impl ::protobuf::Message for UpdateInterfaceRequest {
+impl ::protobuf::Message for ReadOutputResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.interface {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -8911,7 +9404,28 @@ impl ::protobuf::Message for UpdateInterfaceRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.interface)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.dropped = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.eof = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -8925,20 +9439,35 @@ impl ::protobuf::Message for UpdateInterfaceRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.interface.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.dropped != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.dropped, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.eof != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.interface.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(2, self.offset)?;
+        }
+        if self.dropped != 0 {
+            os.write_uint64(3, self.dropped)?;
+        }
+        if self.eof != false {
+            os.write_bool(4, self.eof)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -8970,8 +9499,8 @@ impl ::protobuf::Message for UpdateInterfaceRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> UpdateInterfaceRequest {
-        UpdateInterfaceRequest::new()
+    fn new() -> ReadOutputResponse {
+        ReadOutputResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -8982,13 +9511,28 @@ impl ::protobuf::Message for UpdateInterfaceRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Interface>>(
-                    "interface",
-                    |m: &UpdateInterfaceRequest| { &m.interface },
-                    |m: &mut UpdateInterfaceRequest| { &mut m.interface },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &ReadOutputResponse| { &m.data },
+                    |m: &mut ReadOutputResponse| { &mut m.data },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<UpdateInterfaceRequest>(
-                    "UpdateInterfaceRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "offset",
+                    |m: &ReadOutputResponse| { &m.offset },
+                    |m: &mut ReadOutputResponse| { &mut m.offset },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "dropped",
+                    |m: &ReadOutputResponse| { &m.dropped },
+                    |m: &mut ReadOutputResponse| { &mut m.dropped },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "eof",
+                    |m: &ReadOutputResponse| { &m.eof },
+                    |m: &mut ReadOutputResponse| { &mut m.eof },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReadOutputResponse>(
+                    "ReadOutputResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -8996,93 +9540,211 @@ impl ::protobuf::Message for UpdateInterfaceRequest {
         }
     }
 
-    fn default_instance() -> &'static UpdateInterfaceRequest {
-        static mut instance: ::protobuf::lazy::Lazy<UpdateInterfaceRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ReadOutputResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ReadOutputResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const UpdateInterfaceRequest,
+            ptr: 0 as *const ReadOutputResponse,
         };
         unsafe {
-            instance.get(UpdateInterfaceRequest::new)
+            instance.get(ReadOutputResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for UpdateInterfaceRequest {
+impl ::protobuf::Clear for ReadOutputResponse {
     fn clear(&mut self) {
-        self.interface.clear();
+        self.data.clear();
+        self.offset = 0;
+        self.dropped = 0;
+        self.eof = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for UpdateInterfaceRequest {
+impl ::std::fmt::Debug for ReadOutputResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UpdateInterfaceRequest {
+impl ::protobuf::reflect::ProtobufValue for ReadOutputResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct UpdateRoutesRequest {
+pub struct CreateSandboxRequest {
     // message fields
-    pub routes: ::protobuf::SingularPtrField<Routes>,
+    pub hostname: ::std::string::String,
+    pub dns: ::protobuf::RepeatedField<::std::string::String>,
+    pub storages: ::protobuf::RepeatedField<Storage>,
+    pub sandbox_pidns: bool,
+    pub sandbox_id: ::std::string::String,
+    pub guest_hook_path: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a UpdateRoutesRequest {
-    fn default() -> &'a UpdateRoutesRequest {
-        <UpdateRoutesRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CreateSandboxRequest {
+    fn default() -> &'a CreateSandboxRequest {
+        <CreateSandboxRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl UpdateRoutesRequest {
-    pub fn new() -> UpdateRoutesRequest {
+impl CreateSandboxRequest {
+    pub fn new() -> CreateSandboxRequest {
         ::std::default::Default::default()
     }
 
-    // .grpc.Routes routes = 1;
+    // string hostname = 1;
 
 
-    pub fn get_routes(&self) -> &Routes {
-        self.routes.as_ref().unwrap_or_else(|| Routes::default_instance())
+    pub fn get_hostname(&self) -> &str {
+        &self.hostname
     }
-    pub fn clear_routes(&mut self) {
-        self.routes.clear();
+    pub fn clear_hostname(&mut self) {
+        self.hostname.clear();
     }
 
-    pub fn has_routes(&self) -> bool {
-        self.routes.is_some()
+    // Param is passed by value, moved
+    pub fn set_hostname(&mut self, v: ::std::string::String) {
+        self.hostname = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
+        &mut self.hostname
+    }
+
+    // Take field
+    pub fn take_hostname(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.hostname, ::std::string::String::new())
+    }
+
+    // repeated string dns = 2;
+
+
+    pub fn get_dns(&self) -> &[::std::string::String] {
+        &self.dns
+    }
+    pub fn clear_dns(&mut self) {
+        self.dns.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_routes(&mut self, v: Routes) {
-        self.routes = ::protobuf::SingularPtrField::some(v);
+    pub fn set_dns(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.dns = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_dns(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.dns
+    }
+
+    // Take field
+    pub fn take_dns(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.dns, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .grpc.Storage storages = 3;
+
+
+    pub fn get_storages(&self) -> &[Storage] {
+        &self.storages
+    }
+    pub fn clear_storages(&mut self) {
+        self.storages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storages(&mut self, v: ::protobuf::RepeatedField<Storage>) {
+        self.storages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_storages(&mut self) -> &mut ::protobuf::RepeatedField<Storage> {
+        &mut self.storages
+    }
+
+    // Take field
+    pub fn take_storages(&mut self) -> ::protobuf::RepeatedField<Storage> {
+        ::std::mem::replace(&mut self.storages, ::protobuf::RepeatedField::new())
+    }
+
+    // bool sandbox_pidns = 4;
+
+
+    pub fn get_sandbox_pidns(&self) -> bool {
+        self.sandbox_pidns
+    }
+    pub fn clear_sandbox_pidns(&mut self) {
+        self.sandbox_pidns = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_pidns(&mut self, v: bool) {
+        self.sandbox_pidns = v;
+    }
+
+    // string sandbox_id = 5;
+
+
+    pub fn get_sandbox_id(&self) -> &str {
+        &self.sandbox_id
+    }
+    pub fn clear_sandbox_id(&mut self) {
+        self.sandbox_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sandbox_id(&mut self, v: ::std::string::String) {
+        self.sandbox_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_routes(&mut self) -> &mut Routes {
-        if self.routes.is_none() {
-            self.routes.set_default();
-        }
-        self.routes.as_mut().unwrap()
+    pub fn mut_sandbox_id(&mut self) -> &mut ::std::string::String {
+        &mut self.sandbox_id
     }
 
     // Take field
-    pub fn take_routes(&mut self) -> Routes {
-        self.routes.take().unwrap_or_else(|| Routes::new())
+    pub fn take_sandbox_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sandbox_id, ::std::string::String::new())
+    }
+
+    // string guest_hook_path = 6;
+
+
+    pub fn get_guest_hook_path(&self) -> &str {
+        &self.guest_hook_path
+    }
+    pub fn clear_guest_hook_path(&mut self) {
+        self.guest_hook_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_guest_hook_path(&mut self, v: ::std::string::String) {
+        self.guest_hook_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_guest_hook_path(&mut self) -> &mut ::std::string::String {
+        &mut self.guest_hook_path
+    }
+
+    // Take field
+    pub fn take_guest_hook_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.guest_hook_path, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for UpdateRoutesRequest {
+impl ::protobuf::Message for CreateSandboxRequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.routes {
+        for v in &self.storages {
             if !v.is_initialized() {
                 return false;
             }
@@ -9095,7 +9757,26 @@ impl ::protobuf::Message for UpdateRoutesRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.routes)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.hostname)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.dns)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.storages)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.sandbox_pidns = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sandbox_id)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.guest_hook_path)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -9109,9 +9790,24 @@ impl ::protobuf::Message for UpdateRoutesRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.routes.as_ref() {
-            let len = v.compute_size();
+        if !self.hostname.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hostname);
+        }
+        for value in &self.dns {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.storages {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.sandbox_pidns != false {
+            my_size += 2;
+        }
+        if !self.sandbox_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.sandbox_id);
+        }
+        if !self.guest_hook_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.guest_hook_path);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -9119,10 +9815,25 @@ impl ::protobuf::Message for UpdateRoutesRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.routes.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.hostname.is_empty() {
+            os.write_string(1, &self.hostname)?;
+        }
+        for v in &self.dns {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.storages {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.sandbox_pidns != false {
+            os.write_bool(4, self.sandbox_pidns)?;
+        }
+        if !self.sandbox_id.is_empty() {
+            os.write_string(5, &self.sandbox_id)?;
+        }
+        if !self.guest_hook_path.is_empty() {
+            os.write_string(6, &self.guest_hook_path)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -9154,8 +9865,8 @@ impl ::protobuf::Message for UpdateRoutesRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> UpdateRoutesRequest {
-        UpdateRoutesRequest::new()
+    fn new() -> CreateSandboxRequest {
+        CreateSandboxRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -9166,13 +9877,38 @@ impl ::protobuf::Message for UpdateRoutesRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Routes>>(
-                    "routes",
-                    |m: &UpdateRoutesRequest| { &m.routes },
-                    |m: &mut UpdateRoutesRequest| { &mut m.routes },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "hostname",
+                    |m: &CreateSandboxRequest| { &m.hostname },
+                    |m: &mut CreateSandboxRequest| { &mut m.hostname },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<UpdateRoutesRequest>(
-                    "UpdateRoutesRequest",
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "dns",
+                    |m: &CreateSandboxRequest| { &m.dns },
+                    |m: &mut CreateSandboxRequest| { &mut m.dns },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Storage>>(
+                    "storages",
+                    |m: &CreateSandboxRequest| { &m.storages },
+                    |m: &mut CreateSandboxRequest| { &mut m.storages },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "sandbox_pidns",
+                    |m: &CreateSandboxRequest| { &m.sandbox_pidns },
+                    |m: &mut CreateSandboxRequest| { &mut m.sandbox_pidns },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "sandbox_id",
+                    |m: &CreateSandboxRequest| { &m.sandbox_id },
+                    |m: &mut CreateSandboxRequest| { &mut m.sandbox_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "guest_hook_path",
+                    |m: &CreateSandboxRequest| { &m.guest_hook_path },
+                    |m: &mut CreateSandboxRequest| { &mut m.guest_hook_path },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CreateSandboxRequest>(
+                    "CreateSandboxRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -9180,56 +9916,61 @@ impl ::protobuf::Message for UpdateRoutesRequest {
         }
     }
 
-    fn default_instance() -> &'static UpdateRoutesRequest {
-        static mut instance: ::protobuf::lazy::Lazy<UpdateRoutesRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static CreateSandboxRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CreateSandboxRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const UpdateRoutesRequest,
+            ptr: 0 as *const CreateSandboxRequest,
         };
         unsafe {
-            instance.get(UpdateRoutesRequest::new)
+            instance.get(CreateSandboxRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for UpdateRoutesRequest {
+impl ::protobuf::Clear for CreateSandboxRequest {
     fn clear(&mut self) {
-        self.routes.clear();
+        self.hostname.clear();
+        self.dns.clear();
+        self.storages.clear();
+        self.sandbox_pidns = false;
+        self.sandbox_id.clear();
+        self.guest_hook_path.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for UpdateRoutesRequest {
+impl ::std::fmt::Debug for CreateSandboxRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UpdateRoutesRequest {
+impl ::protobuf::reflect::ProtobufValue for CreateSandboxRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ListInterfacesRequest {
+pub struct DestroySandboxRequest {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ListInterfacesRequest {
-    fn default() -> &'a ListInterfacesRequest {
-        <ListInterfacesRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DestroySandboxRequest {
+    fn default() -> &'a DestroySandboxRequest {
+        <DestroySandboxRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ListInterfacesRequest {
-    pub fn new() -> ListInterfacesRequest {
+impl DestroySandboxRequest {
+    pub fn new() -> DestroySandboxRequest {
         ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for ListInterfacesRequest {
+impl ::protobuf::Message for DestroySandboxRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -9286,8 +10027,8 @@ impl ::protobuf::Message for ListInterfacesRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> ListInterfacesRequest {
-        ListInterfacesRequest::new()
+    fn new() -> DestroySandboxRequest {
+        DestroySandboxRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -9298,8 +10039,8 @@ impl ::protobuf::Message for ListInterfacesRequest {
         unsafe {
             descriptor.get(|| {
                 let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<ListInterfacesRequest>(
-                    "ListInterfacesRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<DestroySandboxRequest>(
+                    "DestroySandboxRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -9307,56 +10048,88 @@ impl ::protobuf::Message for ListInterfacesRequest {
         }
     }
 
-    fn default_instance() -> &'static ListInterfacesRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ListInterfacesRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static DestroySandboxRequest {
+        static mut instance: ::protobuf::lazy::Lazy<DestroySandboxRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ListInterfacesRequest,
+            ptr: 0 as *const DestroySandboxRequest,
         };
         unsafe {
-            instance.get(ListInterfacesRequest::new)
+            instance.get(DestroySandboxRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ListInterfacesRequest {
+impl ::protobuf::Clear for DestroySandboxRequest {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ListInterfacesRequest {
+impl ::std::fmt::Debug for DestroySandboxRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ListInterfacesRequest {
+impl ::protobuf::reflect::ProtobufValue for DestroySandboxRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ListRoutesRequest {
+pub struct Interfaces {
+    // message fields
+    pub Interfaces: ::protobuf::RepeatedField<super::types::Interface>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ListRoutesRequest {
-    fn default() -> &'a ListRoutesRequest {
-        <ListRoutesRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Interfaces {
+    fn default() -> &'a Interfaces {
+        <Interfaces as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ListRoutesRequest {
-    pub fn new() -> ListRoutesRequest {
+impl Interfaces {
+    pub fn new() -> Interfaces {
         ::std::default::Default::default()
     }
+
+    // repeated .types.Interface Interfaces = 1;
+
+
+    pub fn get_Interfaces(&self) -> &[super::types::Interface] {
+        &self.Interfaces
+    }
+    pub fn clear_Interfaces(&mut self) {
+        self.Interfaces.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_Interfaces(&mut self, v: ::protobuf::RepeatedField<super::types::Interface>) {
+        self.Interfaces = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_Interfaces(&mut self) -> &mut ::protobuf::RepeatedField<super::types::Interface> {
+        &mut self.Interfaces
+    }
+
+    // Take field
+    pub fn take_Interfaces(&mut self) -> ::protobuf::RepeatedField<super::types::Interface> {
+        ::std::mem::replace(&mut self.Interfaces, ::protobuf::RepeatedField::new())
+    }
 }
 
-impl ::protobuf::Message for ListRoutesRequest {
+impl ::protobuf::Message for Interfaces {
     fn is_initialized(&self) -> bool {
+        for v in &self.Interfaces {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -9364,6 +10137,9 @@ impl ::protobuf::Message for ListRoutesRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.Interfaces)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -9376,12 +10152,21 @@ impl ::protobuf::Message for ListRoutesRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        for value in &self.Interfaces {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.Interfaces {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -9412,8 +10197,8 @@ impl ::protobuf::Message for ListRoutesRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> ListRoutesRequest {
-        ListRoutesRequest::new()
+    fn new() -> Interfaces {
+        Interfaces::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -9423,9 +10208,14 @@ impl ::protobuf::Message for ListRoutesRequest {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<ListRoutesRequest>(
-                    "ListRoutesRequest",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Interface>>(
+                    "Interfaces",
+                    |m: &Interfaces| { &m.Interfaces },
+                    |m: &mut Interfaces| { &mut m.Interfaces },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Interfaces>(
+                    "Interfaces",
                     fields,
                     file_descriptor_proto()
                 )
@@ -9433,105 +10223,89 @@ impl ::protobuf::Message for ListRoutesRequest {
         }
     }
 
-    fn default_instance() -> &'static ListRoutesRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ListRoutesRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Interfaces {
+        static mut instance: ::protobuf::lazy::Lazy<Interfaces> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ListRoutesRequest,
+            ptr: 0 as *const Interfaces,
         };
         unsafe {
-            instance.get(ListRoutesRequest::new)
+            instance.get(Interfaces::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ListRoutesRequest {
+impl ::protobuf::Clear for Interfaces {
     fn clear(&mut self) {
+        self.Interfaces.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ListRoutesRequest {
+impl ::std::fmt::Debug for Interfaces {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ListRoutesRequest {
+impl ::protobuf::reflect::ProtobufValue for Interfaces {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OnlineCPUMemRequest {
+pub struct Routes {
     // message fields
-    pub wait: bool,
-    pub nb_cpus: u32,
-    pub cpu_only: bool,
+    pub Routes: ::protobuf::RepeatedField<super::types::Route>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a OnlineCPUMemRequest {
-    fn default() -> &'a OnlineCPUMemRequest {
-        <OnlineCPUMemRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Routes {
+    fn default() -> &'a Routes {
+        <Routes as ::protobuf::Message>::default_instance()
     }
 }
 
-impl OnlineCPUMemRequest {
-    pub fn new() -> OnlineCPUMemRequest {
+impl Routes {
+    pub fn new() -> Routes {
         ::std::default::Default::default()
     }
 
-    // bool wait = 1;
+    // repeated .types.Route Routes = 1;
 
 
-    pub fn get_wait(&self) -> bool {
-        self.wait
+    pub fn get_Routes(&self) -> &[super::types::Route] {
+        &self.Routes
     }
-    pub fn clear_wait(&mut self) {
-        self.wait = false;
+    pub fn clear_Routes(&mut self) {
+        self.Routes.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_wait(&mut self, v: bool) {
-        self.wait = v;
+    pub fn set_Routes(&mut self, v: ::protobuf::RepeatedField<super::types::Route>) {
+        self.Routes = v;
     }
 
-    // uint32 nb_cpus = 2;
-
-
-    pub fn get_nb_cpus(&self) -> u32 {
-        self.nb_cpus
-    }
-    pub fn clear_nb_cpus(&mut self) {
-        self.nb_cpus = 0;
+    // Mutable pointer to the field.
+    pub fn mut_Routes(&mut self) -> &mut ::protobuf::RepeatedField<super::types::Route> {
+        &mut self.Routes
     }
 
-    // Param is passed by value, moved
-    pub fn set_nb_cpus(&mut self, v: u32) {
-        self.nb_cpus = v;
+    // Take field
+    pub fn take_Routes(&mut self) -> ::protobuf::RepeatedField<super::types::Route> {
+        ::std::mem::replace(&mut self.Routes, ::protobuf::RepeatedField::new())
     }
+}
 
-    // bool cpu_only = 3;
-
-
-    pub fn get_cpu_only(&self) -> bool {
-        self.cpu_only
-    }
-    pub fn clear_cpu_only(&mut self) {
-        self.cpu_only = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_cpu_only(&mut self, v: bool) {
-        self.cpu_only = v;
-    }
-}
-
-impl ::protobuf::Message for OnlineCPUMemRequest {
+impl ::protobuf::Message for Routes {
     fn is_initialized(&self) -> bool {
+        for v in &self.Routes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -9540,25 +10314,7 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.wait = tmp;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.nb_cpus = tmp;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.cpu_only = tmp;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.Routes)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -9572,30 +10328,21 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.wait != false {
-            my_size += 2;
-        }
-        if self.nb_cpus != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.nb_cpus, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.cpu_only != false {
-            my_size += 2;
-        }
+        for value in &self.Routes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.wait != false {
-            os.write_bool(1, self.wait)?;
-        }
-        if self.nb_cpus != 0 {
-            os.write_uint32(2, self.nb_cpus)?;
-        }
-        if self.cpu_only != false {
-            os.write_bool(3, self.cpu_only)?;
-        }
+        for v in &self.Routes {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -9626,8 +10373,8 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> OnlineCPUMemRequest {
-        OnlineCPUMemRequest::new()
+    fn new() -> Routes {
+        Routes::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -9638,23 +10385,13 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "wait",
-                    |m: &OnlineCPUMemRequest| { &m.wait },
-                    |m: &mut OnlineCPUMemRequest| { &mut m.wait },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "nb_cpus",
-                    |m: &OnlineCPUMemRequest| { &m.nb_cpus },
-                    |m: &mut OnlineCPUMemRequest| { &mut m.nb_cpus },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "cpu_only",
-                    |m: &OnlineCPUMemRequest| { &m.cpu_only },
-                    |m: &mut OnlineCPUMemRequest| { &mut m.cpu_only },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Route>>(
+                    "Routes",
+                    |m: &Routes| { &m.Routes },
+                    |m: &mut Routes| { &mut m.Routes },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OnlineCPUMemRequest>(
-                    "OnlineCPUMemRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<Routes>(
+                    "Routes",
                     fields,
                     file_descriptor_proto()
                 )
@@ -9662,87 +10399,97 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
         }
     }
 
-    fn default_instance() -> &'static OnlineCPUMemRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OnlineCPUMemRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Routes {
+        static mut instance: ::protobuf::lazy::Lazy<Routes> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OnlineCPUMemRequest,
+            ptr: 0 as *const Routes,
         };
         unsafe {
-            instance.get(OnlineCPUMemRequest::new)
+            instance.get(Routes::new)
         }
     }
 }
 
-impl ::protobuf::Clear for OnlineCPUMemRequest {
+impl ::protobuf::Clear for Routes {
     fn clear(&mut self) {
-        self.wait = false;
-        self.nb_cpus = 0;
-        self.cpu_only = false;
+        self.Routes.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OnlineCPUMemRequest {
+impl ::std::fmt::Debug for Routes {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OnlineCPUMemRequest {
+impl ::protobuf::reflect::ProtobufValue for Routes {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ReseedRandomDevRequest {
+pub struct UpdateInterfaceRequest {
     // message fields
-    pub data: ::std::vec::Vec<u8>,
+    pub interface: ::protobuf::SingularPtrField<super::types::Interface>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ReseedRandomDevRequest {
-    fn default() -> &'a ReseedRandomDevRequest {
-        <ReseedRandomDevRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UpdateInterfaceRequest {
+    fn default() -> &'a UpdateInterfaceRequest {
+        <UpdateInterfaceRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ReseedRandomDevRequest {
-    pub fn new() -> ReseedRandomDevRequest {
+impl UpdateInterfaceRequest {
+    pub fn new() -> UpdateInterfaceRequest {
         ::std::default::Default::default()
     }
 
-    // bytes data = 2;
+    // .types.Interface interface = 1;
 
 
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
+    pub fn get_interface(&self) -> &super::types::Interface {
+        self.interface.as_ref().unwrap_or_else(|| super::types::Interface::default_instance())
     }
-    pub fn clear_data(&mut self) {
-        self.data.clear();
+    pub fn clear_interface(&mut self) {
+        self.interface.clear();
+    }
+
+    pub fn has_interface(&self) -> bool {
+        self.interface.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
-        self.data = v;
+    pub fn set_interface(&mut self, v: super::types::Interface) {
+        self.interface = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.data
+    pub fn mut_interface(&mut self) -> &mut super::types::Interface {
+        if self.interface.is_none() {
+            self.interface.set_default();
+        }
+        self.interface.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    pub fn take_interface(&mut self) -> super::types::Interface {
+        self.interface.take().unwrap_or_else(|| super::types::Interface::new())
     }
 }
 
-impl ::protobuf::Message for ReseedRandomDevRequest {
+impl ::protobuf::Message for UpdateInterfaceRequest {
     fn is_initialized(&self) -> bool {
+        for v in &self.interface {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -9750,8 +10497,8 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.interface)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -9765,8 +10512,9 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.data.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.data);
+        if let Some(ref v) = self.interface.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -9774,8 +10522,10 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.data.is_empty() {
-            os.write_bytes(2, &self.data)?;
+        if let Some(ref v) = self.interface.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -9807,8 +10557,8 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> ReseedRandomDevRequest {
-        ReseedRandomDevRequest::new()
+    fn new() -> UpdateInterfaceRequest {
+        UpdateInterfaceRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -9819,13 +10569,13 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data",
-                    |m: &ReseedRandomDevRequest| { &m.data },
-                    |m: &mut ReseedRandomDevRequest| { &mut m.data },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Interface>>(
+                    "interface",
+                    |m: &UpdateInterfaceRequest| { &m.interface },
+                    |m: &mut UpdateInterfaceRequest| { &mut m.interface },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ReseedRandomDevRequest>(
-                    "ReseedRandomDevRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateInterfaceRequest>(
+                    "UpdateInterfaceRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -9833,168 +10583,3861 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         }
     }
 
-    fn default_instance() -> &'static ReseedRandomDevRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ReseedRandomDevRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static UpdateInterfaceRequest {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateInterfaceRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ReseedRandomDevRequest,
+            ptr: 0 as *const UpdateInterfaceRequest,
         };
         unsafe {
-            instance.get(ReseedRandomDevRequest::new)
+            instance.get(UpdateInterfaceRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ReseedRandomDevRequest {
+impl ::protobuf::Clear for UpdateInterfaceRequest {
     fn clear(&mut self) {
-        self.data.clear();
+        self.interface.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ReseedRandomDevRequest {
+impl ::std::fmt::Debug for UpdateInterfaceRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ReseedRandomDevRequest {
+impl ::protobuf::reflect::ProtobufValue for UpdateInterfaceRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AgentDetails {
+pub struct UpdateRoutesRequest {
     // message fields
-    pub version: ::std::string::String,
-    pub init_daemon: bool,
-    pub device_handlers: ::protobuf::RepeatedField<::std::string::String>,
-    pub storage_handlers: ::protobuf::RepeatedField<::std::string::String>,
-    pub supports_seccomp: bool,
+    pub routes: ::protobuf::SingularPtrField<Routes>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a AgentDetails {
-    fn default() -> &'a AgentDetails {
-        <AgentDetails as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UpdateRoutesRequest {
+    fn default() -> &'a UpdateRoutesRequest {
+        <UpdateRoutesRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AgentDetails {
-    pub fn new() -> AgentDetails {
+impl UpdateRoutesRequest {
+    pub fn new() -> UpdateRoutesRequest {
         ::std::default::Default::default()
     }
 
-    // string version = 1;
+    // .grpc.Routes routes = 1;
 
 
-    pub fn get_version(&self) -> &str {
-        &self.version
+    pub fn get_routes(&self) -> &Routes {
+        self.routes.as_ref().unwrap_or_else(|| Routes::default_instance())
     }
-    pub fn clear_version(&mut self) {
-        self.version.clear();
+    pub fn clear_routes(&mut self) {
+        self.routes.clear();
+    }
+
+    pub fn has_routes(&self) -> bool {
+        self.routes.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_version(&mut self, v: ::std::string::String) {
-        self.version = v;
+    pub fn set_routes(&mut self, v: Routes) {
+        self.routes = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_version(&mut self) -> &mut ::std::string::String {
-        &mut self.version
+    pub fn mut_routes(&mut self) -> &mut Routes {
+        if self.routes.is_none() {
+            self.routes.set_default();
+        }
+        self.routes.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_version(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    pub fn take_routes(&mut self) -> Routes {
+        self.routes.take().unwrap_or_else(|| Routes::new())
     }
+}
 
-    // bool init_daemon = 2;
-
+impl ::protobuf::Message for UpdateRoutesRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.routes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.routes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.routes.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.routes.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateRoutesRequest {
+        UpdateRoutesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Routes>>(
+                    "routes",
+                    |m: &UpdateRoutesRequest| { &m.routes },
+                    |m: &mut UpdateRoutesRequest| { &mut m.routes },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateRoutesRequest>(
+                    "UpdateRoutesRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static UpdateRoutesRequest {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateRoutesRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const UpdateRoutesRequest,
+        };
+        unsafe {
+            instance.get(UpdateRoutesRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for UpdateRoutesRequest {
+    fn clear(&mut self) {
+        self.routes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateRoutesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateRoutesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListInterfacesRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListInterfacesRequest {
+    fn default() -> &'a ListInterfacesRequest {
+        <ListInterfacesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListInterfacesRequest {
+    pub fn new() -> ListInterfacesRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for ListInterfacesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListInterfacesRequest {
+        ListInterfacesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<ListInterfacesRequest>(
+                    "ListInterfacesRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ListInterfacesRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ListInterfacesRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ListInterfacesRequest,
+        };
+        unsafe {
+            instance.get(ListInterfacesRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ListInterfacesRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListInterfacesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListInterfacesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ListRoutesRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ListRoutesRequest {
+    fn default() -> &'a ListRoutesRequest {
+        <ListRoutesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ListRoutesRequest {
+    pub fn new() -> ListRoutesRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for ListRoutesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ListRoutesRequest {
+        ListRoutesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<ListRoutesRequest>(
+                    "ListRoutesRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ListRoutesRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ListRoutesRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ListRoutesRequest,
+        };
+        unsafe {
+            instance.get(ListRoutesRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ListRoutesRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ListRoutesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ListRoutesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UpdateDNSRequest {
+    // message fields
+    pub dns: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateDNSRequest {
+    fn default() -> &'a UpdateDNSRequest {
+        <UpdateDNSRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateDNSRequest {
+    pub fn new() -> UpdateDNSRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string dns = 1;
+
+
+    pub fn get_dns(&self) -> &[::std::string::String] {
+        &self.dns
+    }
+    pub fn clear_dns(&mut self) {
+        self.dns.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dns(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.dns = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_dns(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.dns
+    }
+
+    // Take field
+    pub fn take_dns(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.dns, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UpdateDNSRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.dns)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.dns {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.dns {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateDNSRequest {
+        UpdateDNSRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "dns",
+                    |m: &UpdateDNSRequest| { &m.dns },
+                    |m: &mut UpdateDNSRequest| { &mut m.dns },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateDNSRequest>(
+                    "UpdateDNSRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static UpdateDNSRequest {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateDNSRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const UpdateDNSRequest,
+        };
+        unsafe {
+            instance.get(UpdateDNSRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for UpdateDNSRequest {
+    fn clear(&mut self) {
+        self.dns.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateDNSRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateDNSRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OnlineCPUMemRequest {
+    // message fields
+    pub wait: bool,
+    pub nb_cpus: u32,
+    pub cpu_only: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OnlineCPUMemRequest {
+    fn default() -> &'a OnlineCPUMemRequest {
+        <OnlineCPUMemRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OnlineCPUMemRequest {
+    pub fn new() -> OnlineCPUMemRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool wait = 1;
+
+
+    pub fn get_wait(&self) -> bool {
+        self.wait
+    }
+    pub fn clear_wait(&mut self) {
+        self.wait = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_wait(&mut self, v: bool) {
+        self.wait = v;
+    }
+
+    // uint32 nb_cpus = 2;
+
+
+    pub fn get_nb_cpus(&self) -> u32 {
+        self.nb_cpus
+    }
+    pub fn clear_nb_cpus(&mut self) {
+        self.nb_cpus = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nb_cpus(&mut self, v: u32) {
+        self.nb_cpus = v;
+    }
+
+    // bool cpu_only = 3;
+
+
+    pub fn get_cpu_only(&self) -> bool {
+        self.cpu_only
+    }
+    pub fn clear_cpu_only(&mut self) {
+        self.cpu_only = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_only(&mut self, v: bool) {
+        self.cpu_only = v;
+    }
+}
+
+impl ::protobuf::Message for OnlineCPUMemRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.wait = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.nb_cpus = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.cpu_only = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.wait != false {
+            my_size += 2;
+        }
+        if self.nb_cpus != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.nb_cpus, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_only != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.wait != false {
+            os.write_bool(1, self.wait)?;
+        }
+        if self.nb_cpus != 0 {
+            os.write_uint32(2, self.nb_cpus)?;
+        }
+        if self.cpu_only != false {
+            os.write_bool(3, self.cpu_only)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> OnlineCPUMemRequest {
+        OnlineCPUMemRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "wait",
+                    |m: &OnlineCPUMemRequest| { &m.wait },
+                    |m: &mut OnlineCPUMemRequest| { &mut m.wait },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "nb_cpus",
+                    |m: &OnlineCPUMemRequest| { &m.nb_cpus },
+                    |m: &mut OnlineCPUMemRequest| { &mut m.nb_cpus },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "cpu_only",
+                    |m: &OnlineCPUMemRequest| { &m.cpu_only },
+                    |m: &mut OnlineCPUMemRequest| { &mut m.cpu_only },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OnlineCPUMemRequest>(
+                    "OnlineCPUMemRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static OnlineCPUMemRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OnlineCPUMemRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OnlineCPUMemRequest,
+        };
+        unsafe {
+            instance.get(OnlineCPUMemRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for OnlineCPUMemRequest {
+    fn clear(&mut self) {
+        self.wait = false;
+        self.nb_cpus = 0;
+        self.cpu_only = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OnlineCPUMemRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OnlineCPUMemRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReseedRandomDevRequest {
+    // message fields
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReseedRandomDevRequest {
+    fn default() -> &'a ReseedRandomDevRequest {
+        <ReseedRandomDevRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReseedRandomDevRequest {
+    pub fn new() -> ReseedRandomDevRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes data = 2;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for ReseedRandomDevRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_bytes(2, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReseedRandomDevRequest {
+        ReseedRandomDevRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &ReseedRandomDevRequest| { &m.data },
+                    |m: &mut ReseedRandomDevRequest| { &mut m.data },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReseedRandomDevRequest>(
+                    "ReseedRandomDevRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ReseedRandomDevRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ReseedRandomDevRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ReseedRandomDevRequest,
+        };
+        unsafe {
+            instance.get(ReseedRandomDevRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ReseedRandomDevRequest {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReseedRandomDevRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReseedRandomDevRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AgentDetails {
+    // message fields
+    pub version: ::std::string::String,
+    pub init_daemon: bool,
+    pub device_handlers: ::protobuf::RepeatedField<::std::string::String>,
+    pub storage_handlers: ::protobuf::RepeatedField<::std::string::String>,
+    pub supports_seccomp: bool,
+    pub kernel_seccomp: bool,
+    pub cgroup_version: ::std::string::String,
+    pub cgroup_controllers: ::protobuf::RepeatedField<::std::string::String>,
+    pub transport: ::std::string::String,
+    pub kernel_version: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AgentDetails {
+    fn default() -> &'a AgentDetails {
+        <AgentDetails as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AgentDetails {
+    pub fn new() -> AgentDetails {
+        ::std::default::Default::default()
+    }
+
+    // string version = 1;
+
+
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    // bool init_daemon = 2;
+
+
+    pub fn get_init_daemon(&self) -> bool {
+        self.init_daemon
+    }
+    pub fn clear_init_daemon(&mut self) {
+        self.init_daemon = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_init_daemon(&mut self, v: bool) {
+        self.init_daemon = v;
+    }
+
+    // repeated string device_handlers = 3;
+
+
+    pub fn get_device_handlers(&self) -> &[::std::string::String] {
+        &self.device_handlers
+    }
+    pub fn clear_device_handlers(&mut self) {
+        self.device_handlers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.device_handlers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_device_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.device_handlers
+    }
+
+    // Take field
+    pub fn take_device_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.device_handlers, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string storage_handlers = 4;
+
+
+    pub fn get_storage_handlers(&self) -> &[::std::string::String] {
+        &self.storage_handlers
+    }
+    pub fn clear_storage_handlers(&mut self) {
+        self.storage_handlers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storage_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.storage_handlers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_storage_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.storage_handlers
+    }
+
+    // Take field
+    pub fn take_storage_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.storage_handlers, ::protobuf::RepeatedField::new())
+    }
+
+    // bool supports_seccomp = 5;
+
+
+    pub fn get_supports_seccomp(&self) -> bool {
+        self.supports_seccomp
+    }
+    pub fn clear_supports_seccomp(&mut self) {
+        self.supports_seccomp = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_supports_seccomp(&mut self, v: bool) {
+        self.supports_seccomp = v;
+    }
+
+    // bool kernel_seccomp = 6;
+
+
+    pub fn get_kernel_seccomp(&self) -> bool {
+        self.kernel_seccomp
+    }
+    pub fn clear_kernel_seccomp(&mut self) {
+        self.kernel_seccomp = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kernel_seccomp(&mut self, v: bool) {
+        self.kernel_seccomp = v;
+    }
+
+    // string cgroup_version = 7;
+
+
+    pub fn get_cgroup_version(&self) -> &str {
+        &self.cgroup_version
+    }
+    pub fn clear_cgroup_version(&mut self) {
+        self.cgroup_version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cgroup_version(&mut self, v: ::std::string::String) {
+        self.cgroup_version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cgroup_version(&mut self) -> &mut ::std::string::String {
+        &mut self.cgroup_version
+    }
+
+    // Take field
+    pub fn take_cgroup_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.cgroup_version, ::std::string::String::new())
+    }
+
+    // repeated string cgroup_controllers = 8;
+
+
+    pub fn get_cgroup_controllers(&self) -> &[::std::string::String] {
+        &self.cgroup_controllers
+    }
+    pub fn clear_cgroup_controllers(&mut self) {
+        self.cgroup_controllers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cgroup_controllers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.cgroup_controllers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cgroup_controllers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.cgroup_controllers
+    }
+
+    // Take field
+    pub fn take_cgroup_controllers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.cgroup_controllers, ::protobuf::RepeatedField::new())
+    }
+
+    // string transport = 9;
+
+
+    pub fn get_transport(&self) -> &str {
+        &self.transport
+    }
+    pub fn clear_transport(&mut self) {
+        self.transport.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transport(&mut self, v: ::std::string::String) {
+        self.transport = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_transport(&mut self) -> &mut ::std::string::String {
+        &mut self.transport
+    }
+
+    // Take field
+    pub fn take_transport(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.transport, ::std::string::String::new())
+    }
+
+    // string kernel_version = 10;
+
+
+    pub fn get_kernel_version(&self) -> &str {
+        &self.kernel_version
+    }
+    pub fn clear_kernel_version(&mut self) {
+        self.kernel_version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kernel_version(&mut self, v: ::std::string::String) {
+        self.kernel_version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kernel_version(&mut self) -> &mut ::std::string::String {
+        &mut self.kernel_version
+    }
+
+    // Take field
+    pub fn take_kernel_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kernel_version, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AgentDetails {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.init_daemon = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.device_handlers)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.storage_handlers)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.supports_seccomp = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.kernel_seccomp = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.cgroup_version)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.cgroup_controllers)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.transport)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kernel_version)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.version);
+        }
+        if self.init_daemon != false {
+            my_size += 2;
+        }
+        for value in &self.device_handlers {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.storage_handlers {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if self.supports_seccomp != false {
+            my_size += 2;
+        }
+        if self.kernel_seccomp != false {
+            my_size += 2;
+        }
+        if !self.cgroup_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.cgroup_version);
+        }
+        for value in &self.cgroup_controllers {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        if !self.transport.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.transport);
+        }
+        if !self.kernel_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.kernel_version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.version.is_empty() {
+            os.write_string(1, &self.version)?;
+        }
+        if self.init_daemon != false {
+            os.write_bool(2, self.init_daemon)?;
+        }
+        for v in &self.device_handlers {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.storage_handlers {
+            os.write_string(4, &v)?;
+        };
+        if self.supports_seccomp != false {
+            os.write_bool(5, self.supports_seccomp)?;
+        }
+        if self.kernel_seccomp != false {
+            os.write_bool(6, self.kernel_seccomp)?;
+        }
+        if !self.cgroup_version.is_empty() {
+            os.write_string(7, &self.cgroup_version)?;
+        }
+        for v in &self.cgroup_controllers {
+            os.write_string(8, &v)?;
+        };
+        if !self.transport.is_empty() {
+            os.write_string(9, &self.transport)?;
+        }
+        if !self.kernel_version.is_empty() {
+            os.write_string(10, &self.kernel_version)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AgentDetails {
+        AgentDetails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "version",
+                    |m: &AgentDetails| { &m.version },
+                    |m: &mut AgentDetails| { &mut m.version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "init_daemon",
+                    |m: &AgentDetails| { &m.init_daemon },
+                    |m: &mut AgentDetails| { &mut m.init_daemon },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device_handlers",
+                    |m: &AgentDetails| { &m.device_handlers },
+                    |m: &mut AgentDetails| { &mut m.device_handlers },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "storage_handlers",
+                    |m: &AgentDetails| { &m.storage_handlers },
+                    |m: &mut AgentDetails| { &mut m.storage_handlers },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "supports_seccomp",
+                    |m: &AgentDetails| { &m.supports_seccomp },
+                    |m: &mut AgentDetails| { &mut m.supports_seccomp },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "kernel_seccomp",
+                    |m: &AgentDetails| { &m.kernel_seccomp },
+                    |m: &mut AgentDetails| { &mut m.kernel_seccomp },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "cgroup_version",
+                    |m: &AgentDetails| { &m.cgroup_version },
+                    |m: &mut AgentDetails| { &mut m.cgroup_version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "cgroup_controllers",
+                    |m: &AgentDetails| { &m.cgroup_controllers },
+                    |m: &mut AgentDetails| { &mut m.cgroup_controllers },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "transport",
+                    |m: &AgentDetails| { &m.transport },
+                    |m: &mut AgentDetails| { &mut m.transport },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "kernel_version",
+                    |m: &AgentDetails| { &m.kernel_version },
+                    |m: &mut AgentDetails| { &mut m.kernel_version },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AgentDetails>(
+                    "AgentDetails",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AgentDetails {
+        static mut instance: ::protobuf::lazy::Lazy<AgentDetails> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AgentDetails,
+        };
+        unsafe {
+            instance.get(AgentDetails::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AgentDetails {
+    fn clear(&mut self) {
+        self.version.clear();
+        self.init_daemon = false;
+        self.device_handlers.clear();
+        self.storage_handlers.clear();
+        self.supports_seccomp = false;
+        self.kernel_seccomp = false;
+        self.cgroup_version.clear();
+        self.cgroup_controllers.clear();
+        self.transport.clear();
+        self.kernel_version.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AgentDetails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AgentDetails {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GuestDetailsRequest {
+    // message fields
+    pub mem_block_size: bool,
+    pub mem_hotplug_probe: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GuestDetailsRequest {
+    fn default() -> &'a GuestDetailsRequest {
+        <GuestDetailsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GuestDetailsRequest {
+    pub fn new() -> GuestDetailsRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool mem_block_size = 1;
+
+
+    pub fn get_mem_block_size(&self) -> bool {
+        self.mem_block_size
+    }
+    pub fn clear_mem_block_size(&mut self) {
+        self.mem_block_size = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mem_block_size(&mut self, v: bool) {
+        self.mem_block_size = v;
+    }
+
+    // bool mem_hotplug_probe = 2;
+
+
+    pub fn get_mem_hotplug_probe(&self) -> bool {
+        self.mem_hotplug_probe
+    }
+    pub fn clear_mem_hotplug_probe(&mut self) {
+        self.mem_hotplug_probe = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mem_hotplug_probe(&mut self, v: bool) {
+        self.mem_hotplug_probe = v;
+    }
+}
+
+impl ::protobuf::Message for GuestDetailsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.mem_block_size = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.mem_hotplug_probe = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.mem_block_size != false {
+            my_size += 2;
+        }
+        if self.mem_hotplug_probe != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.mem_block_size != false {
+            os.write_bool(1, self.mem_block_size)?;
+        }
+        if self.mem_hotplug_probe != false {
+            os.write_bool(2, self.mem_hotplug_probe)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GuestDetailsRequest {
+        GuestDetailsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "mem_block_size",
+                    |m: &GuestDetailsRequest| { &m.mem_block_size },
+                    |m: &mut GuestDetailsRequest| { &mut m.mem_block_size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "mem_hotplug_probe",
+                    |m: &GuestDetailsRequest| { &m.mem_hotplug_probe },
+                    |m: &mut GuestDetailsRequest| { &mut m.mem_hotplug_probe },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsRequest>(
+                    "GuestDetailsRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GuestDetailsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GuestDetailsRequest,
+        };
+        unsafe {
+            instance.get(GuestDetailsRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GuestDetailsRequest {
+    fn clear(&mut self) {
+        self.mem_block_size = false;
+        self.mem_hotplug_probe = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GuestDetailsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GuestDetailsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GuestDetailsResponse {
+    // message fields
+    pub mem_block_size_bytes: u64,
+    pub agent_details: ::protobuf::SingularPtrField<AgentDetails>,
+    pub support_mem_hotplug_probe: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GuestDetailsResponse {
+    fn default() -> &'a GuestDetailsResponse {
+        <GuestDetailsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GuestDetailsResponse {
+    pub fn new() -> GuestDetailsResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 mem_block_size_bytes = 1;
+
+
+    pub fn get_mem_block_size_bytes(&self) -> u64 {
+        self.mem_block_size_bytes
+    }
+    pub fn clear_mem_block_size_bytes(&mut self) {
+        self.mem_block_size_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mem_block_size_bytes(&mut self, v: u64) {
+        self.mem_block_size_bytes = v;
+    }
+
+    // .grpc.AgentDetails agent_details = 2;
+
+
+    pub fn get_agent_details(&self) -> &AgentDetails {
+        self.agent_details.as_ref().unwrap_or_else(|| AgentDetails::default_instance())
+    }
+    pub fn clear_agent_details(&mut self) {
+        self.agent_details.clear();
+    }
+
+    pub fn has_agent_details(&self) -> bool {
+        self.agent_details.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_agent_details(&mut self, v: AgentDetails) {
+        self.agent_details = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_agent_details(&mut self) -> &mut AgentDetails {
+        if self.agent_details.is_none() {
+            self.agent_details.set_default();
+        }
+        self.agent_details.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_agent_details(&mut self) -> AgentDetails {
+        self.agent_details.take().unwrap_or_else(|| AgentDetails::new())
+    }
+
+    // bool support_mem_hotplug_probe = 3;
+
+
+    pub fn get_support_mem_hotplug_probe(&self) -> bool {
+        self.support_mem_hotplug_probe
+    }
+    pub fn clear_support_mem_hotplug_probe(&mut self) {
+        self.support_mem_hotplug_probe = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_support_mem_hotplug_probe(&mut self, v: bool) {
+        self.support_mem_hotplug_probe = v;
+    }
+}
+
+impl ::protobuf::Message for GuestDetailsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.agent_details {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.mem_block_size_bytes = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.agent_details)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.support_mem_hotplug_probe = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.mem_block_size_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.mem_block_size_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.agent_details.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.support_mem_hotplug_probe != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.mem_block_size_bytes != 0 {
+            os.write_uint64(1, self.mem_block_size_bytes)?;
+        }
+        if let Some(ref v) = self.agent_details.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.support_mem_hotplug_probe != false {
+            os.write_bool(3, self.support_mem_hotplug_probe)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GuestDetailsResponse {
+        GuestDetailsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "mem_block_size_bytes",
+                    |m: &GuestDetailsResponse| { &m.mem_block_size_bytes },
+                    |m: &mut GuestDetailsResponse| { &mut m.mem_block_size_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AgentDetails>>(
+                    "agent_details",
+                    |m: &GuestDetailsResponse| { &m.agent_details },
+                    |m: &mut GuestDetailsResponse| { &mut m.agent_details },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "support_mem_hotplug_probe",
+                    |m: &GuestDetailsResponse| { &m.support_mem_hotplug_probe },
+                    |m: &mut GuestDetailsResponse| { &mut m.support_mem_hotplug_probe },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsResponse>(
+                    "GuestDetailsResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GuestDetailsResponse {
+        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GuestDetailsResponse,
+        };
+        unsafe {
+            instance.get(GuestDetailsResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GuestDetailsResponse {
+    fn clear(&mut self) {
+        self.mem_block_size_bytes = 0;
+        self.agent_details.clear();
+        self.support_mem_hotplug_probe = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GuestDetailsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GuestDetailsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MemHotplugByProbeRequest {
+    // message fields
+    pub memHotplugProbeAddr: ::std::vec::Vec<u64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MemHotplugByProbeRequest {
+    fn default() -> &'a MemHotplugByProbeRequest {
+        <MemHotplugByProbeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MemHotplugByProbeRequest {
+    pub fn new() -> MemHotplugByProbeRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated uint64 memHotplugProbeAddr = 1;
+
+
+    pub fn get_memHotplugProbeAddr(&self) -> &[u64] {
+        &self.memHotplugProbeAddr
+    }
+    pub fn clear_memHotplugProbeAddr(&mut self) {
+        self.memHotplugProbeAddr.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memHotplugProbeAddr(&mut self, v: ::std::vec::Vec<u64>) {
+        self.memHotplugProbeAddr = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_memHotplugProbeAddr(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.memHotplugProbeAddr
+    }
+
+    // Take field
+    pub fn take_memHotplugProbeAddr(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.memHotplugProbeAddr, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for MemHotplugByProbeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.memHotplugProbeAddr)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.memHotplugProbeAddr {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.memHotplugProbeAddr {
+            os.write_uint64(1, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MemHotplugByProbeRequest {
+        MemHotplugByProbeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memHotplugProbeAddr",
+                    |m: &MemHotplugByProbeRequest| { &m.memHotplugProbeAddr },
+                    |m: &mut MemHotplugByProbeRequest| { &mut m.memHotplugProbeAddr },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MemHotplugByProbeRequest>(
+                    "MemHotplugByProbeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MemHotplugByProbeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<MemHotplugByProbeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MemHotplugByProbeRequest,
+        };
+        unsafe {
+            instance.get(MemHotplugByProbeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MemHotplugByProbeRequest {
+    fn clear(&mut self) {
+        self.memHotplugProbeAddr.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MemHotplugByProbeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MemHotplugByProbeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetGuestDateTimeRequest {
+    // message fields
+    pub Sec: i64,
+    pub Usec: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetGuestDateTimeRequest {
+    fn default() -> &'a SetGuestDateTimeRequest {
+        <SetGuestDateTimeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetGuestDateTimeRequest {
+    pub fn new() -> SetGuestDateTimeRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 Sec = 1;
+
+
+    pub fn get_Sec(&self) -> i64 {
+        self.Sec
+    }
+    pub fn clear_Sec(&mut self) {
+        self.Sec = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_Sec(&mut self, v: i64) {
+        self.Sec = v;
+    }
+
+    // int64 Usec = 2;
+
+
+    pub fn get_Usec(&self) -> i64 {
+        self.Usec
+    }
+    pub fn clear_Usec(&mut self) {
+        self.Usec = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_Usec(&mut self, v: i64) {
+        self.Usec = v;
+    }
+}
+
+impl ::protobuf::Message for SetGuestDateTimeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.Sec = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.Usec = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.Sec != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.Sec, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.Usec != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.Usec, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.Sec != 0 {
+            os.write_int64(1, self.Sec)?;
+        }
+        if self.Usec != 0 {
+            os.write_int64(2, self.Usec)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetGuestDateTimeRequest {
+        SetGuestDateTimeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "Sec",
+                    |m: &SetGuestDateTimeRequest| { &m.Sec },
+                    |m: &mut SetGuestDateTimeRequest| { &mut m.Sec },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "Usec",
+                    |m: &SetGuestDateTimeRequest| { &m.Usec },
+                    |m: &mut SetGuestDateTimeRequest| { &mut m.Usec },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SetGuestDateTimeRequest>(
+                    "SetGuestDateTimeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SetGuestDateTimeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SetGuestDateTimeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SetGuestDateTimeRequest,
+        };
+        unsafe {
+            instance.get(SetGuestDateTimeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SetGuestDateTimeRequest {
+    fn clear(&mut self) {
+        self.Sec = 0;
+        self.Usec = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetGuestDateTimeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetGuestDateTimeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Storage {
+    // message fields
+    pub driver: ::std::string::String,
+    pub driver_options: ::protobuf::RepeatedField<::std::string::String>,
+    pub source: ::std::string::String,
+    pub fstype: ::std::string::String,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    pub mount_point: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Storage {
+    fn default() -> &'a Storage {
+        <Storage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Storage {
+    pub fn new() -> Storage {
+        ::std::default::Default::default()
+    }
+
+    // string driver = 1;
+
+
+    pub fn get_driver(&self) -> &str {
+        &self.driver
+    }
+    pub fn clear_driver(&mut self) {
+        self.driver.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_driver(&mut self, v: ::std::string::String) {
+        self.driver = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_driver(&mut self) -> &mut ::std::string::String {
+        &mut self.driver
+    }
+
+    // Take field
+    pub fn take_driver(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.driver, ::std::string::String::new())
+    }
+
+    // repeated string driver_options = 2;
+
+
+    pub fn get_driver_options(&self) -> &[::std::string::String] {
+        &self.driver_options
+    }
+    pub fn clear_driver_options(&mut self) {
+        self.driver_options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_driver_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.driver_options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_driver_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.driver_options
+    }
+
+    // Take field
+    pub fn take_driver_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.driver_options, ::protobuf::RepeatedField::new())
+    }
+
+    // string source = 3;
+
+
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: ::std::string::String) {
+        self.source = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut ::std::string::String {
+        &mut self.source
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source, ::std::string::String::new())
+    }
+
+    // string fstype = 4;
+
+
+    pub fn get_fstype(&self) -> &str {
+        &self.fstype
+    }
+    pub fn clear_fstype(&mut self) {
+        self.fstype.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fstype(&mut self, v: ::std::string::String) {
+        self.fstype = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fstype(&mut self) -> &mut ::std::string::String {
+        &mut self.fstype
+    }
+
+    // Take field
+    pub fn take_fstype(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fstype, ::std::string::String::new())
+    }
+
+    // repeated string options = 5;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+
+    // string mount_point = 6;
+
+
+    pub fn get_mount_point(&self) -> &str {
+        &self.mount_point
+    }
+    pub fn clear_mount_point(&mut self) {
+        self.mount_point.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mount_point(&mut self, v: ::std::string::String) {
+        self.mount_point = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mount_point(&mut self) -> &mut ::std::string::String {
+        &mut self.mount_point
+    }
+
+    // Take field
+    pub fn take_mount_point(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.mount_point, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Storage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.driver)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.driver_options)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fstype)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mount_point)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.driver.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.driver);
+        }
+        for value in &self.driver_options {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if !self.source.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.source);
+        }
+        if !self.fstype.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.fstype);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if !self.mount_point.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.mount_point);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.driver.is_empty() {
+            os.write_string(1, &self.driver)?;
+        }
+        for v in &self.driver_options {
+            os.write_string(2, &v)?;
+        };
+        if !self.source.is_empty() {
+            os.write_string(3, &self.source)?;
+        }
+        if !self.fstype.is_empty() {
+            os.write_string(4, &self.fstype)?;
+        }
+        for v in &self.options {
+            os.write_string(5, &v)?;
+        };
+        if !self.mount_point.is_empty() {
+            os.write_string(6, &self.mount_point)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Storage {
+        Storage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "driver",
+                    |m: &Storage| { &m.driver },
+                    |m: &mut Storage| { &mut m.driver },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "driver_options",
+                    |m: &Storage| { &m.driver_options },
+                    |m: &mut Storage| { &mut m.driver_options },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "source",
+                    |m: &Storage| { &m.source },
+                    |m: &mut Storage| { &mut m.source },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "fstype",
+                    |m: &Storage| { &m.fstype },
+                    |m: &mut Storage| { &mut m.fstype },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "options",
+                    |m: &Storage| { &m.options },
+                    |m: &mut Storage| { &mut m.options },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "mount_point",
+                    |m: &Storage| { &m.mount_point },
+                    |m: &mut Storage| { &mut m.mount_point },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Storage>(
+                    "Storage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Storage {
+        static mut instance: ::protobuf::lazy::Lazy<Storage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Storage,
+        };
+        unsafe {
+            instance.get(Storage::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Storage {
+    fn clear(&mut self) {
+        self.driver.clear();
+        self.driver_options.clear();
+        self.source.clear();
+        self.fstype.clear();
+        self.options.clear();
+        self.mount_point.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Storage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Storage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Device {
+    // message fields
+    pub id: ::std::string::String,
+    pub field_type: ::std::string::String,
+    pub vm_path: ::std::string::String,
+    pub container_path: ::std::string::String,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Device {
+    fn default() -> &'a Device {
+        <Device as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Device {
+    pub fn new() -> Device {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string type = 2;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // string vm_path = 3;
+
+
+    pub fn get_vm_path(&self) -> &str {
+        &self.vm_path
+    }
+    pub fn clear_vm_path(&mut self) {
+        self.vm_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vm_path(&mut self, v: ::std::string::String) {
+        self.vm_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vm_path(&mut self) -> &mut ::std::string::String {
+        &mut self.vm_path
+    }
+
+    // Take field
+    pub fn take_vm_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.vm_path, ::std::string::String::new())
+    }
+
+    // string container_path = 4;
+
+
+    pub fn get_container_path(&self) -> &str {
+        &self.container_path
+    }
+    pub fn clear_container_path(&mut self) {
+        self.container_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_container_path(&mut self, v: ::std::string::String) {
+        self.container_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_container_path(&mut self) -> &mut ::std::string::String {
+        &mut self.container_path
+    }
+
+    // Take field
+    pub fn take_container_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.container_path, ::std::string::String::new())
+    }
+
+    // repeated string options = 5;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Device {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.vm_path)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_path)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.field_type);
+        }
+        if !self.vm_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.vm_path);
+        }
+        if !self.container_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.container_path);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.field_type.is_empty() {
+            os.write_string(2, &self.field_type)?;
+        }
+        if !self.vm_path.is_empty() {
+            os.write_string(3, &self.vm_path)?;
+        }
+        if !self.container_path.is_empty() {
+            os.write_string(4, &self.container_path)?;
+        }
+        for v in &self.options {
+            os.write_string(5, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Device {
+        Device::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &Device| { &m.id },
+                    |m: &mut Device| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "type",
+                    |m: &Device| { &m.field_type },
+                    |m: &mut Device| { &mut m.field_type },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vm_path",
+                    |m: &Device| { &m.vm_path },
+                    |m: &mut Device| { &mut m.vm_path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "container_path",
+                    |m: &Device| { &m.container_path },
+                    |m: &mut Device| { &mut m.container_path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "options",
+                    |m: &Device| { &m.options },
+                    |m: &mut Device| { &mut m.options },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Device>(
+                    "Device",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Device {
+        static mut instance: ::protobuf::lazy::Lazy<Device> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Device,
+        };
+        unsafe {
+            instance.get(Device::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Device {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.field_type.clear();
+        self.vm_path.clear();
+        self.container_path.clear();
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Device {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Device {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StringUser {
+    // message fields
+    pub uid: ::std::string::String,
+    pub gid: ::std::string::String,
+    pub additionalGids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StringUser {
+    fn default() -> &'a StringUser {
+        <StringUser as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StringUser {
+    pub fn new() -> StringUser {
+        ::std::default::Default::default()
+    }
+
+    // string uid = 1;
+
+
+    pub fn get_uid(&self) -> &str {
+        &self.uid
+    }
+    pub fn clear_uid(&mut self) {
+        self.uid.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uid(&mut self, v: ::std::string::String) {
+        self.uid = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_uid(&mut self) -> &mut ::std::string::String {
+        &mut self.uid
+    }
+
+    // Take field
+    pub fn take_uid(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.uid, ::std::string::String::new())
+    }
+
+    // string gid = 2;
+
+
+    pub fn get_gid(&self) -> &str {
+        &self.gid
+    }
+    pub fn clear_gid(&mut self) {
+        self.gid.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gid(&mut self, v: ::std::string::String) {
+        self.gid = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gid(&mut self) -> &mut ::std::string::String {
+        &mut self.gid
+    }
+
+    // Take field
+    pub fn take_gid(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.gid, ::std::string::String::new())
+    }
+
+    // repeated string additionalGids = 3;
+
+
+    pub fn get_additionalGids(&self) -> &[::std::string::String] {
+        &self.additionalGids
+    }
+    pub fn clear_additionalGids(&mut self) {
+        self.additionalGids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_additionalGids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.additionalGids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_additionalGids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.additionalGids
+    }
+
+    // Take field
+    pub fn take_additionalGids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.additionalGids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for StringUser {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.uid)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gid)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.additionalGids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.uid.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.uid);
+        }
+        if !self.gid.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.gid);
+        }
+        for value in &self.additionalGids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.uid.is_empty() {
+            os.write_string(1, &self.uid)?;
+        }
+        if !self.gid.is_empty() {
+            os.write_string(2, &self.gid)?;
+        }
+        for v in &self.additionalGids {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StringUser {
+        StringUser::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "uid",
+                    |m: &StringUser| { &m.uid },
+                    |m: &mut StringUser| { &mut m.uid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "gid",
+                    |m: &StringUser| { &m.gid },
+                    |m: &mut StringUser| { &mut m.gid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "additionalGids",
+                    |m: &StringUser| { &m.additionalGids },
+                    |m: &mut StringUser| { &mut m.additionalGids },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StringUser>(
+                    "StringUser",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StringUser {
+        static mut instance: ::protobuf::lazy::Lazy<StringUser> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StringUser,
+        };
+        unsafe {
+            instance.get(StringUser::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StringUser {
+    fn clear(&mut self) {
+        self.uid.clear();
+        self.gid.clear();
+        self.additionalGids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StringUser {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StringUser {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CopyFileRequest {
+    // message fields
+    pub path: ::std::string::String,
+    pub file_size: i64,
+    pub file_mode: u32,
+    pub dir_mode: u32,
+    pub uid: i32,
+    pub gid: i32,
+    pub offset: i64,
+    pub data: ::std::vec::Vec<u8>,
+    pub sha256: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CopyFileRequest {
+    fn default() -> &'a CopyFileRequest {
+        <CopyFileRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CopyFileRequest {
+    pub fn new() -> CopyFileRequest {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 file_size = 2;
+
+
+    pub fn get_file_size(&self) -> i64 {
+        self.file_size
+    }
+    pub fn clear_file_size(&mut self) {
+        self.file_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_size(&mut self, v: i64) {
+        self.file_size = v;
+    }
+
+    // uint32 file_mode = 3;
+
+
+    pub fn get_file_mode(&self) -> u32 {
+        self.file_mode
+    }
+    pub fn clear_file_mode(&mut self) {
+        self.file_mode = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_mode(&mut self, v: u32) {
+        self.file_mode = v;
+    }
+
+    // uint32 dir_mode = 4;
+
+
+    pub fn get_dir_mode(&self) -> u32 {
+        self.dir_mode
+    }
+    pub fn clear_dir_mode(&mut self) {
+        self.dir_mode = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir_mode(&mut self, v: u32) {
+        self.dir_mode = v;
+    }
+
+    // int32 uid = 5;
+
+
+    pub fn get_uid(&self) -> i32 {
+        self.uid
+    }
+    pub fn clear_uid(&mut self) {
+        self.uid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uid(&mut self, v: i32) {
+        self.uid = v;
+    }
+
+    // int32 gid = 6;
+
+
+    pub fn get_gid(&self) -> i32 {
+        self.gid
+    }
+    pub fn clear_gid(&mut self) {
+        self.gid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gid(&mut self, v: i32) {
+        self.gid = v;
+    }
+
+    // int64 offset = 7;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // bytes data = 8;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // bytes sha256 = 9;
+
+
+    pub fn get_sha256(&self) -> &[u8] {
+        &self.sha256
+    }
+    pub fn clear_sha256(&mut self) {
+        self.sha256.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sha256(&mut self, v: ::std::vec::Vec<u8>) {
+        self.sha256 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sha256(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.sha256
+    }
+
+    // Take field
+    pub fn take_sha256(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.sha256, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CopyFileRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.file_size = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.file_mode = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.dir_mode = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.uid = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.gid = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.sha256)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.file_size != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.file_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.file_mode != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.file_mode, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.dir_mode != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.dir_mode, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.uid != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.uid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.gid != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.gid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.data);
+        }
+        if !self.sha256.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.sha256);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.file_size != 0 {
+            os.write_int64(2, self.file_size)?;
+        }
+        if self.file_mode != 0 {
+            os.write_uint32(3, self.file_mode)?;
+        }
+        if self.dir_mode != 0 {
+            os.write_uint32(4, self.dir_mode)?;
+        }
+        if self.uid != 0 {
+            os.write_int32(5, self.uid)?;
+        }
+        if self.gid != 0 {
+            os.write_int32(6, self.gid)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(7, self.offset)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(8, &self.data)?;
+        }
+        if !self.sha256.is_empty() {
+            os.write_bytes(9, &self.sha256)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
 
-    pub fn get_init_daemon(&self) -> bool {
-        self.init_daemon
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
-    pub fn clear_init_daemon(&mut self) {
-        self.init_daemon = false;
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    // Param is passed by value, moved
-    pub fn set_init_daemon(&mut self, v: bool) {
-        self.init_daemon = v;
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // repeated string device_handlers = 3;
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
 
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
 
-    pub fn get_device_handlers(&self) -> &[::std::string::String] {
-        &self.device_handlers
+    fn new() -> CopyFileRequest {
+        CopyFileRequest::new()
     }
-    pub fn clear_device_handlers(&mut self) {
-        self.device_handlers.clear();
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    |m: &CopyFileRequest| { &m.path },
+                    |m: &mut CopyFileRequest| { &mut m.path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "file_size",
+                    |m: &CopyFileRequest| { &m.file_size },
+                    |m: &mut CopyFileRequest| { &mut m.file_size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "file_mode",
+                    |m: &CopyFileRequest| { &m.file_mode },
+                    |m: &mut CopyFileRequest| { &mut m.file_mode },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "dir_mode",
+                    |m: &CopyFileRequest| { &m.dir_mode },
+                    |m: &mut CopyFileRequest| { &mut m.dir_mode },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "uid",
+                    |m: &CopyFileRequest| { &m.uid },
+                    |m: &mut CopyFileRequest| { &mut m.uid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "gid",
+                    |m: &CopyFileRequest| { &m.gid },
+                    |m: &mut CopyFileRequest| { &mut m.gid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "offset",
+                    |m: &CopyFileRequest| { &m.offset },
+                    |m: &mut CopyFileRequest| { &mut m.offset },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &CopyFileRequest| { &m.data },
+                    |m: &mut CopyFileRequest| { &mut m.data },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "sha256",
+                    |m: &CopyFileRequest| { &m.sha256 },
+                    |m: &mut CopyFileRequest| { &mut m.sha256 },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CopyFileRequest>(
+                    "CopyFileRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
 
-    // Param is passed by value, moved
-    pub fn set_device_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.device_handlers = v;
+    fn default_instance() -> &'static CopyFileRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CopyFileRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CopyFileRequest,
+        };
+        unsafe {
+            instance.get(CopyFileRequest::new)
+        }
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_device_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.device_handlers
+impl ::protobuf::Clear for CopyFileRequest {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.file_size = 0;
+        self.file_mode = 0;
+        self.dir_mode = 0;
+        self.uid = 0;
+        self.gid = 0;
+        self.offset = 0;
+        self.data.clear();
+        self.sha256.clear();
+        self.unknown_fields.clear();
     }
+}
 
-    // Take field
-    pub fn take_device_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.device_handlers, ::protobuf::RepeatedField::new())
+impl ::std::fmt::Debug for CopyFileRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // repeated string storage_handlers = 4;
+impl ::protobuf::reflect::ProtobufValue for CopyFileRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
 
+#[derive(PartialEq,Clone,Default)]
+pub struct CopyFileResponse {
+    // message fields
+    pub offset: i64,
+    pub done: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_storage_handlers(&self) -> &[::std::string::String] {
-        &self.storage_handlers
-    }
-    pub fn clear_storage_handlers(&mut self) {
-        self.storage_handlers.clear();
+impl<'a> ::std::default::Default for &'a CopyFileResponse {
+    fn default() -> &'a CopyFileResponse {
+        <CopyFileResponse as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_storage_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.storage_handlers = v;
+impl CopyFileResponse {
+    pub fn new() -> CopyFileResponse {
+        ::std::default::Default::default()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_storage_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.storage_handlers
+    // int64 offset = 1;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
     }
 
-    // Take field
-    pub fn take_storage_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.storage_handlers, ::protobuf::RepeatedField::new())
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
     }
 
-    // bool supports_seccomp = 5;
+    // bool done = 2;
 
 
-    pub fn get_supports_seccomp(&self) -> bool {
-        self.supports_seccomp
+    pub fn get_done(&self) -> bool {
+        self.done
     }
-    pub fn clear_supports_seccomp(&mut self) {
-        self.supports_seccomp = false;
+    pub fn clear_done(&mut self) {
+        self.done = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_supports_seccomp(&mut self, v: bool) {
-        self.supports_seccomp = v;
+    pub fn set_done(&mut self, v: bool) {
+        self.done = v;
     }
 }
 
-impl ::protobuf::Message for AgentDetails {
+impl ::protobuf::Message for CopyFileResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -10004,27 +14447,18 @@ impl ::protobuf::Message for AgentDetails {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.init_daemon = tmp;
-                },
-                3 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.device_handlers)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.storage_handlers)?;
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
                 },
-                5 => {
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_bool()?;
-                    self.supports_seccomp = tmp;
+                    self.done = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10038,19 +14472,10 @@ impl ::protobuf::Message for AgentDetails {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.version.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.version);
-        }
-        if self.init_daemon != false {
-            my_size += 2;
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.offset, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.device_handlers {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        for value in &self.storage_handlers {
-            my_size += ::protobuf::rt::string_size(4, &value);
-        };
-        if self.supports_seccomp != false {
+        if self.done != false {
             my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -10059,20 +14484,11 @@ impl ::protobuf::Message for AgentDetails {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.version.is_empty() {
-            os.write_string(1, &self.version)?;
-        }
-        if self.init_daemon != false {
-            os.write_bool(2, self.init_daemon)?;
+        if self.offset != 0 {
+            os.write_int64(1, self.offset)?;
         }
-        for v in &self.device_handlers {
-            os.write_string(3, &v)?;
-        };
-        for v in &self.storage_handlers {
-            os.write_string(4, &v)?;
-        };
-        if self.supports_seccomp != false {
-            os.write_bool(5, self.supports_seccomp)?;
+        if self.done != false {
+            os.write_bool(2, self.done)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -10104,8 +14520,8 @@ impl ::protobuf::Message for AgentDetails {
         Self::descriptor_static()
     }
 
-    fn new() -> AgentDetails {
-        AgentDetails::new()
+    fn new() -> CopyFileResponse {
+        CopyFileResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10116,33 +14532,18 @@ impl ::protobuf::Message for AgentDetails {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "version",
-                    |m: &AgentDetails| { &m.version },
-                    |m: &mut AgentDetails| { &mut m.version },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "init_daemon",
-                    |m: &AgentDetails| { &m.init_daemon },
-                    |m: &mut AgentDetails| { &mut m.init_daemon },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "device_handlers",
-                    |m: &AgentDetails| { &m.device_handlers },
-                    |m: &mut AgentDetails| { &mut m.device_handlers },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "storage_handlers",
-                    |m: &AgentDetails| { &m.storage_handlers },
-                    |m: &mut AgentDetails| { &mut m.storage_handlers },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "offset",
+                    |m: &CopyFileResponse| { &m.offset },
+                    |m: &mut CopyFileResponse| { &mut m.offset },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "supports_seccomp",
-                    |m: &AgentDetails| { &m.supports_seccomp },
-                    |m: &mut AgentDetails| { &mut m.supports_seccomp },
+                    "done",
+                    |m: &CopyFileResponse| { &m.done },
+                    |m: &mut CopyFileResponse| { &mut m.done },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AgentDetails>(
-                    "AgentDetails",
+                ::protobuf::reflect::MessageDescriptor::new::<CopyFileResponse>(
+                    "CopyFileResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10150,93 +14551,117 @@ impl ::protobuf::Message for AgentDetails {
         }
     }
 
-    fn default_instance() -> &'static AgentDetails {
-        static mut instance: ::protobuf::lazy::Lazy<AgentDetails> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static CopyFileResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CopyFileResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AgentDetails,
+            ptr: 0 as *const CopyFileResponse,
         };
         unsafe {
-            instance.get(AgentDetails::new)
+            instance.get(CopyFileResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for AgentDetails {
+impl ::protobuf::Clear for CopyFileResponse {
     fn clear(&mut self) {
-        self.version.clear();
-        self.init_daemon = false;
-        self.device_handlers.clear();
-        self.storage_handlers.clear();
-        self.supports_seccomp = false;
+        self.offset = 0;
+        self.done = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AgentDetails {
+impl ::std::fmt::Debug for CopyFileResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AgentDetails {
+impl ::protobuf::reflect::ProtobufValue for CopyFileResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct GuestDetailsRequest {
+pub struct ReadFileRequest {
     // message fields
-    pub mem_block_size: bool,
-    pub mem_hotplug_probe: bool,
+    pub path: ::std::string::String,
+    pub offset: i64,
+    pub len: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a GuestDetailsRequest {
-    fn default() -> &'a GuestDetailsRequest {
-        <GuestDetailsRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ReadFileRequest {
+    fn default() -> &'a ReadFileRequest {
+        <ReadFileRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GuestDetailsRequest {
-    pub fn new() -> GuestDetailsRequest {
+impl ReadFileRequest {
+    pub fn new() -> ReadFileRequest {
         ::std::default::Default::default()
     }
 
-    // bool mem_block_size = 1;
+    // string path = 1;
 
 
-    pub fn get_mem_block_size(&self) -> bool {
-        self.mem_block_size
+    pub fn get_path(&self) -> &str {
+        &self.path
     }
-    pub fn clear_mem_block_size(&mut self) {
-        self.mem_block_size = false;
+    pub fn clear_path(&mut self) {
+        self.path.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_mem_block_size(&mut self, v: bool) {
-        self.mem_block_size = v;
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
     }
 
-    // bool mem_hotplug_probe = 2;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
 
+    // int64 offset = 2;
 
-    pub fn get_mem_hotplug_probe(&self) -> bool {
-        self.mem_hotplug_probe
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
     }
-    pub fn clear_mem_hotplug_probe(&mut self) {
-        self.mem_hotplug_probe = false;
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_mem_hotplug_probe(&mut self, v: bool) {
-        self.mem_hotplug_probe = v;
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // uint32 len = 3;
+
+
+    pub fn get_len(&self) -> u32 {
+        self.len
+    }
+    pub fn clear_len(&mut self) {
+        self.len = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_len(&mut self, v: u32) {
+        self.len = v;
     }
 }
 
-impl ::protobuf::Message for GuestDetailsRequest {
+impl ::protobuf::Message for ReadFileRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -10246,18 +14671,21 @@ impl ::protobuf::Message for GuestDetailsRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.mem_block_size = tmp;
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
                 },
-                2 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.mem_hotplug_probe = tmp;
+                    let tmp = is.read_uint32()?;
+                    self.len = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10271,11 +14699,14 @@ impl ::protobuf::Message for GuestDetailsRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.mem_block_size != false {
-            my_size += 2;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
         }
-        if self.mem_hotplug_probe != false {
-            my_size += 2;
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.len != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.len, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -10283,11 +14714,14 @@ impl ::protobuf::Message for GuestDetailsRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.mem_block_size != false {
-            os.write_bool(1, self.mem_block_size)?;
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
         }
-        if self.mem_hotplug_probe != false {
-            os.write_bool(2, self.mem_hotplug_probe)?;
+        if self.offset != 0 {
+            os.write_int64(2, self.offset)?;
+        }
+        if self.len != 0 {
+            os.write_uint32(3, self.len)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -10319,8 +14753,8 @@ impl ::protobuf::Message for GuestDetailsRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> GuestDetailsRequest {
-        GuestDetailsRequest::new()
+    fn new() -> ReadFileRequest {
+        ReadFileRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10331,18 +14765,23 @@ impl ::protobuf::Message for GuestDetailsRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "mem_block_size",
-                    |m: &GuestDetailsRequest| { &m.mem_block_size },
-                    |m: &mut GuestDetailsRequest| { &mut m.mem_block_size },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    |m: &ReadFileRequest| { &m.path },
+                    |m: &mut ReadFileRequest| { &mut m.path },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "mem_hotplug_probe",
-                    |m: &GuestDetailsRequest| { &m.mem_hotplug_probe },
-                    |m: &mut GuestDetailsRequest| { &mut m.mem_hotplug_probe },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "offset",
+                    |m: &ReadFileRequest| { &m.offset },
+                    |m: &mut ReadFileRequest| { &mut m.offset },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsRequest>(
-                    "GuestDetailsRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "len",
+                    |m: &ReadFileRequest| { &m.len },
+                    |m: &mut ReadFileRequest| { &mut m.len },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReadFileRequest>(
+                    "ReadFileRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10350,130 +14789,151 @@ impl ::protobuf::Message for GuestDetailsRequest {
         }
     }
 
-    fn default_instance() -> &'static GuestDetailsRequest {
-        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ReadFileRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ReadFileRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GuestDetailsRequest,
+            ptr: 0 as *const ReadFileRequest,
         };
         unsafe {
-            instance.get(GuestDetailsRequest::new)
+            instance.get(ReadFileRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for GuestDetailsRequest {
+impl ::protobuf::Clear for ReadFileRequest {
     fn clear(&mut self) {
-        self.mem_block_size = false;
-        self.mem_hotplug_probe = false;
+        self.path.clear();
+        self.offset = 0;
+        self.len = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for GuestDetailsRequest {
+impl ::std::fmt::Debug for ReadFileRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GuestDetailsRequest {
+impl ::protobuf::reflect::ProtobufValue for ReadFileRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct GuestDetailsResponse {
+pub struct DirEntry {
     // message fields
-    pub mem_block_size_bytes: u64,
-    pub agent_details: ::protobuf::SingularPtrField<AgentDetails>,
-    pub support_mem_hotplug_probe: bool,
+    pub name: ::std::string::String,
+    pub dir: bool,
+    pub size: i64,
+    pub mode: u32,
+    pub mtime: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a GuestDetailsResponse {
-    fn default() -> &'a GuestDetailsResponse {
-        <GuestDetailsResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DirEntry {
+    fn default() -> &'a DirEntry {
+        <DirEntry as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GuestDetailsResponse {
-    pub fn new() -> GuestDetailsResponse {
+impl DirEntry {
+    pub fn new() -> DirEntry {
         ::std::default::Default::default()
     }
 
-    // uint64 mem_block_size_bytes = 1;
+    // string name = 1;
 
 
-    pub fn get_mem_block_size_bytes(&self) -> u64 {
-        self.mem_block_size_bytes
+    pub fn get_name(&self) -> &str {
+        &self.name
     }
-    pub fn clear_mem_block_size_bytes(&mut self) {
-        self.mem_block_size_bytes = 0;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_mem_block_size_bytes(&mut self, v: u64) {
-        self.mem_block_size_bytes = v;
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
     }
 
-    // .grpc.AgentDetails agent_details = 2;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
 
+    // bool dir = 2;
 
-    pub fn get_agent_details(&self) -> &AgentDetails {
-        self.agent_details.as_ref().unwrap_or_else(|| AgentDetails::default_instance())
+
+    pub fn get_dir(&self) -> bool {
+        self.dir
     }
-    pub fn clear_agent_details(&mut self) {
-        self.agent_details.clear();
+    pub fn clear_dir(&mut self) {
+        self.dir = false;
     }
 
-    pub fn has_agent_details(&self) -> bool {
-        self.agent_details.is_some()
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: bool) {
+        self.dir = v;
+    }
+
+    // int64 size = 3;
+
+
+    pub fn get_size(&self) -> i64 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_agent_details(&mut self, v: AgentDetails) {
-        self.agent_details = ::protobuf::SingularPtrField::some(v);
+    pub fn set_size(&mut self, v: i64) {
+        self.size = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_agent_details(&mut self) -> &mut AgentDetails {
-        if self.agent_details.is_none() {
-            self.agent_details.set_default();
-        }
-        self.agent_details.as_mut().unwrap()
+    // uint32 mode = 4;
+
+
+    pub fn get_mode(&self) -> u32 {
+        self.mode
+    }
+    pub fn clear_mode(&mut self) {
+        self.mode = 0;
     }
 
-    // Take field
-    pub fn take_agent_details(&mut self) -> AgentDetails {
-        self.agent_details.take().unwrap_or_else(|| AgentDetails::new())
+    // Param is passed by value, moved
+    pub fn set_mode(&mut self, v: u32) {
+        self.mode = v;
     }
 
-    // bool support_mem_hotplug_probe = 3;
+    // int64 mtime = 5;
 
 
-    pub fn get_support_mem_hotplug_probe(&self) -> bool {
-        self.support_mem_hotplug_probe
+    pub fn get_mtime(&self) -> i64 {
+        self.mtime
     }
-    pub fn clear_support_mem_hotplug_probe(&mut self) {
-        self.support_mem_hotplug_probe = false;
+    pub fn clear_mtime(&mut self) {
+        self.mtime = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_support_mem_hotplug_probe(&mut self, v: bool) {
-        self.support_mem_hotplug_probe = v;
+    pub fn set_mtime(&mut self, v: i64) {
+        self.mtime = v;
     }
 }
 
-impl ::protobuf::Message for GuestDetailsResponse {
+impl ::protobuf::Message for DirEntry {
     fn is_initialized(&self) -> bool {
-        for v in &self.agent_details {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -10482,21 +14942,35 @@ impl ::protobuf::Message for GuestDetailsResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.mem_block_size_bytes = tmp;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.agent_details)?;
+                    let tmp = is.read_bool()?;
+                    self.dir = tmp;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.support_mem_hotplug_probe = tmp;
+                    let tmp = is.read_int64()?;
+                    self.size = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mode = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.mtime = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10510,32 +14984,41 @@ impl ::protobuf::Message for GuestDetailsResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.mem_block_size_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.mem_block_size_bytes, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.agent_details.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
         }
-        if self.support_mem_hotplug_probe != false {
+        if self.dir != false {
             my_size += 2;
         }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.mode != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.mode, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.mtime != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.mtime, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.mem_block_size_bytes != 0 {
-            os.write_uint64(1, self.mem_block_size_bytes)?;
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
         }
-        if let Some(ref v) = self.agent_details.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if self.dir != false {
+            os.write_bool(2, self.dir)?;
         }
-        if self.support_mem_hotplug_probe != false {
-            os.write_bool(3, self.support_mem_hotplug_probe)?;
+        if self.size != 0 {
+            os.write_int64(3, self.size)?;
+        }
+        if self.mode != 0 {
+            os.write_uint32(4, self.mode)?;
+        }
+        if self.mtime != 0 {
+            os.write_int64(5, self.mtime)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -10567,8 +15050,8 @@ impl ::protobuf::Message for GuestDetailsResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> GuestDetailsResponse {
-        GuestDetailsResponse::new()
+    fn new() -> DirEntry {
+        DirEntry::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10579,23 +15062,33 @@ impl ::protobuf::Message for GuestDetailsResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "mem_block_size_bytes",
-                    |m: &GuestDetailsResponse| { &m.mem_block_size_bytes },
-                    |m: &mut GuestDetailsResponse| { &mut m.mem_block_size_bytes },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AgentDetails>>(
-                    "agent_details",
-                    |m: &GuestDetailsResponse| { &m.agent_details },
-                    |m: &mut GuestDetailsResponse| { &mut m.agent_details },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &DirEntry| { &m.name },
+                    |m: &mut DirEntry| { &mut m.name },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "support_mem_hotplug_probe",
-                    |m: &GuestDetailsResponse| { &m.support_mem_hotplug_probe },
-                    |m: &mut GuestDetailsResponse| { &mut m.support_mem_hotplug_probe },
+                    "dir",
+                    |m: &DirEntry| { &m.dir },
+                    |m: &mut DirEntry| { &mut m.dir },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsResponse>(
-                    "GuestDetailsResponse",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "size",
+                    |m: &DirEntry| { &m.size },
+                    |m: &mut DirEntry| { &mut m.size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "mode",
+                    |m: &DirEntry| { &m.mode },
+                    |m: &mut DirEntry| { &mut m.mode },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "mtime",
+                    |m: &DirEntry| { &m.mtime },
+                    |m: &mut DirEntry| { &mut m.mtime },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DirEntry>(
+                    "DirEntry",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10603,86 +15096,152 @@ impl ::protobuf::Message for GuestDetailsResponse {
         }
     }
 
-    fn default_instance() -> &'static GuestDetailsResponse {
-        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static DirEntry {
+        static mut instance: ::protobuf::lazy::Lazy<DirEntry> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GuestDetailsResponse,
+            ptr: 0 as *const DirEntry,
         };
         unsafe {
-            instance.get(GuestDetailsResponse::new)
+            instance.get(DirEntry::new)
         }
     }
 }
 
-impl ::protobuf::Clear for GuestDetailsResponse {
+impl ::protobuf::Clear for DirEntry {
     fn clear(&mut self) {
-        self.mem_block_size_bytes = 0;
-        self.agent_details.clear();
-        self.support_mem_hotplug_probe = false;
+        self.name.clear();
+        self.dir = false;
+        self.size = 0;
+        self.mode = 0;
+        self.mtime = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for GuestDetailsResponse {
+impl ::std::fmt::Debug for DirEntry {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GuestDetailsResponse {
+impl ::protobuf::reflect::ProtobufValue for DirEntry {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct MemHotplugByProbeRequest {
+pub struct ReadFileResponse {
     // message fields
-    pub memHotplugProbeAddr: ::std::vec::Vec<u64>,
+    pub data: ::std::vec::Vec<u8>,
+    pub file_size: i64,
+    pub entries: ::protobuf::RepeatedField<DirEntry>,
+    pub dir: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MemHotplugByProbeRequest {
-    fn default() -> &'a MemHotplugByProbeRequest {
-        <MemHotplugByProbeRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ReadFileResponse {
+    fn default() -> &'a ReadFileResponse {
+        <ReadFileResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MemHotplugByProbeRequest {
-    pub fn new() -> MemHotplugByProbeRequest {
+impl ReadFileResponse {
+    pub fn new() -> ReadFileResponse {
         ::std::default::Default::default()
     }
 
-    // repeated uint64 memHotplugProbeAddr = 1;
+    // bytes data = 1;
 
 
-    pub fn get_memHotplugProbeAddr(&self) -> &[u64] {
-        &self.memHotplugProbeAddr
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
     }
-    pub fn clear_memHotplugProbeAddr(&mut self) {
-        self.memHotplugProbeAddr.clear();
+    pub fn clear_data(&mut self) {
+        self.data.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_memHotplugProbeAddr(&mut self, v: ::std::vec::Vec<u64>) {
-        self.memHotplugProbeAddr = v;
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_memHotplugProbeAddr(&mut self) -> &mut ::std::vec::Vec<u64> {
-        &mut self.memHotplugProbeAddr
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
     }
 
     // Take field
-    pub fn take_memHotplugProbeAddr(&mut self) -> ::std::vec::Vec<u64> {
-        ::std::mem::replace(&mut self.memHotplugProbeAddr, ::std::vec::Vec::new())
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // int64 file_size = 2;
+
+
+    pub fn get_file_size(&self) -> i64 {
+        self.file_size
+    }
+    pub fn clear_file_size(&mut self) {
+        self.file_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_size(&mut self, v: i64) {
+        self.file_size = v;
+    }
+
+    // repeated .grpc.DirEntry entries = 3;
+
+
+    pub fn get_entries(&self) -> &[DirEntry] {
+        &self.entries
+    }
+    pub fn clear_entries(&mut self) {
+        self.entries.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entries(&mut self, v: ::protobuf::RepeatedField<DirEntry>) {
+        self.entries = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entries(&mut self) -> &mut ::protobuf::RepeatedField<DirEntry> {
+        &mut self.entries
+    }
+
+    // Take field
+    pub fn take_entries(&mut self) -> ::protobuf::RepeatedField<DirEntry> {
+        ::std::mem::replace(&mut self.entries, ::protobuf::RepeatedField::new())
+    }
+
+    // bool dir = 4;
+
+
+    pub fn get_dir(&self) -> bool {
+        self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: bool) {
+        self.dir = v;
     }
 }
 
-impl ::protobuf::Message for MemHotplugByProbeRequest {
+impl ::protobuf::Message for ReadFileResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.entries {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -10691,7 +15250,24 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.memHotplugProbeAddr)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.file_size = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entries)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.dir = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10705,18 +15281,39 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.memHotplugProbeAddr {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
+        }
+        if self.file_size != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.file_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if self.dir != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.memHotplugProbeAddr {
-            os.write_uint64(1, *v)?;
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
+        }
+        if self.file_size != 0 {
+            os.write_int64(2, self.file_size)?;
+        }
+        for v in &self.entries {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         };
+        if self.dir != false {
+            os.write_bool(4, self.dir)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -10747,8 +15344,8 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> MemHotplugByProbeRequest {
-        MemHotplugByProbeRequest::new()
+    fn new() -> ReadFileResponse {
+        ReadFileResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10759,13 +15356,28 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "memHotplugProbeAddr",
-                    |m: &MemHotplugByProbeRequest| { &m.memHotplugProbeAddr },
-                    |m: &mut MemHotplugByProbeRequest| { &mut m.memHotplugProbeAddr },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &ReadFileResponse| { &m.data },
+                    |m: &mut ReadFileResponse| { &mut m.data },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<MemHotplugByProbeRequest>(
-                    "MemHotplugByProbeRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "file_size",
+                    |m: &ReadFileResponse| { &m.file_size },
+                    |m: &mut ReadFileResponse| { &mut m.file_size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DirEntry>>(
+                    "entries",
+                    |m: &ReadFileResponse| { &m.entries },
+                    |m: &mut ReadFileResponse| { &mut m.entries },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "dir",
+                    |m: &ReadFileResponse| { &m.dir },
+                    |m: &mut ReadFileResponse| { &mut m.dir },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ReadFileResponse>(
+                    "ReadFileResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10773,111 +15385,67 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
         }
     }
 
-    fn default_instance() -> &'static MemHotplugByProbeRequest {
-        static mut instance: ::protobuf::lazy::Lazy<MemHotplugByProbeRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ReadFileResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ReadFileResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MemHotplugByProbeRequest,
+            ptr: 0 as *const ReadFileResponse,
         };
         unsafe {
-            instance.get(MemHotplugByProbeRequest::new)
+            instance.get(ReadFileResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for MemHotplugByProbeRequest {
+impl ::protobuf::Clear for ReadFileResponse {
     fn clear(&mut self) {
-        self.memHotplugProbeAddr.clear();
+        self.data.clear();
+        self.file_size = 0;
+        self.entries.clear();
+        self.dir = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for MemHotplugByProbeRequest {
+impl ::std::fmt::Debug for ReadFileResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MemHotplugByProbeRequest {
+impl ::protobuf::reflect::ProtobufValue for ReadFileResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SetGuestDateTimeRequest {
-    // message fields
-    pub Sec: i64,
-    pub Usec: i64,
+pub struct GetOOMEventRequest {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SetGuestDateTimeRequest {
-    fn default() -> &'a SetGuestDateTimeRequest {
-        <SetGuestDateTimeRequest as ::protobuf::Message>::default_instance()
-    }
-}
-
-impl SetGuestDateTimeRequest {
-    pub fn new() -> SetGuestDateTimeRequest {
-        ::std::default::Default::default()
-    }
-
-    // int64 Sec = 1;
-
-
-    pub fn get_Sec(&self) -> i64 {
-        self.Sec
-    }
-    pub fn clear_Sec(&mut self) {
-        self.Sec = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_Sec(&mut self, v: i64) {
-        self.Sec = v;
-    }
-
-    // int64 Usec = 2;
-
-
-    pub fn get_Usec(&self) -> i64 {
-        self.Usec
-    }
-    pub fn clear_Usec(&mut self) {
-        self.Usec = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_Usec(&mut self, v: i64) {
-        self.Usec = v;
+impl<'a> ::std::default::Default for &'a GetOOMEventRequest {
+    fn default() -> &'a GetOOMEventRequest {
+        <GetOOMEventRequest as ::protobuf::Message>::default_instance()
     }
 }
-
-impl ::protobuf::Message for SetGuestDateTimeRequest {
-    fn is_initialized(&self) -> bool {
-        true
-    }
-
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.Sec = tmp;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.Usec = tmp;
-                },
+
+impl GetOOMEventRequest {
+    pub fn new() -> GetOOMEventRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for GetOOMEventRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -10890,24 +15458,12 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.Sec != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.Sec, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.Usec != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.Usec, ::protobuf::wire_format::WireTypeVarint);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.Sec != 0 {
-            os.write_int64(1, self.Sec)?;
-        }
-        if self.Usec != 0 {
-            os.write_int64(2, self.Usec)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -10938,8 +15494,8 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> SetGuestDateTimeRequest {
-        SetGuestDateTimeRequest::new()
+    fn new() -> GetOOMEventRequest {
+        GetOOMEventRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10949,19 +15505,9 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
         };
         unsafe {
             descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "Sec",
-                    |m: &SetGuestDateTimeRequest| { &m.Sec },
-                    |m: &mut SetGuestDateTimeRequest| { &mut m.Sec },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "Usec",
-                    |m: &SetGuestDateTimeRequest| { &m.Usec },
-                    |m: &mut SetGuestDateTimeRequest| { &mut m.Usec },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<SetGuestDateTimeRequest>(
-                    "SetGuestDateTimeRequest",
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<GetOOMEventRequest>(
+                    "GetOOMEventRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10969,218 +15515,83 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
         }
     }
 
-    fn default_instance() -> &'static SetGuestDateTimeRequest {
-        static mut instance: ::protobuf::lazy::Lazy<SetGuestDateTimeRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static GetOOMEventRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetOOMEventRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SetGuestDateTimeRequest,
+            ptr: 0 as *const GetOOMEventRequest,
         };
         unsafe {
-            instance.get(SetGuestDateTimeRequest::new)
+            instance.get(GetOOMEventRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SetGuestDateTimeRequest {
+impl ::protobuf::Clear for GetOOMEventRequest {
     fn clear(&mut self) {
-        self.Sec = 0;
-        self.Usec = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SetGuestDateTimeRequest {
+impl ::std::fmt::Debug for GetOOMEventRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SetGuestDateTimeRequest {
+impl ::protobuf::reflect::ProtobufValue for GetOOMEventRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Storage {
+pub struct OOMEvent {
     // message fields
-    pub driver: ::std::string::String,
-    pub driver_options: ::protobuf::RepeatedField<::std::string::String>,
-    pub source: ::std::string::String,
-    pub fstype: ::std::string::String,
-    pub options: ::protobuf::RepeatedField<::std::string::String>,
-    pub mount_point: ::std::string::String,
+    pub container_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Storage {
-    fn default() -> &'a Storage {
-        <Storage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a OOMEvent {
+    fn default() -> &'a OOMEvent {
+        <OOMEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Storage {
-    pub fn new() -> Storage {
+impl OOMEvent {
+    pub fn new() -> OOMEvent {
         ::std::default::Default::default()
     }
 
-    // string driver = 1;
-
-
-    pub fn get_driver(&self) -> &str {
-        &self.driver
-    }
-    pub fn clear_driver(&mut self) {
-        self.driver.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_driver(&mut self, v: ::std::string::String) {
-        self.driver = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_driver(&mut self) -> &mut ::std::string::String {
-        &mut self.driver
-    }
-
-    // Take field
-    pub fn take_driver(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.driver, ::std::string::String::new())
-    }
-
-    // repeated string driver_options = 2;
-
-
-    pub fn get_driver_options(&self) -> &[::std::string::String] {
-        &self.driver_options
-    }
-    pub fn clear_driver_options(&mut self) {
-        self.driver_options.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_driver_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.driver_options = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_driver_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.driver_options
-    }
-
-    // Take field
-    pub fn take_driver_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.driver_options, ::protobuf::RepeatedField::new())
-    }
-
-    // string source = 3;
-
-
-    pub fn get_source(&self) -> &str {
-        &self.source
-    }
-    pub fn clear_source(&mut self) {
-        self.source.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_source(&mut self, v: ::std::string::String) {
-        self.source = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_source(&mut self) -> &mut ::std::string::String {
-        &mut self.source
-    }
-
-    // Take field
-    pub fn take_source(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.source, ::std::string::String::new())
-    }
-
-    // string fstype = 4;
-
-
-    pub fn get_fstype(&self) -> &str {
-        &self.fstype
-    }
-    pub fn clear_fstype(&mut self) {
-        self.fstype.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_fstype(&mut self, v: ::std::string::String) {
-        self.fstype = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_fstype(&mut self) -> &mut ::std::string::String {
-        &mut self.fstype
-    }
-
-    // Take field
-    pub fn take_fstype(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.fstype, ::std::string::String::new())
-    }
-
-    // repeated string options = 5;
-
-
-    pub fn get_options(&self) -> &[::std::string::String] {
-        &self.options
-    }
-    pub fn clear_options(&mut self) {
-        self.options.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.options = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.options
-    }
-
-    // Take field
-    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
-    }
-
-    // string mount_point = 6;
+    // string container_id = 1;
 
 
-    pub fn get_mount_point(&self) -> &str {
-        &self.mount_point
+    pub fn get_container_id(&self) -> &str {
+        &self.container_id
     }
-    pub fn clear_mount_point(&mut self) {
-        self.mount_point.clear();
+    pub fn clear_container_id(&mut self) {
+        self.container_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_mount_point(&mut self, v: ::std::string::String) {
-        self.mount_point = v;
+    pub fn set_container_id(&mut self, v: ::std::string::String) {
+        self.container_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_mount_point(&mut self) -> &mut ::std::string::String {
-        &mut self.mount_point
+    pub fn mut_container_id(&mut self) -> &mut ::std::string::String {
+        &mut self.container_id
     }
 
     // Take field
-    pub fn take_mount_point(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.mount_point, ::std::string::String::new())
+    pub fn take_container_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.container_id, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for Storage {
+impl ::protobuf::Message for OOMEvent {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -11190,76 +15601,31 @@ impl ::protobuf::Message for Storage {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.driver)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.driver_options)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fstype)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mount_point)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_id)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
-        ::std::result::Result::Ok(())
-    }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if !self.driver.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.driver);
-        }
-        for value in &self.driver_options {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        if !self.source.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.source);
-        }
-        if !self.fstype.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.fstype);
-        }
-        for value in &self.options {
-            my_size += ::protobuf::rt::string_size(5, &value);
-        };
-        if !self.mount_point.is_empty() {
-            my_size += ::protobuf::rt::string_size(6, &self.mount_point);
-        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.container_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.container_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.driver.is_empty() {
-            os.write_string(1, &self.driver)?;
-        }
-        for v in &self.driver_options {
-            os.write_string(2, &v)?;
-        };
-        if !self.source.is_empty() {
-            os.write_string(3, &self.source)?;
-        }
-        if !self.fstype.is_empty() {
-            os.write_string(4, &self.fstype)?;
-        }
-        for v in &self.options {
-            os.write_string(5, &v)?;
-        };
-        if !self.mount_point.is_empty() {
-            os.write_string(6, &self.mount_point)?;
+        if !self.container_id.is_empty() {
+            os.write_string(1, &self.container_id)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -11291,8 +15657,8 @@ impl ::protobuf::Message for Storage {
         Self::descriptor_static()
     }
 
-    fn new() -> Storage {
-        Storage::new()
+    fn new() -> OOMEvent {
+        OOMEvent::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -11304,37 +15670,12 @@ impl ::protobuf::Message for Storage {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "driver",
-                    |m: &Storage| { &m.driver },
-                    |m: &mut Storage| { &mut m.driver },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "driver_options",
-                    |m: &Storage| { &m.driver_options },
-                    |m: &mut Storage| { &mut m.driver_options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "source",
-                    |m: &Storage| { &m.source },
-                    |m: &mut Storage| { &mut m.source },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "fstype",
-                    |m: &Storage| { &m.fstype },
-                    |m: &mut Storage| { &mut m.fstype },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "options",
-                    |m: &Storage| { &m.options },
-                    |m: &mut Storage| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "mount_point",
-                    |m: &Storage| { &m.mount_point },
-                    |m: &mut Storage| { &mut m.mount_point },
+                    "container_id",
+                    |m: &OOMEvent| { &m.container_id },
+                    |m: &mut OOMEvent| { &mut m.container_id },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Storage>(
-                    "Storage",
+                ::protobuf::reflect::MessageDescriptor::new::<OOMEvent>(
+                    "OOMEvent",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11342,196 +15683,83 @@ impl ::protobuf::Message for Storage {
         }
     }
 
-    fn default_instance() -> &'static Storage {
-        static mut instance: ::protobuf::lazy::Lazy<Storage> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static OOMEvent {
+        static mut instance: ::protobuf::lazy::Lazy<OOMEvent> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Storage,
+            ptr: 0 as *const OOMEvent,
         };
         unsafe {
-            instance.get(Storage::new)
+            instance.get(OOMEvent::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Storage {
+impl ::protobuf::Clear for OOMEvent {
     fn clear(&mut self) {
-        self.driver.clear();
-        self.driver_options.clear();
-        self.source.clear();
-        self.fstype.clear();
-        self.options.clear();
-        self.mount_point.clear();
+        self.container_id.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Storage {
+impl ::std::fmt::Debug for OOMEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Storage {
+impl ::protobuf::reflect::ProtobufValue for OOMEvent {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Device {
+pub struct SubscribeMemoryPressureRequest {
     // message fields
-    pub id: ::std::string::String,
-    pub field_type: ::std::string::String,
-    pub vm_path: ::std::string::String,
-    pub container_path: ::std::string::String,
-    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    pub container_ids: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Device {
-    fn default() -> &'a Device {
-        <Device as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SubscribeMemoryPressureRequest {
+    fn default() -> &'a SubscribeMemoryPressureRequest {
+        <SubscribeMemoryPressureRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Device {
-    pub fn new() -> Device {
+impl SubscribeMemoryPressureRequest {
+    pub fn new() -> SubscribeMemoryPressureRequest {
         ::std::default::Default::default()
     }
 
-    // string id = 1;
-
-
-    pub fn get_id(&self) -> &str {
-        &self.id
-    }
-    pub fn clear_id(&mut self) {
-        self.id.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: ::std::string::String) {
-        self.id = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&mut self) -> &mut ::std::string::String {
-        &mut self.id
-    }
-
-    // Take field
-    pub fn take_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.id, ::std::string::String::new())
-    }
-
-    // string type = 2;
-
-
-    pub fn get_field_type(&self) -> &str {
-        &self.field_type
-    }
-    pub fn clear_field_type(&mut self) {
-        self.field_type.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_field_type(&mut self, v: ::std::string::String) {
-        self.field_type = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
-        &mut self.field_type
-    }
-
-    // Take field
-    pub fn take_field_type(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
-    }
-
-    // string vm_path = 3;
-
-
-    pub fn get_vm_path(&self) -> &str {
-        &self.vm_path
-    }
-    pub fn clear_vm_path(&mut self) {
-        self.vm_path.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_vm_path(&mut self, v: ::std::string::String) {
-        self.vm_path = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_vm_path(&mut self) -> &mut ::std::string::String {
-        &mut self.vm_path
-    }
-
-    // Take field
-    pub fn take_vm_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.vm_path, ::std::string::String::new())
-    }
-
-    // string container_path = 4;
-
-
-    pub fn get_container_path(&self) -> &str {
-        &self.container_path
-    }
-    pub fn clear_container_path(&mut self) {
-        self.container_path.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_container_path(&mut self, v: ::std::string::String) {
-        self.container_path = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_container_path(&mut self) -> &mut ::std::string::String {
-        &mut self.container_path
-    }
-
-    // Take field
-    pub fn take_container_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.container_path, ::std::string::String::new())
-    }
-
-    // repeated string options = 5;
+    // repeated string container_ids = 1;
 
 
-    pub fn get_options(&self) -> &[::std::string::String] {
-        &self.options
+    pub fn get_container_ids(&self) -> &[::std::string::String] {
+        &self.container_ids
     }
-    pub fn clear_options(&mut self) {
-        self.options.clear();
+    pub fn clear_container_ids(&mut self) {
+        self.container_ids.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.options = v;
+    pub fn set_container_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.container_ids = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.options
+    pub fn mut_container_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.container_ids
     }
 
     // Take field
-    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    pub fn take_container_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.container_ids, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for Device {
+impl ::protobuf::Message for SubscribeMemoryPressureRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -11541,19 +15769,7 @@ impl ::protobuf::Message for Device {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.vm_path)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_path)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.container_ids)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -11562,46 +15778,22 @@ impl ::protobuf::Message for Device {
         }
         ::std::result::Result::Ok(())
     }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if !self.id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.id);
-        }
-        if !self.field_type.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.field_type);
-        }
-        if !self.vm_path.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.vm_path);
-        }
-        if !self.container_path.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.container_path);
-        }
-        for value in &self.options {
-            my_size += ::protobuf::rt::string_size(5, &value);
-        };
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
-    }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.id.is_empty() {
-            os.write_string(1, &self.id)?;
-        }
-        if !self.field_type.is_empty() {
-            os.write_string(2, &self.field_type)?;
-        }
-        if !self.vm_path.is_empty() {
-            os.write_string(3, &self.vm_path)?;
-        }
-        if !self.container_path.is_empty() {
-            os.write_string(4, &self.container_path)?;
-        }
-        for v in &self.options {
-            os.write_string(5, &v)?;
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.container_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.container_ids {
+            os.write_string(1, &v)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -11633,8 +15825,8 @@ impl ::protobuf::Message for Device {
         Self::descriptor_static()
     }
 
-    fn new() -> Device {
-        Device::new()
+    fn new() -> SubscribeMemoryPressureRequest {
+        SubscribeMemoryPressureRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -11645,33 +15837,13 @@ impl ::protobuf::Message for Device {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "id",
-                    |m: &Device| { &m.id },
-                    |m: &mut Device| { &mut m.id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "type",
-                    |m: &Device| { &m.field_type },
-                    |m: &mut Device| { &mut m.field_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "vm_path",
-                    |m: &Device| { &m.vm_path },
-                    |m: &mut Device| { &mut m.vm_path },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "container_path",
-                    |m: &Device| { &m.container_path },
-                    |m: &mut Device| { &mut m.container_path },
-                ));
                 fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "options",
-                    |m: &Device| { &m.options },
-                    |m: &mut Device| { &mut m.options },
+                    "container_ids",
+                    |m: &SubscribeMemoryPressureRequest| { &m.container_ids },
+                    |m: &mut SubscribeMemoryPressureRequest| { &mut m.container_ids },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Device>(
-                    "Device",
+                ::protobuf::reflect::MessageDescriptor::new::<SubscribeMemoryPressureRequest>(
+                    "SubscribeMemoryPressureRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11679,141 +15851,111 @@ impl ::protobuf::Message for Device {
         }
     }
 
-    fn default_instance() -> &'static Device {
-        static mut instance: ::protobuf::lazy::Lazy<Device> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static SubscribeMemoryPressureRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SubscribeMemoryPressureRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Device,
+            ptr: 0 as *const SubscribeMemoryPressureRequest,
         };
         unsafe {
-            instance.get(Device::new)
+            instance.get(SubscribeMemoryPressureRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Device {
+impl ::protobuf::Clear for SubscribeMemoryPressureRequest {
     fn clear(&mut self) {
-        self.id.clear();
-        self.field_type.clear();
-        self.vm_path.clear();
-        self.container_path.clear();
-        self.options.clear();
+        self.container_ids.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Device {
+impl ::std::fmt::Debug for SubscribeMemoryPressureRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Device {
+impl ::protobuf::reflect::ProtobufValue for SubscribeMemoryPressureRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct StringUser {
+pub struct MemoryPressureEvent {
     // message fields
-    pub uid: ::std::string::String,
-    pub gid: ::std::string::String,
-    pub additionalGids: ::protobuf::RepeatedField<::std::string::String>,
+    pub container_id: ::std::string::String,
+    pub level: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StringUser {
-    fn default() -> &'a StringUser {
-        <StringUser as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a MemoryPressureEvent {
+    fn default() -> &'a MemoryPressureEvent {
+        <MemoryPressureEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl StringUser {
-    pub fn new() -> StringUser {
+impl MemoryPressureEvent {
+    pub fn new() -> MemoryPressureEvent {
         ::std::default::Default::default()
     }
 
-    // string uid = 1;
+    // string container_id = 1;
 
 
-    pub fn get_uid(&self) -> &str {
-        &self.uid
+    pub fn get_container_id(&self) -> &str {
+        &self.container_id
     }
-    pub fn clear_uid(&mut self) {
-        self.uid.clear();
+    pub fn clear_container_id(&mut self) {
+        self.container_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_uid(&mut self, v: ::std::string::String) {
-        self.uid = v;
+    pub fn set_container_id(&mut self, v: ::std::string::String) {
+        self.container_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_uid(&mut self) -> &mut ::std::string::String {
-        &mut self.uid
+    pub fn mut_container_id(&mut self) -> &mut ::std::string::String {
+        &mut self.container_id
     }
 
     // Take field
-    pub fn take_uid(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.uid, ::std::string::String::new())
+    pub fn take_container_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.container_id, ::std::string::String::new())
     }
 
-    // string gid = 2;
+    // string level = 2;
 
 
-    pub fn get_gid(&self) -> &str {
-        &self.gid
+    pub fn get_level(&self) -> &str {
+        &self.level
     }
-    pub fn clear_gid(&mut self) {
-        self.gid.clear();
+    pub fn clear_level(&mut self) {
+        self.level.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_gid(&mut self, v: ::std::string::String) {
-        self.gid = v;
+    pub fn set_level(&mut self, v: ::std::string::String) {
+        self.level = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_gid(&mut self) -> &mut ::std::string::String {
-        &mut self.gid
-    }
-
-    // Take field
-    pub fn take_gid(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.gid, ::std::string::String::new())
-    }
-
-    // repeated string additionalGids = 3;
-
-
-    pub fn get_additionalGids(&self) -> &[::std::string::String] {
-        &self.additionalGids
-    }
-    pub fn clear_additionalGids(&mut self) {
-        self.additionalGids.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_additionalGids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.additionalGids = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_additionalGids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.additionalGids
+    pub fn mut_level(&mut self) -> &mut ::std::string::String {
+        &mut self.level
     }
 
     // Take field
-    pub fn take_additionalGids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.additionalGids, ::protobuf::RepeatedField::new())
+    pub fn take_level(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.level, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for StringUser {
+impl ::protobuf::Message for MemoryPressureEvent {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -11823,13 +15965,10 @@ impl ::protobuf::Message for StringUser {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.uid)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gid)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.additionalGids)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.level)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -11843,30 +15982,24 @@ impl ::protobuf::Message for StringUser {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.uid.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.uid);
+        if !self.container_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.container_id);
         }
-        if !self.gid.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.gid);
+        if !self.level.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.level);
         }
-        for value in &self.additionalGids {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.uid.is_empty() {
-            os.write_string(1, &self.uid)?;
+        if !self.container_id.is_empty() {
+            os.write_string(1, &self.container_id)?;
         }
-        if !self.gid.is_empty() {
-            os.write_string(2, &self.gid)?;
+        if !self.level.is_empty() {
+            os.write_string(2, &self.level)?;
         }
-        for v in &self.additionalGids {
-            os.write_string(3, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -11897,8 +16030,8 @@ impl ::protobuf::Message for StringUser {
         Self::descriptor_static()
     }
 
-    fn new() -> StringUser {
-        StringUser::new()
+    fn new() -> MemoryPressureEvent {
+        MemoryPressureEvent::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -11910,22 +16043,17 @@ impl ::protobuf::Message for StringUser {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "uid",
-                    |m: &StringUser| { &m.uid },
-                    |m: &mut StringUser| { &mut m.uid },
+                    "container_id",
+                    |m: &MemoryPressureEvent| { &m.container_id },
+                    |m: &mut MemoryPressureEvent| { &mut m.container_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "gid",
-                    |m: &StringUser| { &m.gid },
-                    |m: &mut StringUser| { &mut m.gid },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "additionalGids",
-                    |m: &StringUser| { &m.additionalGids },
-                    |m: &mut StringUser| { &mut m.additionalGids },
+                    "level",
+                    |m: &MemoryPressureEvent| { &m.level },
+                    |m: &mut MemoryPressureEvent| { &mut m.level },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<StringUser>(
-                    "StringUser",
+                ::protobuf::reflect::MessageDescriptor::new::<MemoryPressureEvent>(
+                    "MemoryPressureEvent",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11933,209 +16061,211 @@ impl ::protobuf::Message for StringUser {
         }
     }
 
-    fn default_instance() -> &'static StringUser {
-        static mut instance: ::protobuf::lazy::Lazy<StringUser> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static MemoryPressureEvent {
+        static mut instance: ::protobuf::lazy::Lazy<MemoryPressureEvent> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const StringUser,
+            ptr: 0 as *const MemoryPressureEvent,
         };
         unsafe {
-            instance.get(StringUser::new)
+            instance.get(MemoryPressureEvent::new)
         }
     }
 }
 
-impl ::protobuf::Clear for StringUser {
+impl ::protobuf::Clear for MemoryPressureEvent {
     fn clear(&mut self) {
-        self.uid.clear();
-        self.gid.clear();
-        self.additionalGids.clear();
+        self.container_id.clear();
+        self.level.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for StringUser {
+impl ::std::fmt::Debug for MemoryPressureEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StringUser {
+impl ::protobuf::reflect::ProtobufValue for MemoryPressureEvent {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CopyFileRequest {
-    // message fields
-    pub path: ::std::string::String,
-    pub file_size: i64,
-    pub file_mode: u32,
-    pub dir_mode: u32,
-    pub uid: i32,
-    pub gid: i32,
-    pub offset: i64,
-    pub data: ::std::vec::Vec<u8>,
+pub struct GetMetricsRequest {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CopyFileRequest {
-    fn default() -> &'a CopyFileRequest {
-        <CopyFileRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GetMetricsRequest {
+    fn default() -> &'a GetMetricsRequest {
+        <GetMetricsRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CopyFileRequest {
-    pub fn new() -> CopyFileRequest {
+impl GetMetricsRequest {
+    pub fn new() -> GetMetricsRequest {
         ::std::default::Default::default()
     }
+}
 
-    // string path = 1;
-
-
-    pub fn get_path(&self) -> &str {
-        &self.path
-    }
-    pub fn clear_path(&mut self) {
-        self.path.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_path(&mut self, v: ::std::string::String) {
-        self.path = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_path(&mut self) -> &mut ::std::string::String {
-        &mut self.path
-    }
-
-    // Take field
-    pub fn take_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.path, ::std::string::String::new())
-    }
-
-    // int64 file_size = 2;
-
-
-    pub fn get_file_size(&self) -> i64 {
-        self.file_size
-    }
-    pub fn clear_file_size(&mut self) {
-        self.file_size = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_file_size(&mut self, v: i64) {
-        self.file_size = v;
+impl ::protobuf::Message for GetMetricsRequest {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    // uint32 file_mode = 3;
-
-
-    pub fn get_file_mode(&self) -> u32 {
-        self.file_mode
-    }
-    pub fn clear_file_mode(&mut self) {
-        self.file_mode = 0;
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_file_mode(&mut self, v: u32) {
-        self.file_mode = v;
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // uint32 dir_mode = 4;
-
-
-    pub fn get_dir_mode(&self) -> u32 {
-        self.dir_mode
-    }
-    pub fn clear_dir_mode(&mut self) {
-        self.dir_mode = 0;
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_dir_mode(&mut self, v: u32) {
-        self.dir_mode = v;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // int32 uid = 5;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
 
-    pub fn get_uid(&self) -> i32 {
-        self.uid
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-    pub fn clear_uid(&mut self) {
-        self.uid = 0;
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // Param is passed by value, moved
-    pub fn set_uid(&mut self, v: i32) {
-        self.uid = v;
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // int32 gid = 6;
+    fn new() -> GetMetricsRequest {
+        GetMetricsRequest::new()
+    }
 
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<GetMetricsRequest>(
+                    "GetMetricsRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
 
-    pub fn get_gid(&self) -> i32 {
-        self.gid
+    fn default_instance() -> &'static GetMetricsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetMetricsRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GetMetricsRequest,
+        };
+        unsafe {
+            instance.get(GetMetricsRequest::new)
+        }
     }
-    pub fn clear_gid(&mut self) {
-        self.gid = 0;
+}
+
+impl ::protobuf::Clear for GetMetricsRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_gid(&mut self, v: i32) {
-        self.gid = v;
+impl ::std::fmt::Debug for GetMetricsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // int64 offset = 7;
+impl ::protobuf::reflect::ProtobufValue for GetMetricsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
 
+#[derive(PartialEq,Clone,Default)]
+pub struct Metrics {
+    // message fields
+    pub metrics: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_offset(&self) -> i64 {
-        self.offset
-    }
-    pub fn clear_offset(&mut self) {
-        self.offset = 0;
+impl<'a> ::std::default::Default for &'a Metrics {
+    fn default() -> &'a Metrics {
+        <Metrics as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_offset(&mut self, v: i64) {
-        self.offset = v;
+impl Metrics {
+    pub fn new() -> Metrics {
+        ::std::default::Default::default()
     }
 
-    // bytes data = 8;
+    // string metrics = 1;
 
 
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
+    pub fn get_metrics(&self) -> &str {
+        &self.metrics
     }
-    pub fn clear_data(&mut self) {
-        self.data.clear();
+    pub fn clear_metrics(&mut self) {
+        self.metrics.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
-        self.data = v;
+    pub fn set_metrics(&mut self, v: ::std::string::String) {
+        self.metrics = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.data
+    pub fn mut_metrics(&mut self) -> &mut ::std::string::String {
+        &mut self.metrics
     }
 
     // Take field
-    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    pub fn take_metrics(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.metrics, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for CopyFileRequest {
+impl ::protobuf::Message for Metrics {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -12145,52 +16275,7 @@ impl ::protobuf::Message for CopyFileRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.file_size = tmp;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.file_mode = tmp;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.dir_mode = tmp;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.uid = tmp;
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.gid = tmp;
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.offset = tmp;
-                },
-                8 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.metrics)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -12204,29 +16289,8 @@ impl ::protobuf::Message for CopyFileRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.path.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.path);
-        }
-        if self.file_size != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.file_size, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.file_mode != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.file_mode, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.dir_mode != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.dir_mode, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.uid != 0 {
-            my_size += ::protobuf::rt::value_size(5, self.uid, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.gid != 0 {
-            my_size += ::protobuf::rt::value_size(6, self.gid, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.offset != 0 {
-            my_size += ::protobuf::rt::value_size(7, self.offset, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if !self.data.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(8, &self.data);
+        if !self.metrics.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.metrics);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -12234,29 +16298,8 @@ impl ::protobuf::Message for CopyFileRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.path.is_empty() {
-            os.write_string(1, &self.path)?;
-        }
-        if self.file_size != 0 {
-            os.write_int64(2, self.file_size)?;
-        }
-        if self.file_mode != 0 {
-            os.write_uint32(3, self.file_mode)?;
-        }
-        if self.dir_mode != 0 {
-            os.write_uint32(4, self.dir_mode)?;
-        }
-        if self.uid != 0 {
-            os.write_int32(5, self.uid)?;
-        }
-        if self.gid != 0 {
-            os.write_int32(6, self.gid)?;
-        }
-        if self.offset != 0 {
-            os.write_int64(7, self.offset)?;
-        }
-        if !self.data.is_empty() {
-            os.write_bytes(8, &self.data)?;
+        if !self.metrics.is_empty() {
+            os.write_string(1, &self.metrics)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -12288,8 +16331,8 @@ impl ::protobuf::Message for CopyFileRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> CopyFileRequest {
-        CopyFileRequest::new()
+    fn new() -> Metrics {
+        Metrics::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -12301,47 +16344,12 @@ impl ::protobuf::Message for CopyFileRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "path",
-                    |m: &CopyFileRequest| { &m.path },
-                    |m: &mut CopyFileRequest| { &mut m.path },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "file_size",
-                    |m: &CopyFileRequest| { &m.file_size },
-                    |m: &mut CopyFileRequest| { &mut m.file_size },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "file_mode",
-                    |m: &CopyFileRequest| { &m.file_mode },
-                    |m: &mut CopyFileRequest| { &mut m.file_mode },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "dir_mode",
-                    |m: &CopyFileRequest| { &m.dir_mode },
-                    |m: &mut CopyFileRequest| { &mut m.dir_mode },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "uid",
-                    |m: &CopyFileRequest| { &m.uid },
-                    |m: &mut CopyFileRequest| { &mut m.uid },
+                    "metrics",
+                    |m: &Metrics| { &m.metrics },
+                    |m: &mut Metrics| { &mut m.metrics },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "gid",
-                    |m: &CopyFileRequest| { &m.gid },
-                    |m: &mut CopyFileRequest| { &mut m.gid },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "offset",
-                    |m: &CopyFileRequest| { &m.offset },
-                    |m: &mut CopyFileRequest| { &mut m.offset },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data",
-                    |m: &CopyFileRequest| { &m.data },
-                    |m: &mut CopyFileRequest| { &mut m.data },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CopyFileRequest>(
-                    "CopyFileRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<Metrics>(
+                    "Metrics",
                     fields,
                     file_descriptor_proto()
                 )
@@ -12349,38 +16357,31 @@ impl ::protobuf::Message for CopyFileRequest {
         }
     }
 
-    fn default_instance() -> &'static CopyFileRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CopyFileRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Metrics {
+        static mut instance: ::protobuf::lazy::Lazy<Metrics> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CopyFileRequest,
+            ptr: 0 as *const Metrics,
         };
         unsafe {
-            instance.get(CopyFileRequest::new)
+            instance.get(Metrics::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CopyFileRequest {
+impl ::protobuf::Clear for Metrics {
     fn clear(&mut self) {
-        self.path.clear();
-        self.file_size = 0;
-        self.file_mode = 0;
-        self.dir_mode = 0;
-        self.uid = 0;
-        self.gid = 0;
-        self.offset = 0;
-        self.data.clear();
+        self.metrics.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CopyFileRequest {
+impl ::std::fmt::Debug for Metrics {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CopyFileRequest {
+impl ::protobuf::reflect::ProtobufValue for Metrics {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -12640,1017 +16641,1233 @@ impl ::protobuf::reflect::ProtobufValue for StopTracingRequest {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0bagent.proto\x12\x04grpc\x1a\toci.proto\x1a6github.com/kata-contain\
-    ers/agent/pkg/types/types.proto\x1a\x1bgoogle/protobuf/empty.proto\"\x9d\
-    \x02\n\x16CreateContainerRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\
+    ers/agent/pkg/types/types.proto\x1a\x1bgoogle/protobuf/empty.proto\"\x93\
+    \x03\n\x16CreateContainerRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\
     \tR\x0bcontainerId\x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06execId\
     \x121\n\x0bstring_user\x18\x03\x20\x01(\x0b2\x10.grpc.StringUserR\nstrin\
     gUser\x12&\n\x07devices\x18\x04\x20\x03(\x0b2\x0c.grpc.DeviceR\x07device\
     s\x12)\n\x08storages\x18\x05\x20\x03(\x0b2\r.grpc.StorageR\x08storages\
     \x12\x1c\n\x03OCI\x18\x06\x20\x01(\x0b2\n.grpc.SpecR\x03OCI\x12#\n\rsand\
-    box_pidns\x18\x07\x20\x01(\x08R\x0csandboxPidns\":\n\x15StartContainerRe\
+    box_pidns\x18\x07\x20\x01(\x08R\x0csandboxPidns\x12,\n\x12output_buffer_\
+    size\x18\x08\x20\x01(\rR\x10outputBufferSize\x12F\n\x0fmemory_pressure\
+    \x18\t\x20\x03(\x0b2\x1d.grpc.MemoryPressureThresholdR\x0ememoryPressure\
+    \"g\n\x17MemoryPressureThreshold\x12\x14\n\x05level\x18\x01\x20\x01(\tR\
+    \x05level\x12\x19\n\x08stall_us\x18\x02\x20\x01(\x04R\x07stallUs\x12\x1b\
+    \n\twindow_us\x18\x03\x20\x01(\x04R\x08windowUs\":\n\x15StartContainerRe\
     quest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\"U\n\x16\
     RemoveContainerRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcon\
-    tainerId\x12\x18\n\x07timeout\x18\x02\x20\x01(\rR\x07timeout\"\xac\x01\n\
+    tainerId\x12\x18\n\x07timeout\x18\x02\x20\x01(\rR\x07timeout\"\xda\x01\n\
     \x12ExecProcessRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcon\
     tainerId\x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06execId\x121\n\x0bst\
     ring_user\x18\x03\x20\x01(\x0b2\x10.grpc.StringUserR\nstringUser\x12'\n\
-    \x07process\x18\x04\x20\x01(\x0b2\r.grpc.ProcessR\x07process\"j\n\x14Sig\
-    nalProcessRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontaine\
-    rId\x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06execId\x12\x16\n\x06sign\
-    al\x18\x03\x20\x01(\rR\x06signal\"P\n\x12WaitProcessRequest\x12!\n\x0cco\
-    ntainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\x07exec_id\x18\
-    \x02\x20\x01(\tR\x06execId\"-\n\x13WaitProcessResponse\x12\x16\n\x06stat\
-    us\x18\x01\x20\x01(\x05R\x06status\"e\n\x14ListProcessesRequest\x12!\n\
-    \x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x16\n\x06format\
-    \x18\x02\x20\x01(\tR\x06format\x12\x12\n\x04args\x18\x03\x20\x03(\tR\x04\
-    args\":\n\x15ListProcessesResponse\x12!\n\x0cprocess_list\x18\x01\x20\
-    \x01(\x0cR\x0bprocessList\"o\n\x16UpdateContainerRequest\x12!\n\x0cconta\
-    iner_id\x18\x01\x20\x01(\tR\x0bcontainerId\x122\n\tresources\x18\x02\x20\
-    \x01(\x0b2\x14.grpc.LinuxResourcesR\tresources\":\n\x15StatsContainerReq\
-    uest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\":\n\x15P\
-    auseContainerRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bconta\
-    inerId\";\n\x16ResumeContainerRequest\x12!\n\x0ccontainer_id\x18\x01\x20\
-    \x01(\tR\x0bcontainerId\"\xaa\x01\n\x08CpuUsage\x12\x1f\n\x0btotal_usage\
-    \x18\x01\x20\x01(\x04R\ntotalUsage\x12!\n\x0cpercpu_usage\x18\x02\x20\
-    \x03(\x04R\x0bpercpuUsage\x12.\n\x13usage_in_kernelmode\x18\x03\x20\x01(\
-    \x04R\x11usageInKernelmode\x12*\n\x11usage_in_usermode\x18\x04\x20\x01(\
-    \x04R\x0fusageInUsermode\"~\n\x0eThrottlingData\x12\x18\n\x07periods\x18\
-    \x01\x20\x01(\x04R\x07periods\x12+\n\x11throttled_periods\x18\x02\x20\
-    \x01(\x04R\x10throttledPeriods\x12%\n\x0ethrottled_time\x18\x03\x20\x01(\
-    \x04R\rthrottledTime\"v\n\x08CpuStats\x12+\n\tcpu_usage\x18\x01\x20\x01(\
-    \x0b2\x0e.grpc.CpuUsageR\x08cpuUsage\x12=\n\x0fthrottling_data\x18\x02\
-    \x20\x01(\x0b2\x14.grpc.ThrottlingDataR\x0ethrottlingData\";\n\tPidsStat\
-    s\x12\x18\n\x07current\x18\x01\x20\x01(\x04R\x07current\x12\x14\n\x05lim\
-    it\x18\x02\x20\x01(\x04R\x05limit\"o\n\nMemoryData\x12\x14\n\x05usage\
-    \x18\x01\x20\x01(\x04R\x05usage\x12\x1b\n\tmax_usage\x18\x02\x20\x01(\
-    \x04R\x08maxUsage\x12\x18\n\x07failcnt\x18\x03\x20\x01(\x04R\x07failcnt\
-    \x12\x14\n\x05limit\x18\x04\x20\x01(\x04R\x05limit\"\xc4\x02\n\x0bMemory\
-    Stats\x12\x14\n\x05cache\x18\x01\x20\x01(\x04R\x05cache\x12&\n\x05usage\
-    \x18\x02\x20\x01(\x0b2\x10.grpc.MemoryDataR\x05usage\x12/\n\nswap_usage\
-    \x18\x03\x20\x01(\x0b2\x10.grpc.MemoryDataR\tswapUsage\x123\n\x0ckernel_\
-    usage\x18\x04\x20\x01(\x0b2\x10.grpc.MemoryDataR\x0bkernelUsage\x12#\n\r\
-    use_hierarchy\x18\x05\x20\x01(\x08R\x0cuseHierarchy\x122\n\x05stats\x18\
-    \x06\x20\x03(\x0b2\x1c.grpc.MemoryStats.StatsEntryR\x05stats\x1a8\n\nSta\
-    tsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\
-    \x18\x02\x20\x01(\x04R\x05value:\x028\x01\"c\n\x0fBlkioStatsEntry\x12\
-    \x14\n\x05major\x18\x01\x20\x01(\x04R\x05major\x12\x14\n\x05minor\x18\
-    \x02\x20\x01(\x04R\x05minor\x12\x0e\n\x02op\x18\x03\x20\x01(\tR\x02op\
-    \x12\x14\n\x05value\x18\x04\x20\x01(\x04R\x05value\"\xde\x04\n\nBlkioSta\
-    ts\x12R\n\x1aio_service_bytes_recursive\x18\x01\x20\x03(\x0b2\x15.grpc.B\
-    lkioStatsEntryR\x17ioServiceBytesRecursive\x12I\n\x15io_serviced_recursi\
-    ve\x18\x02\x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\x13ioServicedRecursiv\
-    e\x12E\n\x13io_queued_recursive\x18\x03\x20\x03(\x0b2\x15.grpc.BlkioStat\
-    sEntryR\x11ioQueuedRecursive\x12P\n\x19io_service_time_recursive\x18\x04\
-    \x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\x16ioServiceTimeRecursive\x12J\
-    \n\x16io_wait_time_recursive\x18\x05\x20\x03(\x0b2\x15.grpc.BlkioStatsEn\
-    tryR\x13ioWaitTimeRecursive\x12E\n\x13io_merged_recursive\x18\x06\x20\
-    \x03(\x0b2\x15.grpc.BlkioStatsEntryR\x11ioMergedRecursive\x12A\n\x11io_t\
-    ime_recursive\x18\x07\x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\x0fioTimeR\
-    ecursive\x12B\n\x11sectors_recursive\x18\x08\x20\x03(\x0b2\x15.grpc.Blki\
-    oStatsEntryR\x10sectorsRecursive\"[\n\x0cHugetlbStats\x12\x14\n\x05usage\
-    \x18\x01\x20\x01(\x04R\x05usage\x12\x1b\n\tmax_usage\x18\x02\x20\x01(\
-    \x04R\x08maxUsage\x12\x18\n\x07failcnt\x18\x03\x20\x01(\x04R\x07failcnt\
-    \"\xf2\x02\n\x0bCgroupStats\x12+\n\tcpu_stats\x18\x01\x20\x01(\x0b2\x0e.\
-    grpc.CpuStatsR\x08cpuStats\x124\n\x0cmemory_stats\x18\x02\x20\x01(\x0b2\
-    \x11.grpc.MemoryStatsR\x0bmemoryStats\x12.\n\npids_stats\x18\x03\x20\x01\
-    (\x0b2\x0f.grpc.PidsStatsR\tpidsStats\x121\n\x0bblkio_stats\x18\x04\x20\
-    \x01(\x0b2\x10.grpc.BlkioStatsR\nblkioStats\x12H\n\rhugetlb_stats\x18\
-    \x05\x20\x03(\x0b2#.grpc.CgroupStats.HugetlbStatsEntryR\x0chugetlbStats\
-    \x1aS\n\x11HugetlbStatsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
-    \x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12.grpc.HugetlbStatsR\x05value:\
-    \x028\x01\"\x8e\x02\n\x0cNetworkStats\x12\x12\n\x04name\x18\x01\x20\x01(\
-    \tR\x04name\x12\x19\n\x08rx_bytes\x18\x02\x20\x01(\x04R\x07rxBytes\x12\
-    \x1d\n\nrx_packets\x18\x03\x20\x01(\x04R\trxPackets\x12\x1b\n\trx_errors\
-    \x18\x04\x20\x01(\x04R\x08rxErrors\x12\x1d\n\nrx_dropped\x18\x05\x20\x01\
-    (\x04R\trxDropped\x12\x19\n\x08tx_bytes\x18\x06\x20\x01(\x04R\x07txBytes\
-    \x12\x1d\n\ntx_packets\x18\x07\x20\x01(\x04R\ttxPackets\x12\x1b\n\ttx_er\
-    rors\x18\x08\x20\x01(\x04R\x08txErrors\x12\x1d\n\ntx_dropped\x18\t\x20\
-    \x01(\x04R\ttxDropped\"\x87\x01\n\x16StatsContainerResponse\x124\n\x0ccg\
-    roup_stats\x18\x01\x20\x01(\x0b2\x11.grpc.CgroupStatsR\x0bcgroupStats\
-    \x127\n\rnetwork_stats\x18\x02\x20\x03(\x0b2\x12.grpc.NetworkStatsR\x0cn\
-    etworkStats\"d\n\x12WriteStreamRequest\x12!\n\x0ccontainer_id\x18\x01\
-    \x20\x01(\tR\x0bcontainerId\x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06\
-    execId\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04data\"'\n\x13WriteStre\
-    amResponse\x12\x10\n\x03len\x18\x01\x20\x01(\rR\x03len\"a\n\x11ReadStrea\
-    mRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\
-    \x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06execId\x12\x10\n\x03len\x18\x03\
-    \x20\x01(\rR\x03len\"(\n\x12ReadStreamResponse\x12\x12\n\x04data\x18\x01\
-    \x20\x01(\x0cR\x04data\"O\n\x11CloseStdinRequest\x12!\n\x0ccontainer_id\
-    \x18\x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\x07exec_id\x18\x02\x20\x01\
-    (\tR\x06execId\"{\n\x13TtyWinResizeRequest\x12!\n\x0ccontainer_id\x18\
-    \x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\
-    \x06execId\x12\x10\n\x03row\x18\x03\x20\x01(\rR\x03row\x12\x16\n\x06colu\
-    mn\x18\x04\x20\x01(\rR\x06column\"\xdb\x01\n\x14CreateSandboxRequest\x12\
-    \x1a\n\x08hostname\x18\x01\x20\x01(\tR\x08hostname\x12\x10\n\x03dns\x18\
-    \x02\x20\x03(\tR\x03dns\x12)\n\x08storages\x18\x03\x20\x03(\x0b2\r.grpc.\
-    StorageR\x08storages\x12#\n\rsandbox_pidns\x18\x04\x20\x01(\x08R\x0csand\
-    boxPidns\x12\x1d\n\nsandbox_id\x18\x05\x20\x01(\tR\tsandboxId\x12&\n\x0f\
-    guest_hook_path\x18\x06\x20\x01(\tR\rguestHookPath\"\x17\n\x15DestroySan\
-    dboxRequest\">\n\nInterfaces\x120\n\nInterfaces\x18\x01\x20\x03(\x0b2\
-    \x10.types.InterfaceR\nInterfaces\".\n\x06Routes\x12$\n\x06Routes\x18\
-    \x01\x20\x03(\x0b2\x0c.types.RouteR\x06Routes\"H\n\x16UpdateInterfaceReq\
-    uest\x12.\n\tinterface\x18\x01\x20\x01(\x0b2\x10.types.InterfaceR\tinter\
-    face\";\n\x13UpdateRoutesRequest\x12$\n\x06routes\x18\x01\x20\x01(\x0b2\
-    \x0c.grpc.RoutesR\x06routes\"\x17\n\x15ListInterfacesRequest\"\x13\n\x11\
-    ListRoutesRequest\"]\n\x13OnlineCPUMemRequest\x12\x12\n\x04wait\x18\x01\
-    \x20\x01(\x08R\x04wait\x12\x17\n\x07nb_cpus\x18\x02\x20\x01(\rR\x06nbCpu\
-    s\x12\x19\n\x08cpu_only\x18\x03\x20\x01(\x08R\x07cpuOnly\",\n\x16ReseedR\
-    andomDevRequest\x12\x12\n\x04data\x18\x02\x20\x01(\x0cR\x04data\"\xc8\
-    \x01\n\x0cAgentDetails\x12\x18\n\x07version\x18\x01\x20\x01(\tR\x07versi\
-    on\x12\x1f\n\x0binit_daemon\x18\x02\x20\x01(\x08R\ninitDaemon\x12'\n\x0f\
-    device_handlers\x18\x03\x20\x03(\tR\x0edeviceHandlers\x12)\n\x10storage_\
-    handlers\x18\x04\x20\x03(\tR\x0fstorageHandlers\x12)\n\x10supports_secco\
-    mp\x18\x05\x20\x01(\x08R\x0fsupportsSeccomp\"g\n\x13GuestDetailsRequest\
-    \x12$\n\x0emem_block_size\x18\x01\x20\x01(\x08R\x0cmemBlockSize\x12*\n\
-    \x11mem_hotplug_probe\x18\x02\x20\x01(\x08R\x0fmemHotplugProbe\"\xbb\x01\
-    \n\x14GuestDetailsResponse\x12/\n\x14mem_block_size_bytes\x18\x01\x20\
-    \x01(\x04R\x11memBlockSizeBytes\x127\n\ragent_details\x18\x02\x20\x01(\
-    \x0b2\x12.grpc.AgentDetailsR\x0cagentDetails\x129\n\x19support_mem_hotpl\
-    ug_probe\x18\x03\x20\x01(\x08R\x16supportMemHotplugProbe\"L\n\x18MemHotp\
-    lugByProbeRequest\x120\n\x13memHotplugProbeAddr\x18\x01\x20\x03(\x04R\
-    \x13memHotplugProbeAddr\"?\n\x17SetGuestDateTimeRequest\x12\x10\n\x03Sec\
-    \x18\x01\x20\x01(\x03R\x03Sec\x12\x12\n\x04Usec\x18\x02\x20\x01(\x03R\
-    \x04Usec\"\xb3\x01\n\x07Storage\x12\x16\n\x06driver\x18\x01\x20\x01(\tR\
-    \x06driver\x12%\n\x0edriver_options\x18\x02\x20\x03(\tR\rdriverOptions\
-    \x12\x16\n\x06source\x18\x03\x20\x01(\tR\x06source\x12\x16\n\x06fstype\
-    \x18\x04\x20\x01(\tR\x06fstype\x12\x18\n\x07options\x18\x05\x20\x03(\tR\
-    \x07options\x12\x1f\n\x0bmount_point\x18\x06\x20\x01(\tR\nmountPoint\"\
-    \x86\x01\n\x06Device\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\
-    \x04type\x18\x02\x20\x01(\tR\x04type\x12\x17\n\x07vm_path\x18\x03\x20\
-    \x01(\tR\x06vmPath\x12%\n\x0econtainer_path\x18\x04\x20\x01(\tR\rcontain\
-    erPath\x12\x18\n\x07options\x18\x05\x20\x03(\tR\x07options\"X\n\nStringU\
-    ser\x12\x10\n\x03uid\x18\x01\x20\x01(\tR\x03uid\x12\x10\n\x03gid\x18\x02\
-    \x20\x01(\tR\x03gid\x12&\n\x0eadditionalGids\x18\x03\x20\x03(\tR\x0eaddi\
-    tionalGids\"\xca\x01\n\x0fCopyFileRequest\x12\x12\n\x04path\x18\x01\x20\
-    \x01(\tR\x04path\x12\x1b\n\tfile_size\x18\x02\x20\x01(\x03R\x08fileSize\
-    \x12\x1b\n\tfile_mode\x18\x03\x20\x01(\rR\x08fileMode\x12\x19\n\x08dir_m\
-    ode\x18\x04\x20\x01(\rR\x07dirMode\x12\x10\n\x03uid\x18\x05\x20\x01(\x05\
-    R\x03uid\x12\x10\n\x03gid\x18\x06\x20\x01(\x05R\x03gid\x12\x16\n\x06offs\
-    et\x18\x07\x20\x01(\x03R\x06offset\x12\x12\n\x04data\x18\x08\x20\x01(\
-    \x0cR\x04data\"\x15\n\x13StartTracingRequest\"\x14\n\x12StopTracingReque\
-    st2\x93\x10\n\x0cAgentService\x12G\n\x0fCreateContainer\x12\x1c.grpc.Cre\
-    ateContainerRequest\x1a\x16.google.protobuf.Empty\x12E\n\x0eStartContain\
-    er\x12\x1b.grpc.StartContainerRequest\x1a\x16.google.protobuf.Empty\x12G\
-    \n\x0fRemoveContainer\x12\x1c.grpc.RemoveContainerRequest\x1a\x16.google\
-    .protobuf.Empty\x12?\n\x0bExecProcess\x12\x18.grpc.ExecProcessRequest\
-    \x1a\x16.google.protobuf.Empty\x12C\n\rSignalProcess\x12\x1a.grpc.Signal\
-    ProcessRequest\x1a\x16.google.protobuf.Empty\x12B\n\x0bWaitProcess\x12\
-    \x18.grpc.WaitProcessRequest\x1a\x19.grpc.WaitProcessResponse\x12H\n\rLi\
-    stProcesses\x12\x1a.grpc.ListProcessesRequest\x1a\x1b.grpc.ListProcesses\
-    Response\x12G\n\x0fUpdateContainer\x12\x1c.grpc.UpdateContainerRequest\
-    \x1a\x16.google.protobuf.Empty\x12K\n\x0eStatsContainer\x12\x1b.grpc.Sta\
-    tsContainerRequest\x1a\x1c.grpc.StatsContainerResponse\x12E\n\x0ePauseCo\
-    ntainer\x12\x1b.grpc.PauseContainerRequest\x1a\x16.google.protobuf.Empty\
-    \x12G\n\x0fResumeContainer\x12\x1c.grpc.ResumeContainerRequest\x1a\x16.g\
-    oogle.protobuf.Empty\x12A\n\nWriteStdin\x12\x18.grpc.WriteStreamRequest\
-    \x1a\x19.grpc.WriteStreamResponse\x12?\n\nReadStdout\x12\x17.grpc.ReadSt\
-    reamRequest\x1a\x18.grpc.ReadStreamResponse\x12?\n\nReadStderr\x12\x17.g\
-    rpc.ReadStreamRequest\x1a\x18.grpc.ReadStreamResponse\x12=\n\nCloseStdin\
-    \x12\x17.grpc.CloseStdinRequest\x1a\x16.google.protobuf.Empty\x12A\n\x0c\
-    TtyWinResize\x12\x19.grpc.TtyWinResizeRequest\x1a\x16.google.protobuf.Em\
-    pty\x12A\n\x0fUpdateInterface\x12\x1c.grpc.UpdateInterfaceRequest\x1a\
-    \x10.types.Interface\x127\n\x0cUpdateRoutes\x12\x19.grpc.UpdateRoutesReq\
-    uest\x1a\x0c.grpc.Routes\x12?\n\x0eListInterfaces\x12\x1b.grpc.ListInter\
-    facesRequest\x1a\x10.grpc.Interfaces\x123\n\nListRoutes\x12\x17.grpc.Lis\
-    tRoutesRequest\x1a\x0c.grpc.Routes\x12A\n\x0cStartTracing\x12\x19.grpc.S\
-    tartTracingRequest\x1a\x16.google.protobuf.Empty\x12?\n\x0bStopTracing\
-    \x12\x18.grpc.StopTracingRequest\x1a\x16.google.protobuf.Empty\x12C\n\rC\
-    reateSandbox\x12\x1a.grpc.CreateSandboxRequest\x1a\x16.google.protobuf.E\
-    mpty\x12E\n\x0eDestroySandbox\x12\x1b.grpc.DestroySandboxRequest\x1a\x16\
-    .google.protobuf.Empty\x12A\n\x0cOnlineCPUMem\x12\x19.grpc.OnlineCPUMemR\
-    equest\x1a\x16.google.protobuf.Empty\x12G\n\x0fReseedRandomDev\x12\x1c.g\
-    rpc.ReseedRandomDevRequest\x1a\x16.google.protobuf.Empty\x12H\n\x0fGetGu\
-    estDetails\x12\x19.grpc.GuestDetailsRequest\x1a\x1a.grpc.GuestDetailsRes\
-    ponse\x12K\n\x11MemHotplugByProbe\x12\x1e.grpc.MemHotplugByProbeRequest\
-    \x1a\x16.google.protobuf.Empty\x12I\n\x10SetGuestDateTime\x12\x1d.grpc.S\
-    etGuestDateTimeRequest\x1a\x16.google.protobuf.Empty\x129\n\x08CopyFile\
-    \x12\x15.grpc.CopyFileRequest\x1a\x16.google.protobuf.EmptyJ\xe1\xa4\x01\
-    \n\x07\x12\x05\x06\0\xe5\x03\x01\nO\n\x01\x0c\x12\x03\x06\0\x122E\n\x20C\
-    opyright\x202017\x20HyperHQ\x20Inc.\n\n\x20SPDX-License-Identifier:\x20A\
-    pache-2.0\n\n\n\x08\n\x01\x02\x12\x03\x08\0\r\n\t\n\x02\x03\0\x12\x03\n\
-    \0\x13\n\t\n\x02\x03\x01\x12\x03\x0b\0@\n\t\n\x02\x03\x02\x12\x03\x0c\0%\
-    \n\x16\n\x02\x06\0\x12\x04\x0f\0>\x01\x1a\n\x20unstable\n\n\n\n\x03\x06\
-    \0\x01\x12\x03\x0f\x08\x14\n\x18\n\x04\x06\0\x02\0\x12\x03\x11\x08T\x1a\
-    \x0b\x20execution\n\n\x0c\n\x05\x06\0\x02\0\x01\x12\x03\x11\x0c\x1b\n\
-    \x0c\n\x05\x06\0\x02\0\x02\x12\x03\x11\x1c2\n\x0c\n\x05\x06\0\x02\0\x03\
-    \x12\x03\x11=R\n\x0b\n\x04\x06\0\x02\x01\x12\x03\x12\x08R\n\x0c\n\x05\
-    \x06\0\x02\x01\x01\x12\x03\x12\x0c\x1a\n\x0c\n\x05\x06\0\x02\x01\x02\x12\
-    \x03\x12\x1b0\n\x0c\n\x05\x06\0\x02\x01\x03\x12\x03\x12;P\n\x9c\x03\n\
-    \x04\x06\0\x02\x02\x12\x03\x1a\x08T\x1a\x8e\x03\x20RemoveContainer\x20wi\
-    ll\x20tear\x20down\x20an\x20existing\x20container\x20by\x20forcibly\x20t\
-    erminating\n\x20all\x20processes\x20running\x20inside\x20that\x20contain\
-    er\x20and\x20releasing\x20all\x20internal\n\x20resources\x20associated\
-    \x20with\x20it.\n\x20RemoveContainer\x20will\x20wait\x20for\x20all\x20pr\
-    ocesses\x20termination\x20before\x20returning.\n\x20If\x20any\x20process\
-    \x20can\x20not\x20be\x20killed\x20or\x20if\x20it\x20can\x20not\x20be\x20\
-    killed\x20after\n\x20the\x20RemoveContainerRequest\x20timeout,\x20Remove\
-    Container\x20will\x20return\x20an\x20error.\n\n\x0c\n\x05\x06\0\x02\x02\
-    \x01\x12\x03\x1a\x0c\x1b\n\x0c\n\x05\x06\0\x02\x02\x02\x12\x03\x1a\x1c2\
-    \n\x0c\n\x05\x06\0\x02\x02\x03\x12\x03\x1a=R\n\x0b\n\x04\x06\0\x02\x03\
-    \x12\x03\x1b\x08L\n\x0c\n\x05\x06\0\x02\x03\x01\x12\x03\x1b\x0c\x17\n\
-    \x0c\n\x05\x06\0\x02\x03\x02\x12\x03\x1b\x18*\n\x0c\n\x05\x06\0\x02\x03\
-    \x03\x12\x03\x1b5J\n\x0b\n\x04\x06\0\x02\x04\x12\x03\x1c\x08P\n\x0c\n\
-    \x05\x06\0\x02\x04\x01\x12\x03\x1c\x0c\x19\n\x0c\n\x05\x06\0\x02\x04\x02\
-    \x12\x03\x1c\x1a.\n\x0c\n\x05\x06\0\x02\x04\x03\x12\x03\x1c9N\n*\n\x04\
-    \x06\0\x02\x05\x12\x03\x1d\x08J\"\x1d\x20wait\x20&\x20reap\x20like\x20wa\
-    itpid(2)\n\n\x0c\n\x05\x06\0\x02\x05\x01\x12\x03\x1d\x0c\x17\n\x0c\n\x05\
-    \x06\0\x02\x05\x02\x12\x03\x1d\x18*\n\x0c\n\x05\x06\0\x02\x05\x03\x12\
-    \x03\x1d5H\n\x0b\n\x04\x06\0\x02\x06\x12\x03\x1e\x08P\n\x0c\n\x05\x06\0\
-    \x02\x06\x01\x12\x03\x1e\x0c\x19\n\x0c\n\x05\x06\0\x02\x06\x02\x12\x03\
-    \x1e\x1a.\n\x0c\n\x05\x06\0\x02\x06\x03\x12\x03\x1e9N\n\x0b\n\x04\x06\0\
-    \x02\x07\x12\x03\x1f\x08T\n\x0c\n\x05\x06\0\x02\x07\x01\x12\x03\x1f\x0c\
-    \x1b\n\x0c\n\x05\x06\0\x02\x07\x02\x12\x03\x1f\x1c2\n\x0c\n\x05\x06\0\
-    \x02\x07\x03\x12\x03\x1f=R\n\x0b\n\x04\x06\0\x02\x08\x12\x03\x20\x08S\n\
-    \x0c\n\x05\x06\0\x02\x08\x01\x12\x03\x20\x0c\x1a\n\x0c\n\x05\x06\0\x02\
-    \x08\x02\x12\x03\x20\x1b0\n\x0c\n\x05\x06\0\x02\x08\x03\x12\x03\x20;Q\n\
-    \x0b\n\x04\x06\0\x02\t\x12\x03!\x08R\n\x0c\n\x05\x06\0\x02\t\x01\x12\x03\
-    !\x0c\x1a\n\x0c\n\x05\x06\0\x02\t\x02\x12\x03!\x1b0\n\x0c\n\x05\x06\0\
-    \x02\t\x03\x12\x03!;P\n\x0b\n\x04\x06\0\x02\n\x12\x03\"\x08T\n\x0c\n\x05\
-    \x06\0\x02\n\x01\x12\x03\"\x0c\x1b\n\x0c\n\x05\x06\0\x02\n\x02\x12\x03\"\
-    \x1c2\n\x0c\n\x05\x06\0\x02\n\x03\x12\x03\"=R\n\x14\n\x04\x06\0\x02\x0b\
-    \x12\x03%\x08I\x1a\x07\x20stdio\n\n\x0c\n\x05\x06\0\x02\x0b\x01\x12\x03%\
-    \x0c\x16\n\x0c\n\x05\x06\0\x02\x0b\x02\x12\x03%\x17)\n\x0c\n\x05\x06\0\
-    \x02\x0b\x03\x12\x03%4G\n\x0b\n\x04\x06\0\x02\x0c\x12\x03&\x08G\n\x0c\n\
-    \x05\x06\0\x02\x0c\x01\x12\x03&\x0c\x16\n\x0c\n\x05\x06\0\x02\x0c\x02\
-    \x12\x03&\x17(\n\x0c\n\x05\x06\0\x02\x0c\x03\x12\x03&3E\n\x0b\n\x04\x06\
-    \0\x02\r\x12\x03'\x08G\n\x0c\n\x05\x06\0\x02\r\x01\x12\x03'\x0c\x16\n\
-    \x0c\n\x05\x06\0\x02\r\x02\x12\x03'\x17(\n\x0c\n\x05\x06\0\x02\r\x03\x12\
-    \x03'3E\n\x0b\n\x04\x06\0\x02\x0e\x12\x03(\x08J\n\x0c\n\x05\x06\0\x02\
-    \x0e\x01\x12\x03(\x0c\x16\n\x0c\n\x05\x06\0\x02\x0e\x02\x12\x03(\x17(\n\
-    \x0c\n\x05\x06\0\x02\x0e\x03\x12\x03(3H\n\x0b\n\x04\x06\0\x02\x0f\x12\
-    \x03)\x08N\n\x0c\n\x05\x06\0\x02\x0f\x01\x12\x03)\x0c\x18\n\x0c\n\x05\
-    \x06\0\x02\x0f\x02\x12\x03)\x19,\n\x0c\n\x05\x06\0\x02\x0f\x03\x12\x03)7\
-    L\n\x19\n\x04\x06\0\x02\x10\x12\x03,\x08N\x1a\x0c\x20networking\n\n\x0c\
-    \n\x05\x06\0\x02\x10\x01\x12\x03,\x0c\x1b\n\x0c\n\x05\x06\0\x02\x10\x02\
-    \x12\x03,\x1c2\n\x0c\n\x05\x06\0\x02\x10\x03\x12\x03,=L\n\x0b\n\x04\x06\
-    \0\x02\x11\x12\x03-\x08?\n\x0c\n\x05\x06\0\x02\x11\x01\x12\x03-\x0c\x18\
-    \n\x0c\n\x05\x06\0\x02\x11\x02\x12\x03-\x19,\n\x0c\n\x05\x06\0\x02\x11\
-    \x03\x12\x03-7=\n\x0b\n\x04\x06\0\x02\x12\x12\x03.\x08F\n\x0c\n\x05\x06\
-    \0\x02\x12\x01\x12\x03.\x0c\x1a\n\x0c\n\x05\x06\0\x02\x12\x02\x12\x03.\
-    \x1b0\n\x0c\n\x05\x06\0\x02\x12\x03\x12\x03.:D\n\x0b\n\x04\x06\0\x02\x13\
-    \x12\x03/\x08;\n\x0c\n\x05\x06\0\x02\x13\x01\x12\x03/\x0c\x16\n\x0c\n\
-    \x05\x06\0\x02\x13\x02\x12\x03/\x17(\n\x0c\n\x05\x06\0\x02\x13\x03\x12\
-    \x03/39\n\x16\n\x04\x06\0\x02\x14\x12\x032\x08N\x1a\t\x20tracing\n\n\x0c\
-    \n\x05\x06\0\x02\x14\x01\x12\x032\x0c\x18\n\x0c\n\x05\x06\0\x02\x14\x02\
-    \x12\x032\x19,\n\x0c\n\x05\x06\0\x02\x14\x03\x12\x0327L\n\x0b\n\x04\x06\
-    \0\x02\x15\x12\x033\x08L\n\x0c\n\x05\x06\0\x02\x15\x01\x12\x033\x0c\x17\
-    \n\x0c\n\x05\x06\0\x02\x15\x02\x12\x033\x18*\n\x0c\n\x05\x06\0\x02\x15\
-    \x03\x12\x0335J\nH\n\x04\x06\0\x02\x16\x12\x036\x08P\x1a;\x20misc\x20(TO\
-    DO:\x20some\x20rpcs\x20can\x20be\x20replaced\x20by\x20hyperstart-exec)\n\
-    \n\x0c\n\x05\x06\0\x02\x16\x01\x12\x036\x0c\x19\n\x0c\n\x05\x06\0\x02\
-    \x16\x02\x12\x036\x1a.\n\x0c\n\x05\x06\0\x02\x16\x03\x12\x0369N\n\x0b\n\
-    \x04\x06\0\x02\x17\x12\x037\x08R\n\x0c\n\x05\x06\0\x02\x17\x01\x12\x037\
-    \x0c\x1a\n\x0c\n\x05\x06\0\x02\x17\x02\x12\x037\x1b0\n\x0c\n\x05\x06\0\
-    \x02\x17\x03\x12\x037;P\n\x0b\n\x04\x06\0\x02\x18\x12\x038\x08N\n\x0c\n\
-    \x05\x06\0\x02\x18\x01\x12\x038\x0c\x18\n\x0c\n\x05\x06\0\x02\x18\x02\
-    \x12\x038\x19,\n\x0c\n\x05\x06\0\x02\x18\x03\x12\x0387L\n\x0b\n\x04\x06\
-    \0\x02\x19\x12\x039\x08T\n\x0c\n\x05\x06\0\x02\x19\x01\x12\x039\x0c\x1b\
-    \n\x0c\n\x05\x06\0\x02\x19\x02\x12\x039\x1c2\n\x0c\n\x05\x06\0\x02\x19\
-    \x03\x12\x039=R\n\x0b\n\x04\x06\0\x02\x1a\x12\x03:\x08P\n\x0c\n\x05\x06\
-    \0\x02\x1a\x01\x12\x03:\x0c\x1b\n\x0c\n\x05\x06\0\x02\x1a\x02\x12\x03:\
-    \x1c/\n\x0c\n\x05\x06\0\x02\x1a\x03\x12\x03::N\n\x0b\n\x04\x06\0\x02\x1b\
-    \x12\x03;\x08X\n\x0c\n\x05\x06\0\x02\x1b\x01\x12\x03;\x0c\x1d\n\x0c\n\
-    \x05\x06\0\x02\x1b\x02\x12\x03;\x1e6\n\x0c\n\x05\x06\0\x02\x1b\x03\x12\
-    \x03;AV\n\x0b\n\x04\x06\0\x02\x1c\x12\x03<\x08V\n\x0c\n\x05\x06\0\x02\
-    \x1c\x01\x12\x03<\x0c\x1c\n\x0c\n\x05\x06\0\x02\x1c\x02\x12\x03<\x1d4\n\
-    \x0c\n\x05\x06\0\x02\x1c\x03\x12\x03<?T\n\x0b\n\x04\x06\0\x02\x1d\x12\
-    \x03=\x08F\n\x0c\n\x05\x06\0\x02\x1d\x01\x12\x03=\x0c\x14\n\x0c\n\x05\
-    \x06\0\x02\x1d\x02\x12\x03=\x15$\n\x0c\n\x05\x06\0\x02\x1d\x03\x12\x03=/\
-    D\n\n\n\x02\x04\0\x12\x04@\0N\x01\n\n\n\x03\x04\0\x01\x12\x03@\x08\x1e\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03A\x08\x20\n\r\n\x05\x04\0\x02\0\x04\x12\
-    \x04A\x08@\x20\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03A\x08\x0e\n\x0c\n\x05\
-    \x04\0\x02\0\x01\x12\x03A\x0f\x1b\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03A\
-    \x1e\x1f\n\x0b\n\x04\x04\0\x02\x01\x12\x03B\x08\x1b\n\r\n\x05\x04\0\x02\
-    \x01\x04\x12\x04B\x08A\x20\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03B\x08\
-    \x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03B\x0f\x16\n\x0c\n\x05\x04\0\
-    \x02\x01\x03\x12\x03B\x19\x1a\n\x0b\n\x04\x04\0\x02\x02\x12\x03C\x08#\n\
-    \r\n\x05\x04\0\x02\x02\x04\x12\x04C\x08B\x1b\n\x0c\n\x05\x04\0\x02\x02\
-    \x06\x12\x03C\x08\x12\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03C\x13\x1e\n\
-    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03C!\"\n\x0b\n\x04\x04\0\x02\x03\x12\
-    \x03D\x08$\n\x0c\n\x05\x04\0\x02\x03\x04\x12\x03D\x08\x10\n\x0c\n\x05\
-    \x04\0\x02\x03\x06\x12\x03D\x11\x17\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
-    \x03D\x18\x1f\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03D\"#\n\x0b\n\x04\x04\
-    \0\x02\x04\x12\x03E\x08&\n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03E\x08\x10\
-    \n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03E\x11\x18\n\x0c\n\x05\x04\0\x02\
-    \x04\x01\x12\x03E\x19!\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03E$%\n\x0b\n\
-    \x04\x04\0\x02\x05\x12\x03F\x08\x15\n\r\n\x05\x04\0\x02\x05\x04\x12\x04F\
-    \x08E&\n\x0c\n\x05\x04\0\x02\x05\x06\x12\x03F\x08\x0c\n\x0c\n\x05\x04\0\
-    \x02\x05\x01\x12\x03F\r\x10\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03F\x13\
-    \x14\n\xba\x02\n\x04\x04\0\x02\x06\x12\x03M\x08\x1f\x1a\xac\x02\x20This\
-    \x20field\x20is\x20used\x20to\x20indicate\x20if\x20the\x20container\x20n\
-    eeds\x20to\x20join\n\x20sandbox\x20shared\x20pid\x20ns\x20or\x20create\
-    \x20a\x20new\x20namespace.\x20This\x20field\x20is\n\x20meant\x20to\x20ov\
-    erride\x20the\x20NEWPID\x20config\x20settings\x20in\x20the\x20OCI\x20spe\
-    c.\n\x20The\x20agent\x20would\x20receive\x20an\x20OCI\x20spec\x20with\
-    \x20PID\x20namespace\x20cleared\n\x20out\x20altogether\x20and\x20not\x20\
-    just\x20the\x20pid\x20ns\x20path.\n\n\r\n\x05\x04\0\x02\x06\x04\x12\x04M\
-    \x08F\x15\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03M\x08\x0c\n\x0c\n\x05\x04\
-    \0\x02\x06\x01\x12\x03M\r\x1a\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03M\x1d\
-    \x1e\n\n\n\x02\x04\x01\x12\x04P\0R\x01\n\n\n\x03\x04\x01\x01\x12\x03P\
-    \x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03Q\x08\x20\n\r\n\x05\x04\x01\
-    \x02\0\x04\x12\x04Q\x08P\x1f\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03Q\x08\
-    \x0e\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03Q\x0f\x1b\n\x0c\n\x05\x04\x01\
-    \x02\0\x03\x12\x03Q\x1e\x1f\n\n\n\x02\x04\x02\x12\x04T\0]\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03T\x08\x1e\n\x0b\n\x04\x04\x02\x02\0\x12\x03U\x08\x20\
-    \n\r\n\x05\x04\x02\x02\0\x04\x12\x04U\x08T\x20\n\x0c\n\x05\x04\x02\x02\0\
-    \x05\x12\x03U\x08\x0e\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03U\x0f\x1b\n\
-    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03U\x1e\x1f\n\xbc\x01\n\x04\x04\x02\
-    \x02\x01\x12\x03\\\x08\x1b\x1a\xae\x01\x20RemoveContainer\x20will\x20ret\
-    urn\x20an\x20error\x20if\n\x20it\x20could\x20not\x20kill\x20some\x20cont\
-    ainer\x20processes\n\x20after\x20timeout\x20seconds.\n\x20Setting\x20tim\
-    eout\x20to\x200\x20means\x20RemoveContainer\x20will\n\x20wait\x20for\x20\
-    ever.\n\n\r\n\x05\x04\x02\x02\x01\x04\x12\x04\\\x08U\x20\n\x0c\n\x05\x04\
-    \x02\x02\x01\x05\x12\x03\\\x08\x0e\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
-    \x03\\\x0f\x16\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\\\x19\x1a\n\n\n\
-    \x02\x04\x03\x12\x04_\0d\x01\n\n\n\x03\x04\x03\x01\x12\x03_\x08\x1a\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x03`\x08\x20\n\r\n\x05\x04\x03\x02\0\x04\
-    \x12\x04`\x08_\x1c\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03`\x08\x0e\n\x0c\
-    \n\x05\x04\x03\x02\0\x01\x12\x03`\x0f\x1b\n\x0c\n\x05\x04\x03\x02\0\x03\
-    \x12\x03`\x1e\x1f\n\x0b\n\x04\x04\x03\x02\x01\x12\x03a\x08\x1b\n\r\n\x05\
-    \x04\x03\x02\x01\x04\x12\x04a\x08`\x20\n\x0c\n\x05\x04\x03\x02\x01\x05\
-    \x12\x03a\x08\x0e\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03a\x0f\x16\n\x0c\
-    \n\x05\x04\x03\x02\x01\x03\x12\x03a\x19\x1a\n\x0b\n\x04\x04\x03\x02\x02\
-    \x12\x03b\x08#\n\r\n\x05\x04\x03\x02\x02\x04\x12\x04b\x08a\x1b\n\x0c\n\
-    \x05\x04\x03\x02\x02\x06\x12\x03b\x08\x12\n\x0c\n\x05\x04\x03\x02\x02\
-    \x01\x12\x03b\x13\x1e\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03b!\"\n\x0b\
-    \n\x04\x04\x03\x02\x03\x12\x03c\x08\x1c\n\r\n\x05\x04\x03\x02\x03\x04\
-    \x12\x04c\x08b#\n\x0c\n\x05\x04\x03\x02\x03\x06\x12\x03c\x08\x0f\n\x0c\n\
-    \x05\x04\x03\x02\x03\x01\x12\x03c\x10\x17\n\x0c\n\x05\x04\x03\x02\x03\
-    \x03\x12\x03c\x1a\x1b\n\n\n\x02\x04\x04\x12\x04f\0n\x01\n\n\n\x03\x04\
-    \x04\x01\x12\x03f\x08\x1c\n\x0b\n\x04\x04\x04\x02\0\x12\x03g\x08\x20\n\r\
-    \n\x05\x04\x04\x02\0\x04\x12\x04g\x08f\x1e\n\x0c\n\x05\x04\x04\x02\0\x05\
-    \x12\x03g\x08\x0e\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03g\x0f\x1b\n\x0c\n\
-    \x05\x04\x04\x02\0\x03\x12\x03g\x1e\x1f\n\xe8\x01\n\x04\x04\x04\x02\x01\
-    \x12\x03l\x08\x1b\x1a\xda\x01\x20Special\x20case\x20for\x20SignalProcess\
-    ():\x20exec_id\x20can\x20be\x20empty(\"\"),\n\x20which\x20means\x20to\
-    \x20send\x20the\x20signal\x20to\x20all\x20the\x20processes\x20including\
-    \x20their\x20descendants.\n\x20Other\x20APIs\x20with\x20exec_id\x20shoul\
-    d\x20treat\x20empty\x20exec_id\x20as\x20an\x20invalid\x20request.\n\n\r\
-    \n\x05\x04\x04\x02\x01\x04\x12\x04l\x08g\x20\n\x0c\n\x05\x04\x04\x02\x01\
-    \x05\x12\x03l\x08\x0e\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03l\x0f\x16\n\
-    \x0c\n\x05\x04\x04\x02\x01\x03\x12\x03l\x19\x1a\n\x0b\n\x04\x04\x04\x02\
-    \x02\x12\x03m\x08\x1a\n\r\n\x05\x04\x04\x02\x02\x04\x12\x04m\x08l\x1b\n\
-    \x0c\n\x05\x04\x04\x02\x02\x05\x12\x03m\x08\x0e\n\x0c\n\x05\x04\x04\x02\
-    \x02\x01\x12\x03m\x0f\x15\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03m\x18\
-    \x19\n\n\n\x02\x04\x05\x12\x04p\0s\x01\n\n\n\x03\x04\x05\x01\x12\x03p\
-    \x08\x1a\n\x0b\n\x04\x04\x05\x02\0\x12\x03q\x08\x20\n\r\n\x05\x04\x05\
-    \x02\0\x04\x12\x04q\x08p\x1c\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03q\x08\
-    \x0e\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03q\x0f\x1b\n\x0c\n\x05\x04\x05\
-    \x02\0\x03\x12\x03q\x1e\x1f\n\x0b\n\x04\x04\x05\x02\x01\x12\x03r\x08\x1b\
-    \n\r\n\x05\x04\x05\x02\x01\x04\x12\x04r\x08q\x20\n\x0c\n\x05\x04\x05\x02\
-    \x01\x05\x12\x03r\x08\x0e\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03r\x0f\
-    \x16\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03r\x19\x1a\n\n\n\x02\x04\x06\
-    \x12\x04u\0w\x01\n\n\n\x03\x04\x06\x01\x12\x03u\x08\x1b\n\x0b\n\x04\x04\
-    \x06\x02\0\x12\x03v\x08\x19\n\r\n\x05\x04\x06\x02\0\x04\x12\x04v\x08u\
-    \x1d\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03v\x08\r\n\x0c\n\x05\x04\x06\
-    \x02\0\x01\x12\x03v\x0e\x14\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03v\x17\
-    \x18\nk\n\x02\x04\x07\x12\x04z\0~\x01\x1a_\x20ListProcessesRequest\x20co\
-    ntains\x20the\x20options\x20used\x20to\x20list\x20running\x20processes\
-    \x20inside\x20the\x20container\n\n\n\n\x03\x04\x07\x01\x12\x03z\x08\x1c\
-    \n\x0b\n\x04\x04\x07\x02\0\x12\x03{\x08\x20\n\r\n\x05\x04\x07\x02\0\x04\
-    \x12\x04{\x08z\x1e\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03{\x08\x0e\n\x0c\
-    \n\x05\x04\x07\x02\0\x01\x12\x03{\x0f\x1b\n\x0c\n\x05\x04\x07\x02\0\x03\
-    \x12\x03{\x1e\x1f\n\x0b\n\x04\x04\x07\x02\x01\x12\x03|\x08\x1a\n\r\n\x05\
-    \x04\x07\x02\x01\x04\x12\x04|\x08{\x20\n\x0c\n\x05\x04\x07\x02\x01\x05\
-    \x12\x03|\x08\x0e\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03|\x0f\x15\n\x0c\
-    \n\x05\x04\x07\x02\x01\x03\x12\x03|\x18\x19\n\x0b\n\x04\x04\x07\x02\x02\
-    \x12\x03}\x08!\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\x03}\x08\x10\n\x0c\n\
-    \x05\x04\x07\x02\x02\x05\x12\x03}\x11\x17\n\x0c\n\x05\x04\x07\x02\x02\
-    \x01\x12\x03}\x18\x1c\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03}\x1f\x20\n\
-    c\n\x02\x04\x08\x12\x06\x81\x01\0\x83\x01\x01\x1aU\x20ListProcessesRespo\
-    nse\x20represents\x20the\x20list\x20of\x20running\x20processes\x20inside\
-    \x20the\x20container\n\n\x0b\n\x03\x04\x08\x01\x12\x04\x81\x01\x08\x1d\n\
-    \x0c\n\x04\x04\x08\x02\0\x12\x04\x82\x01\x08\x1f\n\x0f\n\x05\x04\x08\x02\
-    \0\x04\x12\x06\x82\x01\x08\x81\x01\x1f\n\r\n\x05\x04\x08\x02\0\x05\x12\
-    \x04\x82\x01\x08\r\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\x82\x01\x0e\x1a\n\
-    \r\n\x05\x04\x08\x02\0\x03\x12\x04\x82\x01\x1d\x1e\n\x0c\n\x02\x04\t\x12\
-    \x06\x85\x01\0\x88\x01\x01\n\x0b\n\x03\x04\t\x01\x12\x04\x85\x01\x08\x1e\
-    \n\x0c\n\x04\x04\t\x02\0\x12\x04\x86\x01\x08\x20\n\x0f\n\x05\x04\t\x02\0\
-    \x04\x12\x06\x86\x01\x08\x85\x01\x20\n\r\n\x05\x04\t\x02\0\x05\x12\x04\
-    \x86\x01\x08\x0e\n\r\n\x05\x04\t\x02\0\x01\x12\x04\x86\x01\x0f\x1b\n\r\n\
-    \x05\x04\t\x02\0\x03\x12\x04\x86\x01\x1e\x1f\n\x0c\n\x04\x04\t\x02\x01\
-    \x12\x04\x87\x01\x08%\n\x0f\n\x05\x04\t\x02\x01\x04\x12\x06\x87\x01\x08\
-    \x86\x01\x20\n\r\n\x05\x04\t\x02\x01\x06\x12\x04\x87\x01\x08\x16\n\r\n\
-    \x05\x04\t\x02\x01\x01\x12\x04\x87\x01\x17\x20\n\r\n\x05\x04\t\x02\x01\
-    \x03\x12\x04\x87\x01#$\n\x0c\n\x02\x04\n\x12\x06\x8a\x01\0\x8c\x01\x01\n\
-    \x0b\n\x03\x04\n\x01\x12\x04\x8a\x01\x08\x1d\n\x0c\n\x04\x04\n\x02\0\x12\
-    \x04\x8b\x01\x04\x1c\n\x0f\n\x05\x04\n\x02\0\x04\x12\x06\x8b\x01\x04\x8a\
-    \x01\x1f\n\r\n\x05\x04\n\x02\0\x05\x12\x04\x8b\x01\x04\n\n\r\n\x05\x04\n\
-    \x02\0\x01\x12\x04\x8b\x01\x0b\x17\n\r\n\x05\x04\n\x02\0\x03\x12\x04\x8b\
-    \x01\x1a\x1b\n\x0c\n\x02\x04\x0b\x12\x06\x8e\x01\0\x90\x01\x01\n\x0b\n\
-    \x03\x04\x0b\x01\x12\x04\x8e\x01\x08\x1d\n\x0c\n\x04\x04\x0b\x02\0\x12\
-    \x04\x8f\x01\x04\x1c\n\x0f\n\x05\x04\x0b\x02\0\x04\x12\x06\x8f\x01\x04\
-    \x8e\x01\x1f\n\r\n\x05\x04\x0b\x02\0\x05\x12\x04\x8f\x01\x04\n\n\r\n\x05\
-    \x04\x0b\x02\0\x01\x12\x04\x8f\x01\x0b\x17\n\r\n\x05\x04\x0b\x02\0\x03\
-    \x12\x04\x8f\x01\x1a\x1b\n\x0c\n\x02\x04\x0c\x12\x06\x92\x01\0\x94\x01\
-    \x01\n\x0b\n\x03\x04\x0c\x01\x12\x04\x92\x01\x08\x1e\n\x0c\n\x04\x04\x0c\
-    \x02\0\x12\x04\x93\x01\x04\x1c\n\x0f\n\x05\x04\x0c\x02\0\x04\x12\x06\x93\
-    \x01\x04\x92\x01\x20\n\r\n\x05\x04\x0c\x02\0\x05\x12\x04\x93\x01\x04\n\n\
-    \r\n\x05\x04\x0c\x02\0\x01\x12\x04\x93\x01\x0b\x17\n\r\n\x05\x04\x0c\x02\
-    \0\x03\x12\x04\x93\x01\x1a\x1b\n\x0c\n\x02\x04\r\x12\x06\x96\x01\0\x9b\
-    \x01\x01\n\x0b\n\x03\x04\r\x01\x12\x04\x96\x01\x08\x10\n\x0c\n\x04\x04\r\
-    \x02\0\x12\x04\x97\x01\x08\x1f\n\x0f\n\x05\x04\r\x02\0\x04\x12\x06\x97\
-    \x01\x08\x96\x01\x12\n\r\n\x05\x04\r\x02\0\x05\x12\x04\x97\x01\x08\x0e\n\
-    \r\n\x05\x04\r\x02\0\x01\x12\x04\x97\x01\x0f\x1a\n\r\n\x05\x04\r\x02\0\
-    \x03\x12\x04\x97\x01\x1d\x1e\n\x0c\n\x04\x04\r\x02\x01\x12\x04\x98\x01\
-    \x08)\n\r\n\x05\x04\r\x02\x01\x04\x12\x04\x98\x01\x08\x10\n\r\n\x05\x04\
-    \r\x02\x01\x05\x12\x04\x98\x01\x11\x17\n\r\n\x05\x04\r\x02\x01\x01\x12\
-    \x04\x98\x01\x18$\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\x98\x01'(\n\x0c\n\
-    \x04\x04\r\x02\x02\x12\x04\x99\x01\x08'\n\x0f\n\x05\x04\r\x02\x02\x04\
-    \x12\x06\x99\x01\x08\x98\x01)\n\r\n\x05\x04\r\x02\x02\x05\x12\x04\x99\
-    \x01\x08\x0e\n\r\n\x05\x04\r\x02\x02\x01\x12\x04\x99\x01\x0f\"\n\r\n\x05\
-    \x04\r\x02\x02\x03\x12\x04\x99\x01%&\n\x0c\n\x04\x04\r\x02\x03\x12\x04\
-    \x9a\x01\x08%\n\x0f\n\x05\x04\r\x02\x03\x04\x12\x06\x9a\x01\x08\x99\x01'\
-    \n\r\n\x05\x04\r\x02\x03\x05\x12\x04\x9a\x01\x08\x0e\n\r\n\x05\x04\r\x02\
-    \x03\x01\x12\x04\x9a\x01\x0f\x20\n\r\n\x05\x04\r\x02\x03\x03\x12\x04\x9a\
-    \x01#$\n\x0c\n\x02\x04\x0e\x12\x06\x9d\x01\0\xa1\x01\x01\n\x0b\n\x03\x04\
-    \x0e\x01\x12\x04\x9d\x01\x08\x16\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\x9e\
-    \x01\x08\x1b\n\x0f\n\x05\x04\x0e\x02\0\x04\x12\x06\x9e\x01\x08\x9d\x01\
-    \x18\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\x9e\x01\x08\x0e\n\r\n\x05\x04\
-    \x0e\x02\0\x01\x12\x04\x9e\x01\x0f\x16\n\r\n\x05\x04\x0e\x02\0\x03\x12\
-    \x04\x9e\x01\x19\x1a\n\x0c\n\x04\x04\x0e\x02\x01\x12\x04\x9f\x01\x08%\n\
-    \x0f\n\x05\x04\x0e\x02\x01\x04\x12\x06\x9f\x01\x08\x9e\x01\x1b\n\r\n\x05\
-    \x04\x0e\x02\x01\x05\x12\x04\x9f\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\x01\
-    \x01\x12\x04\x9f\x01\x0f\x20\n\r\n\x05\x04\x0e\x02\x01\x03\x12\x04\x9f\
-    \x01#$\n\x0c\n\x04\x04\x0e\x02\x02\x12\x04\xa0\x01\x08\"\n\x0f\n\x05\x04\
-    \x0e\x02\x02\x04\x12\x06\xa0\x01\x08\x9f\x01%\n\r\n\x05\x04\x0e\x02\x02\
-    \x05\x12\x04\xa0\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\x02\x01\x12\x04\xa0\
-    \x01\x0f\x1d\n\r\n\x05\x04\x0e\x02\x02\x03\x12\x04\xa0\x01\x20!\n\x0c\n\
-    \x02\x04\x0f\x12\x06\xa3\x01\0\xa6\x01\x01\n\x0b\n\x03\x04\x0f\x01\x12\
-    \x04\xa3\x01\x08\x10\n\x0c\n\x04\x04\x0f\x02\0\x12\x04\xa4\x01\x08\x1f\n\
-    \x0f\n\x05\x04\x0f\x02\0\x04\x12\x06\xa4\x01\x08\xa3\x01\x12\n\r\n\x05\
-    \x04\x0f\x02\0\x06\x12\x04\xa4\x01\x08\x10\n\r\n\x05\x04\x0f\x02\0\x01\
-    \x12\x04\xa4\x01\x11\x1a\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xa4\x01\x1d\
-    \x1e\n\x0c\n\x04\x04\x0f\x02\x01\x12\x04\xa5\x01\x08+\n\x0f\n\x05\x04\
-    \x0f\x02\x01\x04\x12\x06\xa5\x01\x08\xa4\x01\x1f\n\r\n\x05\x04\x0f\x02\
-    \x01\x06\x12\x04\xa5\x01\x08\x16\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\
-    \xa5\x01\x17&\n\r\n\x05\x04\x0f\x02\x01\x03\x12\x04\xa5\x01)*\n\x0c\n\
-    \x02\x04\x10\x12\x06\xa8\x01\0\xab\x01\x01\n\x0b\n\x03\x04\x10\x01\x12\
-    \x04\xa8\x01\x08\x11\n\x0c\n\x04\x04\x10\x02\0\x12\x04\xa9\x01\x08\x1b\n\
-    \x0f\n\x05\x04\x10\x02\0\x04\x12\x06\xa9\x01\x08\xa8\x01\x13\n\r\n\x05\
-    \x04\x10\x02\0\x05\x12\x04\xa9\x01\x08\x0e\n\r\n\x05\x04\x10\x02\0\x01\
-    \x12\x04\xa9\x01\x0f\x16\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xa9\x01\x19\
-    \x1a\n\x0c\n\x04\x04\x10\x02\x01\x12\x04\xaa\x01\x08\x19\n\x0f\n\x05\x04\
-    \x10\x02\x01\x04\x12\x06\xaa\x01\x08\xa9\x01\x1b\n\r\n\x05\x04\x10\x02\
-    \x01\x05\x12\x04\xaa\x01\x08\x0e\n\r\n\x05\x04\x10\x02\x01\x01\x12\x04\
-    \xaa\x01\x0f\x14\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\xaa\x01\x17\x18\n\
-    \x0c\n\x02\x04\x11\x12\x06\xad\x01\0\xb2\x01\x01\n\x0b\n\x03\x04\x11\x01\
-    \x12\x04\xad\x01\x08\x12\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xae\x01\x08\
-    \x19\n\x0f\n\x05\x04\x11\x02\0\x04\x12\x06\xae\x01\x08\xad\x01\x14\n\r\n\
-    \x05\x04\x11\x02\0\x05\x12\x04\xae\x01\x08\x0e\n\r\n\x05\x04\x11\x02\0\
-    \x01\x12\x04\xae\x01\x0f\x14\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xae\x01\
-    \x17\x18\n\x0c\n\x04\x04\x11\x02\x01\x12\x04\xaf\x01\x08\x1d\n\x0f\n\x05\
-    \x04\x11\x02\x01\x04\x12\x06\xaf\x01\x08\xae\x01\x19\n\r\n\x05\x04\x11\
-    \x02\x01\x05\x12\x04\xaf\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x01\x01\x12\
-    \x04\xaf\x01\x0f\x18\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xaf\x01\x1b\
-    \x1c\n\x0c\n\x04\x04\x11\x02\x02\x12\x04\xb0\x01\x08\x1b\n\x0f\n\x05\x04\
-    \x11\x02\x02\x04\x12\x06\xb0\x01\x08\xaf\x01\x1d\n\r\n\x05\x04\x11\x02\
-    \x02\x05\x12\x04\xb0\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x02\x01\x12\x04\
-    \xb0\x01\x0f\x16\n\r\n\x05\x04\x11\x02\x02\x03\x12\x04\xb0\x01\x19\x1a\n\
-    \x0c\n\x04\x04\x11\x02\x03\x12\x04\xb1\x01\x08\x19\n\x0f\n\x05\x04\x11\
-    \x02\x03\x04\x12\x06\xb1\x01\x08\xb0\x01\x1b\n\r\n\x05\x04\x11\x02\x03\
-    \x05\x12\x04\xb1\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x03\x01\x12\x04\xb1\
-    \x01\x0f\x14\n\r\n\x05\x04\x11\x02\x03\x03\x12\x04\xb1\x01\x17\x18\n\x0c\
-    \n\x02\x04\x12\x12\x06\xb4\x01\0\xbb\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\
-    \x04\xb4\x01\x08\x13\n\x0c\n\x04\x04\x12\x02\0\x12\x04\xb5\x01\x08\x19\n\
-    \x0f\n\x05\x04\x12\x02\0\x04\x12\x06\xb5\x01\x08\xb4\x01\x15\n\r\n\x05\
-    \x04\x12\x02\0\x05\x12\x04\xb5\x01\x08\x0e\n\r\n\x05\x04\x12\x02\0\x01\
-    \x12\x04\xb5\x01\x0f\x14\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\xb5\x01\x17\
-    \x18\n\x0c\n\x04\x04\x12\x02\x01\x12\x04\xb6\x01\x08\x1d\n\x0f\n\x05\x04\
-    \x12\x02\x01\x04\x12\x06\xb6\x01\x08\xb5\x01\x19\n\r\n\x05\x04\x12\x02\
-    \x01\x06\x12\x04\xb6\x01\x08\x12\n\r\n\x05\x04\x12\x02\x01\x01\x12\x04\
-    \xb6\x01\x13\x18\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\xb6\x01\x1b\x1c\n\
-    \x0c\n\x04\x04\x12\x02\x02\x12\x04\xb7\x01\x08\"\n\x0f\n\x05\x04\x12\x02\
-    \x02\x04\x12\x06\xb7\x01\x08\xb6\x01\x1d\n\r\n\x05\x04\x12\x02\x02\x06\
-    \x12\x04\xb7\x01\x08\x12\n\r\n\x05\x04\x12\x02\x02\x01\x12\x04\xb7\x01\
-    \x13\x1d\n\r\n\x05\x04\x12\x02\x02\x03\x12\x04\xb7\x01\x20!\n\x0c\n\x04\
-    \x04\x12\x02\x03\x12\x04\xb8\x01\x08$\n\x0f\n\x05\x04\x12\x02\x03\x04\
-    \x12\x06\xb8\x01\x08\xb7\x01\"\n\r\n\x05\x04\x12\x02\x03\x06\x12\x04\xb8\
-    \x01\x08\x12\n\r\n\x05\x04\x12\x02\x03\x01\x12\x04\xb8\x01\x13\x1f\n\r\n\
-    \x05\x04\x12\x02\x03\x03\x12\x04\xb8\x01\"#\n\x0c\n\x04\x04\x12\x02\x04\
-    \x12\x04\xb9\x01\x08\x1f\n\x0f\n\x05\x04\x12\x02\x04\x04\x12\x06\xb9\x01\
-    \x08\xb8\x01$\n\r\n\x05\x04\x12\x02\x04\x05\x12\x04\xb9\x01\x08\x0c\n\r\
-    \n\x05\x04\x12\x02\x04\x01\x12\x04\xb9\x01\r\x1a\n\r\n\x05\x04\x12\x02\
-    \x04\x03\x12\x04\xb9\x01\x1d\x1e\n\x0c\n\x04\x04\x12\x02\x05\x12\x04\xba\
-    \x01\x08&\n\x0f\n\x05\x04\x12\x02\x05\x04\x12\x06\xba\x01\x08\xb9\x01\
-    \x1f\n\r\n\x05\x04\x12\x02\x05\x06\x12\x04\xba\x01\x08\x1b\n\r\n\x05\x04\
-    \x12\x02\x05\x01\x12\x04\xba\x01\x1c!\n\r\n\x05\x04\x12\x02\x05\x03\x12\
-    \x04\xba\x01$%\n\x0c\n\x02\x04\x13\x12\x06\xbe\x01\0\xc3\x01\x01\n\x0b\n\
-    \x03\x04\x13\x01\x12\x04\xbe\x01\x08\x17\n\x0c\n\x04\x04\x13\x02\0\x12\
-    \x04\xbf\x01\x08\x19\n\x0f\n\x05\x04\x13\x02\0\x04\x12\x06\xbf\x01\x08\
-    \xbe\x01\x19\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\xbf\x01\x08\x0e\n\r\n\
-    \x05\x04\x13\x02\0\x01\x12\x04\xbf\x01\x0f\x14\n\r\n\x05\x04\x13\x02\0\
-    \x03\x12\x04\xbf\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\xc0\x01\
-    \x08\x19\n\x0f\n\x05\x04\x13\x02\x01\x04\x12\x06\xc0\x01\x08\xbf\x01\x19\
-    \n\r\n\x05\x04\x13\x02\x01\x05\x12\x04\xc0\x01\x08\x0e\n\r\n\x05\x04\x13\
-    \x02\x01\x01\x12\x04\xc0\x01\x0f\x14\n\r\n\x05\x04\x13\x02\x01\x03\x12\
-    \x04\xc0\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\xc1\x01\x08\x16\
-    \n\x0f\n\x05\x04\x13\x02\x02\x04\x12\x06\xc1\x01\x08\xc0\x01\x19\n\r\n\
-    \x05\x04\x13\x02\x02\x05\x12\x04\xc1\x01\x08\x0e\n\r\n\x05\x04\x13\x02\
-    \x02\x01\x12\x04\xc1\x01\x0f\x11\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\
-    \xc1\x01\x14\x15\n\x0c\n\x04\x04\x13\x02\x03\x12\x04\xc2\x01\x08\x19\n\
-    \x0f\n\x05\x04\x13\x02\x03\x04\x12\x06\xc2\x01\x08\xc1\x01\x16\n\r\n\x05\
-    \x04\x13\x02\x03\x05\x12\x04\xc2\x01\x08\x0e\n\r\n\x05\x04\x13\x02\x03\
-    \x01\x12\x04\xc2\x01\x0f\x14\n\r\n\x05\x04\x13\x02\x03\x03\x12\x04\xc2\
-    \x01\x17\x18\n\x0c\n\x02\x04\x14\x12\x06\xc5\x01\0\xce\x01\x01\n\x0b\n\
-    \x03\x04\x14\x01\x12\x04\xc5\x01\x08\x12\nH\n\x04\x04\x14\x02\0\x12\x04\
-    \xc6\x01\x08@\":\x20number\x20of\x20bytes\x20transferred\x20to\x20and\
-    \x20from\x20the\x20block\x20device\n\n\r\n\x05\x04\x14\x02\0\x04\x12\x04\
-    \xc6\x01\x08\x10\n\r\n\x05\x04\x14\x02\0\x06\x12\x04\xc6\x01\x11\x20\n\r\
-    \n\x05\x04\x14\x02\0\x01\x12\x04\xc6\x01!;\n\r\n\x05\x04\x14\x02\0\x03\
-    \x12\x04\xc6\x01>?\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\xc7\x01\x08;\n\r\
-    \n\x05\x04\x14\x02\x01\x04\x12\x04\xc7\x01\x08\x10\n\r\n\x05\x04\x14\x02\
-    \x01\x06\x12\x04\xc7\x01\x11\x20\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\
-    \xc7\x01!6\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\xc7\x019:\n\x0c\n\x04\
-    \x04\x14\x02\x02\x12\x04\xc8\x01\x089\n\r\n\x05\x04\x14\x02\x02\x04\x12\
-    \x04\xc8\x01\x08\x10\n\r\n\x05\x04\x14\x02\x02\x06\x12\x04\xc8\x01\x11\
-    \x20\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\xc8\x01!4\n\r\n\x05\x04\x14\
-    \x02\x02\x03\x12\x04\xc8\x0178\n\x0c\n\x04\x04\x14\x02\x03\x12\x04\xc9\
-    \x01\x08?\n\r\n\x05\x04\x14\x02\x03\x04\x12\x04\xc9\x01\x08\x10\n\r\n\
-    \x05\x04\x14\x02\x03\x06\x12\x04\xc9\x01\x11\x20\n\r\n\x05\x04\x14\x02\
-    \x03\x01\x12\x04\xc9\x01!:\n\r\n\x05\x04\x14\x02\x03\x03\x12\x04\xc9\x01\
-    =>\n\x0c\n\x04\x04\x14\x02\x04\x12\x04\xca\x01\x08<\n\r\n\x05\x04\x14\
-    \x02\x04\x04\x12\x04\xca\x01\x08\x10\n\r\n\x05\x04\x14\x02\x04\x06\x12\
-    \x04\xca\x01\x11\x20\n\r\n\x05\x04\x14\x02\x04\x01\x12\x04\xca\x01!7\n\r\
-    \n\x05\x04\x14\x02\x04\x03\x12\x04\xca\x01:;\n\x0c\n\x04\x04\x14\x02\x05\
-    \x12\x04\xcb\x01\x089\n\r\n\x05\x04\x14\x02\x05\x04\x12\x04\xcb\x01\x08\
-    \x10\n\r\n\x05\x04\x14\x02\x05\x06\x12\x04\xcb\x01\x11\x20\n\r\n\x05\x04\
-    \x14\x02\x05\x01\x12\x04\xcb\x01!4\n\r\n\x05\x04\x14\x02\x05\x03\x12\x04\
-    \xcb\x0178\n\x0c\n\x04\x04\x14\x02\x06\x12\x04\xcc\x01\x087\n\r\n\x05\
-    \x04\x14\x02\x06\x04\x12\x04\xcc\x01\x08\x10\n\r\n\x05\x04\x14\x02\x06\
-    \x06\x12\x04\xcc\x01\x11\x20\n\r\n\x05\x04\x14\x02\x06\x01\x12\x04\xcc\
-    \x01!2\n\r\n\x05\x04\x14\x02\x06\x03\x12\x04\xcc\x0156\n\x0c\n\x04\x04\
-    \x14\x02\x07\x12\x04\xcd\x01\x087\n\r\n\x05\x04\x14\x02\x07\x04\x12\x04\
-    \xcd\x01\x08\x10\n\r\n\x05\x04\x14\x02\x07\x06\x12\x04\xcd\x01\x11\x20\n\
-    \r\n\x05\x04\x14\x02\x07\x01\x12\x04\xcd\x01!2\n\r\n\x05\x04\x14\x02\x07\
-    \x03\x12\x04\xcd\x0156\n\x0c\n\x02\x04\x15\x12\x06\xd0\x01\0\xd4\x01\x01\
-    \n\x0b\n\x03\x04\x15\x01\x12\x04\xd0\x01\x08\x14\n\x0c\n\x04\x04\x15\x02\
-    \0\x12\x04\xd1\x01\x08\x19\n\x0f\n\x05\x04\x15\x02\0\x04\x12\x06\xd1\x01\
-    \x08\xd0\x01\x16\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\xd1\x01\x08\x0e\n\r\
-    \n\x05\x04\x15\x02\0\x01\x12\x04\xd1\x01\x0f\x14\n\r\n\x05\x04\x15\x02\0\
-    \x03\x12\x04\xd1\x01\x17\x18\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\xd2\x01\
-    \x08\x1d\n\x0f\n\x05\x04\x15\x02\x01\x04\x12\x06\xd2\x01\x08\xd1\x01\x19\
-    \n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xd2\x01\x08\x0e\n\r\n\x05\x04\x15\
-    \x02\x01\x01\x12\x04\xd2\x01\x0f\x18\n\r\n\x05\x04\x15\x02\x01\x03\x12\
-    \x04\xd2\x01\x1b\x1c\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\xd3\x01\x08\x1b\
-    \n\x0f\n\x05\x04\x15\x02\x02\x04\x12\x06\xd3\x01\x08\xd2\x01\x1d\n\r\n\
-    \x05\x04\x15\x02\x02\x05\x12\x04\xd3\x01\x08\x0e\n\r\n\x05\x04\x15\x02\
-    \x02\x01\x12\x04\xd3\x01\x0f\x16\n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\
-    \xd3\x01\x19\x1a\n\x0c\n\x02\x04\x16\x12\x06\xd6\x01\0\xdd\x01\x01\n\x0b\
-    \n\x03\x04\x16\x01\x12\x04\xd6\x01\x08\x13\n\x0c\n\x04\x04\x16\x02\0\x12\
-    \x04\xd7\x01\x04\x1b\n\x0f\n\x05\x04\x16\x02\0\x04\x12\x06\xd7\x01\x04\
-    \xd6\x01\x15\n\r\n\x05\x04\x16\x02\0\x06\x12\x04\xd7\x01\x04\x0c\n\r\n\
-    \x05\x04\x16\x02\0\x01\x12\x04\xd7\x01\r\x16\n\r\n\x05\x04\x16\x02\0\x03\
-    \x12\x04\xd7\x01\x19\x1a\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\xd8\x01\x04\
-    \"\n\x0f\n\x05\x04\x16\x02\x01\x04\x12\x06\xd8\x01\x04\xd7\x01\x1b\n\r\n\
-    \x05\x04\x16\x02\x01\x06\x12\x04\xd8\x01\x04\x0f\n\r\n\x05\x04\x16\x02\
-    \x01\x01\x12\x04\xd8\x01\x10\x1c\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\
-    \xd8\x01\x20!\n\x0c\n\x04\x04\x16\x02\x02\x12\x04\xd9\x01\x04\x1d\n\x0f\
-    \n\x05\x04\x16\x02\x02\x04\x12\x06\xd9\x01\x04\xd8\x01\"\n\r\n\x05\x04\
-    \x16\x02\x02\x06\x12\x04\xd9\x01\x04\r\n\r\n\x05\x04\x16\x02\x02\x01\x12\
-    \x04\xd9\x01\x0e\x18\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\xd9\x01\x1b\
-    \x1c\n\x0c\n\x04\x04\x16\x02\x03\x12\x04\xda\x01\x04\x1f\n\x0f\n\x05\x04\
-    \x16\x02\x03\x04\x12\x06\xda\x01\x04\xd9\x01\x1d\n\r\n\x05\x04\x16\x02\
-    \x03\x06\x12\x04\xda\x01\x04\x0e\n\r\n\x05\x04\x16\x02\x03\x01\x12\x04\
-    \xda\x01\x0f\x1a\n\r\n\x05\x04\x16\x02\x03\x03\x12\x04\xda\x01\x1d\x1e\n\
-    R\n\x04\x04\x16\x02\x04\x12\x04\xdb\x01\x040\"D\x20the\x20map\x20is\x20i\
-    n\x20the\x20format\x20\"size\x20of\x20hugepage:\x20stats\x20of\x20the\
-    \x20hugepage\"\n\n\x0f\n\x05\x04\x16\x02\x04\x04\x12\x06\xdb\x01\x04\xda\
-    \x01\x1f\n\r\n\x05\x04\x16\x02\x04\x06\x12\x04\xdb\x01\x04\x1d\n\r\n\x05\
-    \x04\x16\x02\x04\x01\x12\x04\xdb\x01\x1e+\n\r\n\x05\x04\x16\x02\x04\x03\
-    \x12\x04\xdb\x01./\n\x0c\n\x02\x04\x17\x12\x06\xdf\x01\0\xe9\x01\x01\n\
-    \x0b\n\x03\x04\x17\x01\x12\x04\xdf\x01\x08\x14\n\x0c\n\x04\x04\x17\x02\0\
-    \x12\x04\xe0\x01\x08\x18\n\x0f\n\x05\x04\x17\x02\0\x04\x12\x06\xe0\x01\
-    \x08\xdf\x01\x16\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xe0\x01\x08\x0e\n\r\
-    \n\x05\x04\x17\x02\0\x01\x12\x04\xe0\x01\x0f\x13\n\r\n\x05\x04\x17\x02\0\
-    \x03\x12\x04\xe0\x01\x16\x17\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xe1\x01\
-    \x08\x1c\n\x0f\n\x05\x04\x17\x02\x01\x04\x12\x06\xe1\x01\x08\xe0\x01\x18\
-    \n\r\n\x05\x04\x17\x02\x01\x05\x12\x04\xe1\x01\x08\x0e\n\r\n\x05\x04\x17\
-    \x02\x01\x01\x12\x04\xe1\x01\x0f\x17\n\r\n\x05\x04\x17\x02\x01\x03\x12\
-    \x04\xe1\x01\x1a\x1b\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\xe2\x01\x08\x1e\
-    \n\x0f\n\x05\x04\x17\x02\x02\x04\x12\x06\xe2\x01\x08\xe1\x01\x1c\n\r\n\
-    \x05\x04\x17\x02\x02\x05\x12\x04\xe2\x01\x08\x0e\n\r\n\x05\x04\x17\x02\
-    \x02\x01\x12\x04\xe2\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\
-    \xe2\x01\x1c\x1d\n\x0c\n\x04\x04\x17\x02\x03\x12\x04\xe3\x01\x08\x1e\n\
-    \x0f\n\x05\x04\x17\x02\x03\x04\x12\x06\xe3\x01\x08\xe2\x01\x1e\n\r\n\x05\
-    \x04\x17\x02\x03\x05\x12\x04\xe3\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x03\
-    \x01\x12\x04\xe3\x01\x0f\x18\n\r\n\x05\x04\x17\x02\x03\x03\x12\x04\xe3\
-    \x01\x1c\x1d\n\x0c\n\x04\x04\x17\x02\x04\x12\x04\xe4\x01\x08\x1e\n\x0f\n\
-    \x05\x04\x17\x02\x04\x04\x12\x06\xe4\x01\x08\xe3\x01\x1e\n\r\n\x05\x04\
-    \x17\x02\x04\x05\x12\x04\xe4\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x04\x01\
-    \x12\x04\xe4\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x04\x03\x12\x04\xe4\x01\
-    \x1c\x1d\n\x0c\n\x04\x04\x17\x02\x05\x12\x04\xe5\x01\x08\x1c\n\x0f\n\x05\
-    \x04\x17\x02\x05\x04\x12\x06\xe5\x01\x08\xe4\x01\x1e\n\r\n\x05\x04\x17\
-    \x02\x05\x05\x12\x04\xe5\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x05\x01\x12\
-    \x04\xe5\x01\x0f\x17\n\r\n\x05\x04\x17\x02\x05\x03\x12\x04\xe5\x01\x1a\
-    \x1b\n\x0c\n\x04\x04\x17\x02\x06\x12\x04\xe6\x01\x08\x1e\n\x0f\n\x05\x04\
-    \x17\x02\x06\x04\x12\x06\xe6\x01\x08\xe5\x01\x1c\n\r\n\x05\x04\x17\x02\
-    \x06\x05\x12\x04\xe6\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x06\x01\x12\x04\
-    \xe6\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x06\x03\x12\x04\xe6\x01\x1c\x1d\n\
-    \x0c\n\x04\x04\x17\x02\x07\x12\x04\xe7\x01\x08\x1d\n\x0f\n\x05\x04\x17\
-    \x02\x07\x04\x12\x06\xe7\x01\x08\xe6\x01\x1e\n\r\n\x05\x04\x17\x02\x07\
-    \x05\x12\x04\xe7\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x07\x01\x12\x04\xe7\
-    \x01\x0f\x18\n\r\n\x05\x04\x17\x02\x07\x03\x12\x04\xe7\x01\x1b\x1c\n\x0c\
-    \n\x04\x04\x17\x02\x08\x12\x04\xe8\x01\x08\x1e\n\x0f\n\x05\x04\x17\x02\
-    \x08\x04\x12\x06\xe8\x01\x08\xe7\x01\x1d\n\r\n\x05\x04\x17\x02\x08\x05\
-    \x12\x04\xe8\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x08\x01\x12\x04\xe8\x01\
-    \x0f\x19\n\r\n\x05\x04\x17\x02\x08\x03\x12\x04\xe8\x01\x1c\x1d\n\x0c\n\
-    \x02\x04\x18\x12\x06\xeb\x01\0\xee\x01\x01\n\x0b\n\x03\x04\x18\x01\x12\
-    \x04\xeb\x01\x08\x1e\n\x0c\n\x04\x04\x18\x02\0\x12\x04\xec\x01\x08%\n\
-    \x0f\n\x05\x04\x18\x02\0\x04\x12\x06\xec\x01\x08\xeb\x01\x20\n\r\n\x05\
-    \x04\x18\x02\0\x06\x12\x04\xec\x01\x08\x13\n\r\n\x05\x04\x18\x02\0\x01\
-    \x12\x04\xec\x01\x14\x20\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\xec\x01#$\n\
-    \x0c\n\x04\x04\x18\x02\x01\x12\x04\xed\x01\x080\n\r\n\x05\x04\x18\x02\
-    \x01\x04\x12\x04\xed\x01\x08\x10\n\r\n\x05\x04\x18\x02\x01\x06\x12\x04\
-    \xed\x01\x11\x1d\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\xed\x01\x1e+\n\r\
-    \n\x05\x04\x18\x02\x01\x03\x12\x04\xed\x01./\n\x0c\n\x02\x04\x19\x12\x06\
-    \xf0\x01\0\xf4\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xf0\x01\x08\x1a\n\
-    \x0c\n\x04\x04\x19\x02\0\x12\x04\xf1\x01\x08\x20\n\x0f\n\x05\x04\x19\x02\
-    \0\x04\x12\x06\xf1\x01\x08\xf0\x01\x1c\n\r\n\x05\x04\x19\x02\0\x05\x12\
-    \x04\xf1\x01\x08\x0e\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xf1\x01\x0f\x1b\
-    \n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xf1\x01\x1e\x1f\n\x0c\n\x04\x04\x19\
-    \x02\x01\x12\x04\xf2\x01\x08\x1b\n\x0f\n\x05\x04\x19\x02\x01\x04\x12\x06\
-    \xf2\x01\x08\xf1\x01\x20\n\r\n\x05\x04\x19\x02\x01\x05\x12\x04\xf2\x01\
-    \x08\x0e\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\xf2\x01\x0f\x16\n\r\n\x05\
-    \x04\x19\x02\x01\x03\x12\x04\xf2\x01\x19\x1a\n\x0c\n\x04\x04\x19\x02\x02\
-    \x12\x04\xf3\x01\x08\x17\n\x0f\n\x05\x04\x19\x02\x02\x04\x12\x06\xf3\x01\
-    \x08\xf2\x01\x1b\n\r\n\x05\x04\x19\x02\x02\x05\x12\x04\xf3\x01\x08\r\n\r\
-    \n\x05\x04\x19\x02\x02\x01\x12\x04\xf3\x01\x0e\x12\n\r\n\x05\x04\x19\x02\
-    \x02\x03\x12\x04\xf3\x01\x15\x16\n\x0c\n\x02\x04\x1a\x12\x06\xf6\x01\0\
-    \xf8\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xf6\x01\x08\x1b\n\x0c\n\x04\
-    \x04\x1a\x02\0\x12\x04\xf7\x01\x08\x17\n\x0f\n\x05\x04\x1a\x02\0\x04\x12\
-    \x06\xf7\x01\x08\xf6\x01\x1d\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xf7\x01\
-    \x08\x0e\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xf7\x01\x0f\x12\n\r\n\x05\
-    \x04\x1a\x02\0\x03\x12\x04\xf7\x01\x15\x16\n\x0c\n\x02\x04\x1b\x12\x06\
-    \xfa\x01\0\xfe\x01\x01\n\x0b\n\x03\x04\x1b\x01\x12\x04\xfa\x01\x08\x19\n\
-    \x0c\n\x04\x04\x1b\x02\0\x12\x04\xfb\x01\x08\x20\n\x0f\n\x05\x04\x1b\x02\
-    \0\x04\x12\x06\xfb\x01\x08\xfa\x01\x1b\n\r\n\x05\x04\x1b\x02\0\x05\x12\
-    \x04\xfb\x01\x08\x0e\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\xfb\x01\x0f\x1b\
-    \n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xfb\x01\x1e\x1f\n\x0c\n\x04\x04\x1b\
-    \x02\x01\x12\x04\xfc\x01\x08\x1b\n\x0f\n\x05\x04\x1b\x02\x01\x04\x12\x06\
-    \xfc\x01\x08\xfb\x01\x20\n\r\n\x05\x04\x1b\x02\x01\x05\x12\x04\xfc\x01\
-    \x08\x0e\n\r\n\x05\x04\x1b\x02\x01\x01\x12\x04\xfc\x01\x0f\x16\n\r\n\x05\
-    \x04\x1b\x02\x01\x03\x12\x04\xfc\x01\x19\x1a\n\x0c\n\x04\x04\x1b\x02\x02\
-    \x12\x04\xfd\x01\x08\x17\n\x0f\n\x05\x04\x1b\x02\x02\x04\x12\x06\xfd\x01\
-    \x08\xfc\x01\x1b\n\r\n\x05\x04\x1b\x02\x02\x05\x12\x04\xfd\x01\x08\x0e\n\
-    \r\n\x05\x04\x1b\x02\x02\x01\x12\x04\xfd\x01\x0f\x12\n\r\n\x05\x04\x1b\
-    \x02\x02\x03\x12\x04\xfd\x01\x15\x16\n\x0c\n\x02\x04\x1c\x12\x06\x80\x02\
-    \0\x82\x02\x01\n\x0b\n\x03\x04\x1c\x01\x12\x04\x80\x02\x08\x1a\n\x0c\n\
-    \x04\x04\x1c\x02\0\x12\x04\x81\x02\x08\x17\n\x0f\n\x05\x04\x1c\x02\0\x04\
-    \x12\x06\x81\x02\x08\x80\x02\x1c\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\x81\
-    \x02\x08\r\n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\x81\x02\x0e\x12\n\r\n\x05\
-    \x04\x1c\x02\0\x03\x12\x04\x81\x02\x15\x16\n\x0c\n\x02\x04\x1d\x12\x06\
-    \x84\x02\0\x87\x02\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\x84\x02\x08\x19\n\
-    \x0c\n\x04\x04\x1d\x02\0\x12\x04\x85\x02\x08\x20\n\x0f\n\x05\x04\x1d\x02\
-    \0\x04\x12\x06\x85\x02\x08\x84\x02\x1b\n\r\n\x05\x04\x1d\x02\0\x05\x12\
-    \x04\x85\x02\x08\x0e\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\x85\x02\x0f\x1b\
-    \n\r\n\x05\x04\x1d\x02\0\x03\x12\x04\x85\x02\x1e\x1f\n\x0c\n\x04\x04\x1d\
-    \x02\x01\x12\x04\x86\x02\x08\x1b\n\x0f\n\x05\x04\x1d\x02\x01\x04\x12\x06\
-    \x86\x02\x08\x85\x02\x20\n\r\n\x05\x04\x1d\x02\x01\x05\x12\x04\x86\x02\
-    \x08\x0e\n\r\n\x05\x04\x1d\x02\x01\x01\x12\x04\x86\x02\x0f\x16\n\r\n\x05\
-    \x04\x1d\x02\x01\x03\x12\x04\x86\x02\x19\x1a\n\x0c\n\x02\x04\x1e\x12\x06\
-    \x89\x02\0\x8e\x02\x01\n\x0b\n\x03\x04\x1e\x01\x12\x04\x89\x02\x08\x1b\n\
-    \x0c\n\x04\x04\x1e\x02\0\x12\x04\x8a\x02\x08\x20\n\x0f\n\x05\x04\x1e\x02\
-    \0\x04\x12\x06\x8a\x02\x08\x89\x02\x1d\n\r\n\x05\x04\x1e\x02\0\x05\x12\
-    \x04\x8a\x02\x08\x0e\n\r\n\x05\x04\x1e\x02\0\x01\x12\x04\x8a\x02\x0f\x1b\
-    \n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\x8a\x02\x1e\x1f\n\x0c\n\x04\x04\x1e\
-    \x02\x01\x12\x04\x8b\x02\x08\x1b\n\x0f\n\x05\x04\x1e\x02\x01\x04\x12\x06\
-    \x8b\x02\x08\x8a\x02\x20\n\r\n\x05\x04\x1e\x02\x01\x05\x12\x04\x8b\x02\
-    \x08\x0e\n\r\n\x05\x04\x1e\x02\x01\x01\x12\x04\x8b\x02\x0f\x16\n\r\n\x05\
-    \x04\x1e\x02\x01\x03\x12\x04\x8b\x02\x19\x1a\n\x0c\n\x04\x04\x1e\x02\x02\
-    \x12\x04\x8c\x02\x08\x17\n\x0f\n\x05\x04\x1e\x02\x02\x04\x12\x06\x8c\x02\
-    \x08\x8b\x02\x1b\n\r\n\x05\x04\x1e\x02\x02\x05\x12\x04\x8c\x02\x08\x0e\n\
-    \r\n\x05\x04\x1e\x02\x02\x01\x12\x04\x8c\x02\x0f\x12\n\r\n\x05\x04\x1e\
-    \x02\x02\x03\x12\x04\x8c\x02\x15\x16\n\x0c\n\x04\x04\x1e\x02\x03\x12\x04\
-    \x8d\x02\x08\x1a\n\x0f\n\x05\x04\x1e\x02\x03\x04\x12\x06\x8d\x02\x08\x8c\
-    \x02\x17\n\r\n\x05\x04\x1e\x02\x03\x05\x12\x04\x8d\x02\x08\x0e\n\r\n\x05\
-    \x04\x1e\x02\x03\x01\x12\x04\x8d\x02\x0f\x15\n\r\n\x05\x04\x1e\x02\x03\
-    \x03\x12\x04\x8d\x02\x18\x19\n\x0c\n\x02\x04\x1f\x12\x06\x90\x02\0\xa1\
-    \x02\x01\n\x0b\n\x03\x04\x1f\x01\x12\x04\x90\x02\x08\x1c\n\x0c\n\x04\x04\
-    \x1f\x02\0\x12\x04\x91\x02\x08\x1c\n\x0f\n\x05\x04\x1f\x02\0\x04\x12\x06\
-    \x91\x02\x08\x90\x02\x1e\n\r\n\x05\x04\x1f\x02\0\x05\x12\x04\x91\x02\x08\
-    \x0e\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\x91\x02\x0f\x17\n\r\n\x05\x04\
-    \x1f\x02\0\x03\x12\x04\x91\x02\x1a\x1b\n\x0c\n\x04\x04\x1f\x02\x01\x12\
-    \x04\x92\x02\x08\x20\n\r\n\x05\x04\x1f\x02\x01\x04\x12\x04\x92\x02\x08\
-    \x10\n\r\n\x05\x04\x1f\x02\x01\x05\x12\x04\x92\x02\x11\x17\n\r\n\x05\x04\
-    \x1f\x02\x01\x01\x12\x04\x92\x02\x18\x1b\n\r\n\x05\x04\x1f\x02\x01\x03\
-    \x12\x04\x92\x02\x1e\x1f\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\x93\x02\x08\
-    &\n\r\n\x05\x04\x1f\x02\x02\x04\x12\x04\x93\x02\x08\x10\n\r\n\x05\x04\
-    \x1f\x02\x02\x06\x12\x04\x93\x02\x11\x18\n\r\n\x05\x04\x1f\x02\x02\x01\
-    \x12\x04\x93\x02\x19!\n\r\n\x05\x04\x1f\x02\x02\x03\x12\x04\x93\x02$%\n\
-    \xea\x01\n\x04\x04\x1f\x02\x03\x12\x04\x99\x02\x08\x1f\x1a\xdb\x01\x20Th\
-    is\x20field\x20means\x20that\x20a\x20pause\x20process\x20needs\x20to\x20\
-    be\x20created\x20by\x20the\n\x20agent.\x20This\x20pid\x20namespace\x20of\
-    \x20the\x20pause\x20process\x20will\x20be\x20treated\x20as\n\x20a\x20sha\
-    red\x20pid\x20namespace.\x20All\x20containers\x20created\x20will\x20join\
-    \x20this\x20shared\n\x20pid\x20namespace.\n\n\x0f\n\x05\x04\x1f\x02\x03\
-    \x04\x12\x06\x99\x02\x08\x93\x02&\n\r\n\x05\x04\x1f\x02\x03\x05\x12\x04\
-    \x99\x02\x08\x0c\n\r\n\x05\x04\x1f\x02\x03\x01\x12\x04\x99\x02\r\x1a\n\r\
-    \n\x05\x04\x1f\x02\x03\x03\x12\x04\x99\x02\x1d\x1e\n\xc5\x01\n\x04\x04\
-    \x1f\x02\x04\x12\x04\x9d\x02\x08\x1e\x1a\xb6\x01\x20SandboxId\x20identif\
-    ies\x20which\x20sandbox\x20is\x20using\x20the\x20agent.\x20We\x20allow\
-    \x20only\n\x20one\x20sandbox\x20per\x20agent\x20and\x20implicitly\x20req\
-    uire\x20that\x20CreateSandbox\x20is\n\x20called\x20before\x20other\x20sa\
-    ndbox/network\x20calls.\n\n\x0f\n\x05\x04\x1f\x02\x04\x04\x12\x06\x9d\
-    \x02\x08\x99\x02\x1f\n\r\n\x05\x04\x1f\x02\x04\x05\x12\x04\x9d\x02\x08\
-    \x0e\n\r\n\x05\x04\x1f\x02\x04\x01\x12\x04\x9d\x02\x0f\x19\n\r\n\x05\x04\
-    \x1f\x02\x04\x03\x12\x04\x9d\x02\x1c\x1d\n\x98\x01\n\x04\x04\x1f\x02\x05\
-    \x12\x04\xa0\x02\x08#\x1a\x89\x01\x20This\x20field,\x20if\x20non-empty,\
-    \x20designates\x20an\x20absolute\x20path\x20to\x20a\x20directory\n\x20th\
-    at\x20the\x20agent\x20will\x20search\x20for\x20OCI\x20hooks\x20to\x20run\
-    \x20within\x20the\x20guest.\n\n\x0f\n\x05\x04\x1f\x02\x05\x04\x12\x06\
-    \xa0\x02\x08\x9d\x02\x1e\n\r\n\x05\x04\x1f\x02\x05\x05\x12\x04\xa0\x02\
-    \x08\x0e\n\r\n\x05\x04\x1f\x02\x05\x01\x12\x04\xa0\x02\x0f\x1e\n\r\n\x05\
-    \x04\x1f\x02\x05\x03\x12\x04\xa0\x02!\"\n\x0c\n\x02\x04\x20\x12\x06\xa3\
-    \x02\0\xa4\x02\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\xa3\x02\x08\x1d\n\x0c\
-    \n\x02\x04!\x12\x06\xa6\x02\0\xa8\x02\x01\n\x0b\n\x03\x04!\x01\x12\x04\
-    \xa6\x02\x08\x12\n\x0c\n\x04\x04!\x02\0\x12\x04\xa7\x02\x080\n\r\n\x05\
-    \x04!\x02\0\x04\x12\x04\xa7\x02\x08\x10\n\r\n\x05\x04!\x02\0\x06\x12\x04\
-    \xa7\x02\x11\x20\n\r\n\x05\x04!\x02\0\x01\x12\x04\xa7\x02!+\n\r\n\x05\
-    \x04!\x02\0\x03\x12\x04\xa7\x02./\n\x0c\n\x02\x04\"\x12\x06\xaa\x02\0\
-    \xac\x02\x01\n\x0b\n\x03\x04\"\x01\x12\x04\xaa\x02\x08\x0e\n\x0c\n\x04\
-    \x04\"\x02\0\x12\x04\xab\x02\x08(\n\r\n\x05\x04\"\x02\0\x04\x12\x04\xab\
-    \x02\x08\x10\n\r\n\x05\x04\"\x02\0\x06\x12\x04\xab\x02\x11\x1c\n\r\n\x05\
-    \x04\"\x02\0\x01\x12\x04\xab\x02\x1d#\n\r\n\x05\x04\"\x02\0\x03\x12\x04\
-    \xab\x02&'\n\x0c\n\x02\x04#\x12\x06\xae\x02\0\xb0\x02\x01\n\x0b\n\x03\
-    \x04#\x01\x12\x04\xae\x02\x08\x1e\n\x0c\n\x04\x04#\x02\0\x12\x04\xaf\x02\
-    \x08&\n\x0f\n\x05\x04#\x02\0\x04\x12\x06\xaf\x02\x08\xae\x02\x20\n\r\n\
-    \x05\x04#\x02\0\x06\x12\x04\xaf\x02\x08\x17\n\r\n\x05\x04#\x02\0\x01\x12\
-    \x04\xaf\x02\x18!\n\r\n\x05\x04#\x02\0\x03\x12\x04\xaf\x02$%\n\x0c\n\x02\
-    \x04$\x12\x06\xb2\x02\0\xb4\x02\x01\n\x0b\n\x03\x04$\x01\x12\x04\xb2\x02\
-    \x08\x1b\n\x0c\n\x04\x04$\x02\0\x12\x04\xb3\x02\x08\x1a\n\x0f\n\x05\x04$\
-    \x02\0\x04\x12\x06\xb3\x02\x08\xb2\x02\x1d\n\r\n\x05\x04$\x02\0\x06\x12\
-    \x04\xb3\x02\x08\x0e\n\r\n\x05\x04$\x02\0\x01\x12\x04\xb3\x02\x0f\x15\n\
-    \r\n\x05\x04$\x02\0\x03\x12\x04\xb3\x02\x18\x19\n\x0c\n\x02\x04%\x12\x06\
-    \xb6\x02\0\xb7\x02\x01\n\x0b\n\x03\x04%\x01\x12\x04\xb6\x02\x08\x1d\n\
-    \x0c\n\x02\x04&\x12\x06\xb9\x02\0\xba\x02\x01\n\x0b\n\x03\x04&\x01\x12\
-    \x04\xb9\x02\x08\x19\n\x0c\n\x02\x04'\x12\x06\xbc\x02\0\xc7\x02\x01\n\
-    \x0b\n\x03\x04'\x01\x12\x04\xbc\x02\x08\x1b\n\xf6\x01\n\x04\x04'\x02\0\
-    \x12\x04\xc0\x02\x08\x16\x1a\xe7\x01\x20Wait\x20specifies\x20if\x20the\
-    \x20caller\x20waits\x20for\x20the\x20agent\x20to\x20online\x20all\x20res\
-    ources.\n\x20If\x20true\x20the\x20agent\x20returns\x20once\x20all\x20res\
-    ources\x20have\x20been\x20connected,\x20otherwise\x20all\n\x20resources\
-    \x20are\x20connected\x20asynchronously\x20and\x20the\x20agent\x20returns\
-    \x20immediately.\n\n\x0f\n\x05\x04'\x02\0\x04\x12\x06\xc0\x02\x08\xbc\
-    \x02\x1d\n\r\n\x05\x04'\x02\0\x05\x12\x04\xc0\x02\x08\x0c\n\r\n\x05\x04'\
-    \x02\0\x01\x12\x04\xc0\x02\r\x11\n\r\n\x05\x04'\x02\0\x03\x12\x04\xc0\
-    \x02\x14\x15\n`\n\x04\x04'\x02\x01\x12\x04\xc3\x02\x08\x1b\x1aR\x20NbCpu\
-    s\x20specifies\x20the\x20number\x20of\x20CPUs\x20that\x20were\x20added\
-    \x20and\x20the\x20agent\x20has\x20to\x20online.\n\n\x0f\n\x05\x04'\x02\
-    \x01\x04\x12\x06\xc3\x02\x08\xc0\x02\x16\n\r\n\x05\x04'\x02\x01\x05\x12\
-    \x04\xc3\x02\x08\x0e\n\r\n\x05\x04'\x02\x01\x01\x12\x04\xc3\x02\x0f\x16\
-    \n\r\n\x05\x04'\x02\x01\x03\x12\x04\xc3\x02\x19\x1a\nA\n\x04\x04'\x02\
-    \x02\x12\x04\xc6\x02\x08\x1a\x1a3\x20CpuOnly\x20specifies\x20whether\x20\
-    only\x20online\x20CPU\x20or\x20not.\n\n\x0f\n\x05\x04'\x02\x02\x04\x12\
-    \x06\xc6\x02\x08\xc3\x02\x1b\n\r\n\x05\x04'\x02\x02\x05\x12\x04\xc6\x02\
-    \x08\x0c\n\r\n\x05\x04'\x02\x02\x01\x12\x04\xc6\x02\r\x15\n\r\n\x05\x04'\
-    \x02\x02\x03\x12\x04\xc6\x02\x18\x19\n\x0c\n\x02\x04(\x12\x06\xc9\x02\0\
-    \xcc\x02\x01\n\x0b\n\x03\x04(\x01\x12\x04\xc9\x02\x08\x1e\nM\n\x04\x04(\
-    \x02\0\x12\x04\xcb\x02\x08\x17\x1a?\x20Data\x20specifies\x20the\x20rando\
-    m\x20data\x20used\x20to\x20reseed\x20the\x20guest\x20crng.\n\n\x0f\n\x05\
-    \x04(\x02\0\x04\x12\x06\xcb\x02\x08\xc9\x02\x20\n\r\n\x05\x04(\x02\0\x05\
-    \x12\x04\xcb\x02\x08\r\n\r\n\x05\x04(\x02\0\x01\x12\x04\xcb\x02\x0e\x12\
-    \n\r\n\x05\x04(\x02\0\x03\x12\x04\xcb\x02\x15\x16\nX\n\x02\x04)\x12\x06\
-    \xcf\x02\0\xdf\x02\x01\x1aJ\x20AgentDetails\x20provides\x20information\
-    \x20to\x20the\x20client\x20about\x20the\x20running\x20agent.\n\n\x0b\n\
-    \x03\x04)\x01\x12\x04\xcf\x02\x08\x14\nC\n\x04\x04)\x02\0\x12\x04\xd1\
-    \x02\x08\x1b\x1a5\x20Semantic\x20version\x20of\x20agent\x20(see\x20https\
-    ://semver.org).\n\n\x0f\n\x05\x04)\x02\0\x04\x12\x06\xd1\x02\x08\xcf\x02\
-    \x16\n\r\n\x05\x04)\x02\0\x05\x12\x04\xd1\x02\x08\x0e\n\r\n\x05\x04)\x02\
-    \0\x01\x12\x04\xd1\x02\x0f\x16\n\r\n\x05\x04)\x02\0\x03\x12\x04\xd1\x02\
-    \x19\x1a\n5\n\x04\x04)\x02\x01\x12\x04\xd4\x02\x08\x1d\x1a'\x20Set\x20if\
-    \x20the\x20agent\x20is\x20running\x20as\x20PID\x201.\n\n\x0f\n\x05\x04)\
-    \x02\x01\x04\x12\x06\xd4\x02\x08\xd1\x02\x1b\n\r\n\x05\x04)\x02\x01\x05\
-    \x12\x04\xd4\x02\x08\x0c\n\r\n\x05\x04)\x02\x01\x01\x12\x04\xd4\x02\r\
-    \x18\n\r\n\x05\x04)\x02\x01\x03\x12\x04\xd4\x02\x1b\x1c\n2\n\x04\x04)\
-    \x02\x02\x12\x04\xd7\x02\x08,\x1a$\x20List\x20of\x20available\x20device\
-    \x20handlers.\n\n\r\n\x05\x04)\x02\x02\x04\x12\x04\xd7\x02\x08\x10\n\r\n\
-    \x05\x04)\x02\x02\x05\x12\x04\xd7\x02\x11\x17\n\r\n\x05\x04)\x02\x02\x01\
-    \x12\x04\xd7\x02\x18'\n\r\n\x05\x04)\x02\x02\x03\x12\x04\xd7\x02*+\n3\n\
-    \x04\x04)\x02\x03\x12\x04\xda\x02\x08-\x1a%\x20List\x20of\x20available\
-    \x20storage\x20handlers.\n\n\r\n\x05\x04)\x02\x03\x04\x12\x04\xda\x02\
-    \x08\x10\n\r\n\x05\x04)\x02\x03\x05\x12\x04\xda\x02\x11\x17\n\r\n\x05\
-    \x04)\x02\x03\x01\x12\x04\xda\x02\x18(\n\r\n\x05\x04)\x02\x03\x03\x12\
-    \x04\xda\x02+,\np\n\x04\x04)\x02\x04\x12\x04\xde\x02\x08\"\x1ab\x20Set\
-    \x20only\x20if\x20the\x20agent\x20is\x20built\x20with\x20seccomp\x20supp\
-    ort\x20and\x20the\x20guest\n\x20environment\x20supports\x20seccomp.\n\n\
-    \x0f\n\x05\x04)\x02\x04\x04\x12\x06\xde\x02\x08\xda\x02-\n\r\n\x05\x04)\
-    \x02\x04\x05\x12\x04\xde\x02\x08\x0c\n\r\n\x05\x04)\x02\x04\x01\x12\x04\
-    \xde\x02\r\x1d\n\r\n\x05\x04)\x02\x04\x03\x12\x04\xde\x02\x20!\n\x0c\n\
-    \x02\x04*\x12\x06\xe1\x02\0\xeb\x02\x01\n\x0b\n\x03\x04*\x01\x12\x04\xe1\
-    \x02\x08\x1b\n\xd5\x01\n\x04\x04*\x02\0\x12\x04\xe5\x02\x08\x20\x1a\xc6\
-    \x01\x20MemBlockSize\x20asks\x20server\x20to\x20return\x20the\x20system\
-    \x20memory\x20block\x20size\x20that\x20can\x20be\x20used\n\x20for\x20mem\
-    ory\x20hotplug\x20alignment.\x20Typically\x20the\x20server\x20returns\
-    \x20what's\x20in\n\x20/sys/devices/system/memory/block_size_bytes.\n\n\
-    \x0f\n\x05\x04*\x02\0\x04\x12\x06\xe5\x02\x08\xe1\x02\x1d\n\r\n\x05\x04*\
-    \x02\0\x05\x12\x04\xe5\x02\x08\x0c\n\r\n\x05\x04*\x02\0\x01\x12\x04\xe5\
-    \x02\r\x1b\n\r\n\x05\x04*\x02\0\x03\x12\x04\xe5\x02\x1e\x1f\n\xd1\x01\n\
-    \x04\x04*\x02\x01\x12\x04\xea\x02\x08#\x1a\xc2\x01\x20MemoryHotplugProbe\
-    \x20asks\x20server\x20to\x20return\x20whether\x20guest\x20kernel\x20supp\
-    orts\x20memory\x20hotplug\n\x20via\x20probeinterface.\x20Typically\x20th\
-    e\x20server\x20will\x20check\x20if\x20the\x20path\n\x20/sys/devices/syst\
-    em/memory/probe\x20exists.\n\n\x0f\n\x05\x04*\x02\x01\x04\x12\x06\xea\
-    \x02\x08\xe5\x02\x20\n\r\n\x05\x04*\x02\x01\x05\x12\x04\xea\x02\x08\x0c\
-    \n\r\n\x05\x04*\x02\x01\x01\x12\x04\xea\x02\r\x1e\n\r\n\x05\x04*\x02\x01\
-    \x03\x12\x04\xea\x02!\"\n\x0c\n\x02\x04+\x12\x06\xed\x02\0\xf4\x02\x01\n\
-    \x0b\n\x03\x04+\x01\x12\x04\xed\x02\x08\x1c\nP\n\x04\x04+\x02\0\x12\x04\
-    \xef\x02\x08(\x1aB\x20MemBlockSizeBytes\x20returns\x20the\x20system\x20m\
-    emory\x20block\x20size\x20in\x20bytes.\n\n\x0f\n\x05\x04+\x02\0\x04\x12\
-    \x06\xef\x02\x08\xed\x02\x1e\n\r\n\x05\x04+\x02\0\x05\x12\x04\xef\x02\
-    \x08\x0e\n\r\n\x05\x04+\x02\0\x01\x12\x04\xef\x02\x0f#\n\r\n\x05\x04+\
-    \x02\0\x03\x12\x04\xef\x02&'\n\x0c\n\x04\x04+\x02\x01\x12\x04\xf1\x02\
-    \x08'\n\x0f\n\x05\x04+\x02\x01\x04\x12\x06\xf1\x02\x08\xef\x02(\n\r\n\
-    \x05\x04+\x02\x01\x06\x12\x04\xf1\x02\x08\x14\n\r\n\x05\x04+\x02\x01\x01\
-    \x12\x04\xf1\x02\x15\"\n\r\n\x05\x04+\x02\x01\x03\x12\x04\xf1\x02%&\n\
-    \x0c\n\x04\x04+\x02\x02\x12\x04\xf3\x02\x08+\n\x0f\n\x05\x04+\x02\x02\
-    \x04\x12\x06\xf3\x02\x08\xf1\x02'\n\r\n\x05\x04+\x02\x02\x05\x12\x04\xf3\
-    \x02\x08\x0c\n\r\n\x05\x04+\x02\x02\x01\x12\x04\xf3\x02\r&\n\r\n\x05\x04\
-    +\x02\x02\x03\x12\x04\xf3\x02)*\n\x0c\n\x02\x04,\x12\x06\xf6\x02\0\xfa\
-    \x02\x01\n\x0b\n\x03\x04,\x01\x12\x04\xf6\x02\x08\x20\n\xb2\x01\n\x04\
-    \x04,\x02\0\x12\x04\xf9\x02\x080\x1a\xa3\x01\x20server\x20needs\x20to\
-    \x20send\x20the\x20value\x20of\x20memHotplugProbeAddr\x20into\x20file\
-    \x20/sys/devices/system/memory/probe,\n\x20in\x20order\x20to\x20notify\
-    \x20the\x20guest\x20kernel\x20about\x20hot-add\x20memory\x20event\n\n\r\
-    \n\x05\x04,\x02\0\x04\x12\x04\xf9\x02\x08\x10\n\r\n\x05\x04,\x02\0\x05\
-    \x12\x04\xf9\x02\x11\x17\n\r\n\x05\x04,\x02\0\x01\x12\x04\xf9\x02\x18+\n\
-    \r\n\x05\x04,\x02\0\x03\x12\x04\xf9\x02./\n\x0c\n\x02\x04-\x12\x06\xfc\
-    \x02\0\x81\x03\x01\n\x0b\n\x03\x04-\x01\x12\x04\xfc\x02\x08\x1f\n/\n\x04\
-    \x04-\x02\0\x12\x04\xfe\x02\x08\x16\x1a!\x20Sec\x20the\x20second\x20sinc\
-    e\x20the\x20Epoch.\n\n\x0f\n\x05\x04-\x02\0\x04\x12\x06\xfe\x02\x08\xfc\
-    \x02!\n\r\n\x05\x04-\x02\0\x05\x12\x04\xfe\x02\x08\r\n\r\n\x05\x04-\x02\
-    \0\x01\x12\x04\xfe\x02\x0e\x11\n\r\n\x05\x04-\x02\0\x03\x12\x04\xfe\x02\
-    \x14\x15\nF\n\x04\x04-\x02\x01\x12\x04\x80\x03\x08\x17\x1a8\x20Usec\x20t\
-    he\x20microseconds\x20portion\x20of\x20time\x20since\x20the\x20Epoch.\n\
-    \n\x0f\n\x05\x04-\x02\x01\x04\x12\x06\x80\x03\x08\xfe\x02\x16\n\r\n\x05\
-    \x04-\x02\x01\x05\x12\x04\x80\x03\x08\r\n\r\n\x05\x04-\x02\x01\x01\x12\
-    \x04\x80\x03\x0e\x12\n\r\n\x05\x04-\x02\x01\x03\x12\x04\x80\x03\x15\x16\
-    \n\xa3\x01\n\x02\x04.\x12\x06\x85\x03\0\x9f\x03\x01\x1a\x94\x01\x20Stora\
-    ge\x20represents\x20both\x20the\x20rootfs\x20of\x20the\x20container,\x20\
-    and\x20any\x20volume\x20that\n\x20could\x20have\x20been\x20defined\x20th\
-    rough\x20the\x20Mount\x20list\x20of\x20the\x20OCI\x20specification.\n\n\
-    \x0b\n\x03\x04.\x01\x12\x04\x85\x03\x08\x0f\n\x8b\x02\n\x04\x04.\x02\0\
-    \x12\x04\x8a\x03\x08\x1a\x1a\xfc\x01\x20Driver\x20is\x20used\x20to\x20de\
-    fine\x20the\x20way\x20the\x20storage\x20is\x20passed\x20through\x20the\n\
-    \x20virtual\x20machine.\x20It\x20can\x20be\x20\"9p\",\x20\"blk\",\x20or\
-    \x20something\x20else,\x20but\x20for\n\x20all\x20cases,\x20this\x20will\
-    \x20define\x20if\x20some\x20extra\x20steps\x20are\x20required\x20before\
-    \n\x20this\x20storage\x20gets\x20mounted\x20into\x20the\x20container.\n\
-    \n\x0f\n\x05\x04.\x02\0\x04\x12\x06\x8a\x03\x08\x85\x03\x11\n\r\n\x05\
-    \x04.\x02\0\x05\x12\x04\x8a\x03\x08\x0e\n\r\n\x05\x04.\x02\0\x01\x12\x04\
-    \x8a\x03\x0f\x15\n\r\n\x05\x04.\x02\0\x03\x12\x04\x8a\x03\x18\x19\n\xd0\
-    \x01\n\x04\x04.\x02\x01\x12\x04\x8e\x03\x08+\x1a\xc1\x01\x20DriverOption\
+    \x07process\x18\x04\x20\x01(\x0b2\r.grpc.ProcessR\x07process\x12,\n\x12o\
+    utput_buffer_size\x18\x05\x20\x01(\rR\x10outputBufferSize\"j\n\x14Signal\
+    ProcessRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\
+    \x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06execId\x12\x16\n\x06signal\
+    \x18\x03\x20\x01(\rR\x06signal\"P\n\x12WaitProcessRequest\x12!\n\x0ccont\
+    ainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\x07exec_id\x18\x02\
+    \x20\x01(\tR\x06execId\"m\n\x13WaitProcessResponse\x12\x16\n\x06status\
+    \x18\x01\x20\x01(\x05R\x06status\x12\x1f\n\x0bcore_dumped\x18\x02\x20\
+    \x01(\x08R\ncoreDumped\x12\x1d\n\noom_killed\x18\x03\x20\x01(\x08R\toomK\
+    illed\"e\n\x14ListProcessesRequest\x12!\n\x0ccontainer_id\x18\x01\x20\
+    \x01(\tR\x0bcontainerId\x12\x16\n\x06format\x18\x02\x20\x01(\tR\x06forma\
+    t\x12\x12\n\x04args\x18\x03\x20\x03(\tR\x04args\":\n\x15ListProcessesRes\
+    ponse\x12!\n\x0cprocess_list\x18\x01\x20\x01(\x0cR\x0bprocessList\"o\n\
+    \x16UpdateContainerRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\
+    \x0bcontainerId\x122\n\tresources\x18\x02\x20\x01(\x0b2\x14.grpc.LinuxRe\
+    sourcesR\tresources\":\n\x15StatsContainerRequest\x12!\n\x0ccontainer_id\
+    \x18\x01\x20\x01(\tR\x0bcontainerId\":\n\x15PauseContainerRequest\x12!\n\
+    \x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\";\n\x16ResumeContai\
+    nerRequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\"\
+    \xaa\x01\n\x08CpuUsage\x12\x1f\n\x0btotal_usage\x18\x01\x20\x01(\x04R\nt\
+    otalUsage\x12!\n\x0cpercpu_usage\x18\x02\x20\x03(\x04R\x0bpercpuUsage\
+    \x12.\n\x13usage_in_kernelmode\x18\x03\x20\x01(\x04R\x11usageInKernelmod\
+    e\x12*\n\x11usage_in_usermode\x18\x04\x20\x01(\x04R\x0fusageInUsermode\"\
+    ~\n\x0eThrottlingData\x12\x18\n\x07periods\x18\x01\x20\x01(\x04R\x07peri\
+    ods\x12+\n\x11throttled_periods\x18\x02\x20\x01(\x04R\x10throttledPeriod\
+    s\x12%\n\x0ethrottled_time\x18\x03\x20\x01(\x04R\rthrottledTime\"v\n\x08\
+    CpuStats\x12+\n\tcpu_usage\x18\x01\x20\x01(\x0b2\x0e.grpc.CpuUsageR\x08c\
+    puUsage\x12=\n\x0fthrottling_data\x18\x02\x20\x01(\x0b2\x14.grpc.Throttl\
+    ingDataR\x0ethrottlingData\";\n\tPidsStats\x12\x18\n\x07current\x18\x01\
+    \x20\x01(\x04R\x07current\x12\x14\n\x05limit\x18\x02\x20\x01(\x04R\x05li\
+    mit\"o\n\nMemoryData\x12\x14\n\x05usage\x18\x01\x20\x01(\x04R\x05usage\
+    \x12\x1b\n\tmax_usage\x18\x02\x20\x01(\x04R\x08maxUsage\x12\x18\n\x07fai\
+    lcnt\x18\x03\x20\x01(\x04R\x07failcnt\x12\x14\n\x05limit\x18\x04\x20\x01\
+    (\x04R\x05limit\"\xc4\x02\n\x0bMemoryStats\x12\x14\n\x05cache\x18\x01\
+    \x20\x01(\x04R\x05cache\x12&\n\x05usage\x18\x02\x20\x01(\x0b2\x10.grpc.M\
+    emoryDataR\x05usage\x12/\n\nswap_usage\x18\x03\x20\x01(\x0b2\x10.grpc.Me\
+    moryDataR\tswapUsage\x123\n\x0ckernel_usage\x18\x04\x20\x01(\x0b2\x10.gr\
+    pc.MemoryDataR\x0bkernelUsage\x12#\n\ruse_hierarchy\x18\x05\x20\x01(\x08\
+    R\x0cuseHierarchy\x122\n\x05stats\x18\x06\x20\x03(\x0b2\x1c.grpc.MemoryS\
+    tats.StatsEntryR\x05stats\x1a8\n\nStatsEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x04R\x05value:\
+    \x028\x01\"c\n\x0fBlkioStatsEntry\x12\x14\n\x05major\x18\x01\x20\x01(\
+    \x04R\x05major\x12\x14\n\x05minor\x18\x02\x20\x01(\x04R\x05minor\x12\x0e\
+    \n\x02op\x18\x03\x20\x01(\tR\x02op\x12\x14\n\x05value\x18\x04\x20\x01(\
+    \x04R\x05value\"\xde\x04\n\nBlkioStats\x12R\n\x1aio_service_bytes_recurs\
+    ive\x18\x01\x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\x17ioServiceBytesRec\
+    ursive\x12I\n\x15io_serviced_recursive\x18\x02\x20\x03(\x0b2\x15.grpc.Bl\
+    kioStatsEntryR\x13ioServicedRecursive\x12E\n\x13io_queued_recursive\x18\
+    \x03\x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\x11ioQueuedRecursive\x12P\n\
+    \x19io_service_time_recursive\x18\x04\x20\x03(\x0b2\x15.grpc.BlkioStatsE\
+    ntryR\x16ioServiceTimeRecursive\x12J\n\x16io_wait_time_recursive\x18\x05\
+    \x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\x13ioWaitTimeRecursive\x12E\n\
+    \x13io_merged_recursive\x18\x06\x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\
+    \x11ioMergedRecursive\x12A\n\x11io_time_recursive\x18\x07\x20\x03(\x0b2\
+    \x15.grpc.BlkioStatsEntryR\x0fioTimeRecursive\x12B\n\x11sectors_recursiv\
+    e\x18\x08\x20\x03(\x0b2\x15.grpc.BlkioStatsEntryR\x10sectorsRecursive\"[\
+    \n\x0cHugetlbStats\x12\x14\n\x05usage\x18\x01\x20\x01(\x04R\x05usage\x12\
+    \x1b\n\tmax_usage\x18\x02\x20\x01(\x04R\x08maxUsage\x12\x18\n\x07failcnt\
+    \x18\x03\x20\x01(\x04R\x07failcnt\"\xf2\x02\n\x0bCgroupStats\x12+\n\tcpu\
+    _stats\x18\x01\x20\x01(\x0b2\x0e.grpc.CpuStatsR\x08cpuStats\x124\n\x0cme\
+    mory_stats\x18\x02\x20\x01(\x0b2\x11.grpc.MemoryStatsR\x0bmemoryStats\
+    \x12.\n\npids_stats\x18\x03\x20\x01(\x0b2\x0f.grpc.PidsStatsR\tpidsStats\
+    \x121\n\x0bblkio_stats\x18\x04\x20\x01(\x0b2\x10.grpc.BlkioStatsR\nblkio\
+    Stats\x12H\n\rhugetlb_stats\x18\x05\x20\x03(\x0b2#.grpc.CgroupStats.Huge\
+    tlbStatsEntryR\x0chugetlbStats\x1aS\n\x11HugetlbStatsEntry\x12\x10\n\x03\
+    key\x18\x01\x20\x01(\tR\x03key\x12(\n\x05value\x18\x02\x20\x01(\x0b2\x12\
+    .grpc.HugetlbStatsR\x05value:\x028\x01\"\x8e\x02\n\x0cNetworkStats\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x19\n\x08rx_bytes\x18\x02\
+    \x20\x01(\x04R\x07rxBytes\x12\x1d\n\nrx_packets\x18\x03\x20\x01(\x04R\tr\
+    xPackets\x12\x1b\n\trx_errors\x18\x04\x20\x01(\x04R\x08rxErrors\x12\x1d\
+    \n\nrx_dropped\x18\x05\x20\x01(\x04R\trxDropped\x12\x19\n\x08tx_bytes\
+    \x18\x06\x20\x01(\x04R\x07txBytes\x12\x1d\n\ntx_packets\x18\x07\x20\x01(\
+    \x04R\ttxPackets\x12\x1b\n\ttx_errors\x18\x08\x20\x01(\x04R\x08txErrors\
+    \x12\x1d\n\ntx_dropped\x18\t\x20\x01(\x04R\ttxDropped\"\x87\x01\n\x16Sta\
+    tsContainerResponse\x124\n\x0ccgroup_stats\x18\x01\x20\x01(\x0b2\x11.grp\
+    c.CgroupStatsR\x0bcgroupStats\x127\n\rnetwork_stats\x18\x02\x20\x03(\x0b\
+    2\x12.grpc.NetworkStatsR\x0cnetworkStats\"d\n\x12WriteStreamRequest\x12!\
+    \n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\x07exec_\
+    id\x18\x02\x20\x01(\tR\x06execId\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\
+    \x04data\"'\n\x13WriteStreamResponse\x12\x10\n\x03len\x18\x01\x20\x01(\r\
+    R\x03len\"a\n\x11ReadStreamRequest\x12!\n\x0ccontainer_id\x18\x01\x20\
+    \x01(\tR\x0bcontainerId\x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06exec\
+    Id\x12\x10\n\x03len\x18\x03\x20\x01(\rR\x03len\"(\n\x12ReadStreamRespons\
+    e\x12\x12\n\x04data\x18\x01\x20\x01(\x0cR\x04data\"O\n\x11CloseStdinRequ\
+    est\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\
+    \x07exec_id\x18\x02\x20\x01(\tR\x06execId\"{\n\x13TtyWinResizeRequest\
+    \x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\x07\
+    exec_id\x18\x02\x20\x01(\tR\x06execId\x12\x10\n\x03row\x18\x03\x20\x01(\
+    \rR\x03row\x12\x16\n\x06column\x18\x04\x20\x01(\rR\x06column\"\x82\x01\n\
+    \x0fStreamIORequest\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontai\
+    nerId\x12\x17\n\x07exec_id\x18\x02\x20\x01(\tR\x06execId\x12\x12\n\x04da\
+    ta\x18\x03\x20\x01(\x0cR\x04data\x12\x1f\n\x0bclose_stdin\x18\x04\x20\
+    \x01(\x08R\ncloseStdin\"r\n\x10StreamIOResponse\x12\x16\n\x06stdout\x18\
+    \x01\x20\x01(\x0cR\x06stdout\x12\x16\n\x06stderr\x18\x02\x20\x01(\x0cR\
+    \x06stderr\x12\x16\n\x06exited\x18\x03\x20\x01(\x08R\x06exited\x12\x16\n\
+    \x06status\x18\x04\x20\x01(\x05R\x06status\"\x91\x01\n\x11ReadOutputRequ\
+    est\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x17\n\
+    \x07exec_id\x18\x02\x20\x01(\tR\x06execId\x12\x16\n\x06stderr\x18\x03\
+    \x20\x01(\x08R\x06stderr\x12\x16\n\x06offset\x18\x04\x20\x01(\x04R\x06of\
+    fset\x12\x10\n\x03len\x18\x05\x20\x01(\rR\x03len\"l\n\x12ReadOutputRespo\
+    nse\x12\x12\n\x04data\x18\x01\x20\x01(\x0cR\x04data\x12\x16\n\x06offset\
+    \x18\x02\x20\x01(\x04R\x06offset\x12\x18\n\x07dropped\x18\x03\x20\x01(\
+    \x04R\x07dropped\x12\x10\n\x03eof\x18\x04\x20\x01(\x08R\x03eof\"\xdb\x01\
+    \n\x14CreateSandboxRequest\x12\x1a\n\x08hostname\x18\x01\x20\x01(\tR\x08\
+    hostname\x12\x10\n\x03dns\x18\x02\x20\x03(\tR\x03dns\x12)\n\x08storages\
+    \x18\x03\x20\x03(\x0b2\r.grpc.StorageR\x08storages\x12#\n\rsandbox_pidns\
+    \x18\x04\x20\x01(\x08R\x0csandboxPidns\x12\x1d\n\nsandbox_id\x18\x05\x20\
+    \x01(\tR\tsandboxId\x12&\n\x0fguest_hook_path\x18\x06\x20\x01(\tR\rguest\
+    HookPath\"\x17\n\x15DestroySandboxRequest\">\n\nInterfaces\x120\n\nInter\
+    faces\x18\x01\x20\x03(\x0b2\x10.types.InterfaceR\nInterfaces\".\n\x06Rou\
+    tes\x12$\n\x06Routes\x18\x01\x20\x03(\x0b2\x0c.types.RouteR\x06Routes\"H\
+    \n\x16UpdateInterfaceRequest\x12.\n\tinterface\x18\x01\x20\x01(\x0b2\x10\
+    .types.InterfaceR\tinterface\";\n\x13UpdateRoutesRequest\x12$\n\x06route\
+    s\x18\x01\x20\x01(\x0b2\x0c.grpc.RoutesR\x06routes\"\x17\n\x15ListInterf\
+    acesRequest\"\x13\n\x11ListRoutesRequest\"$\n\x10UpdateDNSRequest\x12\
+    \x10\n\x03dns\x18\x01\x20\x03(\tR\x03dns\"]\n\x13OnlineCPUMemRequest\x12\
+    \x12\n\x04wait\x18\x01\x20\x01(\x08R\x04wait\x12\x17\n\x07nb_cpus\x18\
+    \x02\x20\x01(\rR\x06nbCpus\x12\x19\n\x08cpu_only\x18\x03\x20\x01(\x08R\
+    \x07cpuOnly\",\n\x16ReseedRandomDevRequest\x12\x12\n\x04data\x18\x02\x20\
+    \x01(\x0cR\x04data\"\x8a\x03\n\x0cAgentDetails\x12\x18\n\x07version\x18\
+    \x01\x20\x01(\tR\x07version\x12\x1f\n\x0binit_daemon\x18\x02\x20\x01(\
+    \x08R\ninitDaemon\x12'\n\x0fdevice_handlers\x18\x03\x20\x03(\tR\x0edevic\
+    eHandlers\x12)\n\x10storage_handlers\x18\x04\x20\x03(\tR\x0fstorageHandl\
+    ers\x12)\n\x10supports_seccomp\x18\x05\x20\x01(\x08R\x0fsupportsSeccomp\
+    \x12%\n\x0ekernel_seccomp\x18\x06\x20\x01(\x08R\rkernelSeccomp\x12%\n\
+    \x0ecgroup_version\x18\x07\x20\x01(\tR\rcgroupVersion\x12-\n\x12cgroup_c\
+    ontrollers\x18\x08\x20\x03(\tR\x11cgroupControllers\x12\x1c\n\ttransport\
+    \x18\t\x20\x01(\tR\ttransport\x12%\n\x0ekernel_version\x18\n\x20\x01(\tR\
+    \rkernelVersion\"g\n\x13GuestDetailsRequest\x12$\n\x0emem_block_size\x18\
+    \x01\x20\x01(\x08R\x0cmemBlockSize\x12*\n\x11mem_hotplug_probe\x18\x02\
+    \x20\x01(\x08R\x0fmemHotplugProbe\"\xbb\x01\n\x14GuestDetailsResponse\
+    \x12/\n\x14mem_block_size_bytes\x18\x01\x20\x01(\x04R\x11memBlockSizeByt\
+    es\x127\n\ragent_details\x18\x02\x20\x01(\x0b2\x12.grpc.AgentDetailsR\
+    \x0cagentDetails\x129\n\x19support_mem_hotplug_probe\x18\x03\x20\x01(\
+    \x08R\x16supportMemHotplugProbe\"L\n\x18MemHotplugByProbeRequest\x120\n\
+    \x13memHotplugProbeAddr\x18\x01\x20\x03(\x04R\x13memHotplugProbeAddr\"?\
+    \n\x17SetGuestDateTimeRequest\x12\x10\n\x03Sec\x18\x01\x20\x01(\x03R\x03\
+    Sec\x12\x12\n\x04Usec\x18\x02\x20\x01(\x03R\x04Usec\"\xb3\x01\n\x07Stora\
+    ge\x12\x16\n\x06driver\x18\x01\x20\x01(\tR\x06driver\x12%\n\x0edriver_op\
+    tions\x18\x02\x20\x03(\tR\rdriverOptions\x12\x16\n\x06source\x18\x03\x20\
+    \x01(\tR\x06source\x12\x16\n\x06fstype\x18\x04\x20\x01(\tR\x06fstype\x12\
+    \x18\n\x07options\x18\x05\x20\x03(\tR\x07options\x12\x1f\n\x0bmount_poin\
+    t\x18\x06\x20\x01(\tR\nmountPoint\"\x86\x01\n\x06Device\x12\x0e\n\x02id\
+    \x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04type\x18\x02\x20\x01(\tR\x04type\
+    \x12\x17\n\x07vm_path\x18\x03\x20\x01(\tR\x06vmPath\x12%\n\x0econtainer_\
+    path\x18\x04\x20\x01(\tR\rcontainerPath\x12\x18\n\x07options\x18\x05\x20\
+    \x03(\tR\x07options\"X\n\nStringUser\x12\x10\n\x03uid\x18\x01\x20\x01(\t\
+    R\x03uid\x12\x10\n\x03gid\x18\x02\x20\x01(\tR\x03gid\x12&\n\x0eadditiona\
+    lGids\x18\x03\x20\x03(\tR\x0eadditionalGids\"\xe2\x01\n\x0fCopyFileReque\
+    st\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\x1b\n\tfile_size\
+    \x18\x02\x20\x01(\x03R\x08fileSize\x12\x1b\n\tfile_mode\x18\x03\x20\x01(\
+    \rR\x08fileMode\x12\x19\n\x08dir_mode\x18\x04\x20\x01(\rR\x07dirMode\x12\
+    \x10\n\x03uid\x18\x05\x20\x01(\x05R\x03uid\x12\x10\n\x03gid\x18\x06\x20\
+    \x01(\x05R\x03gid\x12\x16\n\x06offset\x18\x07\x20\x01(\x03R\x06offset\
+    \x12\x12\n\x04data\x18\x08\x20\x01(\x0cR\x04data\x12\x16\n\x06sha256\x18\
+    \t\x20\x01(\x0cR\x06sha256\">\n\x10CopyFileResponse\x12\x16\n\x06offset\
+    \x18\x01\x20\x01(\x03R\x06offset\x12\x12\n\x04done\x18\x02\x20\x01(\x08R\
+    \x04done\"O\n\x0fReadFileRequest\x12\x12\n\x04path\x18\x01\x20\x01(\tR\
+    \x04path\x12\x16\n\x06offset\x18\x02\x20\x01(\x03R\x06offset\x12\x10\n\
+    \x03len\x18\x03\x20\x01(\rR\x03len\"n\n\x08DirEntry\x12\x12\n\x04name\
+    \x18\x01\x20\x01(\tR\x04name\x12\x10\n\x03dir\x18\x02\x20\x01(\x08R\x03d\
+    ir\x12\x12\n\x04size\x18\x03\x20\x01(\x03R\x04size\x12\x12\n\x04mode\x18\
+    \x04\x20\x01(\rR\x04mode\x12\x14\n\x05mtime\x18\x05\x20\x01(\x03R\x05mti\
+    me\"\x7f\n\x10ReadFileResponse\x12\x12\n\x04data\x18\x01\x20\x01(\x0cR\
+    \x04data\x12\x1b\n\tfile_size\x18\x02\x20\x01(\x03R\x08fileSize\x12(\n\
+    \x07entries\x18\x03\x20\x03(\x0b2\x0e.grpc.DirEntryR\x07entries\x12\x10\
+    \n\x03dir\x18\x04\x20\x01(\x08R\x03dir\"\x14\n\x12GetOOMEventRequest\"-\
+    \n\x08OOMEvent\x12!\n\x0ccontainer_id\x18\x01\x20\x01(\tR\x0bcontainerId\
+    \"E\n\x1eSubscribeMemoryPressureRequest\x12#\n\rcontainer_ids\x18\x01\
+    \x20\x03(\tR\x0ccontainerIds\"N\n\x13MemoryPressureEvent\x12!\n\x0cconta\
+    iner_id\x18\x01\x20\x01(\tR\x0bcontainerId\x12\x14\n\x05level\x18\x02\
+    \x20\x01(\tR\x05level\"\x13\n\x11GetMetricsRequest\"#\n\x07Metrics\x12\
+    \x18\n\x07metrics\x18\x01\x20\x01(\tR\x07metrics\"\x15\n\x13StartTracing\
+    Request\"\x14\n\x12StopTracingRequest2\xd8\x13\n\x0cAgentService\x12G\n\
+    \x0fCreateContainer\x12\x1c.grpc.CreateContainerRequest\x1a\x16.google.p\
+    rotobuf.Empty\x12E\n\x0eStartContainer\x12\x1b.grpc.StartContainerReques\
+    t\x1a\x16.google.protobuf.Empty\x12G\n\x0fRemoveContainer\x12\x1c.grpc.R\
+    emoveContainerRequest\x1a\x16.google.protobuf.Empty\x12?\n\x0bExecProces\
+    s\x12\x18.grpc.ExecProcessRequest\x1a\x16.google.protobuf.Empty\x12C\n\r\
+    SignalProcess\x12\x1a.grpc.SignalProcessRequest\x1a\x16.google.protobuf.\
+    Empty\x12B\n\x0bWaitProcess\x12\x18.grpc.WaitProcessRequest\x1a\x19.grpc\
+    .WaitProcessResponse\x12H\n\rListProcesses\x12\x1a.grpc.ListProcessesReq\
+    uest\x1a\x1b.grpc.ListProcessesResponse\x12G\n\x0fUpdateContainer\x12\
+    \x1c.grpc.UpdateContainerRequest\x1a\x16.google.protobuf.Empty\x12K\n\
+    \x0eStatsContainer\x12\x1b.grpc.StatsContainerRequest\x1a\x1c.grpc.Stats\
+    ContainerResponse\x12E\n\x0ePauseContainer\x12\x1b.grpc.PauseContainerRe\
+    quest\x1a\x16.google.protobuf.Empty\x12G\n\x0fResumeContainer\x12\x1c.gr\
+    pc.ResumeContainerRequest\x1a\x16.google.protobuf.Empty\x127\n\x0bGetOOM\
+    Event\x12\x18.grpc.GetOOMEventRequest\x1a\x0e.grpc.OOMEvent\x12\\\n\x17S\
+    ubscribeMemoryPressure\x12$.grpc.SubscribeMemoryPressureRequest\x1a\x19.\
+    grpc.MemoryPressureEvent0\x01\x12A\n\nWriteStdin\x12\x18.grpc.WriteStrea\
+    mRequest\x1a\x19.grpc.WriteStreamResponse\x12?\n\nReadStdout\x12\x17.grp\
+    c.ReadStreamRequest\x1a\x18.grpc.ReadStreamResponse\x12?\n\nReadStderr\
+    \x12\x17.grpc.ReadStreamRequest\x1a\x18.grpc.ReadStreamResponse\x12=\n\n\
+    CloseStdin\x12\x17.grpc.CloseStdinRequest\x1a\x16.google.protobuf.Empty\
+    \x12A\n\x0cTtyWinResize\x12\x19.grpc.TtyWinResizeRequest\x1a\x16.google.\
+    protobuf.Empty\x12=\n\x08StreamIO\x12\x15.grpc.StreamIORequest\x1a\x16.g\
+    rpc.StreamIOResponse(\x010\x01\x12?\n\nReadOutput\x12\x17.grpc.ReadOutpu\
+    tRequest\x1a\x18.grpc.ReadOutputResponse\x12A\n\x0fUpdateInterface\x12\
+    \x1c.grpc.UpdateInterfaceRequest\x1a\x10.types.Interface\x127\n\x0cUpdat\
+    eRoutes\x12\x19.grpc.UpdateRoutesRequest\x1a\x0c.grpc.Routes\x12?\n\x0eL\
+    istInterfaces\x12\x1b.grpc.ListInterfacesRequest\x1a\x10.grpc.Interfaces\
+    \x123\n\nListRoutes\x12\x17.grpc.ListRoutesRequest\x1a\x0c.grpc.Routes\
+    \x12;\n\tUpdateDNS\x12\x16.grpc.UpdateDNSRequest\x1a\x16.google.protobuf\
+    .Empty\x12A\n\x0cStartTracing\x12\x19.grpc.StartTracingRequest\x1a\x16.g\
+    oogle.protobuf.Empty\x12?\n\x0bStopTracing\x12\x18.grpc.StopTracingReque\
+    st\x1a\x16.google.protobuf.Empty\x12C\n\rCreateSandbox\x12\x1a.grpc.Crea\
+    teSandboxRequest\x1a\x16.google.protobuf.Empty\x12E\n\x0eDestroySandbox\
+    \x12\x1b.grpc.DestroySandboxRequest\x1a\x16.google.protobuf.Empty\x12A\n\
+    \x0cOnlineCPUMem\x12\x19.grpc.OnlineCPUMemRequest\x1a\x16.google.protobu\
+    f.Empty\x12G\n\x0fReseedRandomDev\x12\x1c.grpc.ReseedRandomDevRequest\
+    \x1a\x16.google.protobuf.Empty\x12H\n\x0fGetGuestDetails\x12\x19.grpc.Gu\
+    estDetailsRequest\x1a\x1a.grpc.GuestDetailsResponse\x12K\n\x11MemHotplug\
+    ByProbe\x12\x1e.grpc.MemHotplugByProbeRequest\x1a\x16.google.protobuf.Em\
+    pty\x12I\n\x10SetGuestDateTime\x12\x1d.grpc.SetGuestDateTimeRequest\x1a\
+    \x16.google.protobuf.Empty\x129\n\x08CopyFile\x12\x15.grpc.CopyFileReque\
+    st\x1a\x16.grpc.CopyFileResponse\x129\n\x08ReadFile\x12\x15.grpc.ReadFil\
+    eRequest\x1a\x16.grpc.ReadFileResponse\x124\n\nGetMetrics\x12\x17.grpc.G\
+    etMetricsRequest\x1a\r.grpc.MetricsJ\xe9\xce\x01\n\x07\x12\x05\x07\0\x9a\
+    \x05\x01\nm\n\x01\x0c\x12\x03\x07\0\x122c\n\x20Copyright\x202017\x20Hype\
+    rHQ\x20Inc.\n\x20Copyright\x202019\x20Ant\x20Financial\n\n\x20SPDX-Licen\
+    se-Identifier:\x20Apache-2.0\n\n\n\x08\n\x01\x02\x12\x03\t\0\r\n\t\n\x02\
+    \x03\0\x12\x03\x0b\0\x13\n\t\n\x02\x03\x01\x12\x03\x0c\0@\n\t\n\x02\x03\
+    \x02\x12\x03\r\0%\n\x16\n\x02\x06\0\x12\x04\x10\0[\x01\x1a\n\x20unstable\
+    \n\n\n\n\x03\x06\0\x01\x12\x03\x10\x08\x14\n\x18\n\x04\x06\0\x02\0\x12\
+    \x03\x12\x08T\x1a\x0b\x20execution\n\n\x0c\n\x05\x06\0\x02\0\x01\x12\x03\
+    \x12\x0c\x1b\n\x0c\n\x05\x06\0\x02\0\x02\x12\x03\x12\x1c2\n\x0c\n\x05\
+    \x06\0\x02\0\x03\x12\x03\x12=R\n\x0b\n\x04\x06\0\x02\x01\x12\x03\x13\x08\
+    R\n\x0c\n\x05\x06\0\x02\x01\x01\x12\x03\x13\x0c\x1a\n\x0c\n\x05\x06\0\
+    \x02\x01\x02\x12\x03\x13\x1b0\n\x0c\n\x05\x06\0\x02\x01\x03\x12\x03\x13;\
+    P\n\x9c\x03\n\x04\x06\0\x02\x02\x12\x03\x1b\x08T\x1a\x8e\x03\x20RemoveCo\
+    ntainer\x20will\x20tear\x20down\x20an\x20existing\x20container\x20by\x20\
+    forcibly\x20terminating\n\x20all\x20processes\x20running\x20inside\x20th\
+    at\x20container\x20and\x20releasing\x20all\x20internal\n\x20resources\
+    \x20associated\x20with\x20it.\n\x20RemoveContainer\x20will\x20wait\x20fo\
+    r\x20all\x20processes\x20termination\x20before\x20returning.\n\x20If\x20\
+    any\x20process\x20can\x20not\x20be\x20killed\x20or\x20if\x20it\x20can\
+    \x20not\x20be\x20killed\x20after\n\x20the\x20RemoveContainerRequest\x20t\
+    imeout,\x20RemoveContainer\x20will\x20return\x20an\x20error.\n\n\x0c\n\
+    \x05\x06\0\x02\x02\x01\x12\x03\x1b\x0c\x1b\n\x0c\n\x05\x06\0\x02\x02\x02\
+    \x12\x03\x1b\x1c2\n\x0c\n\x05\x06\0\x02\x02\x03\x12\x03\x1b=R\n\x0b\n\
+    \x04\x06\0\x02\x03\x12\x03\x1c\x08L\n\x0c\n\x05\x06\0\x02\x03\x01\x12\
+    \x03\x1c\x0c\x17\n\x0c\n\x05\x06\0\x02\x03\x02\x12\x03\x1c\x18*\n\x0c\n\
+    \x05\x06\0\x02\x03\x03\x12\x03\x1c5J\n\x0b\n\x04\x06\0\x02\x04\x12\x03\
+    \x1d\x08P\n\x0c\n\x05\x06\0\x02\x04\x01\x12\x03\x1d\x0c\x19\n\x0c\n\x05\
+    \x06\0\x02\x04\x02\x12\x03\x1d\x1a.\n\x0c\n\x05\x06\0\x02\x04\x03\x12\
+    \x03\x1d9N\n*\n\x04\x06\0\x02\x05\x12\x03\x1e\x08J\"\x1d\x20wait\x20&\
+    \x20reap\x20like\x20waitpid(2)\n\n\x0c\n\x05\x06\0\x02\x05\x01\x12\x03\
+    \x1e\x0c\x17\n\x0c\n\x05\x06\0\x02\x05\x02\x12\x03\x1e\x18*\n\x0c\n\x05\
+    \x06\0\x02\x05\x03\x12\x03\x1e5H\n\x0b\n\x04\x06\0\x02\x06\x12\x03\x1f\
+    \x08P\n\x0c\n\x05\x06\0\x02\x06\x01\x12\x03\x1f\x0c\x19\n\x0c\n\x05\x06\
+    \0\x02\x06\x02\x12\x03\x1f\x1a.\n\x0c\n\x05\x06\0\x02\x06\x03\x12\x03\
+    \x1f9N\n\x0b\n\x04\x06\0\x02\x07\x12\x03\x20\x08T\n\x0c\n\x05\x06\0\x02\
+    \x07\x01\x12\x03\x20\x0c\x1b\n\x0c\n\x05\x06\0\x02\x07\x02\x12\x03\x20\
+    \x1c2\n\x0c\n\x05\x06\0\x02\x07\x03\x12\x03\x20=R\n\x0b\n\x04\x06\0\x02\
+    \x08\x12\x03!\x08S\n\x0c\n\x05\x06\0\x02\x08\x01\x12\x03!\x0c\x1a\n\x0c\
+    \n\x05\x06\0\x02\x08\x02\x12\x03!\x1b0\n\x0c\n\x05\x06\0\x02\x08\x03\x12\
+    \x03!;Q\n\x0b\n\x04\x06\0\x02\t\x12\x03\"\x08R\n\x0c\n\x05\x06\0\x02\t\
+    \x01\x12\x03\"\x0c\x1a\n\x0c\n\x05\x06\0\x02\t\x02\x12\x03\"\x1b0\n\x0c\
+    \n\x05\x06\0\x02\t\x03\x12\x03\";P\n\x0b\n\x04\x06\0\x02\n\x12\x03#\x08T\
+    \n\x0c\n\x05\x06\0\x02\n\x01\x12\x03#\x0c\x1b\n\x0c\n\x05\x06\0\x02\n\
+    \x02\x12\x03#\x1c2\n\x0c\n\x05\x06\0\x02\n\x03\x12\x03#=R\n\xac\x01\n\
+    \x04\x06\0\x02\x0b\x12\x03(\x08?\x1a\x9e\x01\x20GetOOMEvent\x20blocks\
+    \x20until\x20the\x20kernel\x20OOM-kills\x20a\x20process\x20in\x20one\x20\
+    of\x20the\n\x20containers\x20and\x20returns\x20the\x20id\x20of\x20that\
+    \x20container.\x20Each\x20event\x20goes\x20to\n\x20one\x20caller\x20only\
+    .\n\n\x0c\n\x05\x06\0\x02\x0b\x01\x12\x03(\x0c\x17\n\x0c\n\x05\x06\0\x02\
+    \x0b\x02\x12\x03(\x18*\n\x0c\n\x05\x06\0\x02\x0b\x03\x12\x03(5=\n\xde\
+    \x01\n\x04\x06\0\x02\x0c\x12\x03-\x08i\x1a\xd0\x01\x20SubscribeMemoryPre\
+    ssure\x20streams\x20the\x20memory\x20pressure\x20events\x20of\x20the\n\
+    \x20containers,\x20as\x20set\x20up\x20by\x20CreateContainerRequest.memor\
+    y_pressure,\x20until\n\x20the\x20caller\x20goes\x20away.\x20Every\x20sub\
+    scriber\x20gets\x20every\x20event.\x20gRPC\x20only.\n\n\x0c\n\x05\x06\0\
+    \x02\x0c\x01\x12\x03-\x0c#\n\x0c\n\x05\x06\0\x02\x0c\x02\x12\x03-$B\n\
+    \x0c\n\x05\x06\0\x02\x0c\x06\x12\x03-MS\n\x0c\n\x05\x06\0\x02\x0c\x03\
+    \x12\x03-Tg\n\x14\n\x04\x06\0\x02\r\x12\x030\x08I\x1a\x07\x20stdio\n\n\
+    \x0c\n\x05\x06\0\x02\r\x01\x12\x030\x0c\x16\n\x0c\n\x05\x06\0\x02\r\x02\
+    \x12\x030\x17)\n\x0c\n\x05\x06\0\x02\r\x03\x12\x0304G\n\x0b\n\x04\x06\0\
+    \x02\x0e\x12\x031\x08G\n\x0c\n\x05\x06\0\x02\x0e\x01\x12\x031\x0c\x16\n\
+    \x0c\n\x05\x06\0\x02\x0e\x02\x12\x031\x17(\n\x0c\n\x05\x06\0\x02\x0e\x03\
+    \x12\x0313E\n\x0b\n\x04\x06\0\x02\x0f\x12\x032\x08G\n\x0c\n\x05\x06\0\
+    \x02\x0f\x01\x12\x032\x0c\x16\n\x0c\n\x05\x06\0\x02\x0f\x02\x12\x032\x17\
+    (\n\x0c\n\x05\x06\0\x02\x0f\x03\x12\x0323E\n\x0b\n\x04\x06\0\x02\x10\x12\
+    \x033\x08J\n\x0c\n\x05\x06\0\x02\x10\x01\x12\x033\x0c\x16\n\x0c\n\x05\
+    \x06\0\x02\x10\x02\x12\x033\x17(\n\x0c\n\x05\x06\0\x02\x10\x03\x12\x0333\
+    H\n\x0b\n\x04\x06\0\x02\x11\x12\x034\x08N\n\x0c\n\x05\x06\0\x02\x11\x01\
+    \x12\x034\x0c\x18\n\x0c\n\x05\x06\0\x02\x11\x02\x12\x034\x19,\n\x0c\n\
+    \x05\x06\0\x02\x11\x03\x12\x0347L\n\x84\x02\n\x04\x06\0\x02\x12\x12\x03:\
+    \x08O\x1a\xf6\x01\x20StreamIO\x20attaches\x20to\x20the\x20stdio\x20of\
+    \x20a\x20process.\x20The\x20first\x20request\x20selects\n\x20the\x20proc\
+    ess,\x20the\x20following\x20ones\x20carry\x20stdin\x20data\x20or\x20clos\
+    e\x20stdin.\n\x20Output\x20is\x20pushed\x20as\x20soon\x20as\x20it\x20is\
+    \x20available\x20and\x20the\x20last\x20response\n\x20carries\x20the\x20e\
+    xit\x20status\x20of\x20the\x20process.\n\n\x0c\n\x05\x06\0\x02\x12\x01\
+    \x12\x03:\x0c\x14\n\x0c\n\x05\x06\0\x02\x12\x05\x12\x03:\x15\x1b\n\x0c\n\
+    \x05\x06\0\x02\x12\x02\x12\x03:\x1c+\n\x0c\n\x05\x06\0\x02\x12\x06\x12\
+    \x03:6<\n\x0c\n\x05\x06\0\x02\x12\x03\x12\x03:=M\n\x95\x01\n\x04\x06\0\
+    \x02\x13\x12\x03>\x08G\x1a\x87\x01\x20ReadOutput\x20replays\x20the\x20ca\
+    ptured\x20output\x20of\x20a\x20process\x20starting\x20at\n\x20offset,\
+    \x20it\x20blocks\x20until\x20some\x20output\x20is\x20available\x20or\x20\
+    the\x20stream\x20ends.\n\n\x0c\n\x05\x06\0\x02\x13\x01\x12\x03>\x0c\x16\
+    \n\x0c\n\x05\x06\0\x02\x13\x02\x12\x03>\x17(\n\x0c\n\x05\x06\0\x02\x13\
+    \x03\x12\x03>3E\n\x19\n\x04\x06\0\x02\x14\x12\x03A\x08N\x1a\x0c\x20netwo\
+    rking\n\n\x0c\n\x05\x06\0\x02\x14\x01\x12\x03A\x0c\x1b\n\x0c\n\x05\x06\0\
+    \x02\x14\x02\x12\x03A\x1c2\n\x0c\n\x05\x06\0\x02\x14\x03\x12\x03A=L\n\
+    \x0b\n\x04\x06\0\x02\x15\x12\x03B\x08?\n\x0c\n\x05\x06\0\x02\x15\x01\x12\
+    \x03B\x0c\x18\n\x0c\n\x05\x06\0\x02\x15\x02\x12\x03B\x19,\n\x0c\n\x05\
+    \x06\0\x02\x15\x03\x12\x03B7=\n\x0b\n\x04\x06\0\x02\x16\x12\x03C\x08F\n\
+    \x0c\n\x05\x06\0\x02\x16\x01\x12\x03C\x0c\x1a\n\x0c\n\x05\x06\0\x02\x16\
+    \x02\x12\x03C\x1b0\n\x0c\n\x05\x06\0\x02\x16\x03\x12\x03C:D\n\x0b\n\x04\
+    \x06\0\x02\x17\x12\x03D\x08;\n\x0c\n\x05\x06\0\x02\x17\x01\x12\x03D\x0c\
+    \x16\n\x0c\n\x05\x06\0\x02\x17\x02\x12\x03D\x17(\n\x0c\n\x05\x06\0\x02\
+    \x17\x03\x12\x03D39\nv\n\x04\x06\0\x02\x18\x12\x03G\x08H\x1ai\x20UpdateD\
+    NS\x20replaces\x20the\x20resolv.conf\x20shared\x20by\x20all\x20the\x20co\
+    ntainers\n\x20of\x20the\x20sandbox,\x20running\x20ones\x20included.\n\n\
+    \x0c\n\x05\x06\0\x02\x18\x01\x12\x03G\x0c\x15\n\x0c\n\x05\x06\0\x02\x18\
+    \x02\x12\x03G\x16&\n\x0c\n\x05\x06\0\x02\x18\x03\x12\x03G1F\n\x16\n\x04\
+    \x06\0\x02\x19\x12\x03J\x08N\x1a\t\x20tracing\n\n\x0c\n\x05\x06\0\x02\
+    \x19\x01\x12\x03J\x0c\x18\n\x0c\n\x05\x06\0\x02\x19\x02\x12\x03J\x19,\n\
+    \x0c\n\x05\x06\0\x02\x19\x03\x12\x03J7L\n\x0b\n\x04\x06\0\x02\x1a\x12\
+    \x03K\x08L\n\x0c\n\x05\x06\0\x02\x1a\x01\x12\x03K\x0c\x17\n\x0c\n\x05\
+    \x06\0\x02\x1a\x02\x12\x03K\x18*\n\x0c\n\x05\x06\0\x02\x1a\x03\x12\x03K5\
+    J\nH\n\x04\x06\0\x02\x1b\x12\x03N\x08P\x1a;\x20misc\x20(TODO:\x20some\
+    \x20rpcs\x20can\x20be\x20replaced\x20by\x20hyperstart-exec)\n\n\x0c\n\
+    \x05\x06\0\x02\x1b\x01\x12\x03N\x0c\x19\n\x0c\n\x05\x06\0\x02\x1b\x02\
+    \x12\x03N\x1a.\n\x0c\n\x05\x06\0\x02\x1b\x03\x12\x03N9N\n\x0b\n\x04\x06\
+    \0\x02\x1c\x12\x03O\x08R\n\x0c\n\x05\x06\0\x02\x1c\x01\x12\x03O\x0c\x1a\
+    \n\x0c\n\x05\x06\0\x02\x1c\x02\x12\x03O\x1b0\n\x0c\n\x05\x06\0\x02\x1c\
+    \x03\x12\x03O;P\n\x0b\n\x04\x06\0\x02\x1d\x12\x03P\x08N\n\x0c\n\x05\x06\
+    \0\x02\x1d\x01\x12\x03P\x0c\x18\n\x0c\n\x05\x06\0\x02\x1d\x02\x12\x03P\
+    \x19,\n\x0c\n\x05\x06\0\x02\x1d\x03\x12\x03P7L\n\x0b\n\x04\x06\0\x02\x1e\
+    \x12\x03Q\x08T\n\x0c\n\x05\x06\0\x02\x1e\x01\x12\x03Q\x0c\x1b\n\x0c\n\
+    \x05\x06\0\x02\x1e\x02\x12\x03Q\x1c2\n\x0c\n\x05\x06\0\x02\x1e\x03\x12\
+    \x03Q=R\n\x0b\n\x04\x06\0\x02\x1f\x12\x03R\x08P\n\x0c\n\x05\x06\0\x02\
+    \x1f\x01\x12\x03R\x0c\x1b\n\x0c\n\x05\x06\0\x02\x1f\x02\x12\x03R\x1c/\n\
+    \x0c\n\x05\x06\0\x02\x1f\x03\x12\x03R:N\n\x0b\n\x04\x06\0\x02\x20\x12\
+    \x03S\x08X\n\x0c\n\x05\x06\0\x02\x20\x01\x12\x03S\x0c\x1d\n\x0c\n\x05\
+    \x06\0\x02\x20\x02\x12\x03S\x1e6\n\x0c\n\x05\x06\0\x02\x20\x03\x12\x03SA\
+    V\n\x0b\n\x04\x06\0\x02!\x12\x03T\x08V\n\x0c\n\x05\x06\0\x02!\x01\x12\
+    \x03T\x0c\x1c\n\x0c\n\x05\x06\0\x02!\x02\x12\x03T\x1d4\n\x0c\n\x05\x06\0\
+    \x02!\x03\x12\x03T?T\n\x0b\n\x04\x06\0\x02\"\x12\x03U\x08A\n\x0c\n\x05\
+    \x06\0\x02\"\x01\x12\x03U\x0c\x14\n\x0c\n\x05\x06\0\x02\"\x02\x12\x03U\
+    \x15$\n\x0c\n\x05\x06\0\x02\"\x03\x12\x03U/?\n\x0b\n\x04\x06\0\x02#\x12\
+    \x03V\x08A\n\x0c\n\x05\x06\0\x02#\x01\x12\x03V\x0c\x14\n\x0c\n\x05\x06\0\
+    \x02#\x02\x12\x03V\x15$\n\x0c\n\x05\x06\0\x02#\x03\x12\x03V/?\n\x89\x01\
+    \n\x04\x06\0\x02$\x12\x03Z\x08<\x1a|\x20GetMetrics\x20returns\x20the\x20\
+    metrics\x20of\x20the\x20guest,\x20of\x20the\x20agent\x20and\x20of\x20the\
+    \n\x20containers\x20in\x20the\x20Prometheus\x20text\x20exposition\x20for\
+    mat.\n\n\x0c\n\x05\x06\0\x02$\x01\x12\x03Z\x0c\x16\n\x0c\n\x05\x06\0\x02\
+    $\x02\x12\x03Z\x17(\n\x0c\n\x05\x06\0\x02$\x03\x12\x03Z3:\n\n\n\x02\x04\
+    \0\x12\x04]\0r\x01\n\n\n\x03\x04\0\x01\x12\x03]\x08\x1e\n\x0b\n\x04\x04\
+    \0\x02\0\x12\x03^\x08\x20\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03^\x08\x0e\n\
+    \x0c\n\x05\x04\0\x02\0\x01\x12\x03^\x0f\x1b\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03^\x1e\x1f\n\x0b\n\x04\x04\0\x02\x01\x12\x03_\x08\x1b\n\x0c\n\x05\
+    \x04\0\x02\x01\x05\x12\x03_\x08\x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
+    \x03_\x0f\x16\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03_\x19\x1a\n\x0b\n\x04\
+    \x04\0\x02\x02\x12\x03`\x08#\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03`\x08\
+    \x12\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03`\x13\x1e\n\x0c\n\x05\x04\0\
+    \x02\x02\x03\x12\x03`!\"\n\x0b\n\x04\x04\0\x02\x03\x12\x03a\x08$\n\x0c\n\
+    \x05\x04\0\x02\x03\x04\x12\x03a\x08\x10\n\x0c\n\x05\x04\0\x02\x03\x06\
+    \x12\x03a\x11\x17\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03a\x18\x1f\n\x0c\n\
+    \x05\x04\0\x02\x03\x03\x12\x03a\"#\n\x0b\n\x04\x04\0\x02\x04\x12\x03b\
+    \x08&\n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03b\x08\x10\n\x0c\n\x05\x04\0\
+    \x02\x04\x06\x12\x03b\x11\x18\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03b\x19\
+    !\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03b$%\n\x0b\n\x04\x04\0\x02\x05\x12\
+    \x03c\x08\x15\n\x0c\n\x05\x04\0\x02\x05\x06\x12\x03c\x08\x0c\n\x0c\n\x05\
+    \x04\0\x02\x05\x01\x12\x03c\r\x10\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03c\
+    \x13\x14\n\xba\x02\n\x04\x04\0\x02\x06\x12\x03j\x08\x1f\x1a\xac\x02\x20T\
+    his\x20field\x20is\x20used\x20to\x20indicate\x20if\x20the\x20container\
+    \x20needs\x20to\x20join\n\x20sandbox\x20shared\x20pid\x20ns\x20or\x20cre\
+    ate\x20a\x20new\x20namespace.\x20This\x20field\x20is\n\x20meant\x20to\
+    \x20override\x20the\x20NEWPID\x20config\x20settings\x20in\x20the\x20OCI\
+    \x20spec.\n\x20The\x20agent\x20would\x20receive\x20an\x20OCI\x20spec\x20\
+    with\x20PID\x20namespace\x20cleared\n\x20out\x20altogether\x20and\x20not\
+    \x20just\x20the\x20pid\x20ns\x20path.\n\n\x0c\n\x05\x04\0\x02\x06\x05\
+    \x12\x03j\x08\x0c\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03j\r\x1a\n\x0c\n\
+    \x05\x04\0\x02\x06\x03\x12\x03j\x1d\x1e\n\x7f\n\x04\x04\0\x02\x07\x12\
+    \x03n\x08&\x1ar\x20Size\x20in\x20bytes\x20of\x20the\x20ring\x20buffers\
+    \x20keeping\x20the\x20stdout\x20and\x20stderr\n\x20of\x20the\x20init\x20\
+    process,\x200\x20means\x20the\x20agent\x20default.\n\n\x0c\n\x05\x04\0\
+    \x02\x07\x05\x12\x03n\x08\x0e\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03n\x0f\
+    !\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03n$%\nR\n\x04\x04\0\x02\x08\x12\
+    \x03q\x08=\x1aE\x20Memory\x20pressure\x20levels\x20reported\x20for\x20th\
+    e\x20container,\x20none\x20by\x20default.\n\n\x0c\n\x05\x04\0\x02\x08\
+    \x04\x12\x03q\x08\x10\n\x0c\n\x05\x04\0\x02\x08\x06\x12\x03q\x11(\n\x0c\
+    \n\x05\x04\0\x02\x08\x01\x12\x03q)8\n\x0c\n\x05\x04\0\x02\x08\x03\x12\
+    \x03q;<\n\n\n\x02\x04\x01\x12\x04t\0}\x01\n\n\n\x03\x04\x01\x01\x12\x03t\
+    \x08\x1f\n[\n\x04\x04\x01\x02\0\x12\x03w\x08\x19\x1aN\x20Level\x20is\x20\
+    low,\x20medium\x20or\x20critical,\x20as\x20in\x20memory.pressure_level\
+    \x20of\n\x20cgroup\x20v1.\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03w\x08\
+    \x0e\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03w\x0f\x14\n\x0c\n\x05\x04\x01\
+    \x02\0\x03\x12\x03w\x17\x18\n\xcb\x01\n\x04\x04\x01\x02\x01\x12\x03{\x08\
+    \x1c\x1a\xbd\x01\x20With\x20cgroup\x20v2\x20the\x20level\x20is\x20report\
+    ed\x20when\x20the\x20tasks\x20of\x20the\x20container\n\x20stall\x20on\
+    \x20memory\x20for\x20StallUs\x20within\x20any\x20WindowUs,\x20all\x20of\
+    \x20them\x20at\x20once\n\x20for\x20critical.\x200\x20means\x20the\x20def\
+    ault\x20of\x20the\x20level.\n\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03{\
+    \x08\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03{\x0f\x17\n\x0c\n\x05\
+    \x04\x01\x02\x01\x03\x12\x03{\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x02\x12\
+    \x03|\x08\x1d\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03|\x08\x0e\n\x0c\n\
+    \x05\x04\x01\x02\x02\x01\x12\x03|\x0f\x18\n\x0c\n\x05\x04\x01\x02\x02\
+    \x03\x12\x03|\x1b\x1c\n\x0b\n\x02\x04\x02\x12\x05\x7f\0\x81\x01\x01\n\n\
+    \n\x03\x04\x02\x01\x12\x03\x7f\x08\x1d\n\x0c\n\x04\x04\x02\x02\0\x12\x04\
+    \x80\x01\x08\x20\n\r\n\x05\x04\x02\x02\0\x05\x12\x04\x80\x01\x08\x0e\n\r\
+    \n\x05\x04\x02\x02\0\x01\x12\x04\x80\x01\x0f\x1b\n\r\n\x05\x04\x02\x02\0\
+    \x03\x12\x04\x80\x01\x1e\x1f\n\x0c\n\x02\x04\x03\x12\x06\x83\x01\0\x8c\
+    \x01\x01\n\x0b\n\x03\x04\x03\x01\x12\x04\x83\x01\x08\x1e\n\x0c\n\x04\x04\
+    \x03\x02\0\x12\x04\x84\x01\x08\x20\n\r\n\x05\x04\x03\x02\0\x05\x12\x04\
+    \x84\x01\x08\x0e\n\r\n\x05\x04\x03\x02\0\x01\x12\x04\x84\x01\x0f\x1b\n\r\
+    \n\x05\x04\x03\x02\0\x03\x12\x04\x84\x01\x1e\x1f\n\xbd\x01\n\x04\x04\x03\
+    \x02\x01\x12\x04\x8b\x01\x08\x1b\x1a\xae\x01\x20RemoveContainer\x20will\
+    \x20return\x20an\x20error\x20if\n\x20it\x20could\x20not\x20kill\x20some\
+    \x20container\x20processes\n\x20after\x20timeout\x20seconds.\n\x20Settin\
+    g\x20timeout\x20to\x200\x20means\x20RemoveContainer\x20will\n\x20wait\
+    \x20for\x20ever.\n\n\r\n\x05\x04\x03\x02\x01\x05\x12\x04\x8b\x01\x08\x0e\
+    \n\r\n\x05\x04\x03\x02\x01\x01\x12\x04\x8b\x01\x0f\x16\n\r\n\x05\x04\x03\
+    \x02\x01\x03\x12\x04\x8b\x01\x19\x1a\n\x0c\n\x02\x04\x04\x12\x06\x8e\x01\
+    \0\x97\x01\x01\n\x0b\n\x03\x04\x04\x01\x12\x04\x8e\x01\x08\x1a\n\x0c\n\
+    \x04\x04\x04\x02\0\x12\x04\x8f\x01\x08\x20\n\r\n\x05\x04\x04\x02\0\x05\
+    \x12\x04\x8f\x01\x08\x0e\n\r\n\x05\x04\x04\x02\0\x01\x12\x04\x8f\x01\x0f\
+    \x1b\n\r\n\x05\x04\x04\x02\0\x03\x12\x04\x8f\x01\x1e\x1f\n\x0c\n\x04\x04\
+    \x04\x02\x01\x12\x04\x90\x01\x08\x1b\n\r\n\x05\x04\x04\x02\x01\x05\x12\
+    \x04\x90\x01\x08\x0e\n\r\n\x05\x04\x04\x02\x01\x01\x12\x04\x90\x01\x0f\
+    \x16\n\r\n\x05\x04\x04\x02\x01\x03\x12\x04\x90\x01\x19\x1a\n\x0c\n\x04\
+    \x04\x04\x02\x02\x12\x04\x91\x01\x08#\n\r\n\x05\x04\x04\x02\x02\x06\x12\
+    \x04\x91\x01\x08\x12\n\r\n\x05\x04\x04\x02\x02\x01\x12\x04\x91\x01\x13\
+    \x1e\n\r\n\x05\x04\x04\x02\x02\x03\x12\x04\x91\x01!\"\n\x0c\n\x04\x04\
+    \x04\x02\x03\x12\x04\x92\x01\x08\x1c\n\r\n\x05\x04\x04\x02\x03\x06\x12\
+    \x04\x92\x01\x08\x0f\n\r\n\x05\x04\x04\x02\x03\x01\x12\x04\x92\x01\x10\
+    \x17\n\r\n\x05\x04\x04\x02\x03\x03\x12\x04\x92\x01\x1a\x1b\n{\n\x04\x04\
+    \x04\x02\x04\x12\x04\x96\x01\x08&\x1am\x20Size\x20in\x20bytes\x20of\x20t\
+    he\x20ring\x20buffers\x20keeping\x20the\x20stdout\x20and\x20stderr\n\x20\
+    of\x20the\x20process,\x200\x20means\x20the\x20agent\x20default.\n\n\r\n\
+    \x05\x04\x04\x02\x04\x05\x12\x04\x96\x01\x08\x0e\n\r\n\x05\x04\x04\x02\
+    \x04\x01\x12\x04\x96\x01\x0f!\n\r\n\x05\x04\x04\x02\x04\x03\x12\x04\x96\
+    \x01$%\n\x0c\n\x02\x04\x05\x12\x06\x99\x01\0\xa1\x01\x01\n\x0b\n\x03\x04\
+    \x05\x01\x12\x04\x99\x01\x08\x1c\n\x0c\n\x04\x04\x05\x02\0\x12\x04\x9a\
+    \x01\x08\x20\n\r\n\x05\x04\x05\x02\0\x05\x12\x04\x9a\x01\x08\x0e\n\r\n\
+    \x05\x04\x05\x02\0\x01\x12\x04\x9a\x01\x0f\x1b\n\r\n\x05\x04\x05\x02\0\
+    \x03\x12\x04\x9a\x01\x1e\x1f\n\xe9\x01\n\x04\x04\x05\x02\x01\x12\x04\x9f\
+    \x01\x08\x1b\x1a\xda\x01\x20Special\x20case\x20for\x20SignalProcess():\
+    \x20exec_id\x20can\x20be\x20empty(\"\"),\n\x20which\x20means\x20to\x20se\
+    nd\x20the\x20signal\x20to\x20all\x20the\x20processes\x20including\x20the\
+    ir\x20descendants.\n\x20Other\x20APIs\x20with\x20exec_id\x20should\x20tr\
+    eat\x20empty\x20exec_id\x20as\x20an\x20invalid\x20request.\n\n\r\n\x05\
+    \x04\x05\x02\x01\x05\x12\x04\x9f\x01\x08\x0e\n\r\n\x05\x04\x05\x02\x01\
+    \x01\x12\x04\x9f\x01\x0f\x16\n\r\n\x05\x04\x05\x02\x01\x03\x12\x04\x9f\
+    \x01\x19\x1a\n\x0c\n\x04\x04\x05\x02\x02\x12\x04\xa0\x01\x08\x1a\n\r\n\
+    \x05\x04\x05\x02\x02\x05\x12\x04\xa0\x01\x08\x0e\n\r\n\x05\x04\x05\x02\
+    \x02\x01\x12\x04\xa0\x01\x0f\x15\n\r\n\x05\x04\x05\x02\x02\x03\x12\x04\
+    \xa0\x01\x18\x19\n\x0c\n\x02\x04\x06\x12\x06\xa3\x01\0\xa6\x01\x01\n\x0b\
+    \n\x03\x04\x06\x01\x12\x04\xa3\x01\x08\x1a\n\x0c\n\x04\x04\x06\x02\0\x12\
+    \x04\xa4\x01\x08\x20\n\r\n\x05\x04\x06\x02\0\x05\x12\x04\xa4\x01\x08\x0e\
+    \n\r\n\x05\x04\x06\x02\0\x01\x12\x04\xa4\x01\x0f\x1b\n\r\n\x05\x04\x06\
+    \x02\0\x03\x12\x04\xa4\x01\x1e\x1f\n\x0c\n\x04\x04\x06\x02\x01\x12\x04\
+    \xa5\x01\x08\x1b\n\r\n\x05\x04\x06\x02\x01\x05\x12\x04\xa5\x01\x08\x0e\n\
+    \r\n\x05\x04\x06\x02\x01\x01\x12\x04\xa5\x01\x0f\x16\n\r\n\x05\x04\x06\
+    \x02\x01\x03\x12\x04\xa5\x01\x19\x1a\n\x0c\n\x02\x04\x07\x12\x06\xa8\x01\
+    \0\xaf\x01\x01\n\x0b\n\x03\x04\x07\x01\x12\x04\xa8\x01\x08\x1b\nW\n\x04\
+    \x04\x07\x02\0\x12\x04\xaa\x01\x08\x19\x1aI\x20exit\x20code,\x20or\x2012\
+    8\x20+\x20signal\x20number\x20if\x20the\x20process\x20was\x20killed\x20b\
+    y\x20a\x20signal\n\n\r\n\x05\x04\x07\x02\0\x05\x12\x04\xaa\x01\x08\r\n\r\
+    \n\x05\x04\x07\x02\0\x01\x12\x04\xaa\x01\x0e\x14\n\r\n\x05\x04\x07\x02\0\
+    \x03\x12\x04\xaa\x01\x17\x18\n\x0c\n\x04\x04\x07\x02\x01\x12\x04\xab\x01\
+    \x08\x1d\n\r\n\x05\x04\x07\x02\x01\x05\x12\x04\xab\x01\x08\x0c\n\r\n\x05\
+    \x04\x07\x02\x01\x01\x12\x04\xab\x01\r\x18\n\r\n\x05\x04\x07\x02\x01\x03\
+    \x12\x04\xab\x01\x1b\x1c\nm\n\x04\x04\x07\x02\x02\x12\x04\xae\x01\x08\
+    \x1c\x1a_\x20the\x20process\x20was\x20SIGKILLed\x20and\x20the\x20contain\
+    er\x20had\x20an\x20OOM\x20kill\n\x20not\x20accounted\x20to\x20another\
+    \x20process\n\n\r\n\x05\x04\x07\x02\x02\x05\x12\x04\xae\x01\x08\x0c\n\r\
+    \n\x05\x04\x07\x02\x02\x01\x12\x04\xae\x01\r\x17\n\r\n\x05\x04\x07\x02\
+    \x02\x03\x12\x04\xae\x01\x1a\x1b\nm\n\x02\x04\x08\x12\x06\xb2\x01\0\xb6\
+    \x01\x01\x1a_\x20ListProcessesRequest\x20contains\x20the\x20options\x20u\
+    sed\x20to\x20list\x20running\x20processes\x20inside\x20the\x20container\
+    \n\n\x0b\n\x03\x04\x08\x01\x12\x04\xb2\x01\x08\x1c\n\x0c\n\x04\x04\x08\
+    \x02\0\x12\x04\xb3\x01\x08\x20\n\r\n\x05\x04\x08\x02\0\x05\x12\x04\xb3\
+    \x01\x08\x0e\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\xb3\x01\x0f\x1b\n\r\n\
+    \x05\x04\x08\x02\0\x03\x12\x04\xb3\x01\x1e\x1f\n\x0c\n\x04\x04\x08\x02\
+    \x01\x12\x04\xb4\x01\x08\x1a\n\r\n\x05\x04\x08\x02\x01\x05\x12\x04\xb4\
+    \x01\x08\x0e\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\xb4\x01\x0f\x15\n\r\n\
+    \x05\x04\x08\x02\x01\x03\x12\x04\xb4\x01\x18\x19\n\x0c\n\x04\x04\x08\x02\
+    \x02\x12\x04\xb5\x01\x08!\n\r\n\x05\x04\x08\x02\x02\x04\x12\x04\xb5\x01\
+    \x08\x10\n\r\n\x05\x04\x08\x02\x02\x05\x12\x04\xb5\x01\x11\x17\n\r\n\x05\
+    \x04\x08\x02\x02\x01\x12\x04\xb5\x01\x18\x1c\n\r\n\x05\x04\x08\x02\x02\
+    \x03\x12\x04\xb5\x01\x1f\x20\nc\n\x02\x04\t\x12\x06\xb9\x01\0\xbb\x01\
+    \x01\x1aU\x20ListProcessesResponse\x20represents\x20the\x20list\x20of\
+    \x20running\x20processes\x20inside\x20the\x20container\n\n\x0b\n\x03\x04\
+    \t\x01\x12\x04\xb9\x01\x08\x1d\n\x0c\n\x04\x04\t\x02\0\x12\x04\xba\x01\
+    \x08\x1f\n\r\n\x05\x04\t\x02\0\x05\x12\x04\xba\x01\x08\r\n\r\n\x05\x04\t\
+    \x02\0\x01\x12\x04\xba\x01\x0e\x1a\n\r\n\x05\x04\t\x02\0\x03\x12\x04\xba\
+    \x01\x1d\x1e\n\x0c\n\x02\x04\n\x12\x06\xbd\x01\0\xc0\x01\x01\n\x0b\n\x03\
+    \x04\n\x01\x12\x04\xbd\x01\x08\x1e\n\x0c\n\x04\x04\n\x02\0\x12\x04\xbe\
+    \x01\x08\x20\n\r\n\x05\x04\n\x02\0\x05\x12\x04\xbe\x01\x08\x0e\n\r\n\x05\
+    \x04\n\x02\0\x01\x12\x04\xbe\x01\x0f\x1b\n\r\n\x05\x04\n\x02\0\x03\x12\
+    \x04\xbe\x01\x1e\x1f\n\x0c\n\x04\x04\n\x02\x01\x12\x04\xbf\x01\x08%\n\r\
+    \n\x05\x04\n\x02\x01\x06\x12\x04\xbf\x01\x08\x16\n\r\n\x05\x04\n\x02\x01\
+    \x01\x12\x04\xbf\x01\x17\x20\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\xbf\x01\
+    #$\n\x0c\n\x02\x04\x0b\x12\x06\xc2\x01\0\xc4\x01\x01\n\x0b\n\x03\x04\x0b\
+    \x01\x12\x04\xc2\x01\x08\x1d\n\x0c\n\x04\x04\x0b\x02\0\x12\x04\xc3\x01\
+    \x04\x1c\n\r\n\x05\x04\x0b\x02\0\x05\x12\x04\xc3\x01\x04\n\n\r\n\x05\x04\
+    \x0b\x02\0\x01\x12\x04\xc3\x01\x0b\x17\n\r\n\x05\x04\x0b\x02\0\x03\x12\
+    \x04\xc3\x01\x1a\x1b\n\x0c\n\x02\x04\x0c\x12\x06\xc6\x01\0\xc8\x01\x01\n\
+    \x0b\n\x03\x04\x0c\x01\x12\x04\xc6\x01\x08\x1d\n\x0c\n\x04\x04\x0c\x02\0\
+    \x12\x04\xc7\x01\x04\x1c\n\r\n\x05\x04\x0c\x02\0\x05\x12\x04\xc7\x01\x04\
+    \n\n\r\n\x05\x04\x0c\x02\0\x01\x12\x04\xc7\x01\x0b\x17\n\r\n\x05\x04\x0c\
+    \x02\0\x03\x12\x04\xc7\x01\x1a\x1b\n\x0c\n\x02\x04\r\x12\x06\xca\x01\0\
+    \xcc\x01\x01\n\x0b\n\x03\x04\r\x01\x12\x04\xca\x01\x08\x1e\n\x0c\n\x04\
+    \x04\r\x02\0\x12\x04\xcb\x01\x04\x1c\n\r\n\x05\x04\r\x02\0\x05\x12\x04\
+    \xcb\x01\x04\n\n\r\n\x05\x04\r\x02\0\x01\x12\x04\xcb\x01\x0b\x17\n\r\n\
+    \x05\x04\r\x02\0\x03\x12\x04\xcb\x01\x1a\x1b\n\x0c\n\x02\x04\x0e\x12\x06\
+    \xce\x01\0\xd3\x01\x01\n\x0b\n\x03\x04\x0e\x01\x12\x04\xce\x01\x08\x10\n\
+    \x0c\n\x04\x04\x0e\x02\0\x12\x04\xcf\x01\x08\x1f\n\r\n\x05\x04\x0e\x02\0\
+    \x05\x12\x04\xcf\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\xcf\x01\
+    \x0f\x1a\n\r\n\x05\x04\x0e\x02\0\x03\x12\x04\xcf\x01\x1d\x1e\n\x0c\n\x04\
+    \x04\x0e\x02\x01\x12\x04\xd0\x01\x08)\n\r\n\x05\x04\x0e\x02\x01\x04\x12\
+    \x04\xd0\x01\x08\x10\n\r\n\x05\x04\x0e\x02\x01\x05\x12\x04\xd0\x01\x11\
+    \x17\n\r\n\x05\x04\x0e\x02\x01\x01\x12\x04\xd0\x01\x18$\n\r\n\x05\x04\
+    \x0e\x02\x01\x03\x12\x04\xd0\x01'(\n\x0c\n\x04\x04\x0e\x02\x02\x12\x04\
+    \xd1\x01\x08'\n\r\n\x05\x04\x0e\x02\x02\x05\x12\x04\xd1\x01\x08\x0e\n\r\
+    \n\x05\x04\x0e\x02\x02\x01\x12\x04\xd1\x01\x0f\"\n\r\n\x05\x04\x0e\x02\
+    \x02\x03\x12\x04\xd1\x01%&\n\x0c\n\x04\x04\x0e\x02\x03\x12\x04\xd2\x01\
+    \x08%\n\r\n\x05\x04\x0e\x02\x03\x05\x12\x04\xd2\x01\x08\x0e\n\r\n\x05\
+    \x04\x0e\x02\x03\x01\x12\x04\xd2\x01\x0f\x20\n\r\n\x05\x04\x0e\x02\x03\
+    \x03\x12\x04\xd2\x01#$\n\x0c\n\x02\x04\x0f\x12\x06\xd5\x01\0\xd9\x01\x01\
+    \n\x0b\n\x03\x04\x0f\x01\x12\x04\xd5\x01\x08\x16\n\x0c\n\x04\x04\x0f\x02\
+    \0\x12\x04\xd6\x01\x08\x1b\n\r\n\x05\x04\x0f\x02\0\x05\x12\x04\xd6\x01\
+    \x08\x0e\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\xd6\x01\x0f\x16\n\r\n\x05\
+    \x04\x0f\x02\0\x03\x12\x04\xd6\x01\x19\x1a\n\x0c\n\x04\x04\x0f\x02\x01\
+    \x12\x04\xd7\x01\x08%\n\r\n\x05\x04\x0f\x02\x01\x05\x12\x04\xd7\x01\x08\
+    \x0e\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xd7\x01\x0f\x20\n\r\n\x05\x04\
+    \x0f\x02\x01\x03\x12\x04\xd7\x01#$\n\x0c\n\x04\x04\x0f\x02\x02\x12\x04\
+    \xd8\x01\x08\"\n\r\n\x05\x04\x0f\x02\x02\x05\x12\x04\xd8\x01\x08\x0e\n\r\
+    \n\x05\x04\x0f\x02\x02\x01\x12\x04\xd8\x01\x0f\x1d\n\r\n\x05\x04\x0f\x02\
+    \x02\x03\x12\x04\xd8\x01\x20!\n\x0c\n\x02\x04\x10\x12\x06\xdb\x01\0\xde\
+    \x01\x01\n\x0b\n\x03\x04\x10\x01\x12\x04\xdb\x01\x08\x10\n\x0c\n\x04\x04\
+    \x10\x02\0\x12\x04\xdc\x01\x08\x1f\n\r\n\x05\x04\x10\x02\0\x06\x12\x04\
+    \xdc\x01\x08\x10\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\xdc\x01\x11\x1a\n\r\
+    \n\x05\x04\x10\x02\0\x03\x12\x04\xdc\x01\x1d\x1e\n\x0c\n\x04\x04\x10\x02\
+    \x01\x12\x04\xdd\x01\x08+\n\r\n\x05\x04\x10\x02\x01\x06\x12\x04\xdd\x01\
+    \x08\x16\n\r\n\x05\x04\x10\x02\x01\x01\x12\x04\xdd\x01\x17&\n\r\n\x05\
+    \x04\x10\x02\x01\x03\x12\x04\xdd\x01)*\n\x0c\n\x02\x04\x11\x12\x06\xe0\
+    \x01\0\xe3\x01\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\xe0\x01\x08\x11\n\x0c\
+    \n\x04\x04\x11\x02\0\x12\x04\xe1\x01\x08\x1b\n\r\n\x05\x04\x11\x02\0\x05\
+    \x12\x04\xe1\x01\x08\x0e\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xe1\x01\x0f\
+    \x16\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xe1\x01\x19\x1a\n\x0c\n\x04\x04\
+    \x11\x02\x01\x12\x04\xe2\x01\x08\x19\n\r\n\x05\x04\x11\x02\x01\x05\x12\
+    \x04\xe2\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x01\x01\x12\x04\xe2\x01\x0f\
+    \x14\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xe2\x01\x17\x18\n\x0c\n\x02\
+    \x04\x12\x12\x06\xe5\x01\0\xea\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\x04\
+    \xe5\x01\x08\x12\n\x0c\n\x04\x04\x12\x02\0\x12\x04\xe6\x01\x08\x19\n\r\n\
+    \x05\x04\x12\x02\0\x05\x12\x04\xe6\x01\x08\x0e\n\r\n\x05\x04\x12\x02\0\
+    \x01\x12\x04\xe6\x01\x0f\x14\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\xe6\x01\
+    \x17\x18\n\x0c\n\x04\x04\x12\x02\x01\x12\x04\xe7\x01\x08\x1d\n\r\n\x05\
+    \x04\x12\x02\x01\x05\x12\x04\xe7\x01\x08\x0e\n\r\n\x05\x04\x12\x02\x01\
+    \x01\x12\x04\xe7\x01\x0f\x18\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\xe7\
+    \x01\x1b\x1c\n\x0c\n\x04\x04\x12\x02\x02\x12\x04\xe8\x01\x08\x1b\n\r\n\
+    \x05\x04\x12\x02\x02\x05\x12\x04\xe8\x01\x08\x0e\n\r\n\x05\x04\x12\x02\
+    \x02\x01\x12\x04\xe8\x01\x0f\x16\n\r\n\x05\x04\x12\x02\x02\x03\x12\x04\
+    \xe8\x01\x19\x1a\n\x0c\n\x04\x04\x12\x02\x03\x12\x04\xe9\x01\x08\x19\n\r\
+    \n\x05\x04\x12\x02\x03\x05\x12\x04\xe9\x01\x08\x0e\n\r\n\x05\x04\x12\x02\
+    \x03\x01\x12\x04\xe9\x01\x0f\x14\n\r\n\x05\x04\x12\x02\x03\x03\x12\x04\
+    \xe9\x01\x17\x18\n\x0c\n\x02\x04\x13\x12\x06\xec\x01\0\xf3\x01\x01\n\x0b\
+    \n\x03\x04\x13\x01\x12\x04\xec\x01\x08\x13\n\x0c\n\x04\x04\x13\x02\0\x12\
+    \x04\xed\x01\x08\x19\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\xed\x01\x08\x0e\
+    \n\r\n\x05\x04\x13\x02\0\x01\x12\x04\xed\x01\x0f\x14\n\r\n\x05\x04\x13\
+    \x02\0\x03\x12\x04\xed\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\
+    \xee\x01\x08\x1d\n\r\n\x05\x04\x13\x02\x01\x06\x12\x04\xee\x01\x08\x12\n\
+    \r\n\x05\x04\x13\x02\x01\x01\x12\x04\xee\x01\x13\x18\n\r\n\x05\x04\x13\
+    \x02\x01\x03\x12\x04\xee\x01\x1b\x1c\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\
+    \xef\x01\x08\"\n\r\n\x05\x04\x13\x02\x02\x06\x12\x04\xef\x01\x08\x12\n\r\
+    \n\x05\x04\x13\x02\x02\x01\x12\x04\xef\x01\x13\x1d\n\r\n\x05\x04\x13\x02\
+    \x02\x03\x12\x04\xef\x01\x20!\n\x0c\n\x04\x04\x13\x02\x03\x12\x04\xf0\
+    \x01\x08$\n\r\n\x05\x04\x13\x02\x03\x06\x12\x04\xf0\x01\x08\x12\n\r\n\
+    \x05\x04\x13\x02\x03\x01\x12\x04\xf0\x01\x13\x1f\n\r\n\x05\x04\x13\x02\
+    \x03\x03\x12\x04\xf0\x01\"#\n\x0c\n\x04\x04\x13\x02\x04\x12\x04\xf1\x01\
+    \x08\x1f\n\r\n\x05\x04\x13\x02\x04\x05\x12\x04\xf1\x01\x08\x0c\n\r\n\x05\
+    \x04\x13\x02\x04\x01\x12\x04\xf1\x01\r\x1a\n\r\n\x05\x04\x13\x02\x04\x03\
+    \x12\x04\xf1\x01\x1d\x1e\n\x0c\n\x04\x04\x13\x02\x05\x12\x04\xf2\x01\x08\
+    &\n\r\n\x05\x04\x13\x02\x05\x06\x12\x04\xf2\x01\x08\x1b\n\r\n\x05\x04\
+    \x13\x02\x05\x01\x12\x04\xf2\x01\x1c!\n\r\n\x05\x04\x13\x02\x05\x03\x12\
+    \x04\xf2\x01$%\n\x0c\n\x02\x04\x14\x12\x06\xf6\x01\0\xfb\x01\x01\n\x0b\n\
+    \x03\x04\x14\x01\x12\x04\xf6\x01\x08\x17\n\x0c\n\x04\x04\x14\x02\0\x12\
+    \x04\xf7\x01\x08\x19\n\r\n\x05\x04\x14\x02\0\x05\x12\x04\xf7\x01\x08\x0e\
+    \n\r\n\x05\x04\x14\x02\0\x01\x12\x04\xf7\x01\x0f\x14\n\r\n\x05\x04\x14\
+    \x02\0\x03\x12\x04\xf7\x01\x17\x18\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\
+    \xf8\x01\x08\x19\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\xf8\x01\x08\x0e\n\
+    \r\n\x05\x04\x14\x02\x01\x01\x12\x04\xf8\x01\x0f\x14\n\r\n\x05\x04\x14\
+    \x02\x01\x03\x12\x04\xf8\x01\x17\x18\n\x0c\n\x04\x04\x14\x02\x02\x12\x04\
+    \xf9\x01\x08\x16\n\r\n\x05\x04\x14\x02\x02\x05\x12\x04\xf9\x01\x08\x0e\n\
+    \r\n\x05\x04\x14\x02\x02\x01\x12\x04\xf9\x01\x0f\x11\n\r\n\x05\x04\x14\
+    \x02\x02\x03\x12\x04\xf9\x01\x14\x15\n\x0c\n\x04\x04\x14\x02\x03\x12\x04\
+    \xfa\x01\x08\x19\n\r\n\x05\x04\x14\x02\x03\x05\x12\x04\xfa\x01\x08\x0e\n\
+    \r\n\x05\x04\x14\x02\x03\x01\x12\x04\xfa\x01\x0f\x14\n\r\n\x05\x04\x14\
+    \x02\x03\x03\x12\x04\xfa\x01\x17\x18\n\x0c\n\x02\x04\x15\x12\x06\xfd\x01\
+    \0\x86\x02\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\xfd\x01\x08\x12\nH\n\x04\
+    \x04\x15\x02\0\x12\x04\xfe\x01\x08@\":\x20number\x20of\x20bytes\x20trans\
+    ferred\x20to\x20and\x20from\x20the\x20block\x20device\n\n\r\n\x05\x04\
+    \x15\x02\0\x04\x12\x04\xfe\x01\x08\x10\n\r\n\x05\x04\x15\x02\0\x06\x12\
+    \x04\xfe\x01\x11\x20\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\xfe\x01!;\n\r\n\
+    \x05\x04\x15\x02\0\x03\x12\x04\xfe\x01>?\n\x0c\n\x04\x04\x15\x02\x01\x12\
+    \x04\xff\x01\x08;\n\r\n\x05\x04\x15\x02\x01\x04\x12\x04\xff\x01\x08\x10\
+    \n\r\n\x05\x04\x15\x02\x01\x06\x12\x04\xff\x01\x11\x20\n\r\n\x05\x04\x15\
+    \x02\x01\x01\x12\x04\xff\x01!6\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\xff\
+    \x019:\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\x80\x02\x089\n\r\n\x05\x04\
+    \x15\x02\x02\x04\x12\x04\x80\x02\x08\x10\n\r\n\x05\x04\x15\x02\x02\x06\
+    \x12\x04\x80\x02\x11\x20\n\r\n\x05\x04\x15\x02\x02\x01\x12\x04\x80\x02!4\
+    \n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\x80\x0278\n\x0c\n\x04\x04\x15\x02\
+    \x03\x12\x04\x81\x02\x08?\n\r\n\x05\x04\x15\x02\x03\x04\x12\x04\x81\x02\
+    \x08\x10\n\r\n\x05\x04\x15\x02\x03\x06\x12\x04\x81\x02\x11\x20\n\r\n\x05\
+    \x04\x15\x02\x03\x01\x12\x04\x81\x02!:\n\r\n\x05\x04\x15\x02\x03\x03\x12\
+    \x04\x81\x02=>\n\x0c\n\x04\x04\x15\x02\x04\x12\x04\x82\x02\x08<\n\r\n\
+    \x05\x04\x15\x02\x04\x04\x12\x04\x82\x02\x08\x10\n\r\n\x05\x04\x15\x02\
+    \x04\x06\x12\x04\x82\x02\x11\x20\n\r\n\x05\x04\x15\x02\x04\x01\x12\x04\
+    \x82\x02!7\n\r\n\x05\x04\x15\x02\x04\x03\x12\x04\x82\x02:;\n\x0c\n\x04\
+    \x04\x15\x02\x05\x12\x04\x83\x02\x089\n\r\n\x05\x04\x15\x02\x05\x04\x12\
+    \x04\x83\x02\x08\x10\n\r\n\x05\x04\x15\x02\x05\x06\x12\x04\x83\x02\x11\
+    \x20\n\r\n\x05\x04\x15\x02\x05\x01\x12\x04\x83\x02!4\n\r\n\x05\x04\x15\
+    \x02\x05\x03\x12\x04\x83\x0278\n\x0c\n\x04\x04\x15\x02\x06\x12\x04\x84\
+    \x02\x087\n\r\n\x05\x04\x15\x02\x06\x04\x12\x04\x84\x02\x08\x10\n\r\n\
+    \x05\x04\x15\x02\x06\x06\x12\x04\x84\x02\x11\x20\n\r\n\x05\x04\x15\x02\
+    \x06\x01\x12\x04\x84\x02!2\n\r\n\x05\x04\x15\x02\x06\x03\x12\x04\x84\x02\
+    56\n\x0c\n\x04\x04\x15\x02\x07\x12\x04\x85\x02\x087\n\r\n\x05\x04\x15\
+    \x02\x07\x04\x12\x04\x85\x02\x08\x10\n\r\n\x05\x04\x15\x02\x07\x06\x12\
+    \x04\x85\x02\x11\x20\n\r\n\x05\x04\x15\x02\x07\x01\x12\x04\x85\x02!2\n\r\
+    \n\x05\x04\x15\x02\x07\x03\x12\x04\x85\x0256\n\x0c\n\x02\x04\x16\x12\x06\
+    \x88\x02\0\x8c\x02\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\x88\x02\x08\x14\n\
+    \x0c\n\x04\x04\x16\x02\0\x12\x04\x89\x02\x08\x19\n\r\n\x05\x04\x16\x02\0\
+    \x05\x12\x04\x89\x02\x08\x0e\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\x89\x02\
+    \x0f\x14\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\x89\x02\x17\x18\n\x0c\n\x04\
+    \x04\x16\x02\x01\x12\x04\x8a\x02\x08\x1d\n\r\n\x05\x04\x16\x02\x01\x05\
+    \x12\x04\x8a\x02\x08\x0e\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\x8a\x02\
+    \x0f\x18\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\x8a\x02\x1b\x1c\n\x0c\n\
+    \x04\x04\x16\x02\x02\x12\x04\x8b\x02\x08\x1b\n\r\n\x05\x04\x16\x02\x02\
+    \x05\x12\x04\x8b\x02\x08\x0e\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\x8b\
+    \x02\x0f\x16\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\x8b\x02\x19\x1a\n\x0c\
+    \n\x02\x04\x17\x12\x06\x8e\x02\0\x95\x02\x01\n\x0b\n\x03\x04\x17\x01\x12\
+    \x04\x8e\x02\x08\x13\n\x0c\n\x04\x04\x17\x02\0\x12\x04\x8f\x02\x04\x1b\n\
+    \r\n\x05\x04\x17\x02\0\x06\x12\x04\x8f\x02\x04\x0c\n\r\n\x05\x04\x17\x02\
+    \0\x01\x12\x04\x8f\x02\r\x16\n\r\n\x05\x04\x17\x02\0\x03\x12\x04\x8f\x02\
+    \x19\x1a\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\x90\x02\x04\"\n\r\n\x05\x04\
+    \x17\x02\x01\x06\x12\x04\x90\x02\x04\x0f\n\r\n\x05\x04\x17\x02\x01\x01\
+    \x12\x04\x90\x02\x10\x1c\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\x90\x02\
+    \x20!\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\x91\x02\x04\x1d\n\r\n\x05\x04\
+    \x17\x02\x02\x06\x12\x04\x91\x02\x04\r\n\r\n\x05\x04\x17\x02\x02\x01\x12\
+    \x04\x91\x02\x0e\x18\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\x91\x02\x1b\
+    \x1c\n\x0c\n\x04\x04\x17\x02\x03\x12\x04\x92\x02\x04\x1f\n\r\n\x05\x04\
+    \x17\x02\x03\x06\x12\x04\x92\x02\x04\x0e\n\r\n\x05\x04\x17\x02\x03\x01\
+    \x12\x04\x92\x02\x0f\x1a\n\r\n\x05\x04\x17\x02\x03\x03\x12\x04\x92\x02\
+    \x1d\x1e\nR\n\x04\x04\x17\x02\x04\x12\x04\x93\x02\x040\"D\x20the\x20map\
+    \x20is\x20in\x20the\x20format\x20\"size\x20of\x20hugepage:\x20stats\x20o\
+    f\x20the\x20hugepage\"\n\n\r\n\x05\x04\x17\x02\x04\x06\x12\x04\x93\x02\
+    \x04\x1d\n\r\n\x05\x04\x17\x02\x04\x01\x12\x04\x93\x02\x1e+\n\r\n\x05\
+    \x04\x17\x02\x04\x03\x12\x04\x93\x02./\n\x0c\n\x02\x04\x18\x12\x06\x97\
+    \x02\0\xa1\x02\x01\n\x0b\n\x03\x04\x18\x01\x12\x04\x97\x02\x08\x14\n\x0c\
+    \n\x04\x04\x18\x02\0\x12\x04\x98\x02\x08\x18\n\r\n\x05\x04\x18\x02\0\x05\
+    \x12\x04\x98\x02\x08\x0e\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\x98\x02\x0f\
+    \x13\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\x98\x02\x16\x17\n\x0c\n\x04\x04\
+    \x18\x02\x01\x12\x04\x99\x02\x08\x1c\n\r\n\x05\x04\x18\x02\x01\x05\x12\
+    \x04\x99\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\x99\x02\x0f\
+    \x17\n\r\n\x05\x04\x18\x02\x01\x03\x12\x04\x99\x02\x1a\x1b\n\x0c\n\x04\
+    \x04\x18\x02\x02\x12\x04\x9a\x02\x08\x1e\n\r\n\x05\x04\x18\x02\x02\x05\
+    \x12\x04\x9a\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x02\x01\x12\x04\x9a\x02\
+    \x0f\x19\n\r\n\x05\x04\x18\x02\x02\x03\x12\x04\x9a\x02\x1c\x1d\n\x0c\n\
+    \x04\x04\x18\x02\x03\x12\x04\x9b\x02\x08\x1e\n\r\n\x05\x04\x18\x02\x03\
+    \x05\x12\x04\x9b\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x03\x01\x12\x04\x9b\
+    \x02\x0f\x18\n\r\n\x05\x04\x18\x02\x03\x03\x12\x04\x9b\x02\x1c\x1d\n\x0c\
+    \n\x04\x04\x18\x02\x04\x12\x04\x9c\x02\x08\x1e\n\r\n\x05\x04\x18\x02\x04\
+    \x05\x12\x04\x9c\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x04\x01\x12\x04\x9c\
+    \x02\x0f\x19\n\r\n\x05\x04\x18\x02\x04\x03\x12\x04\x9c\x02\x1c\x1d\n\x0c\
+    \n\x04\x04\x18\x02\x05\x12\x04\x9d\x02\x08\x1c\n\r\n\x05\x04\x18\x02\x05\
+    \x05\x12\x04\x9d\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x05\x01\x12\x04\x9d\
+    \x02\x0f\x17\n\r\n\x05\x04\x18\x02\x05\x03\x12\x04\x9d\x02\x1a\x1b\n\x0c\
+    \n\x04\x04\x18\x02\x06\x12\x04\x9e\x02\x08\x1e\n\r\n\x05\x04\x18\x02\x06\
+    \x05\x12\x04\x9e\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x06\x01\x12\x04\x9e\
+    \x02\x0f\x19\n\r\n\x05\x04\x18\x02\x06\x03\x12\x04\x9e\x02\x1c\x1d\n\x0c\
+    \n\x04\x04\x18\x02\x07\x12\x04\x9f\x02\x08\x1d\n\r\n\x05\x04\x18\x02\x07\
+    \x05\x12\x04\x9f\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x07\x01\x12\x04\x9f\
+    \x02\x0f\x18\n\r\n\x05\x04\x18\x02\x07\x03\x12\x04\x9f\x02\x1b\x1c\n\x0c\
+    \n\x04\x04\x18\x02\x08\x12\x04\xa0\x02\x08\x1e\n\r\n\x05\x04\x18\x02\x08\
+    \x05\x12\x04\xa0\x02\x08\x0e\n\r\n\x05\x04\x18\x02\x08\x01\x12\x04\xa0\
+    \x02\x0f\x19\n\r\n\x05\x04\x18\x02\x08\x03\x12\x04\xa0\x02\x1c\x1d\n\x0c\
+    \n\x02\x04\x19\x12\x06\xa3\x02\0\xa6\x02\x01\n\x0b\n\x03\x04\x19\x01\x12\
+    \x04\xa3\x02\x08\x1e\n\x0c\n\x04\x04\x19\x02\0\x12\x04\xa4\x02\x08%\n\r\
+    \n\x05\x04\x19\x02\0\x06\x12\x04\xa4\x02\x08\x13\n\r\n\x05\x04\x19\x02\0\
+    \x01\x12\x04\xa4\x02\x14\x20\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xa4\x02\
+    #$\n\x0c\n\x04\x04\x19\x02\x01\x12\x04\xa5\x02\x080\n\r\n\x05\x04\x19\
+    \x02\x01\x04\x12\x04\xa5\x02\x08\x10\n\r\n\x05\x04\x19\x02\x01\x06\x12\
+    \x04\xa5\x02\x11\x1d\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\xa5\x02\x1e+\
+    \n\r\n\x05\x04\x19\x02\x01\x03\x12\x04\xa5\x02./\n\x0c\n\x02\x04\x1a\x12\
+    \x06\xa8\x02\0\xac\x02\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xa8\x02\x08\
+    \x1a\n\x0c\n\x04\x04\x1a\x02\0\x12\x04\xa9\x02\x08\x20\n\r\n\x05\x04\x1a\
+    \x02\0\x05\x12\x04\xa9\x02\x08\x0e\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\
+    \xa9\x02\x0f\x1b\n\r\n\x05\x04\x1a\x02\0\x03\x12\x04\xa9\x02\x1e\x1f\n\
+    \x0c\n\x04\x04\x1a\x02\x01\x12\x04\xaa\x02\x08\x1b\n\r\n\x05\x04\x1a\x02\
+    \x01\x05\x12\x04\xaa\x02\x08\x0e\n\r\n\x05\x04\x1a\x02\x01\x01\x12\x04\
+    \xaa\x02\x0f\x16\n\r\n\x05\x04\x1a\x02\x01\x03\x12\x04\xaa\x02\x19\x1a\n\
+    \x0c\n\x04\x04\x1a\x02\x02\x12\x04\xab\x02\x08\x17\n\r\n\x05\x04\x1a\x02\
+    \x02\x05\x12\x04\xab\x02\x08\r\n\r\n\x05\x04\x1a\x02\x02\x01\x12\x04\xab\
+    \x02\x0e\x12\n\r\n\x05\x04\x1a\x02\x02\x03\x12\x04\xab\x02\x15\x16\n\x0c\
+    \n\x02\x04\x1b\x12\x06\xae\x02\0\xb0\x02\x01\n\x0b\n\x03\x04\x1b\x01\x12\
+    \x04\xae\x02\x08\x1b\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\xaf\x02\x08\x17\n\
+    \r\n\x05\x04\x1b\x02\0\x05\x12\x04\xaf\x02\x08\x0e\n\r\n\x05\x04\x1b\x02\
+    \0\x01\x12\x04\xaf\x02\x0f\x12\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xaf\
+    \x02\x15\x16\n\x0c\n\x02\x04\x1c\x12\x06\xb2\x02\0\xb6\x02\x01\n\x0b\n\
+    \x03\x04\x1c\x01\x12\x04\xb2\x02\x08\x19\n\x0c\n\x04\x04\x1c\x02\0\x12\
+    \x04\xb3\x02\x08\x20\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\xb3\x02\x08\x0e\
+    \n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\xb3\x02\x0f\x1b\n\r\n\x05\x04\x1c\
+    \x02\0\x03\x12\x04\xb3\x02\x1e\x1f\n\x0c\n\x04\x04\x1c\x02\x01\x12\x04\
+    \xb4\x02\x08\x1b\n\r\n\x05\x04\x1c\x02\x01\x05\x12\x04\xb4\x02\x08\x0e\n\
+    \r\n\x05\x04\x1c\x02\x01\x01\x12\x04\xb4\x02\x0f\x16\n\r\n\x05\x04\x1c\
+    \x02\x01\x03\x12\x04\xb4\x02\x19\x1a\n\x0c\n\x04\x04\x1c\x02\x02\x12\x04\
+    \xb5\x02\x08\x17\n\r\n\x05\x04\x1c\x02\x02\x05\x12\x04\xb5\x02\x08\x0e\n\
+    \r\n\x05\x04\x1c\x02\x02\x01\x12\x04\xb5\x02\x0f\x12\n\r\n\x05\x04\x1c\
+    \x02\x02\x03\x12\x04\xb5\x02\x15\x16\n\x0c\n\x02\x04\x1d\x12\x06\xb8\x02\
+    \0\xba\x02\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\xb8\x02\x08\x1a\n\x0c\n\
+    \x04\x04\x1d\x02\0\x12\x04\xb9\x02\x08\x17\n\r\n\x05\x04\x1d\x02\0\x05\
+    \x12\x04\xb9\x02\x08\r\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\xb9\x02\x0e\
+    \x12\n\r\n\x05\x04\x1d\x02\0\x03\x12\x04\xb9\x02\x15\x16\n\x0c\n\x02\x04\
+    \x1e\x12\x06\xbc\x02\0\xbf\x02\x01\n\x0b\n\x03\x04\x1e\x01\x12\x04\xbc\
+    \x02\x08\x19\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\xbd\x02\x08\x20\n\r\n\x05\
+    \x04\x1e\x02\0\x05\x12\x04\xbd\x02\x08\x0e\n\r\n\x05\x04\x1e\x02\0\x01\
+    \x12\x04\xbd\x02\x0f\x1b\n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\xbd\x02\x1e\
+    \x1f\n\x0c\n\x04\x04\x1e\x02\x01\x12\x04\xbe\x02\x08\x1b\n\r\n\x05\x04\
+    \x1e\x02\x01\x05\x12\x04\xbe\x02\x08\x0e\n\r\n\x05\x04\x1e\x02\x01\x01\
+    \x12\x04\xbe\x02\x0f\x16\n\r\n\x05\x04\x1e\x02\x01\x03\x12\x04\xbe\x02\
+    \x19\x1a\n\x0c\n\x02\x04\x1f\x12\x06\xc1\x02\0\xc6\x02\x01\n\x0b\n\x03\
+    \x04\x1f\x01\x12\x04\xc1\x02\x08\x1b\n\x0c\n\x04\x04\x1f\x02\0\x12\x04\
+    \xc2\x02\x08\x20\n\r\n\x05\x04\x1f\x02\0\x05\x12\x04\xc2\x02\x08\x0e\n\r\
+    \n\x05\x04\x1f\x02\0\x01\x12\x04\xc2\x02\x0f\x1b\n\r\n\x05\x04\x1f\x02\0\
+    \x03\x12\x04\xc2\x02\x1e\x1f\n\x0c\n\x04\x04\x1f\x02\x01\x12\x04\xc3\x02\
+    \x08\x1b\n\r\n\x05\x04\x1f\x02\x01\x05\x12\x04\xc3\x02\x08\x0e\n\r\n\x05\
+    \x04\x1f\x02\x01\x01\x12\x04\xc3\x02\x0f\x16\n\r\n\x05\x04\x1f\x02\x01\
+    \x03\x12\x04\xc3\x02\x19\x1a\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\xc4\x02\
+    \x08\x17\n\r\n\x05\x04\x1f\x02\x02\x05\x12\x04\xc4\x02\x08\x0e\n\r\n\x05\
+    \x04\x1f\x02\x02\x01\x12\x04\xc4\x02\x0f\x12\n\r\n\x05\x04\x1f\x02\x02\
+    \x03\x12\x04\xc4\x02\x15\x16\n\x0c\n\x04\x04\x1f\x02\x03\x12\x04\xc5\x02\
+    \x08\x1a\n\r\n\x05\x04\x1f\x02\x03\x05\x12\x04\xc5\x02\x08\x0e\n\r\n\x05\
+    \x04\x1f\x02\x03\x01\x12\x04\xc5\x02\x0f\x15\n\r\n\x05\x04\x1f\x02\x03\
+    \x03\x12\x04\xc5\x02\x18\x19\n\x0c\n\x02\x04\x20\x12\x06\xc8\x02\0\xcd\
+    \x02\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\xc8\x02\x08\x17\n\x0c\n\x04\x04\
+    \x20\x02\0\x12\x04\xc9\x02\x08\x20\n\r\n\x05\x04\x20\x02\0\x05\x12\x04\
+    \xc9\x02\x08\x0e\n\r\n\x05\x04\x20\x02\0\x01\x12\x04\xc9\x02\x0f\x1b\n\r\
+    \n\x05\x04\x20\x02\0\x03\x12\x04\xc9\x02\x1e\x1f\n\x0c\n\x04\x04\x20\x02\
+    \x01\x12\x04\xca\x02\x08\x1b\n\r\n\x05\x04\x20\x02\x01\x05\x12\x04\xca\
+    \x02\x08\x0e\n\r\n\x05\x04\x20\x02\x01\x01\x12\x04\xca\x02\x0f\x16\n\r\n\
+    \x05\x04\x20\x02\x01\x03\x12\x04\xca\x02\x19\x1a\n\x0c\n\x04\x04\x20\x02\
+    \x02\x12\x04\xcb\x02\x08\x17\n\r\n\x05\x04\x20\x02\x02\x05\x12\x04\xcb\
+    \x02\x08\r\n\r\n\x05\x04\x20\x02\x02\x01\x12\x04\xcb\x02\x0e\x12\n\r\n\
+    \x05\x04\x20\x02\x02\x03\x12\x04\xcb\x02\x15\x16\n\x0c\n\x04\x04\x20\x02\
+    \x03\x12\x04\xcc\x02\x08\x1d\n\r\n\x05\x04\x20\x02\x03\x05\x12\x04\xcc\
+    \x02\x08\x0c\n\r\n\x05\x04\x20\x02\x03\x01\x12\x04\xcc\x02\r\x18\n\r\n\
+    \x05\x04\x20\x02\x03\x03\x12\x04\xcc\x02\x1b\x1c\n\x0c\n\x02\x04!\x12\
+    \x06\xcf\x02\0\xd4\x02\x01\n\x0b\n\x03\x04!\x01\x12\x04\xcf\x02\x08\x18\
+    \n\x0c\n\x04\x04!\x02\0\x12\x04\xd0\x02\x08\x19\n\r\n\x05\x04!\x02\0\x05\
+    \x12\x04\xd0\x02\x08\r\n\r\n\x05\x04!\x02\0\x01\x12\x04\xd0\x02\x0e\x14\
+    \n\r\n\x05\x04!\x02\0\x03\x12\x04\xd0\x02\x17\x18\n\x0c\n\x04\x04!\x02\
+    \x01\x12\x04\xd1\x02\x08\x19\n\r\n\x05\x04!\x02\x01\x05\x12\x04\xd1\x02\
+    \x08\r\n\r\n\x05\x04!\x02\x01\x01\x12\x04\xd1\x02\x0e\x14\n\r\n\x05\x04!\
+    \x02\x01\x03\x12\x04\xd1\x02\x17\x18\n\x0c\n\x04\x04!\x02\x02\x12\x04\
+    \xd2\x02\x08\x18\n\r\n\x05\x04!\x02\x02\x05\x12\x04\xd2\x02\x08\x0c\n\r\
+    \n\x05\x04!\x02\x02\x01\x12\x04\xd2\x02\r\x13\n\r\n\x05\x04!\x02\x02\x03\
+    \x12\x04\xd2\x02\x16\x17\n\x0c\n\x04\x04!\x02\x03\x12\x04\xd3\x02\x08\
+    \x19\n\r\n\x05\x04!\x02\x03\x05\x12\x04\xd3\x02\x08\r\n\r\n\x05\x04!\x02\
+    \x03\x01\x12\x04\xd3\x02\x0e\x14\n\r\n\x05\x04!\x02\x03\x03\x12\x04\xd3\
+    \x02\x17\x18\n\x0c\n\x02\x04\"\x12\x06\xd6\x02\0\xde\x02\x01\n\x0b\n\x03\
+    \x04\"\x01\x12\x04\xd6\x02\x08\x19\n\x0c\n\x04\x04\"\x02\0\x12\x04\xd7\
+    \x02\x08\x20\n\r\n\x05\x04\"\x02\0\x05\x12\x04\xd7\x02\x08\x0e\n\r\n\x05\
+    \x04\"\x02\0\x01\x12\x04\xd7\x02\x0f\x1b\n\r\n\x05\x04\"\x02\0\x03\x12\
+    \x04\xd7\x02\x1e\x1f\n\x0c\n\x04\x04\"\x02\x01\x12\x04\xd8\x02\x08\x1b\n\
+    \r\n\x05\x04\"\x02\x01\x05\x12\x04\xd8\x02\x08\x0e\n\r\n\x05\x04\"\x02\
+    \x01\x01\x12\x04\xd8\x02\x0f\x16\n\r\n\x05\x04\"\x02\x01\x03\x12\x04\xd8\
+    \x02\x19\x1a\n\x0c\n\x04\x04\"\x02\x02\x12\x04\xd9\x02\x08\x18\n\r\n\x05\
+    \x04\"\x02\x02\x05\x12\x04\xd9\x02\x08\x0c\n\r\n\x05\x04\"\x02\x02\x01\
+    \x12\x04\xd9\x02\r\x13\n\r\n\x05\x04\"\x02\x02\x03\x12\x04\xd9\x02\x16\
+    \x17\ne\n\x04\x04\"\x02\x03\x12\x04\xdc\x02\x08\x1a\x1aW\x20offset\x20in\
+    \x20the\x20output\x20stream,\x20counted\x20from\x20the\x20first\x20byte\
+    \n\x20ever\x20written\x20by\x20the\x20process\n\n\r\n\x05\x04\"\x02\x03\
+    \x05\x12\x04\xdc\x02\x08\x0e\n\r\n\x05\x04\"\x02\x03\x01\x12\x04\xdc\x02\
+    \x0f\x15\n\r\n\x05\x04\"\x02\x03\x03\x12\x04\xdc\x02\x18\x19\n\x0c\n\x04\
+    \x04\"\x02\x04\x12\x04\xdd\x02\x08\x17\n\r\n\x05\x04\"\x02\x04\x05\x12\
+    \x04\xdd\x02\x08\x0e\n\r\n\x05\x04\"\x02\x04\x01\x12\x04\xdd\x02\x0f\x12\
+    \n\r\n\x05\x04\"\x02\x04\x03\x12\x04\xdd\x02\x15\x16\n\x0c\n\x02\x04#\
+    \x12\x06\xe0\x02\0\xe9\x02\x01\n\x0b\n\x03\x04#\x01\x12\x04\xe0\x02\x08\
+    \x1a\n\x0c\n\x04\x04#\x02\0\x12\x04\xe1\x02\x08\x17\n\r\n\x05\x04#\x02\0\
+    \x05\x12\x04\xe1\x02\x08\r\n\r\n\x05\x04#\x02\0\x01\x12\x04\xe1\x02\x0e\
+    \x12\n\r\n\x05\x04#\x02\0\x03\x12\x04\xe1\x02\x15\x16\nr\n\x04\x04#\x02\
+    \x01\x12\x04\xe4\x02\x08\x1a\x1ad\x20offset\x20of\x20data,\x20greater\
+    \x20than\x20the\x20requested\x20offset\x20if\x20some\n\x20output\x20was\
+    \x20dropped\x20from\x20the\x20ring\x20buffer\n\n\r\n\x05\x04#\x02\x01\
+    \x05\x12\x04\xe4\x02\x08\x0e\n\r\n\x05\x04#\x02\x01\x01\x12\x04\xe4\x02\
+    \x0f\x15\n\r\n\x05\x04#\x02\x01\x03\x12\x04\xe4\x02\x18\x19\n@\n\x04\x04\
+    #\x02\x02\x12\x04\xe6\x02\x08\x1b\x1a2\x20bytes\x20lost\x20between\x20th\
+    e\x20requested\x20offset\x20and\x20data\n\n\r\n\x05\x04#\x02\x02\x05\x12\
+    \x04\xe6\x02\x08\x0e\n\r\n\x05\x04#\x02\x02\x01\x12\x04\xe6\x02\x0f\x16\
+    \n\r\n\x05\x04#\x02\x02\x03\x12\x04\xe6\x02\x19\x1a\n<\n\x04\x04#\x02\
+    \x03\x12\x04\xe8\x02\x08\x15\x1a.\x20the\x20stream\x20is\x20closed\x20an\
+    d\x20everything\x20was\x20read\n\n\r\n\x05\x04#\x02\x03\x05\x12\x04\xe8\
+    \x02\x08\x0c\n\r\n\x05\x04#\x02\x03\x01\x12\x04\xe8\x02\r\x10\n\r\n\x05\
+    \x04#\x02\x03\x03\x12\x04\xe8\x02\x13\x14\n\x0c\n\x02\x04$\x12\x06\xeb\
+    \x02\0\xfc\x02\x01\n\x0b\n\x03\x04$\x01\x12\x04\xeb\x02\x08\x1c\n\x0c\n\
+    \x04\x04$\x02\0\x12\x04\xec\x02\x08\x1c\n\r\n\x05\x04$\x02\0\x05\x12\x04\
+    \xec\x02\x08\x0e\n\r\n\x05\x04$\x02\0\x01\x12\x04\xec\x02\x0f\x17\n\r\n\
+    \x05\x04$\x02\0\x03\x12\x04\xec\x02\x1a\x1b\n\x0c\n\x04\x04$\x02\x01\x12\
+    \x04\xed\x02\x08\x20\n\r\n\x05\x04$\x02\x01\x04\x12\x04\xed\x02\x08\x10\
+    \n\r\n\x05\x04$\x02\x01\x05\x12\x04\xed\x02\x11\x17\n\r\n\x05\x04$\x02\
+    \x01\x01\x12\x04\xed\x02\x18\x1b\n\r\n\x05\x04$\x02\x01\x03\x12\x04\xed\
+    \x02\x1e\x1f\n\x0c\n\x04\x04$\x02\x02\x12\x04\xee\x02\x08&\n\r\n\x05\x04\
+    $\x02\x02\x04\x12\x04\xee\x02\x08\x10\n\r\n\x05\x04$\x02\x02\x06\x12\x04\
+    \xee\x02\x11\x18\n\r\n\x05\x04$\x02\x02\x01\x12\x04\xee\x02\x19!\n\r\n\
+    \x05\x04$\x02\x02\x03\x12\x04\xee\x02$%\n\xea\x01\n\x04\x04$\x02\x03\x12\
+    \x04\xf4\x02\x08\x1f\x1a\xdb\x01\x20This\x20field\x20means\x20that\x20a\
+    \x20pause\x20process\x20needs\x20to\x20be\x20created\x20by\x20the\n\x20a\
+    gent.\x20This\x20pid\x20namespace\x20of\x20the\x20pause\x20process\x20wi\
+    ll\x20be\x20treated\x20as\n\x20a\x20shared\x20pid\x20namespace.\x20All\
+    \x20containers\x20created\x20will\x20join\x20this\x20shared\n\x20pid\x20\
+    namespace.\n\n\r\n\x05\x04$\x02\x03\x05\x12\x04\xf4\x02\x08\x0c\n\r\n\
+    \x05\x04$\x02\x03\x01\x12\x04\xf4\x02\r\x1a\n\r\n\x05\x04$\x02\x03\x03\
+    \x12\x04\xf4\x02\x1d\x1e\n\xc5\x01\n\x04\x04$\x02\x04\x12\x04\xf8\x02\
+    \x08\x1e\x1a\xb6\x01\x20SandboxId\x20identifies\x20which\x20sandbox\x20i\
+    s\x20using\x20the\x20agent.\x20We\x20allow\x20only\n\x20one\x20sandbox\
+    \x20per\x20agent\x20and\x20implicitly\x20require\x20that\x20CreateSandbo\
+    x\x20is\n\x20called\x20before\x20other\x20sandbox/network\x20calls.\n\n\
+    \r\n\x05\x04$\x02\x04\x05\x12\x04\xf8\x02\x08\x0e\n\r\n\x05\x04$\x02\x04\
+    \x01\x12\x04\xf8\x02\x0f\x19\n\r\n\x05\x04$\x02\x04\x03\x12\x04\xf8\x02\
+    \x1c\x1d\n\x98\x01\n\x04\x04$\x02\x05\x12\x04\xfb\x02\x08#\x1a\x89\x01\
+    \x20This\x20field,\x20if\x20non-empty,\x20designates\x20an\x20absolute\
+    \x20path\x20to\x20a\x20directory\n\x20that\x20the\x20agent\x20will\x20se\
+    arch\x20for\x20OCI\x20hooks\x20to\x20run\x20within\x20the\x20guest.\n\n\
+    \r\n\x05\x04$\x02\x05\x05\x12\x04\xfb\x02\x08\x0e\n\r\n\x05\x04$\x02\x05\
+    \x01\x12\x04\xfb\x02\x0f\x1e\n\r\n\x05\x04$\x02\x05\x03\x12\x04\xfb\x02!\
+    \"\n\x0c\n\x02\x04%\x12\x06\xfe\x02\0\xff\x02\x01\n\x0b\n\x03\x04%\x01\
+    \x12\x04\xfe\x02\x08\x1d\n\x0c\n\x02\x04&\x12\x06\x81\x03\0\x83\x03\x01\
+    \n\x0b\n\x03\x04&\x01\x12\x04\x81\x03\x08\x12\n\x0c\n\x04\x04&\x02\0\x12\
+    \x04\x82\x03\x080\n\r\n\x05\x04&\x02\0\x04\x12\x04\x82\x03\x08\x10\n\r\n\
+    \x05\x04&\x02\0\x06\x12\x04\x82\x03\x11\x20\n\r\n\x05\x04&\x02\0\x01\x12\
+    \x04\x82\x03!+\n\r\n\x05\x04&\x02\0\x03\x12\x04\x82\x03./\n\x0c\n\x02\
+    \x04'\x12\x06\x85\x03\0\x87\x03\x01\n\x0b\n\x03\x04'\x01\x12\x04\x85\x03\
+    \x08\x0e\n\x0c\n\x04\x04'\x02\0\x12\x04\x86\x03\x08(\n\r\n\x05\x04'\x02\
+    \0\x04\x12\x04\x86\x03\x08\x10\n\r\n\x05\x04'\x02\0\x06\x12\x04\x86\x03\
+    \x11\x1c\n\r\n\x05\x04'\x02\0\x01\x12\x04\x86\x03\x1d#\n\r\n\x05\x04'\
+    \x02\0\x03\x12\x04\x86\x03&'\n\x0c\n\x02\x04(\x12\x06\x89\x03\0\x8b\x03\
+    \x01\n\x0b\n\x03\x04(\x01\x12\x04\x89\x03\x08\x1e\n\x0c\n\x04\x04(\x02\0\
+    \x12\x04\x8a\x03\x08&\n\r\n\x05\x04(\x02\0\x06\x12\x04\x8a\x03\x08\x17\n\
+    \r\n\x05\x04(\x02\0\x01\x12\x04\x8a\x03\x18!\n\r\n\x05\x04(\x02\0\x03\
+    \x12\x04\x8a\x03$%\n\x0c\n\x02\x04)\x12\x06\x8d\x03\0\x8f\x03\x01\n\x0b\
+    \n\x03\x04)\x01\x12\x04\x8d\x03\x08\x1b\n\x0c\n\x04\x04)\x02\0\x12\x04\
+    \x8e\x03\x08\x1a\n\r\n\x05\x04)\x02\0\x06\x12\x04\x8e\x03\x08\x0e\n\r\n\
+    \x05\x04)\x02\0\x01\x12\x04\x8e\x03\x0f\x15\n\r\n\x05\x04)\x02\0\x03\x12\
+    \x04\x8e\x03\x18\x19\n\x0c\n\x02\x04*\x12\x06\x91\x03\0\x92\x03\x01\n\
+    \x0b\n\x03\x04*\x01\x12\x04\x91\x03\x08\x1d\n\x0c\n\x02\x04+\x12\x06\x94\
+    \x03\0\x95\x03\x01\n\x0b\n\x03\x04+\x01\x12\x04\x94\x03\x08\x19\n\x0c\n\
+    \x02\x04,\x12\x06\x97\x03\0\x9a\x03\x01\n\x0b\n\x03\x04,\x01\x12\x04\x97\
+    \x03\x08\x18\n?\n\x04\x04,\x02\0\x12\x04\x99\x03\x08\x20\x1a1\x20resolv.\
+    conf\x20lines,\x20or\x20bare\x20nameserver\x20addresses\n\n\r\n\x05\x04,\
+    \x02\0\x04\x12\x04\x99\x03\x08\x10\n\r\n\x05\x04,\x02\0\x05\x12\x04\x99\
+    \x03\x11\x17\n\r\n\x05\x04,\x02\0\x01\x12\x04\x99\x03\x18\x1b\n\r\n\x05\
+    \x04,\x02\0\x03\x12\x04\x99\x03\x1e\x1f\n\x0c\n\x02\x04-\x12\x06\x9c\x03\
+    \0\xa7\x03\x01\n\x0b\n\x03\x04-\x01\x12\x04\x9c\x03\x08\x1b\n\xf6\x01\n\
+    \x04\x04-\x02\0\x12\x04\xa0\x03\x08\x16\x1a\xe7\x01\x20Wait\x20specifies\
+    \x20if\x20the\x20caller\x20waits\x20for\x20the\x20agent\x20to\x20online\
+    \x20all\x20resources.\n\x20If\x20true\x20the\x20agent\x20returns\x20once\
+    \x20all\x20resources\x20have\x20been\x20connected,\x20otherwise\x20all\n\
+    \x20resources\x20are\x20connected\x20asynchronously\x20and\x20the\x20age\
+    nt\x20returns\x20immediately.\n\n\r\n\x05\x04-\x02\0\x05\x12\x04\xa0\x03\
+    \x08\x0c\n\r\n\x05\x04-\x02\0\x01\x12\x04\xa0\x03\r\x11\n\r\n\x05\x04-\
+    \x02\0\x03\x12\x04\xa0\x03\x14\x15\n`\n\x04\x04-\x02\x01\x12\x04\xa3\x03\
+    \x08\x1b\x1aR\x20NbCpus\x20specifies\x20the\x20number\x20of\x20CPUs\x20t\
+    hat\x20were\x20added\x20and\x20the\x20agent\x20has\x20to\x20online.\n\n\
+    \r\n\x05\x04-\x02\x01\x05\x12\x04\xa3\x03\x08\x0e\n\r\n\x05\x04-\x02\x01\
+    \x01\x12\x04\xa3\x03\x0f\x16\n\r\n\x05\x04-\x02\x01\x03\x12\x04\xa3\x03\
+    \x19\x1a\nA\n\x04\x04-\x02\x02\x12\x04\xa6\x03\x08\x1a\x1a3\x20CpuOnly\
+    \x20specifies\x20whether\x20only\x20online\x20CPU\x20or\x20not.\n\n\r\n\
+    \x05\x04-\x02\x02\x05\x12\x04\xa6\x03\x08\x0c\n\r\n\x05\x04-\x02\x02\x01\
+    \x12\x04\xa6\x03\r\x15\n\r\n\x05\x04-\x02\x02\x03\x12\x04\xa6\x03\x18\
+    \x19\n\x0c\n\x02\x04.\x12\x06\xa9\x03\0\xac\x03\x01\n\x0b\n\x03\x04.\x01\
+    \x12\x04\xa9\x03\x08\x1e\nM\n\x04\x04.\x02\0\x12\x04\xab\x03\x08\x17\x1a\
+    ?\x20Data\x20specifies\x20the\x20random\x20data\x20used\x20to\x20reseed\
+    \x20the\x20guest\x20crng.\n\n\r\n\x05\x04.\x02\0\x05\x12\x04\xab\x03\x08\
+    \r\n\r\n\x05\x04.\x02\0\x01\x12\x04\xab\x03\x0e\x12\n\r\n\x05\x04.\x02\0\
+    \x03\x12\x04\xab\x03\x15\x16\nX\n\x02\x04/\x12\x06\xaf\x03\0\xd0\x03\x01\
+    \x1aJ\x20AgentDetails\x20provides\x20information\x20to\x20the\x20client\
+    \x20about\x20the\x20running\x20agent.\n\n\x0b\n\x03\x04/\x01\x12\x04\xaf\
+    \x03\x08\x14\nC\n\x04\x04/\x02\0\x12\x04\xb1\x03\x08\x1b\x1a5\x20Semanti\
+    c\x20version\x20of\x20agent\x20(see\x20https://semver.org).\n\n\r\n\x05\
+    \x04/\x02\0\x05\x12\x04\xb1\x03\x08\x0e\n\r\n\x05\x04/\x02\0\x01\x12\x04\
+    \xb1\x03\x0f\x16\n\r\n\x05\x04/\x02\0\x03\x12\x04\xb1\x03\x19\x1a\n5\n\
+    \x04\x04/\x02\x01\x12\x04\xb4\x03\x08\x1d\x1a'\x20Set\x20if\x20the\x20ag\
+    ent\x20is\x20running\x20as\x20PID\x201.\n\n\r\n\x05\x04/\x02\x01\x05\x12\
+    \x04\xb4\x03\x08\x0c\n\r\n\x05\x04/\x02\x01\x01\x12\x04\xb4\x03\r\x18\n\
+    \r\n\x05\x04/\x02\x01\x03\x12\x04\xb4\x03\x1b\x1c\n2\n\x04\x04/\x02\x02\
+    \x12\x04\xb7\x03\x08,\x1a$\x20List\x20of\x20available\x20device\x20handl\
+    ers.\n\n\r\n\x05\x04/\x02\x02\x04\x12\x04\xb7\x03\x08\x10\n\r\n\x05\x04/\
+    \x02\x02\x05\x12\x04\xb7\x03\x11\x17\n\r\n\x05\x04/\x02\x02\x01\x12\x04\
+    \xb7\x03\x18'\n\r\n\x05\x04/\x02\x02\x03\x12\x04\xb7\x03*+\n3\n\x04\x04/\
+    \x02\x03\x12\x04\xba\x03\x08-\x1a%\x20List\x20of\x20available\x20storage\
+    \x20handlers.\n\n\r\n\x05\x04/\x02\x03\x04\x12\x04\xba\x03\x08\x10\n\r\n\
+    \x05\x04/\x02\x03\x05\x12\x04\xba\x03\x11\x17\n\r\n\x05\x04/\x02\x03\x01\
+    \x12\x04\xba\x03\x18(\n\r\n\x05\x04/\x02\x03\x03\x12\x04\xba\x03+,\np\n\
+    \x04\x04/\x02\x04\x12\x04\xbe\x03\x08\"\x1ab\x20Set\x20only\x20if\x20the\
+    \x20agent\x20is\x20built\x20with\x20seccomp\x20support\x20and\x20the\x20\
+    guest\n\x20environment\x20supports\x20seccomp.\n\n\r\n\x05\x04/\x02\x04\
+    \x05\x12\x04\xbe\x03\x08\x0c\n\r\n\x05\x04/\x02\x04\x01\x12\x04\xbe\x03\
+    \r\x1d\n\r\n\x05\x04/\x02\x04\x03\x12\x04\xbe\x03\x20!\ni\n\x04\x04/\x02\
+    \x05\x12\x04\xc2\x03\x08\x20\x1a[\x20Set\x20if\x20the\x20guest\x20kernel\
+    \x20supports\x20seccomp\x20filters,\x20whether\x20or\x20not\n\x20the\x20\
+    agent\x20applies\x20them.\n\n\r\n\x05\x04/\x02\x05\x05\x12\x04\xc2\x03\
+    \x08\x0c\n\r\n\x05\x04/\x02\x05\x01\x12\x04\xc2\x03\r\x1b\n\r\n\x05\x04/\
+    \x02\x05\x03\x12\x04\xc2\x03\x1e\x1f\nF\n\x04\x04/\x02\x06\x12\x04\xc5\
+    \x03\x08\"\x1a8\x20cgroup\x20hierarchy\x20of\x20the\x20guest:\x20\"v1\",\
+    \x20\"v2\"\x20or\x20\"hybrid\".\n\n\r\n\x05\x04/\x02\x06\x05\x12\x04\xc5\
+    \x03\x08\x0e\n\r\n\x05\x04/\x02\x06\x01\x12\x04\xc5\x03\x0f\x1d\n\r\n\
+    \x05\x04/\x02\x06\x03\x12\x04\xc5\x03\x20!\nL\n\x04\x04/\x02\x07\x12\x04\
+    \xc8\x03\x08/\x1a>\x20List\x20of\x20the\x20cgroup\x20controllers\x20the\
+    \x20guest\x20kernel\x20has\x20enabled.\n\n\r\n\x05\x04/\x02\x07\x04\x12\
+    \x04\xc8\x03\x08\x10\n\r\n\x05\x04/\x02\x07\x05\x12\x04\xc8\x03\x11\x17\
+    \n\r\n\x05\x04/\x02\x07\x01\x12\x04\xc8\x03\x18*\n\r\n\x05\x04/\x02\x07\
+    \x03\x12\x04\xc8\x03-.\nZ\n\x04\x04/\x02\x08\x12\x04\xcc\x03\x08\x1d\x1a\
+    L\x20Transport\x20the\x20agent\x20API\x20is\x20served\x20on:\x20\"vsock\
+    \",\x20\"serial\",\x20\"unix\"\n\x20or\x20\"tcp\".\n\n\r\n\x05\x04/\x02\
+    \x08\x05\x12\x04\xcc\x03\x08\x0e\n\r\n\x05\x04/\x02\x08\x01\x12\x04\xcc\
+    \x03\x0f\x18\n\r\n\x05\x04/\x02\x08\x03\x12\x04\xcc\x03\x1b\x1c\n<\n\x04\
+    \x04/\x02\t\x12\x04\xcf\x03\x08#\x1a.\x20Release\x20of\x20the\x20guest\
+    \x20kernel,\x20as\x20in\x20uname\x20-r.\n\n\r\n\x05\x04/\x02\t\x05\x12\
+    \x04\xcf\x03\x08\x0e\n\r\n\x05\x04/\x02\t\x01\x12\x04\xcf\x03\x0f\x1d\n\
+    \r\n\x05\x04/\x02\t\x03\x12\x04\xcf\x03\x20\"\n\x0c\n\x02\x040\x12\x06\
+    \xd2\x03\0\xdc\x03\x01\n\x0b\n\x03\x040\x01\x12\x04\xd2\x03\x08\x1b\n\
+    \xd5\x01\n\x04\x040\x02\0\x12\x04\xd6\x03\x08\x20\x1a\xc6\x01\x20MemBloc\
+    kSize\x20asks\x20server\x20to\x20return\x20the\x20system\x20memory\x20bl\
+    ock\x20size\x20that\x20can\x20be\x20used\n\x20for\x20memory\x20hotplug\
+    \x20alignment.\x20Typically\x20the\x20server\x20returns\x20what's\x20in\
+    \n\x20/sys/devices/system/memory/block_size_bytes.\n\n\r\n\x05\x040\x02\
+    \0\x05\x12\x04\xd6\x03\x08\x0c\n\r\n\x05\x040\x02\0\x01\x12\x04\xd6\x03\
+    \r\x1b\n\r\n\x05\x040\x02\0\x03\x12\x04\xd6\x03\x1e\x1f\n\xd1\x01\n\x04\
+    \x040\x02\x01\x12\x04\xdb\x03\x08#\x1a\xc2\x01\x20MemoryHotplugProbe\x20\
+    asks\x20server\x20to\x20return\x20whether\x20guest\x20kernel\x20supports\
+    \x20memory\x20hotplug\n\x20via\x20probeinterface.\x20Typically\x20the\
+    \x20server\x20will\x20check\x20if\x20the\x20path\n\x20/sys/devices/syste\
+    m/memory/probe\x20exists.\n\n\r\n\x05\x040\x02\x01\x05\x12\x04\xdb\x03\
+    \x08\x0c\n\r\n\x05\x040\x02\x01\x01\x12\x04\xdb\x03\r\x1e\n\r\n\x05\x040\
+    \x02\x01\x03\x12\x04\xdb\x03!\"\n\x0c\n\x02\x041\x12\x06\xde\x03\0\xe5\
+    \x03\x01\n\x0b\n\x03\x041\x01\x12\x04\xde\x03\x08\x1c\nP\n\x04\x041\x02\
+    \0\x12\x04\xe0\x03\x08(\x1aB\x20MemBlockSizeBytes\x20returns\x20the\x20s\
+    ystem\x20memory\x20block\x20size\x20in\x20bytes.\n\n\r\n\x05\x041\x02\0\
+    \x05\x12\x04\xe0\x03\x08\x0e\n\r\n\x05\x041\x02\0\x01\x12\x04\xe0\x03\
+    \x0f#\n\r\n\x05\x041\x02\0\x03\x12\x04\xe0\x03&'\n\x0c\n\x04\x041\x02\
+    \x01\x12\x04\xe2\x03\x08'\n\r\n\x05\x041\x02\x01\x06\x12\x04\xe2\x03\x08\
+    \x14\n\r\n\x05\x041\x02\x01\x01\x12\x04\xe2\x03\x15\"\n\r\n\x05\x041\x02\
+    \x01\x03\x12\x04\xe2\x03%&\n\x0c\n\x04\x041\x02\x02\x12\x04\xe4\x03\x08+\
+    \n\r\n\x05\x041\x02\x02\x05\x12\x04\xe4\x03\x08\x0c\n\r\n\x05\x041\x02\
+    \x02\x01\x12\x04\xe4\x03\r&\n\r\n\x05\x041\x02\x02\x03\x12\x04\xe4\x03)*\
+    \n\x0c\n\x02\x042\x12\x06\xe7\x03\0\xeb\x03\x01\n\x0b\n\x03\x042\x01\x12\
+    \x04\xe7\x03\x08\x20\n\xb2\x01\n\x04\x042\x02\0\x12\x04\xea\x03\x080\x1a\
+    \xa3\x01\x20server\x20needs\x20to\x20send\x20the\x20value\x20of\x20memHo\
+    tplugProbeAddr\x20into\x20file\x20/sys/devices/system/memory/probe,\n\
+    \x20in\x20order\x20to\x20notify\x20the\x20guest\x20kernel\x20about\x20ho\
+    t-add\x20memory\x20event\n\n\r\n\x05\x042\x02\0\x04\x12\x04\xea\x03\x08\
+    \x10\n\r\n\x05\x042\x02\0\x05\x12\x04\xea\x03\x11\x17\n\r\n\x05\x042\x02\
+    \0\x01\x12\x04\xea\x03\x18+\n\r\n\x05\x042\x02\0\x03\x12\x04\xea\x03./\n\
+    \x0c\n\x02\x043\x12\x06\xed\x03\0\xf2\x03\x01\n\x0b\n\x03\x043\x01\x12\
+    \x04\xed\x03\x08\x1f\n/\n\x04\x043\x02\0\x12\x04\xef\x03\x08\x16\x1a!\
+    \x20Sec\x20the\x20second\x20since\x20the\x20Epoch.\n\n\r\n\x05\x043\x02\
+    \0\x05\x12\x04\xef\x03\x08\r\n\r\n\x05\x043\x02\0\x01\x12\x04\xef\x03\
+    \x0e\x11\n\r\n\x05\x043\x02\0\x03\x12\x04\xef\x03\x14\x15\nF\n\x04\x043\
+    \x02\x01\x12\x04\xf1\x03\x08\x17\x1a8\x20Usec\x20the\x20microseconds\x20\
+    portion\x20of\x20time\x20since\x20the\x20Epoch.\n\n\r\n\x05\x043\x02\x01\
+    \x05\x12\x04\xf1\x03\x08\r\n\r\n\x05\x043\x02\x01\x01\x12\x04\xf1\x03\
+    \x0e\x12\n\r\n\x05\x043\x02\x01\x03\x12\x04\xf1\x03\x15\x16\n\xa3\x01\n\
+    \x02\x044\x12\x06\xf6\x03\0\x90\x04\x01\x1a\x94\x01\x20Storage\x20repres\
+    ents\x20both\x20the\x20rootfs\x20of\x20the\x20container,\x20and\x20any\
+    \x20volume\x20that\n\x20could\x20have\x20been\x20defined\x20through\x20t\
+    he\x20Mount\x20list\x20of\x20the\x20OCI\x20specification.\n\n\x0b\n\x03\
+    \x044\x01\x12\x04\xf6\x03\x08\x0f\n\x8b\x02\n\x04\x044\x02\0\x12\x04\xfb\
+    \x03\x08\x1a\x1a\xfc\x01\x20Driver\x20is\x20used\x20to\x20define\x20the\
+    \x20way\x20the\x20storage\x20is\x20passed\x20through\x20the\n\x20virtual\
+    \x20machine.\x20It\x20can\x20be\x20\"9p\",\x20\"blk\",\x20or\x20somethin\
+    g\x20else,\x20but\x20for\n\x20all\x20cases,\x20this\x20will\x20define\
+    \x20if\x20some\x20extra\x20steps\x20are\x20required\x20before\n\x20this\
+    \x20storage\x20gets\x20mounted\x20into\x20the\x20container.\n\n\r\n\x05\
+    \x044\x02\0\x05\x12\x04\xfb\x03\x08\x0e\n\r\n\x05\x044\x02\0\x01\x12\x04\
+    \xfb\x03\x0f\x15\n\r\n\x05\x044\x02\0\x03\x12\x04\xfb\x03\x18\x19\n\xd0\
+    \x01\n\x04\x044\x02\x01\x12\x04\xff\x03\x08+\x1a\xc1\x01\x20DriverOption\
     s\x20allows\x20the\x20caller\x20to\x20define\x20a\x20list\x20of\x20optio\
     ns\x20such\n\x20as\x20block\x20sizes,\x20numbers\x20of\x20luns,\x20...\
     \x20which\x20are\x20very\x20specific\x20to\n\x20every\x20device\x20and\
     \x20cannot\x20be\x20generalized\x20through\x20extra\x20fields.\n\n\r\n\
-    \x05\x04.\x02\x01\x04\x12\x04\x8e\x03\x08\x10\n\r\n\x05\x04.\x02\x01\x05\
-    \x12\x04\x8e\x03\x11\x17\n\r\n\x05\x04.\x02\x01\x01\x12\x04\x8e\x03\x18&\
-    \n\r\n\x05\x04.\x02\x01\x03\x12\x04\x8e\x03)*\n\xce\x02\n\x04\x04.\x02\
-    \x02\x12\x04\x94\x03\x08\x1a\x1a\xbf\x02\x20Source\x20can\x20be\x20anyth\
+    \x05\x044\x02\x01\x04\x12\x04\xff\x03\x08\x10\n\r\n\x05\x044\x02\x01\x05\
+    \x12\x04\xff\x03\x11\x17\n\r\n\x05\x044\x02\x01\x01\x12\x04\xff\x03\x18&\
+    \n\r\n\x05\x044\x02\x01\x03\x12\x04\xff\x03)*\n\xce\x02\n\x04\x044\x02\
+    \x02\x12\x04\x85\x04\x08\x1a\x1a\xbf\x02\x20Source\x20can\x20be\x20anyth\
     ing\x20representing\x20the\x20source\x20of\x20the\x20storage.\x20This\n\
     \x20will\x20be\x20handled\x20by\x20the\x20proper\x20handler\x20based\x20\
     on\x20the\x20Driver\x20used.\n\x20For\x20instance,\x20it\x20can\x20be\
     \x20a\x20very\x20simple\x20path\x20if\x20the\x20caller\x20knows\x20the\n\
     \x20name\x20of\x20device\x20inside\x20the\x20VM,\x20or\x20it\x20can\x20b\
     e\x20some\x20sort\x20of\x20identifier\n\x20to\x20let\x20the\x20agent\x20\
-    find\x20the\x20device\x20inside\x20the\x20VM.\n\n\x0f\n\x05\x04.\x02\x02\
-    \x04\x12\x06\x94\x03\x08\x8e\x03+\n\r\n\x05\x04.\x02\x02\x05\x12\x04\x94\
-    \x03\x08\x0e\n\r\n\x05\x04.\x02\x02\x01\x12\x04\x94\x03\x0f\x15\n\r\n\
-    \x05\x04.\x02\x02\x03\x12\x04\x94\x03\x18\x19\n\xdb\x01\n\x04\x04.\x02\
-    \x03\x12\x04\x98\x03\x08\x1a\x1a\xcc\x01\x20Fstype\x20represents\x20the\
-    \x20filesystem\x20that\x20needs\x20to\x20be\x20used\x20to\x20mount\x20th\
-    e\n\x20storage\x20inside\x20the\x20VM.\x20For\x20instance,\x20it\x20coul\
-    d\x20be\x20\"xfs\"\x20for\x20block\n\x20device,\x20\"9p\"\x20for\x20shar\
-    ed\x20filesystem,\x20or\x20\"tmpfs\"\x20for\x20shared\x20/dev/shm.\n\n\
-    \x0f\n\x05\x04.\x02\x03\x04\x12\x06\x98\x03\x08\x94\x03\x1a\n\r\n\x05\
-    \x04.\x02\x03\x05\x12\x04\x98\x03\x08\x0e\n\r\n\x05\x04.\x02\x03\x01\x12\
-    \x04\x98\x03\x0f\x15\n\r\n\x05\x04.\x02\x03\x03\x12\x04\x98\x03\x18\x19\
-    \nw\n\x04\x04.\x02\x04\x12\x04\x9b\x03\x08$\x1ai\x20Options\x20describes\
-    \x20the\x20additional\x20options\x20that\x20might\x20be\x20needed\x20to\
-    \n\x20mount\x20properly\x20the\x20storage\x20filesytem.\n\n\r\n\x05\x04.\
-    \x02\x04\x04\x12\x04\x9b\x03\x08\x10\n\r\n\x05\x04.\x02\x04\x05\x12\x04\
-    \x9b\x03\x11\x17\n\r\n\x05\x04.\x02\x04\x01\x12\x04\x9b\x03\x18\x1f\n\r\
-    \n\x05\x04.\x02\x04\x03\x12\x04\x9b\x03\"#\na\n\x04\x04.\x02\x05\x12\x04\
-    \x9e\x03\x08\x1f\x1aS\x20MountPoint\x20refers\x20to\x20the\x20path\x20wh\
-    ere\x20the\x20storage\x20should\x20be\x20mounted\n\x20inside\x20the\x20V\
-    M.\n\n\x0f\n\x05\x04.\x02\x05\x04\x12\x06\x9e\x03\x08\x9b\x03$\n\r\n\x05\
-    \x04.\x02\x05\x05\x12\x04\x9e\x03\x08\x0e\n\r\n\x05\x04.\x02\x05\x01\x12\
-    \x04\x9e\x03\x0f\x1a\n\r\n\x05\x04.\x02\x05\x03\x12\x04\x9e\x03\x1d\x1e\
-    \n\x88\x01\n\x02\x04/\x12\x06\xa3\x03\0\xc3\x03\x01\x1az\x20Device\x20re\
-    presents\x20only\x20the\x20devices\x20that\x20could\x20have\x20been\x20d\
-    efined\x20through\x20the\n\x20Linux\x20Device\x20list\x20of\x20the\x20OC\
-    I\x20specification.\n\n\x0b\n\x03\x04/\x01\x12\x04\xa3\x03\x08\x0e\n\xb0\
-    \x01\n\x04\x04/\x02\0\x12\x04\xa7\x03\x08\x16\x1a\xa1\x01\x20Id\x20can\
-    \x20be\x20used\x20to\x20identify\x20the\x20device\x20inside\x20the\x20VM\
-    .\x20Some\x20devices\n\x20might\x20not\x20need\x20it\x20to\x20be\x20iden\
-    tified\x20on\x20the\x20VM,\x20and\x20will\x20rely\x20on\x20the\n\x20prov\
-    ided\x20VmPath\x20instead.\n\n\x0f\n\x05\x04/\x02\0\x04\x12\x06\xa7\x03\
-    \x08\xa3\x03\x10\n\r\n\x05\x04/\x02\0\x05\x12\x04\xa7\x03\x08\x0e\n\r\n\
-    \x05\x04/\x02\0\x01\x12\x04\xa7\x03\x0f\x11\n\r\n\x05\x04/\x02\0\x03\x12\
-    \x04\xa7\x03\x14\x15\n\xbd\x01\n\x04\x04/\x02\x01\x12\x04\xac\x03\x08\
-    \x18\x1a\xae\x01\x20Type\x20defines\x20the\x20type\x20of\x20device\x20de\
-    scribed.\x20This\x20can\x20be\x20\"blk\",\n\x20\"scsi\",\x20\"vfio\",\
-    \x20...\n\x20Particularly,\x20this\x20should\x20be\x20used\x20to\x20trig\
-    ger\x20the\x20use\x20of\x20the\n\x20appropriate\x20device\x20handler.\n\
-    \n\x0f\n\x05\x04/\x02\x01\x04\x12\x06\xac\x03\x08\xa7\x03\x16\n\r\n\x05\
-    \x04/\x02\x01\x05\x12\x04\xac\x03\x08\x0e\n\r\n\x05\x04/\x02\x01\x01\x12\
-    \x04\xac\x03\x0f\x13\n\r\n\x05\x04/\x02\x01\x03\x12\x04\xac\x03\x16\x17\
-    \n\xab\x02\n\x04\x04/\x02\x02\x12\x04\xb2\x03\x08\x1b\x1a\x9c\x02\x20VmP\
+    find\x20the\x20device\x20inside\x20the\x20VM.\n\n\r\n\x05\x044\x02\x02\
+    \x05\x12\x04\x85\x04\x08\x0e\n\r\n\x05\x044\x02\x02\x01\x12\x04\x85\x04\
+    \x0f\x15\n\r\n\x05\x044\x02\x02\x03\x12\x04\x85\x04\x18\x19\n\xdb\x01\n\
+    \x04\x044\x02\x03\x12\x04\x89\x04\x08\x1a\x1a\xcc\x01\x20Fstype\x20repre\
+    sents\x20the\x20filesystem\x20that\x20needs\x20to\x20be\x20used\x20to\
+    \x20mount\x20the\n\x20storage\x20inside\x20the\x20VM.\x20For\x20instance\
+    ,\x20it\x20could\x20be\x20\"xfs\"\x20for\x20block\n\x20device,\x20\"9p\"\
+    \x20for\x20shared\x20filesystem,\x20or\x20\"tmpfs\"\x20for\x20shared\x20\
+    /dev/shm.\n\n\r\n\x05\x044\x02\x03\x05\x12\x04\x89\x04\x08\x0e\n\r\n\x05\
+    \x044\x02\x03\x01\x12\x04\x89\x04\x0f\x15\n\r\n\x05\x044\x02\x03\x03\x12\
+    \x04\x89\x04\x18\x19\nw\n\x04\x044\x02\x04\x12\x04\x8c\x04\x08$\x1ai\x20\
+    Options\x20describes\x20the\x20additional\x20options\x20that\x20might\
+    \x20be\x20needed\x20to\n\x20mount\x20properly\x20the\x20storage\x20files\
+    ytem.\n\n\r\n\x05\x044\x02\x04\x04\x12\x04\x8c\x04\x08\x10\n\r\n\x05\x04\
+    4\x02\x04\x05\x12\x04\x8c\x04\x11\x17\n\r\n\x05\x044\x02\x04\x01\x12\x04\
+    \x8c\x04\x18\x1f\n\r\n\x05\x044\x02\x04\x03\x12\x04\x8c\x04\"#\na\n\x04\
+    \x044\x02\x05\x12\x04\x8f\x04\x08\x1f\x1aS\x20MountPoint\x20refers\x20to\
+    \x20the\x20path\x20where\x20the\x20storage\x20should\x20be\x20mounted\n\
+    \x20inside\x20the\x20VM.\n\n\r\n\x05\x044\x02\x05\x05\x12\x04\x8f\x04\
+    \x08\x0e\n\r\n\x05\x044\x02\x05\x01\x12\x04\x8f\x04\x0f\x1a\n\r\n\x05\
+    \x044\x02\x05\x03\x12\x04\x8f\x04\x1d\x1e\n\x88\x01\n\x02\x045\x12\x06\
+    \x94\x04\0\xb4\x04\x01\x1az\x20Device\x20represents\x20only\x20the\x20de\
+    vices\x20that\x20could\x20have\x20been\x20defined\x20through\x20the\n\
+    \x20Linux\x20Device\x20list\x20of\x20the\x20OCI\x20specification.\n\n\
+    \x0b\n\x03\x045\x01\x12\x04\x94\x04\x08\x0e\n\xb0\x01\n\x04\x045\x02\0\
+    \x12\x04\x98\x04\x08\x16\x1a\xa1\x01\x20Id\x20can\x20be\x20used\x20to\
+    \x20identify\x20the\x20device\x20inside\x20the\x20VM.\x20Some\x20devices\
+    \n\x20might\x20not\x20need\x20it\x20to\x20be\x20identified\x20on\x20the\
+    \x20VM,\x20and\x20will\x20rely\x20on\x20the\n\x20provided\x20VmPath\x20i\
+    nstead.\n\n\r\n\x05\x045\x02\0\x05\x12\x04\x98\x04\x08\x0e\n\r\n\x05\x04\
+    5\x02\0\x01\x12\x04\x98\x04\x0f\x11\n\r\n\x05\x045\x02\0\x03\x12\x04\x98\
+    \x04\x14\x15\n\xbd\x01\n\x04\x045\x02\x01\x12\x04\x9d\x04\x08\x18\x1a\
+    \xae\x01\x20Type\x20defines\x20the\x20type\x20of\x20device\x20described.\
+    \x20This\x20can\x20be\x20\"blk\",\n\x20\"scsi\",\x20\"vfio\",\x20...\n\
+    \x20Particularly,\x20this\x20should\x20be\x20used\x20to\x20trigger\x20th\
+    e\x20use\x20of\x20the\n\x20appropriate\x20device\x20handler.\n\n\r\n\x05\
+    \x045\x02\x01\x05\x12\x04\x9d\x04\x08\x0e\n\r\n\x05\x045\x02\x01\x01\x12\
+    \x04\x9d\x04\x0f\x13\n\r\n\x05\x045\x02\x01\x03\x12\x04\x9d\x04\x16\x17\
+    \n\xab\x02\n\x04\x045\x02\x02\x12\x04\xa3\x04\x08\x1b\x1a\x9c\x02\x20VmP\
     ath\x20can\x20be\x20used\x20by\x20the\x20caller\x20to\x20provide\x20dire\
     ctly\x20the\x20path\x20of\n\x20the\x20device\x20as\x20it\x20will\x20appe\
     ar\x20inside\x20the\x20VM.\x20For\x20some\x20devices,\x20the\n\x20device\
     \x20id\x20or\x20the\x20list\x20of\x20options\x20passed\x20might\x20not\
     \x20be\x20enough\x20to\x20find\n\x20the\x20device.\x20In\x20those\x20cas\
     es,\x20the\x20caller\x20should\x20predict\x20and\x20provide\n\x20this\
-    \x20vm_path.\n\n\x0f\n\x05\x04/\x02\x02\x04\x12\x06\xb2\x03\x08\xac\x03\
-    \x18\n\r\n\x05\x04/\x02\x02\x05\x12\x04\xb2\x03\x08\x0e\n\r\n\x05\x04/\
-    \x02\x02\x01\x12\x04\xb2\x03\x0f\x16\n\r\n\x05\x04/\x02\x02\x03\x12\x04\
-    \xb2\x03\x19\x1a\n\xd4\x05\n\x04\x04/\x02\x03\x12\x04\xbe\x03\x08\"\x1a\
-    \xc5\x05\x20ContainerPath\x20defines\x20the\x20path\x20where\x20the\x20d\
-    evice\x20should\x20be\x20found\x20inside\n\x20the\x20container.\x20This\
-    \x20path\x20should\x20match\x20the\x20path\x20of\x20the\x20device\x20fro\
-    m\n\x20the\x20device\x20list\x20listed\x20inside\x20the\x20OCI\x20spec.\
-    \x20This\x20is\x20used\x20in\x20order\n\x20to\x20identify\x20the\x20righ\
-    t\x20device\x20in\x20the\x20spec\x20and\x20update\x20it\x20with\x20the\n\
-    \x20right\x20options\x20such\x20as\x20major/minor\x20numbers\x20as\x20th\
-    ey\x20appear\x20inside\n\x20the\x20VM\x20for\x20instance.\x20Note\x20tha\
-    t\x20an\x20empty\x20ctr_path\x20should\x20be\x20used\n\x20to\x20make\x20\
-    sure\x20the\x20device\x20handler\x20inside\x20the\x20agent\x20is\x20call\
-    ed,\x20but\n\x20no\x20spec\x20update\x20needs\x20to\x20be\x20performed.\
-    \x20This\x20has\x20to\x20happen\x20for\x20the\n\x20case\x20of\x20rootfs,\
-    \x20when\x20a\x20device\x20has\x20to\x20be\x20waited\x20for\x20after\x20\
-    it\x20has\n\x20been\x20hotplugged.\x20An\x20equivalent\x20Storage\x20ent\
-    ry\x20should\x20be\x20defined\x20if\n\x20any\x20mount\x20needs\x20to\x20\
-    be\x20performed\x20afterwards.\n\n\x0f\n\x05\x04/\x02\x03\x04\x12\x06\
-    \xbe\x03\x08\xb2\x03\x1b\n\r\n\x05\x04/\x02\x03\x05\x12\x04\xbe\x03\x08\
-    \x0e\n\r\n\x05\x04/\x02\x03\x01\x12\x04\xbe\x03\x0f\x1d\n\r\n\x05\x04/\
-    \x02\x03\x03\x12\x04\xbe\x03\x20!\n\xca\x01\n\x04\x04/\x02\x04\x12\x04\
-    \xc2\x03\x08$\x1a\xbb\x01\x20Options\x20allows\x20the\x20caller\x20to\
-    \x20define\x20a\x20list\x20of\x20options\x20such\x20as\x20block\n\x20siz\
-    es,\x20numbers\x20of\x20luns,\x20...\x20which\x20are\x20very\x20specific\
-    \x20to\x20every\x20device\n\x20and\x20cannot\x20be\x20generalized\x20thr\
-    ough\x20extra\x20fields.\n\n\r\n\x05\x04/\x02\x04\x04\x12\x04\xc2\x03\
-    \x08\x10\n\r\n\x05\x04/\x02\x04\x05\x12\x04\xc2\x03\x11\x17\n\r\n\x05\
-    \x04/\x02\x04\x01\x12\x04\xc2\x03\x18\x1f\n\r\n\x05\x04/\x02\x04\x03\x12\
-    \x04\xc2\x03\"#\n\x0c\n\x02\x040\x12\x06\xc5\x03\0\xc9\x03\x01\n\x0b\n\
-    \x03\x040\x01\x12\x04\xc5\x03\x08\x12\n\x0c\n\x04\x040\x02\0\x12\x04\xc6\
-    \x03\x08\x17\n\x0f\n\x05\x040\x02\0\x04\x12\x06\xc6\x03\x08\xc5\x03\x14\
-    \n\r\n\x05\x040\x02\0\x05\x12\x04\xc6\x03\x08\x0e\n\r\n\x05\x040\x02\0\
-    \x01\x12\x04\xc6\x03\x0f\x12\n\r\n\x05\x040\x02\0\x03\x12\x04\xc6\x03\
-    \x15\x16\n\x0c\n\x04\x040\x02\x01\x12\x04\xc7\x03\x08\x17\n\x0f\n\x05\
-    \x040\x02\x01\x04\x12\x06\xc7\x03\x08\xc6\x03\x17\n\r\n\x05\x040\x02\x01\
-    \x05\x12\x04\xc7\x03\x08\x0e\n\r\n\x05\x040\x02\x01\x01\x12\x04\xc7\x03\
-    \x0f\x12\n\r\n\x05\x040\x02\x01\x03\x12\x04\xc7\x03\x15\x16\n\x0c\n\x04\
-    \x040\x02\x02\x12\x04\xc8\x03\x08+\n\r\n\x05\x040\x02\x02\x04\x12\x04\
-    \xc8\x03\x08\x10\n\r\n\x05\x040\x02\x02\x05\x12\x04\xc8\x03\x11\x17\n\r\
-    \n\x05\x040\x02\x02\x01\x12\x04\xc8\x03\x18&\n\r\n\x05\x040\x02\x02\x03\
-    \x12\x04\xc8\x03)*\n\x0c\n\x02\x041\x12\x06\xcb\x03\0\xdf\x03\x01\n\x0b\
-    \n\x03\x041\x01\x12\x04\xcb\x03\x08\x17\nj\n\x04\x041\x02\0\x12\x04\xce\
-    \x03\x08\x18\x1a\\\x20Path\x20is\x20the\x20destination\x20file\x20in\x20\
-    the\x20guest.\x20It\x20must\x20be\x20absolute,\n\x20canonical\x20and\x20\
-    below\x20/run.\n\n\x0f\n\x05\x041\x02\0\x04\x12\x06\xce\x03\x08\xcb\x03\
-    \x19\n\r\n\x05\x041\x02\0\x05\x12\x04\xce\x03\x08\x0e\n\r\n\x05\x041\x02\
-    \0\x01\x12\x04\xce\x03\x0f\x13\n\r\n\x05\x041\x02\0\x03\x12\x04\xce\x03\
-    \x16\x17\n\xbd\x01\n\x04\x041\x02\x01\x12\x04\xd2\x03\x08\x1c\x1a\xae\
+    \x20vm_path.\n\n\r\n\x05\x045\x02\x02\x05\x12\x04\xa3\x04\x08\x0e\n\r\n\
+    \x05\x045\x02\x02\x01\x12\x04\xa3\x04\x0f\x16\n\r\n\x05\x045\x02\x02\x03\
+    \x12\x04\xa3\x04\x19\x1a\n\xd4\x05\n\x04\x045\x02\x03\x12\x04\xaf\x04\
+    \x08\"\x1a\xc5\x05\x20ContainerPath\x20defines\x20the\x20path\x20where\
+    \x20the\x20device\x20should\x20be\x20found\x20inside\n\x20the\x20contain\
+    er.\x20This\x20path\x20should\x20match\x20the\x20path\x20of\x20the\x20de\
+    vice\x20from\n\x20the\x20device\x20list\x20listed\x20inside\x20the\x20OC\
+    I\x20spec.\x20This\x20is\x20used\x20in\x20order\n\x20to\x20identify\x20t\
+    he\x20right\x20device\x20in\x20the\x20spec\x20and\x20update\x20it\x20wit\
+    h\x20the\n\x20right\x20options\x20such\x20as\x20major/minor\x20numbers\
+    \x20as\x20they\x20appear\x20inside\n\x20the\x20VM\x20for\x20instance.\
+    \x20Note\x20that\x20an\x20empty\x20ctr_path\x20should\x20be\x20used\n\
+    \x20to\x20make\x20sure\x20the\x20device\x20handler\x20inside\x20the\x20a\
+    gent\x20is\x20called,\x20but\n\x20no\x20spec\x20update\x20needs\x20to\
+    \x20be\x20performed.\x20This\x20has\x20to\x20happen\x20for\x20the\n\x20c\
+    ase\x20of\x20rootfs,\x20when\x20a\x20device\x20has\x20to\x20be\x20waited\
+    \x20for\x20after\x20it\x20has\n\x20been\x20hotplugged.\x20An\x20equivale\
+    nt\x20Storage\x20entry\x20should\x20be\x20defined\x20if\n\x20any\x20moun\
+    t\x20needs\x20to\x20be\x20performed\x20afterwards.\n\n\r\n\x05\x045\x02\
+    \x03\x05\x12\x04\xaf\x04\x08\x0e\n\r\n\x05\x045\x02\x03\x01\x12\x04\xaf\
+    \x04\x0f\x1d\n\r\n\x05\x045\x02\x03\x03\x12\x04\xaf\x04\x20!\n\xca\x01\n\
+    \x04\x045\x02\x04\x12\x04\xb3\x04\x08$\x1a\xbb\x01\x20Options\x20allows\
+    \x20the\x20caller\x20to\x20define\x20a\x20list\x20of\x20options\x20such\
+    \x20as\x20block\n\x20sizes,\x20numbers\x20of\x20luns,\x20...\x20which\
+    \x20are\x20very\x20specific\x20to\x20every\x20device\n\x20and\x20cannot\
+    \x20be\x20generalized\x20through\x20extra\x20fields.\n\n\r\n\x05\x045\
+    \x02\x04\x04\x12\x04\xb3\x04\x08\x10\n\r\n\x05\x045\x02\x04\x05\x12\x04\
+    \xb3\x04\x11\x17\n\r\n\x05\x045\x02\x04\x01\x12\x04\xb3\x04\x18\x1f\n\r\
+    \n\x05\x045\x02\x04\x03\x12\x04\xb3\x04\"#\n\x0c\n\x02\x046\x12\x06\xb6\
+    \x04\0\xba\x04\x01\n\x0b\n\x03\x046\x01\x12\x04\xb6\x04\x08\x12\n\x0c\n\
+    \x04\x046\x02\0\x12\x04\xb7\x04\x08\x17\n\r\n\x05\x046\x02\0\x05\x12\x04\
+    \xb7\x04\x08\x0e\n\r\n\x05\x046\x02\0\x01\x12\x04\xb7\x04\x0f\x12\n\r\n\
+    \x05\x046\x02\0\x03\x12\x04\xb7\x04\x15\x16\n\x0c\n\x04\x046\x02\x01\x12\
+    \x04\xb8\x04\x08\x17\n\r\n\x05\x046\x02\x01\x05\x12\x04\xb8\x04\x08\x0e\
+    \n\r\n\x05\x046\x02\x01\x01\x12\x04\xb8\x04\x0f\x12\n\r\n\x05\x046\x02\
+    \x01\x03\x12\x04\xb8\x04\x15\x16\n\x0c\n\x04\x046\x02\x02\x12\x04\xb9\
+    \x04\x08+\n\r\n\x05\x046\x02\x02\x04\x12\x04\xb9\x04\x08\x10\n\r\n\x05\
+    \x046\x02\x02\x05\x12\x04\xb9\x04\x11\x17\n\r\n\x05\x046\x02\x02\x01\x12\
+    \x04\xb9\x04\x18&\n\r\n\x05\x046\x02\x02\x03\x12\x04\xb9\x04)*\n\x0c\n\
+    \x02\x047\x12\x06\xbc\x04\0\xd5\x04\x01\n\x0b\n\x03\x047\x01\x12\x04\xbc\
+    \x04\x08\x17\n\x99\x01\n\x04\x047\x02\0\x12\x04\xbf\x04\x08\x18\x1a\x8a\
+    \x01\x20Path\x20is\x20the\x20destination\x20file\x20in\x20the\x20guest.\
+    \x20It\x20must\x20be\x20absolute\x20and\n\x20below\x20the\x20container\
+    \x20base\x20or\x20one\x20of\x20the\x20agent.file_paths\x20directories.\n\
+    \n\r\n\x05\x047\x02\0\x05\x12\x04\xbf\x04\x08\x0e\n\r\n\x05\x047\x02\0\
+    \x01\x12\x04\xbf\x04\x0f\x13\n\r\n\x05\x047\x02\0\x03\x12\x04\xbf\x04\
+    \x16\x17\n\xbd\x01\n\x04\x047\x02\x01\x12\x04\xc3\x04\x08\x1c\x1a\xae\
     \x01\x20FileSize\x20is\x20the\x20expected\x20file\x20size,\x20for\x20sec\
     urity\x20reasons\x20write\x20operations\n\x20are\x20made\x20in\x20a\x20t\
     emporary\x20file,\x20once\x20it\x20has\x20the\x20expected\x20size,\x20it\
-    's\x20moved\n\x20to\x20the\x20destination\x20path.\n\n\x0f\n\x05\x041\
-    \x02\x01\x04\x12\x06\xd2\x03\x08\xce\x03\x18\n\r\n\x05\x041\x02\x01\x05\
-    \x12\x04\xd2\x03\x08\r\n\r\n\x05\x041\x02\x01\x01\x12\x04\xd2\x03\x0e\
-    \x17\n\r\n\x05\x041\x02\x01\x03\x12\x04\xd2\x03\x1a\x1b\n*\n\x04\x041\
-    \x02\x02\x12\x04\xd4\x03\x08\x1d\x1a\x1c\x20FileMode\x20is\x20the\x20fil\
-    e\x20mode.\n\n\x0f\n\x05\x041\x02\x02\x04\x12\x06\xd4\x03\x08\xd2\x03\
-    \x1c\n\r\n\x05\x041\x02\x02\x05\x12\x04\xd4\x03\x08\x0e\n\r\n\x05\x041\
-    \x02\x02\x01\x12\x04\xd4\x03\x0f\x18\n\r\n\x05\x041\x02\x02\x03\x12\x04\
-    \xd4\x03\x1b\x1c\nS\n\x04\x041\x02\x03\x12\x04\xd6\x03\x08\x1c\x1aE\x20D\
-    irMode\x20is\x20the\x20mode\x20for\x20the\x20parent\x20directories\x20of\
-    \x20destination\x20path.\n\n\x0f\n\x05\x041\x02\x03\x04\x12\x06\xd6\x03\
-    \x08\xd4\x03\x1d\n\r\n\x05\x041\x02\x03\x05\x12\x04\xd6\x03\x08\x0e\n\r\
-    \n\x05\x041\x02\x03\x01\x12\x04\xd6\x03\x0f\x17\n\r\n\x05\x041\x02\x03\
-    \x03\x12\x04\xd6\x03\x1a\x1b\n+\n\x04\x041\x02\x04\x12\x04\xd8\x03\x08\
-    \x16\x1a\x1d\x20Uid\x20is\x20the\x20numeric\x20user\x20id.\n\n\x0f\n\x05\
-    \x041\x02\x04\x04\x12\x06\xd8\x03\x08\xd6\x03\x1c\n\r\n\x05\x041\x02\x04\
-    \x05\x12\x04\xd8\x03\x08\r\n\r\n\x05\x041\x02\x04\x01\x12\x04\xd8\x03\
-    \x0e\x11\n\r\n\x05\x041\x02\x04\x03\x12\x04\xd8\x03\x14\x15\n,\n\x04\x04\
-    1\x02\x05\x12\x04\xda\x03\x08\x16\x1a\x1e\x20Gid\x20is\x20the\x20numeric\
-    \x20group\x20id.\n\n\x0f\n\x05\x041\x02\x05\x04\x12\x06\xda\x03\x08\xd8\
-    \x03\x16\n\r\n\x05\x041\x02\x05\x05\x12\x04\xda\x03\x08\r\n\r\n\x05\x041\
-    \x02\x05\x01\x12\x04\xda\x03\x0e\x11\n\r\n\x05\x041\x02\x05\x03\x12\x04\
-    \xda\x03\x14\x15\n4\n\x04\x041\x02\x06\x12\x04\xdc\x03\x08\x19\x1a&\x20O\
-    ffset\x20for\x20the\x20next\x20write\x20operation.\n\n\x0f\n\x05\x041\
-    \x02\x06\x04\x12\x06\xdc\x03\x08\xda\x03\x16\n\r\n\x05\x041\x02\x06\x05\
-    \x12\x04\xdc\x03\x08\r\n\r\n\x05\x041\x02\x06\x01\x12\x04\xdc\x03\x0e\
-    \x14\n\r\n\x05\x041\x02\x06\x03\x12\x04\xdc\x03\x17\x18\n6\n\x04\x041\
-    \x02\x07\x12\x04\xde\x03\x08\x17\x1a(\x20Data\x20to\x20write\x20in\x20th\
-    e\x20destination\x20file.\n\n\x0f\n\x05\x041\x02\x07\x04\x12\x06\xde\x03\
-    \x08\xdc\x03\x19\n\r\n\x05\x041\x02\x07\x05\x12\x04\xde\x03\x08\r\n\r\n\
-    \x05\x041\x02\x07\x01\x12\x04\xde\x03\x0e\x12\n\r\n\x05\x041\x02\x07\x03\
-    \x12\x04\xde\x03\x15\x16\n\x0c\n\x02\x042\x12\x06\xe1\x03\0\xe2\x03\x01\
-    \n\x0b\n\x03\x042\x01\x12\x04\xe1\x03\x08\x1b\n\x0c\n\x02\x043\x12\x06\
-    \xe4\x03\0\xe5\x03\x01\n\x0b\n\x03\x043\x01\x12\x04\xe4\x03\x08\x1ab\x06\
-    proto3\
+    's\x20moved\n\x20to\x20the\x20destination\x20path.\n\n\r\n\x05\x047\x02\
+    \x01\x05\x12\x04\xc3\x04\x08\r\n\r\n\x05\x047\x02\x01\x01\x12\x04\xc3\
+    \x04\x0e\x17\n\r\n\x05\x047\x02\x01\x03\x12\x04\xc3\x04\x1a\x1b\n*\n\x04\
+    \x047\x02\x02\x12\x04\xc5\x04\x08\x1d\x1a\x1c\x20FileMode\x20is\x20the\
+    \x20file\x20mode.\n\n\r\n\x05\x047\x02\x02\x05\x12\x04\xc5\x04\x08\x0e\n\
+    \r\n\x05\x047\x02\x02\x01\x12\x04\xc5\x04\x0f\x18\n\r\n\x05\x047\x02\x02\
+    \x03\x12\x04\xc5\x04\x1b\x1c\nS\n\x04\x047\x02\x03\x12\x04\xc7\x04\x08\
+    \x1c\x1aE\x20DirMode\x20is\x20the\x20mode\x20for\x20the\x20parent\x20dir\
+    ectories\x20of\x20destination\x20path.\n\n\r\n\x05\x047\x02\x03\x05\x12\
+    \x04\xc7\x04\x08\x0e\n\r\n\x05\x047\x02\x03\x01\x12\x04\xc7\x04\x0f\x17\
+    \n\r\n\x05\x047\x02\x03\x03\x12\x04\xc7\x04\x1a\x1b\n+\n\x04\x047\x02\
+    \x04\x12\x04\xc9\x04\x08\x16\x1a\x1d\x20Uid\x20is\x20the\x20numeric\x20u\
+    ser\x20id.\n\n\r\n\x05\x047\x02\x04\x05\x12\x04\xc9\x04\x08\r\n\r\n\x05\
+    \x047\x02\x04\x01\x12\x04\xc9\x04\x0e\x11\n\r\n\x05\x047\x02\x04\x03\x12\
+    \x04\xc9\x04\x14\x15\n,\n\x04\x047\x02\x05\x12\x04\xcb\x04\x08\x16\x1a\
+    \x1e\x20Gid\x20is\x20the\x20numeric\x20group\x20id.\n\n\r\n\x05\x047\x02\
+    \x05\x05\x12\x04\xcb\x04\x08\r\n\r\n\x05\x047\x02\x05\x01\x12\x04\xcb\
+    \x04\x0e\x11\n\r\n\x05\x047\x02\x05\x03\x12\x04\xcb\x04\x14\x15\n`\n\x04\
+    \x047\x02\x06\x12\x04\xce\x04\x08\x19\x1aR\x20Offset\x20for\x20the\x20ne\
+    xt\x20write\x20operation.\x20Data\x20at\x20offset\x200\x20starts\x20the\
+    \n\x20transfer\x20over.\n\n\r\n\x05\x047\x02\x06\x05\x12\x04\xce\x04\x08\
+    \r\n\r\n\x05\x047\x02\x06\x01\x12\x04\xce\x04\x0e\x14\n\r\n\x05\x047\x02\
+    \x06\x03\x12\x04\xce\x04\x17\x18\n\x83\x01\n\x04\x047\x02\x07\x12\x04\
+    \xd1\x04\x08\x17\x1au\x20Data\x20to\x20write\x20in\x20the\x20destination\
+    \x20file.\x20A\x20request\x20without\x20data\x20only\n\x20reports\x20how\
+    \x20far\x20the\x20transfer\x20got,\x20to\x20resume\x20it.\n\n\r\n\x05\
+    \x047\x02\x07\x05\x12\x04\xd1\x04\x08\r\n\r\n\x05\x047\x02\x07\x01\x12\
+    \x04\xd1\x04\x0e\x12\n\r\n\x05\x047\x02\x07\x03\x12\x04\xd1\x04\x15\x16\
+    \n\x83\x01\n\x04\x047\x02\x08\x12\x04\xd4\x04\x08\x19\x1au\x20Sha256\x20\
+    is\x20the\x20digest\x20of\x20the\x20whole\x20file,\x20checked\x20before\
+    \x20it\x20is\x20moved\n\x20to\x20the\x20destination\x20path.\x20Empty\
+    \x20skips\x20the\x20check.\n\n\r\n\x05\x047\x02\x08\x05\x12\x04\xd4\x04\
+    \x08\r\n\r\n\x05\x047\x02\x08\x01\x12\x04\xd4\x04\x0e\x14\n\r\n\x05\x047\
+    \x02\x08\x03\x12\x04\xd4\x04\x17\x18\n\x0c\n\x02\x048\x12\x06\xd7\x04\0\
+    \xdd\x04\x01\n\x0b\n\x03\x048\x01\x12\x04\xd7\x04\x08\x18\n_\n\x04\x048\
+    \x02\0\x12\x04\xda\x04\x08\x19\x1aQ\x20Offset\x20is\x20how\x20much\x20of\
+    \x20the\x20file\x20the\x20guest\x20has,\x20the\x20transfer\x20resumes\n\
+    \x20from\x20there.\n\n\r\n\x05\x048\x02\0\x05\x12\x04\xda\x04\x08\r\n\r\
+    \n\x05\x048\x02\0\x01\x12\x04\xda\x04\x0e\x14\n\r\n\x05\x048\x02\0\x03\
+    \x12\x04\xda\x04\x17\x18\nE\n\x04\x048\x02\x01\x12\x04\xdc\x04\x08\x16\
+    \x1a7\x20Done\x20is\x20set\x20once\x20the\x20file\x20is\x20at\x20the\x20\
+    destination\x20path.\n\n\r\n\x05\x048\x02\x01\x05\x12\x04\xdc\x04\x08\
+    \x0c\n\r\n\x05\x048\x02\x01\x01\x12\x04\xdc\x04\r\x11\n\r\n\x05\x048\x02\
+    \x01\x03\x12\x04\xdc\x04\x14\x15\n\x0c\n\x02\x049\x12\x06\xdf\x04\0\xe7\
+    \x04\x01\n\x0b\n\x03\x049\x01\x12\x04\xdf\x04\x08\x17\nh\n\x04\x049\x02\
+    \0\x12\x04\xe2\x04\x08\x18\x1aZ\x20Path\x20is\x20a\x20file\x20or\x20a\
+    \x20directory\x20in\x20the\x20guest,\x20below\x20the\x20same\n\x20direct\
+    ories\x20as\x20for\x20CopyFile.\n\n\r\n\x05\x049\x02\0\x05\x12\x04\xe2\
+    \x04\x08\x0e\n\r\n\x05\x049\x02\0\x01\x12\x04\xe2\x04\x0f\x13\n\r\n\x05\
+    \x049\x02\0\x03\x12\x04\xe2\x04\x16\x17\n-\n\x04\x049\x02\x01\x12\x04\
+    \xe4\x04\x08\x19\x1a\x1f\x20Offset\x20to\x20read\x20the\x20file\x20from.\
+    \n\n\r\n\x05\x049\x02\x01\x05\x12\x04\xe4\x04\x08\r\n\r\n\x05\x049\x02\
+    \x01\x01\x12\x04\xe4\x04\x0e\x14\n\r\n\x05\x049\x02\x01\x03\x12\x04\xe4\
+    \x04\x17\x18\nN\n\x04\x049\x02\x02\x12\x04\xe6\x04\x08\x17\x1a@\x20Len\
+    \x20is\x20the\x20most\x20to\x20read,\x200\x20for\x20as\x20much\x20as\x20\
+    fits\x20in\x20one\x20message.\n\n\r\n\x05\x049\x02\x02\x05\x12\x04\xe6\
+    \x04\x08\x0e\n\r\n\x05\x049\x02\x02\x01\x12\x04\xe6\x04\x0f\x12\n\r\n\
+    \x05\x049\x02\x02\x03\x12\x04\xe6\x04\x15\x16\n\x0c\n\x02\x04:\x12\x06\
+    \xe9\x04\0\xf0\x04\x01\n\x0b\n\x03\x04:\x01\x12\x04\xe9\x04\x08\x10\n\
+    \x0c\n\x04\x04:\x02\0\x12\x04\xea\x04\x08\x18\n\r\n\x05\x04:\x02\0\x05\
+    \x12\x04\xea\x04\x08\x0e\n\r\n\x05\x04:\x02\0\x01\x12\x04\xea\x04\x0f\
+    \x13\n\r\n\x05\x04:\x02\0\x03\x12\x04\xea\x04\x16\x17\n\x0c\n\x04\x04:\
+    \x02\x01\x12\x04\xeb\x04\x08\x15\n\r\n\x05\x04:\x02\x01\x05\x12\x04\xeb\
+    \x04\x08\x0c\n\r\n\x05\x04:\x02\x01\x01\x12\x04\xeb\x04\r\x10\n\r\n\x05\
+    \x04:\x02\x01\x03\x12\x04\xeb\x04\x13\x14\n\x0c\n\x04\x04:\x02\x02\x12\
+    \x04\xec\x04\x08\x17\n\r\n\x05\x04:\x02\x02\x05\x12\x04\xec\x04\x08\r\n\
+    \r\n\x05\x04:\x02\x02\x01\x12\x04\xec\x04\x0e\x12\n\r\n\x05\x04:\x02\x02\
+    \x03\x12\x04\xec\x04\x15\x16\n\x0c\n\x04\x04:\x02\x03\x12\x04\xed\x04\
+    \x08\x18\n\r\n\x05\x04:\x02\x03\x05\x12\x04\xed\x04\x08\x0e\n\r\n\x05\
+    \x04:\x02\x03\x01\x12\x04\xed\x04\x0f\x13\n\r\n\x05\x04:\x02\x03\x03\x12\
+    \x04\xed\x04\x16\x17\n1\n\x04\x04:\x02\x04\x12\x04\xef\x04\x08\x18\x1a#\
+    \x20Mtime\x20in\x20seconds\x20since\x20the\x20epoch.\n\n\r\n\x05\x04:\
+    \x02\x04\x05\x12\x04\xef\x04\x08\r\n\r\n\x05\x04:\x02\x04\x01\x12\x04\
+    \xef\x04\x0e\x13\n\r\n\x05\x04:\x02\x04\x03\x12\x04\xef\x04\x16\x17\n\
+    \x0c\n\x02\x04;\x12\x06\xf2\x04\0\xfc\x04\x01\n\x0b\n\x03\x04;\x01\x12\
+    \x04\xf2\x04\x08\x18\n:\n\x04\x04;\x02\0\x12\x04\xf4\x04\x08\x17\x1a,\
+    \x20Data\x20read\x20from\x20the\x20file,\x20empty\x20at\x20its\x20end.\n\
+    \n\r\n\x05\x04;\x02\0\x05\x12\x04\xf4\x04\x08\r\n\r\n\x05\x04;\x02\0\x01\
+    \x12\x04\xf4\x04\x0e\x12\n\r\n\x05\x04;\x02\0\x03\x12\x04\xf4\x04\x15\
+    \x16\n@\n\x04\x04;\x02\x01\x12\x04\xf6\x04\x08\x1c\x1a2\x20FileSize\x20i\
+    s\x20the\x20size\x20of\x20the\x20file\x20as\x20it\x20was\x20read.\n\n\r\
+    \n\x05\x04;\x02\x01\x05\x12\x04\xf6\x04\x08\r\n\r\n\x05\x04;\x02\x01\x01\
+    \x12\x04\xf6\x04\x0e\x17\n\r\n\x05\x04;\x02\x01\x03\x12\x04\xf6\x04\x1a\
+    \x1b\n_\n\x04\x04;\x02\x02\x12\x04\xf9\x04\x08&\x1aQ\x20Entries\x20of\
+    \x20a\x20directory,\x20sorted\x20by\x20name.\x20Data\x20and\x20FileSize\
+    \x20are\x20left\n\x20empty\x20then.\n\n\r\n\x05\x04;\x02\x02\x04\x12\x04\
+    \xf9\x04\x08\x10\n\r\n\x05\x04;\x02\x02\x06\x12\x04\xf9\x04\x11\x19\n\r\
+    \n\x05\x04;\x02\x02\x01\x12\x04\xf9\x04\x1a!\n\r\n\x05\x04;\x02\x02\x03\
+    \x12\x04\xf9\x04$%\n4\n\x04\x04;\x02\x03\x12\x04\xfb\x04\x08\x15\x1a&\
+    \x20Dir\x20is\x20set\x20when\x20Path\x20is\x20a\x20directory.\n\n\r\n\
+    \x05\x04;\x02\x03\x05\x12\x04\xfb\x04\x08\x0c\n\r\n\x05\x04;\x02\x03\x01\
+    \x12\x04\xfb\x04\r\x10\n\r\n\x05\x04;\x02\x03\x03\x12\x04\xfb\x04\x13\
+    \x14\n\x0c\n\x02\x04<\x12\x06\xfe\x04\0\xff\x04\x01\n\x0b\n\x03\x04<\x01\
+    \x12\x04\xfe\x04\x08\x1a\n\x0c\n\x02\x04=\x12\x06\x81\x05\0\x83\x05\x01\
+    \n\x0b\n\x03\x04=\x01\x12\x04\x81\x05\x08\x10\n\x0c\n\x04\x04=\x02\0\x12\
+    \x04\x82\x05\x08\x20\n\r\n\x05\x04=\x02\0\x05\x12\x04\x82\x05\x08\x0e\n\
+    \r\n\x05\x04=\x02\0\x01\x12\x04\x82\x05\x0f\x1b\n\r\n\x05\x04=\x02\0\x03\
+    \x12\x04\x82\x05\x1e\x1f\n\x0c\n\x02\x04>\x12\x06\x85\x05\0\x88\x05\x01\
+    \n\x0b\n\x03\x04>\x01\x12\x04\x85\x05\x08&\n=\n\x04\x04>\x02\0\x12\x04\
+    \x87\x05\x08*\x1a/\x20Containers\x20to\x20report,\x20all\x20of\x20them\
+    \x20when\x20empty.\n\n\r\n\x05\x04>\x02\0\x04\x12\x04\x87\x05\x08\x10\n\
+    \r\n\x05\x04>\x02\0\x05\x12\x04\x87\x05\x11\x17\n\r\n\x05\x04>\x02\0\x01\
+    \x12\x04\x87\x05\x18%\n\r\n\x05\x04>\x02\0\x03\x12\x04\x87\x05()\n\x0c\n\
+    \x02\x04?\x12\x06\x8a\x05\0\x8d\x05\x01\n\x0b\n\x03\x04?\x01\x12\x04\x8a\
+    \x05\x08\x1b\n\x0c\n\x04\x04?\x02\0\x12\x04\x8b\x05\x08\x20\n\r\n\x05\
+    \x04?\x02\0\x05\x12\x04\x8b\x05\x08\x0e\n\r\n\x05\x04?\x02\0\x01\x12\x04\
+    \x8b\x05\x0f\x1b\n\r\n\x05\x04?\x02\0\x03\x12\x04\x8b\x05\x1e\x1f\n\x0c\
+    \n\x04\x04?\x02\x01\x12\x04\x8c\x05\x08\x19\n\r\n\x05\x04?\x02\x01\x05\
+    \x12\x04\x8c\x05\x08\x0e\n\r\n\x05\x04?\x02\x01\x01\x12\x04\x8c\x05\x0f\
+    \x14\n\r\n\x05\x04?\x02\x01\x03\x12\x04\x8c\x05\x17\x18\n\x0c\n\x02\x04@\
+    \x12\x06\x8f\x05\0\x90\x05\x01\n\x0b\n\x03\x04@\x01\x12\x04\x8f\x05\x08\
+    \x19\n\x0c\n\x02\x04A\x12\x06\x92\x05\0\x94\x05\x01\n\x0b\n\x03\x04A\x01\
+    \x12\x04\x92\x05\x08\x0f\n\x0c\n\x04\x04A\x02\0\x12\x04\x93\x05\x08\x1b\
+    \n\r\n\x05\x04A\x02\0\x05\x12\x04\x93\x05\x08\x0e\n\r\n\x05\x04A\x02\0\
+    \x01\x12\x04\x93\x05\x0f\x16\n\r\n\x05\x04A\x02\0\x03\x12\x04\x93\x05\
+    \x19\x1a\n\x0c\n\x02\x04B\x12\x06\x96\x05\0\x97\x05\x01\n\x0b\n\x03\x04B\
+    \x01\x12\x04\x96\x05\x08\x1b\n\x0c\n\x02\x04C\x12\x06\x99\x05\0\x9a\x05\
+    \x01\n\x0b\n\x03\x04C\x01\x12\x04\x99\x05\x08\x1ab\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -95,6 +95,20 @@ const METHOD_AGENT_SERVICE_RESUME_CONTAINER: ::grpcio::Method<super::agent::Resu
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_GET_OOM_EVENT: ::grpcio::Method<super::agent::GetOOMEventRequest, super::agent::OOMEvent> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/GetOOMEvent",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_SUBSCRIBE_MEMORY_PRESSURE: ::grpcio::Method<super::agent::SubscribeMemoryPressureRequest, super::agent::MemoryPressureEvent> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/grpc.AgentService/SubscribeMemoryPressure",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_WRITE_STDIN: ::grpcio::Method<super::agent::WriteStreamRequest, super::agent::WriteStreamResponse> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/WriteStdin",
@@ -130,6 +144,20 @@ const METHOD_AGENT_SERVICE_TTY_WIN_RESIZE: ::grpcio::Method<super::agent::TtyWin
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_STREAM_IO: ::grpcio::Method<super::agent::StreamIORequest, super::agent::StreamIOResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Duplex,
+    name: "/grpc.AgentService/StreamIO",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_READ_OUTPUT: ::grpcio::Method<super::agent::ReadOutputRequest, super::agent::ReadOutputResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/ReadOutput",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_UPDATE_INTERFACE: ::grpcio::Method<super::agent::UpdateInterfaceRequest, super::types::Interface> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/UpdateInterface",
@@ -158,6 +186,13 @@ const METHOD_AGENT_SERVICE_LIST_ROUTES: ::grpcio::Method<super::agent::ListRoute
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_UPDATE_DNS: ::grpcio::Method<super::agent::UpdateDNSRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/UpdateDNS",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_START_TRACING: ::grpcio::Method<super::agent::StartTracingRequest, super::empty::Empty> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/StartTracing",
@@ -221,13 +256,27 @@ const METHOD_AGENT_SERVICE_SET_GUEST_DATE_TIME: ::grpcio::Method<super::agent::S
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
-const METHOD_AGENT_SERVICE_COPY_FILE: ::grpcio::Method<super::agent::CopyFileRequest, super::empty::Empty> = ::grpcio::Method {
+const METHOD_AGENT_SERVICE_COPY_FILE: ::grpcio::Method<super::agent::CopyFileRequest, super::agent::CopyFileResponse> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/CopyFile",
     req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_READ_FILE: ::grpcio::Method<super::agent::ReadFileRequest, super::agent::ReadFileResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/ReadFile",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_GET_METRICS: ::grpcio::Method<super::agent::GetMetricsRequest, super::agent::Metrics> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/GetMetrics",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 #[derive(Clone)]
 pub struct AgentServiceClient {
     client: ::grpcio::Client,
@@ -416,6 +465,30 @@ impl AgentServiceClient {
         self.resume_container_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn get_oom_event_opt(&self, req: &super::agent::GetOOMEventRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::OOMEvent> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_GET_OOM_EVENT, req, opt)
+    }
+
+    pub fn get_oom_event(&self, req: &super::agent::GetOOMEventRequest) -> ::grpcio::Result<super::agent::OOMEvent> {
+        self.get_oom_event_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_oom_event_async_opt(&self, req: &super::agent::GetOOMEventRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::OOMEvent>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_GET_OOM_EVENT, req, opt)
+    }
+
+    pub fn get_oom_event_async(&self, req: &super::agent::GetOOMEventRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::OOMEvent>> {
+        self.get_oom_event_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn subscribe_memory_pressure_opt(&self, req: &super::agent::SubscribeMemoryPressureRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::MemoryPressureEvent>> {
+        self.client.server_streaming(&METHOD_AGENT_SERVICE_SUBSCRIBE_MEMORY_PRESSURE, req, opt)
+    }
+
+    pub fn subscribe_memory_pressure(&self, req: &super::agent::SubscribeMemoryPressureRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::MemoryPressureEvent>> {
+        self.subscribe_memory_pressure_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn write_stdin_opt(&self, req: &super::agent::WriteStreamRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::WriteStreamResponse> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_WRITE_STDIN, req, opt)
     }
@@ -496,6 +569,30 @@ impl AgentServiceClient {
         self.tty_win_resize_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn stream_io_opt(&self, opt: ::grpcio::CallOption) -> ::grpcio::Result<(::grpcio::ClientDuplexSender<super::agent::StreamIORequest>, ::grpcio::ClientDuplexReceiver<super::agent::StreamIOResponse>)> {
+        self.client.duplex_streaming(&METHOD_AGENT_SERVICE_STREAM_IO, opt)
+    }
+
+    pub fn stream_io(&self) -> ::grpcio::Result<(::grpcio::ClientDuplexSender<super::agent::StreamIORequest>, ::grpcio::ClientDuplexReceiver<super::agent::StreamIOResponse>)> {
+        self.stream_io_opt(::grpcio::CallOption::default())
+    }
+
+    pub fn read_output_opt(&self, req: &super::agent::ReadOutputRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::ReadOutputResponse> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_READ_OUTPUT, req, opt)
+    }
+
+    pub fn read_output(&self, req: &super::agent::ReadOutputRequest) -> ::grpcio::Result<super::agent::ReadOutputResponse> {
+        self.read_output_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn read_output_async_opt(&self, req: &super::agent::ReadOutputRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::ReadOutputResponse>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_READ_OUTPUT, req, opt)
+    }
+
+    pub fn read_output_async(&self, req: &super::agent::ReadOutputRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::ReadOutputResponse>> {
+        self.read_output_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn update_interface_opt(&self, req: &super::agent::UpdateInterfaceRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::types::Interface> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_UPDATE_INTERFACE, req, opt)
     }
@@ -560,6 +657,22 @@ impl AgentServiceClient {
         self.list_routes_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn update_dns_opt(&self, req: &super::agent::UpdateDNSRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_UPDATE_DNS, req, opt)
+    }
+
+    pub fn update_dns(&self, req: &super::agent::UpdateDNSRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.update_dns_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn update_dns_async_opt(&self, req: &super::agent::UpdateDNSRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_UPDATE_DNS, req, opt)
+    }
+
+    pub fn update_dns_async(&self, req: &super::agent::UpdateDNSRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.update_dns_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn start_tracing_opt(&self, req: &super::agent::StartTracingRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_START_TRACING, req, opt)
     }
@@ -704,21 +817,53 @@ impl AgentServiceClient {
         self.set_guest_date_time_async_opt(req, ::grpcio::CallOption::default())
     }
 
-    pub fn copy_file_opt(&self, req: &super::agent::CopyFileRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+    pub fn copy_file_opt(&self, req: &super::agent::CopyFileRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::CopyFileResponse> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_COPY_FILE, req, opt)
     }
 
-    pub fn copy_file(&self, req: &super::agent::CopyFileRequest) -> ::grpcio::Result<super::empty::Empty> {
+    pub fn copy_file(&self, req: &super::agent::CopyFileRequest) -> ::grpcio::Result<super::agent::CopyFileResponse> {
         self.copy_file_opt(req, ::grpcio::CallOption::default())
     }
 
-    pub fn copy_file_async_opt(&self, req: &super::agent::CopyFileRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+    pub fn copy_file_async_opt(&self, req: &super::agent::CopyFileRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::CopyFileResponse>> {
         self.client.unary_call_async(&METHOD_AGENT_SERVICE_COPY_FILE, req, opt)
     }
 
-    pub fn copy_file_async(&self, req: &super::agent::CopyFileRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+    pub fn copy_file_async(&self, req: &super::agent::CopyFileRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::CopyFileResponse>> {
         self.copy_file_async_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn read_file_opt(&self, req: &super::agent::ReadFileRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::ReadFileResponse> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_READ_FILE, req, opt)
+    }
+
+    pub fn read_file(&self, req: &super::agent::ReadFileRequest) -> ::grpcio::Result<super::agent::ReadFileResponse> {
+        self.read_file_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn read_file_async_opt(&self, req: &super::agent::ReadFileRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::ReadFileResponse>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_READ_FILE, req, opt)
+    }
+
+    pub fn read_file_async(&self, req: &super::agent::ReadFileRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::ReadFileResponse>> {
+        self.read_file_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_metrics_opt(&self, req: &super::agent::GetMetricsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::Metrics> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_GET_METRICS, req, opt)
+    }
+
+    pub fn get_metrics(&self, req: &super::agent::GetMetricsRequest) -> ::grpcio::Result<super::agent::Metrics> {
+        self.get_metrics_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_metrics_async_opt(&self, req: &super::agent::GetMetricsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::Metrics>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_GET_METRICS, req, opt)
+    }
+
+    pub fn get_metrics_async(&self, req: &super::agent::GetMetricsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::Metrics>> {
+        self.get_metrics_async_opt(req, ::grpcio::CallOption::default())
+    }
     pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
         self.client.spawn(f)
     }
@@ -736,15 +881,20 @@ pub trait AgentService {
     fn stats_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StatsContainerRequest, sink: ::grpcio::UnarySink<super::agent::StatsContainerResponse>);
     fn pause_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::PauseContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn resume_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ResumeContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn get_oom_event(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GetOOMEventRequest, sink: ::grpcio::UnarySink<super::agent::OOMEvent>);
+    fn subscribe_memory_pressure(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::SubscribeMemoryPressureRequest, sink: ::grpcio::ServerStreamingSink<super::agent::MemoryPressureEvent>);
     fn write_stdin(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::WriteStreamRequest, sink: ::grpcio::UnarySink<super::agent::WriteStreamResponse>);
     fn read_stdout(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadStreamRequest, sink: ::grpcio::UnarySink<super::agent::ReadStreamResponse>);
     fn read_stderr(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadStreamRequest, sink: ::grpcio::UnarySink<super::agent::ReadStreamResponse>);
     fn close_stdin(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CloseStdinRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn tty_win_resize(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::TtyWinResizeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn stream_io(&mut self, ctx: ::grpcio::RpcContext, stream: ::grpcio::RequestStream<super::agent::StreamIORequest>, sink: ::grpcio::DuplexSink<super::agent::StreamIOResponse>);
+    fn read_output(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadOutputRequest, sink: ::grpcio::UnarySink<super::agent::ReadOutputResponse>);
     fn update_interface(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateInterfaceRequest, sink: ::grpcio::UnarySink<super::types::Interface>);
     fn update_routes(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateRoutesRequest, sink: ::grpcio::UnarySink<super::agent::Routes>);
     fn list_interfaces(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ListInterfacesRequest, sink: ::grpcio::UnarySink<super::agent::Interfaces>);
     fn list_routes(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ListRoutesRequest, sink: ::grpcio::UnarySink<super::agent::Routes>);
+    fn update_dns(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateDNSRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn start_tracing(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StartTracingRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn stop_tracing(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StopTracingRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn create_sandbox(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CreateSandboxRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
@@ -754,7 +904,9 @@ pub trait AgentService {
     fn get_guest_details(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GuestDetailsRequest, sink: ::grpcio::UnarySink<super::agent::GuestDetailsResponse>);
     fn mem_hotplug_by_probe(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::MemHotplugByProbeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn set_guest_date_time(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::SetGuestDateTimeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
-    fn copy_file(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CopyFileRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn copy_file(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CopyFileRequest, sink: ::grpcio::UnarySink<super::agent::CopyFileResponse>);
+    fn read_file(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadFileRequest, sink: ::grpcio::UnarySink<super::agent::ReadFileResponse>);
+    fn get_metrics(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GetMetricsRequest, sink: ::grpcio::UnarySink<super::agent::Metrics>);
 }
 
 pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -804,6 +956,14 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.resume_container(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_GET_OOM_EVENT, move |ctx, req, resp| {
+        instance.get_oom_event(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_AGENT_SERVICE_SUBSCRIBE_MEMORY_PRESSURE, move |ctx, req, resp| {
+        instance.subscribe_memory_pressure(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_WRITE_STDIN, move |ctx, req, resp| {
         instance.write_stdin(ctx, req, resp)
     });
@@ -824,6 +984,14 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.tty_win_resize(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_duplex_streaming_handler(&METHOD_AGENT_SERVICE_STREAM_IO, move |ctx, req, resp| {
+        instance.stream_io(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_READ_OUTPUT, move |ctx, req, resp| {
+        instance.read_output(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_UPDATE_INTERFACE, move |ctx, req, resp| {
         instance.update_interface(ctx, req, resp)
     });
@@ -840,6 +1008,10 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.list_routes(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_UPDATE_DNS, move |ctx, req, resp| {
+        instance.update_dns(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_START_TRACING, move |ctx, req, resp| {
         instance.start_tracing(ctx, req, resp)
     });
@@ -879,5 +1051,13 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_COPY_FILE, move |ctx, req, resp| {
         instance.copy_file(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_READ_FILE, move |ctx, req, resp| {
+        instance.read_file(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_GET_METRICS, move |ctx, req, resp| {
+        instance.get_metrics(ctx, req, resp)
+    });
     builder.build()
 }
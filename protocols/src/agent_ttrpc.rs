@@ -0,0 +1,356 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+#[derive(Clone)]
+pub struct AgentServiceClient {
+    client: ::ttrpc::Client,
+}
+
+impl AgentServiceClient {
+    pub fn new(client: ::ttrpc::Client) -> Self {
+        AgentServiceClient {
+            client: client,
+        }
+    }
+
+    pub fn create_container(&self, req: &super::agent::CreateContainerRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "CreateContainer", req, timeout_nano)
+    }
+
+    pub fn start_container(&self, req: &super::agent::StartContainerRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "StartContainer", req, timeout_nano)
+    }
+
+    pub fn remove_container(&self, req: &super::agent::RemoveContainerRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "RemoveContainer", req, timeout_nano)
+    }
+
+    pub fn exec_process(&self, req: &super::agent::ExecProcessRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "ExecProcess", req, timeout_nano)
+    }
+
+    pub fn signal_process(&self, req: &super::agent::SignalProcessRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "SignalProcess", req, timeout_nano)
+    }
+
+    pub fn wait_process(&self, req: &super::agent::WaitProcessRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::WaitProcessResponse> {
+        self.client.call("grpc.AgentService", "WaitProcess", req, timeout_nano)
+    }
+
+    pub fn list_processes(&self, req: &super::agent::ListProcessesRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::ListProcessesResponse> {
+        self.client.call("grpc.AgentService", "ListProcesses", req, timeout_nano)
+    }
+
+    pub fn update_container(&self, req: &super::agent::UpdateContainerRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "UpdateContainer", req, timeout_nano)
+    }
+
+    pub fn stats_container(&self, req: &super::agent::StatsContainerRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::StatsContainerResponse> {
+        self.client.call("grpc.AgentService", "StatsContainer", req, timeout_nano)
+    }
+
+    pub fn pause_container(&self, req: &super::agent::PauseContainerRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "PauseContainer", req, timeout_nano)
+    }
+
+    pub fn resume_container(&self, req: &super::agent::ResumeContainerRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "ResumeContainer", req, timeout_nano)
+    }
+
+    pub fn get_oom_event(&self, req: &super::agent::GetOOMEventRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::OOMEvent> {
+        self.client.call("grpc.AgentService", "GetOOMEvent", req, timeout_nano)
+    }
+
+    pub fn write_stdin(&self, req: &super::agent::WriteStreamRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::WriteStreamResponse> {
+        self.client.call("grpc.AgentService", "WriteStdin", req, timeout_nano)
+    }
+
+    pub fn read_stdout(&self, req: &super::agent::ReadStreamRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::ReadStreamResponse> {
+        self.client.call("grpc.AgentService", "ReadStdout", req, timeout_nano)
+    }
+
+    pub fn read_stderr(&self, req: &super::agent::ReadStreamRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::ReadStreamResponse> {
+        self.client.call("grpc.AgentService", "ReadStderr", req, timeout_nano)
+    }
+
+    pub fn close_stdin(&self, req: &super::agent::CloseStdinRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "CloseStdin", req, timeout_nano)
+    }
+
+    pub fn tty_win_resize(&self, req: &super::agent::TtyWinResizeRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "TtyWinResize", req, timeout_nano)
+    }
+
+    pub fn read_output(&self, req: &super::agent::ReadOutputRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::ReadOutputResponse> {
+        self.client.call("grpc.AgentService", "ReadOutput", req, timeout_nano)
+    }
+
+    pub fn update_interface(&self, req: &super::agent::UpdateInterfaceRequest, timeout_nano: i64) -> ::ttrpc::Result<super::types::Interface> {
+        self.client.call("grpc.AgentService", "UpdateInterface", req, timeout_nano)
+    }
+
+    pub fn update_routes(&self, req: &super::agent::UpdateRoutesRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::Routes> {
+        self.client.call("grpc.AgentService", "UpdateRoutes", req, timeout_nano)
+    }
+
+    pub fn list_interfaces(&self, req: &super::agent::ListInterfacesRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::Interfaces> {
+        self.client.call("grpc.AgentService", "ListInterfaces", req, timeout_nano)
+    }
+
+    pub fn list_routes(&self, req: &super::agent::ListRoutesRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::Routes> {
+        self.client.call("grpc.AgentService", "ListRoutes", req, timeout_nano)
+    }
+
+    pub fn update_dns(&self, req: &super::agent::UpdateDNSRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "UpdateDNS", req, timeout_nano)
+    }
+
+    pub fn start_tracing(&self, req: &super::agent::StartTracingRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "StartTracing", req, timeout_nano)
+    }
+
+    pub fn stop_tracing(&self, req: &super::agent::StopTracingRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "StopTracing", req, timeout_nano)
+    }
+
+    pub fn create_sandbox(&self, req: &super::agent::CreateSandboxRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "CreateSandbox", req, timeout_nano)
+    }
+
+    pub fn destroy_sandbox(&self, req: &super::agent::DestroySandboxRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "DestroySandbox", req, timeout_nano)
+    }
+
+    pub fn online_cpu_mem(&self, req: &super::agent::OnlineCPUMemRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "OnlineCPUMem", req, timeout_nano)
+    }
+
+    pub fn reseed_random_dev(&self, req: &super::agent::ReseedRandomDevRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "ReseedRandomDev", req, timeout_nano)
+    }
+
+    pub fn get_guest_details(&self, req: &super::agent::GuestDetailsRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::GuestDetailsResponse> {
+        self.client.call("grpc.AgentService", "GetGuestDetails", req, timeout_nano)
+    }
+
+    pub fn mem_hotplug_by_probe(&self, req: &super::agent::MemHotplugByProbeRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "MemHotplugByProbe", req, timeout_nano)
+    }
+
+    pub fn set_guest_date_time(&self, req: &super::agent::SetGuestDateTimeRequest, timeout_nano: i64) -> ::ttrpc::Result<super::empty::Empty> {
+        self.client.call("grpc.AgentService", "SetGuestDateTime", req, timeout_nano)
+    }
+
+    pub fn copy_file(&self, req: &super::agent::CopyFileRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::CopyFileResponse> {
+        self.client.call("grpc.AgentService", "CopyFile", req, timeout_nano)
+    }
+
+    pub fn read_file(&self, req: &super::agent::ReadFileRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::ReadFileResponse> {
+        self.client.call("grpc.AgentService", "ReadFile", req, timeout_nano)
+    }
+
+    pub fn get_metrics(&self, req: &super::agent::GetMetricsRequest, timeout_nano: i64) -> ::ttrpc::Result<super::agent::Metrics> {
+        self.client.call("grpc.AgentService", "GetMetrics", req, timeout_nano)
+    }
+}
+
+pub trait AgentService {
+    fn create_container(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::CreateContainerRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn start_container(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::StartContainerRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn remove_container(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::RemoveContainerRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn exec_process(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ExecProcessRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn signal_process(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::SignalProcessRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn wait_process(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::WaitProcessRequest) -> ::ttrpc::Result<super::agent::WaitProcessResponse>;
+    fn list_processes(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ListProcessesRequest) -> ::ttrpc::Result<super::agent::ListProcessesResponse>;
+    fn update_container(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::UpdateContainerRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn stats_container(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::StatsContainerRequest) -> ::ttrpc::Result<super::agent::StatsContainerResponse>;
+    fn pause_container(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::PauseContainerRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn resume_container(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ResumeContainerRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn get_oom_event(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::GetOOMEventRequest) -> ::ttrpc::Result<super::agent::OOMEvent>;
+    fn write_stdin(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::WriteStreamRequest) -> ::ttrpc::Result<super::agent::WriteStreamResponse>;
+    fn read_stdout(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ReadStreamRequest) -> ::ttrpc::Result<super::agent::ReadStreamResponse>;
+    fn read_stderr(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ReadStreamRequest) -> ::ttrpc::Result<super::agent::ReadStreamResponse>;
+    fn close_stdin(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::CloseStdinRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn tty_win_resize(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::TtyWinResizeRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn read_output(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ReadOutputRequest) -> ::ttrpc::Result<super::agent::ReadOutputResponse>;
+    fn update_interface(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::UpdateInterfaceRequest) -> ::ttrpc::Result<super::types::Interface>;
+    fn update_routes(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::UpdateRoutesRequest) -> ::ttrpc::Result<super::agent::Routes>;
+    fn list_interfaces(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ListInterfacesRequest) -> ::ttrpc::Result<super::agent::Interfaces>;
+    fn list_routes(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ListRoutesRequest) -> ::ttrpc::Result<super::agent::Routes>;
+    fn update_dns(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::UpdateDNSRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn start_tracing(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::StartTracingRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn stop_tracing(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::StopTracingRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn create_sandbox(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::CreateSandboxRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn destroy_sandbox(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::DestroySandboxRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn online_cpu_mem(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::OnlineCPUMemRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn reseed_random_dev(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ReseedRandomDevRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn get_guest_details(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::GuestDetailsRequest) -> ::ttrpc::Result<super::agent::GuestDetailsResponse>;
+    fn mem_hotplug_by_probe(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::MemHotplugByProbeRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn set_guest_date_time(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::SetGuestDateTimeRequest) -> ::ttrpc::Result<super::empty::Empty>;
+    fn copy_file(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::CopyFileRequest) -> ::ttrpc::Result<super::agent::CopyFileResponse>;
+    fn read_file(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::ReadFileRequest) -> ::ttrpc::Result<super::agent::ReadFileResponse>;
+    fn get_metrics(&self, ctx: &::ttrpc::TtrpcContext, req: super::agent::GetMetricsRequest) -> ::ttrpc::Result<super::agent::Metrics>;
+}
+
+pub fn create_agent_service(service: ::std::sync::Arc<Box<dyn AgentService + Send + Sync>>) -> ::ttrpc::Methods {
+    let mut methods = ::ttrpc::Methods::new();
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "CreateContainer"),
+        ::ttrpc::unary(move |ctx, req: super::agent::CreateContainerRequest| s.create_container(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "StartContainer"),
+        ::ttrpc::unary(move |ctx, req: super::agent::StartContainerRequest| s.start_container(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "RemoveContainer"),
+        ::ttrpc::unary(move |ctx, req: super::agent::RemoveContainerRequest| s.remove_container(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ExecProcess"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ExecProcessRequest| s.exec_process(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "SignalProcess"),
+        ::ttrpc::unary(move |ctx, req: super::agent::SignalProcessRequest| s.signal_process(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "WaitProcess"),
+        ::ttrpc::unary(move |ctx, req: super::agent::WaitProcessRequest| s.wait_process(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ListProcesses"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ListProcessesRequest| s.list_processes(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "UpdateContainer"),
+        ::ttrpc::unary(move |ctx, req: super::agent::UpdateContainerRequest| s.update_container(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "StatsContainer"),
+        ::ttrpc::unary(move |ctx, req: super::agent::StatsContainerRequest| s.stats_container(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "PauseContainer"),
+        ::ttrpc::unary(move |ctx, req: super::agent::PauseContainerRequest| s.pause_container(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ResumeContainer"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ResumeContainerRequest| s.resume_container(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "GetOOMEvent"),
+        ::ttrpc::unary(move |ctx, req: super::agent::GetOOMEventRequest| s.get_oom_event(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "WriteStdin"),
+        ::ttrpc::unary(move |ctx, req: super::agent::WriteStreamRequest| s.write_stdin(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ReadStdout"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ReadStreamRequest| s.read_stdout(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ReadStderr"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ReadStreamRequest| s.read_stderr(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "CloseStdin"),
+        ::ttrpc::unary(move |ctx, req: super::agent::CloseStdinRequest| s.close_stdin(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "TtyWinResize"),
+        ::ttrpc::unary(move |ctx, req: super::agent::TtyWinResizeRequest| s.tty_win_resize(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ReadOutput"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ReadOutputRequest| s.read_output(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "UpdateInterface"),
+        ::ttrpc::unary(move |ctx, req: super::agent::UpdateInterfaceRequest| s.update_interface(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "UpdateRoutes"),
+        ::ttrpc::unary(move |ctx, req: super::agent::UpdateRoutesRequest| s.update_routes(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ListInterfaces"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ListInterfacesRequest| s.list_interfaces(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ListRoutes"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ListRoutesRequest| s.list_routes(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "UpdateDNS"),
+        ::ttrpc::unary(move |ctx, req: super::agent::UpdateDNSRequest| s.update_dns(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "StartTracing"),
+        ::ttrpc::unary(move |ctx, req: super::agent::StartTracingRequest| s.start_tracing(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "StopTracing"),
+        ::ttrpc::unary(move |ctx, req: super::agent::StopTracingRequest| s.stop_tracing(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "CreateSandbox"),
+        ::ttrpc::unary(move |ctx, req: super::agent::CreateSandboxRequest| s.create_sandbox(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "DestroySandbox"),
+        ::ttrpc::unary(move |ctx, req: super::agent::DestroySandboxRequest| s.destroy_sandbox(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "OnlineCPUMem"),
+        ::ttrpc::unary(move |ctx, req: super::agent::OnlineCPUMemRequest| s.online_cpu_mem(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ReseedRandomDev"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ReseedRandomDevRequest| s.reseed_random_dev(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "GetGuestDetails"),
+        ::ttrpc::unary(move |ctx, req: super::agent::GuestDetailsRequest| s.get_guest_details(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "MemHotplugByProbe"),
+        ::ttrpc::unary(move |ctx, req: super::agent::MemHotplugByProbeRequest| s.mem_hotplug_by_probe(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "SetGuestDateTime"),
+        ::ttrpc::unary(move |ctx, req: super::agent::SetGuestDateTimeRequest| s.set_guest_date_time(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "CopyFile"),
+        ::ttrpc::unary(move |ctx, req: super::agent::CopyFileRequest| s.copy_file(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "ReadFile"),
+        ::ttrpc::unary(move |ctx, req: super::agent::ReadFileRequest| s.read_file(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.AgentService", "GetMetrics"),
+        ::ttrpc::unary(move |ctx, req: super::agent::GetMetricsRequest| s.get_metrics(ctx, req)));
+
+    methods
+}
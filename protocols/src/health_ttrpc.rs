@@ -0,0 +1,59 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+#[derive(Clone)]
+pub struct HealthClient {
+    client: ::ttrpc::Client,
+}
+
+impl HealthClient {
+    pub fn new(client: ::ttrpc::Client) -> Self {
+        HealthClient {
+            client: client,
+        }
+    }
+
+    pub fn check(&self, req: &super::health::CheckRequest, timeout_nano: i64) -> ::ttrpc::Result<super::health::HealthCheckResponse> {
+        self.client.call("grpc.Health", "Check", req, timeout_nano)
+    }
+
+    pub fn version(&self, req: &super::health::CheckRequest, timeout_nano: i64) -> ::ttrpc::Result<super::health::VersionCheckResponse> {
+        self.client.call("grpc.Health", "Version", req, timeout_nano)
+    }
+}
+
+pub trait Health {
+    fn check(&self, ctx: &::ttrpc::TtrpcContext, req: super::health::CheckRequest) -> ::ttrpc::Result<super::health::HealthCheckResponse>;
+    fn version(&self, ctx: &::ttrpc::TtrpcContext, req: super::health::CheckRequest) -> ::ttrpc::Result<super::health::VersionCheckResponse>;
+}
+
+pub fn create_health(service: ::std::sync::Arc<Box<dyn Health + Send + Sync>>) -> ::ttrpc::Methods {
+    let mut methods = ::ttrpc::Methods::new();
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.Health", "Check"),
+        ::ttrpc::unary(move |ctx, req: super::health::CheckRequest| s.check(ctx, req)));
+
+    let s = service.clone();
+    methods.insert(::ttrpc::method_name("grpc.Health", "Version"),
+        ::ttrpc::unary(move |ctx, req: super::health::CheckRequest| s.version(ctx, req)));
+
+    methods
+}
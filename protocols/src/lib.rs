@@ -1,11 +1,20 @@
 pub mod agent;
+#[cfg(feature = "grpc")]
 pub mod agent_grpc;
+#[cfg(feature = "ttrpc")]
+pub mod agent_ttrpc;
 pub mod health;
+#[cfg(feature = "grpc")]
 pub mod health_grpc;
+#[cfg(feature = "ttrpc")]
+pub mod health_ttrpc;
 pub mod oci;
 pub mod types;
 pub mod empty;
 
+#[cfg(feature = "ttrpc")]
+pub use ttrpc;
+
 #[cfg(test)]
 mod tests {
     #[test]
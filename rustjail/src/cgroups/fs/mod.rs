@@ -10,12 +10,15 @@ use nix::errno::Errno;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::fs::{self, File};
+use std::path::Path;
 use protocols::oci::{Spec, LinuxDeviceCgroup, LinuxResources, LinuxWeightDevice, LinuxThrottleDevice};
 use libc::{self, pid_t};
 use protocols::agent::{CgroupStats,CpuStats, MemoryStats, PidsStats,BlkioStats, HugetlbStats, CpuUsage, ThrottlingData, MemoryData, BlkioStatsEntry};
 use protobuf::{SingularPtrField, UnknownFields, CachedSize, RepeatedField};
 use crate::cgroups::FreezerState;
 use std::io::Write;
+use std::thread;
+use std::time::Duration;
 use nix::sys::stat;
 use crate::container::DEFAULT_DEVICES;
 use regex::Regex;
@@ -229,6 +232,8 @@ fn get_hugepage_sizes() -> Result<Vec<String>> {
 pub const CPUSET_CPUS: &'static str = "cpuset.cpus";
 pub const CPUSET_MEMS: &'static str = "cpuset.mems";
 pub const CGROUP_PROCS: &'static str = "cgroup.procs";
+pub const CGROUP2_ROOT: &'static str = "/sys/fs/cgroup";
+pub const CGROUP2_CONTROLLERS: &'static str = "/sys/fs/cgroup/cgroup.controllers";
 pub const CPU_RT_PERIOD_US: &'static str = "cpu.rt_period_us";
 pub const CPU_RT_RUNTIME_US: &'static str = "cpu.rt_runtime_us";
 pub const CPU_SHARES: &'static str = "cpu.shares";
@@ -1219,6 +1224,23 @@ pub struct Manager {
 
 pub const THAWED: &'static str = "THAWED";
 pub const FROZEN: &'static str = "FROZEN";
+const FREEZER_RETRIES: u32 = 1000;
+const FREEZER_INTERVAL_MS: u64 = 1;
+
+// the kernel may report FREEZING for a while, keep writing the state
+// until it confirms the transition
+fn set_freezer_state(dir: &str, state: FreezerState) -> Result<()> {
+	for _ in 0..FREEZER_RETRIES {
+		write_file(dir, "freezer.state", state)?;
+		if read_file(dir, "freezer.state")?.trim() == state {
+			return Ok(());
+		}
+		thread::sleep(Duration::from_millis(FREEZER_INTERVAL_MS));
+	}
+
+	Err(ErrorKind::ErrorCode(format!(
+		"timeout waiting for freezer state {}", state)).into())
+}
 
 impl CgroupManager for Manager {
 	fn apply(&self, pid: pid_t) -> Result<()> {
@@ -1320,7 +1342,13 @@ impl CgroupManager for Manager {
 		if state == THAWED || state == FROZEN {
 			if self.paths.get("freezer").is_some() {
 				let dir = self.paths.get("freezer").unwrap();
-				write_file(dir, "freezer.state", state)?;
+				let r = set_freezer_state(dir, state);
+				// do not leave the tasks half frozen, as runc does
+				if r.is_err() && state == FROZEN {
+					let _ = write_file(dir, "freezer.state", THAWED);
+				}
+
+				return r;
 			}
 		} else {
 			if !state.is_empty() {
@@ -1436,6 +1464,21 @@ impl Manager {
 
 		Ok(())
 	}
+
+	// the memory controller of v1, or the cgroup of the unified hierarchy
+	// mounted at /sys/fs/cgroup on v2
+	pub fn memory_path(&self) -> Option<String> {
+		if let Some(p) = self.paths.get("memory") {
+			return Some(p.to_string());
+		}
+
+		let rel = self.rels.get("")?;
+		if !Path::new(CGROUP2_CONTROLLERS).exists() {
+			return None;
+		}
+
+		Some(format!("{}{}{}", CGROUP2_ROOT, rel.trim_end_matches('/'), self.cpath))
+	}
 }
 
 pub fn get_guest_cpuset() -> Result<String> {
@@ -11,6 +11,7 @@ use protocols::agent::{CgroupStats};
 
 
 pub mod fs;
+pub mod notify;
 pub mod systemd;
 
 pub type FreezerState = &'static str;
@@ -0,0 +1,285 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Events of the memory cgroup of a container, the way runc gets them: an
+// eventfd registered in cgroup.event_control on v1, inotify on
+// memory.events or a PSI trigger on memory.pressure on v2. Every watch has
+// a thread of its own that sends the container id for each event, and
+// ends along with the cgroup.
+
+use nix::errno::Errno;
+use nix::poll::{self, EventFlags, PollFd};
+use nix::sys::eventfd::{eventfd, EfdFlags};
+use nix::unistd;
+use std::ffi::CString;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+
+use crate::errors::*;
+
+const EVENT_CONTROL: &'static str = "cgroup.event_control";
+const OOM_CONTROL: &'static str = "memory.oom_control";
+const MEMORY_EVENTS: &'static str = "memory.events";
+const PRESSURE_LEVEL: &'static str = "memory.pressure_level";
+const MEMORY_PRESSURE: &'static str = "memory.pressure";
+
+// how often a PSI watch checks that its cgroup is still there
+const PSI_POLL_MS: i32 = 1000;
+
+pub const PRESSURE_LOW: &'static str = "low";
+pub const PRESSURE_MEDIUM: &'static str = "medium";
+pub const PRESSURE_CRITICAL: &'static str = "critical";
+
+// a PSI trigger: stall_us of stall within any window_us, of all the tasks
+// of the cgroup at once when full
+#[derive(Debug, Clone, PartialEq)]
+pub struct PsiTrigger {
+	pub full: bool,
+	pub stall_us: u64,
+	pub window_us: u64,
+}
+
+impl PsiTrigger {
+	fn trigger(&self) -> String {
+		format!("{} {} {}", if self.full { "full" } else { "some" }, self.stall_us, self.window_us)
+	}
+}
+
+// dir is the memory cgroup of the container
+pub fn notify_oom(cid: &str, dir: &str) -> Result<Receiver<String>> {
+	let (tx, rx) = channel();
+
+	if Path::new(dir).join(EVENT_CONTROL).exists() {
+		let (efd, target) = register_event(dir, OOM_CONTROL, "")?;
+		watch_eventfd(cid, dir, efd, target, tx);
+	} else {
+		watch_memory_events(cid, dir, tx)?;
+	}
+
+	Ok(rx)
+}
+
+// level is one of the PRESSURE_ levels of memory.pressure_level on v1,
+// psi what stands for it on v2
+pub fn notify_memory_pressure(cid: &str, dir: &str, level: &str, psi: &PsiTrigger) -> Result<Receiver<String>> {
+	match level {
+		PRESSURE_LOW | PRESSURE_MEDIUM | PRESSURE_CRITICAL => {}
+		_ => return Err(ErrorKind::InvalidArgument(format!(
+			"unknown memory pressure level {}", level)).into()),
+	}
+
+	let (tx, rx) = channel();
+
+	if Path::new(dir).join(EVENT_CONTROL).exists() {
+		let (efd, target) = register_event(dir, PRESSURE_LEVEL, level)?;
+		watch_eventfd(cid, dir, efd, target, tx);
+	} else {
+		watch_psi(cid, dir, psi, tx)?;
+	}
+
+	Ok(rx)
+}
+
+// the OOM kills in the cgroup so far, the same key in memory.events and in
+// the memory.oom_control of kernels since 4.13
+pub fn oom_kill_count(dir: &str) -> Option<u64> {
+	[MEMORY_EVENTS, OOM_CONTROL].iter()
+		.filter_map(|f| fs::read_to_string(Path::new(dir).join(f)).ok())
+		.next()
+		.and_then(|s| parse_oom_kill(s.as_str()))
+}
+
+fn parse_oom_kill(content: &str) -> Option<u64> {
+	content.lines()
+		.filter_map(|l| {
+			let mut f = l.split_whitespace();
+			match (f.next(), f.next()) {
+				(Some("oom_kill"), Some(n)) => n.parse().ok(),
+				_ => None,
+			}
+		})
+		.next()
+}
+
+// an eventfd the kernel signals for file of the cgroup, with arg for the
+// files taking one; file is kept open as long as the eventfd
+fn register_event(dir: &str, file: &str, arg: &str) -> Result<(RawFd, File)> {
+	let target = File::open(Path::new(dir).join(file))?;
+	let efd = eventfd(0, EfdFlags::EFD_CLOEXEC)?;
+
+	let control = format!("{} {} {}", efd, target.as_raw_fd(), arg);
+	if let Err(e) = fs::write(Path::new(dir).join(EVENT_CONTROL), control.trim_end()) {
+		let _ = unistd::close(efd);
+		return Err(e.into());
+	}
+
+	Ok((efd, target))
+}
+
+fn watch_eventfd(cid: &str, dir: &str, efd: RawFd, target: File, tx: Sender<String>) {
+	let cid = cid.to_string();
+	let control = Path::new(dir).join(EVENT_CONTROL);
+
+	thread::spawn(move || {
+		let _target = target;
+		let mut buf = [0u8; 8];
+
+		loop {
+			match unistd::read(efd, &mut buf) {
+				Err(nix::Error::Sys(Errno::EINTR)) => continue,
+				Err(e) => {
+					warn!("cannot read the cgroup events of {}: {}", cid, e);
+					break;
+				}
+				Ok(_) => {}
+			}
+
+			// signalled one last time when the cgroup is removed
+			if !control.exists() || tx.send(cid.clone()).is_err() {
+				break;
+			}
+		}
+
+		let _ = unistd::close(efd);
+	});
+}
+
+fn watch_memory_events(cid: &str, dir: &str, tx: Sender<String>) -> Result<()> {
+	let events = format!("{}/{}", dir, MEMORY_EVENTS);
+	let path = CString::new(events.as_str())?;
+
+	let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+	if fd < 0 {
+		return Err(nix::Error::last().into());
+	}
+
+	if unsafe { libc::inotify_add_watch(fd, path.as_ptr(), libc::IN_MODIFY) } < 0 {
+		let e = nix::Error::last();
+		let _ = unistd::close(fd);
+		return Err(e.into());
+	}
+
+	// only the kills from now on
+	let mut last = oom_kill_count(dir).unwrap_or(0);
+	let cid = cid.to_string();
+
+	thread::spawn(move || {
+		let mut buf = [0u8; 4096];
+
+		loop {
+			match unistd::read(fd, &mut buf) {
+				Err(nix::Error::Sys(Errno::EINTR)) => continue,
+				Err(e) => {
+					warn!("cannot read the cgroup events of {}: {}", cid, e);
+					break;
+				}
+				Ok(_) => {}
+			}
+
+			// IN_IGNORED once the cgroup is removed, and nothing to read
+			let count = match fs::read_to_string(events.as_str()) {
+				Ok(s) => parse_oom_kill(s.as_str()).unwrap_or(last),
+				Err(_) => break,
+			};
+
+			if (last..count).any(|_| tx.send(cid.clone()).is_err()) {
+				break;
+			}
+			last = count.max(last);
+		}
+
+		let _ = unistd::close(fd);
+	});
+
+	Ok(())
+}
+
+// the trigger lives as long as the file stays open, the kernel wants it
+// in a single write
+fn watch_psi(cid: &str, dir: &str, psi: &PsiTrigger, tx: Sender<String>) -> Result<()> {
+	let path = Path::new(dir).join(MEMORY_PRESSURE);
+	let mut file = OpenOptions::new()
+		.read(true)
+		.write(true)
+		.custom_flags(libc::O_NONBLOCK)
+		.open(&path)?;
+	file.write_all(psi.trigger().as_bytes())?;
+
+	let cid = cid.to_string();
+
+	thread::spawn(move || {
+		let mut fds = [PollFd::new(file.as_raw_fd(), EventFlags::POLLPRI)];
+
+		loop {
+			match poll::poll(&mut fds, PSI_POLL_MS) {
+				Err(nix::Error::Sys(Errno::EINTR)) => continue,
+				Err(e) => {
+					warn!("cannot poll the memory pressure of {}: {}", cid, e);
+					break;
+				}
+				Ok(_) => {}
+			}
+
+			let revents = fds[0].revents().unwrap_or(EventFlags::empty());
+			if revents.contains(EventFlags::POLLERR) || !path.exists() {
+				break;
+			}
+
+			if revents.contains(EventFlags::POLLPRI) && tx.send(cid.clone()).is_err() {
+				break;
+			}
+		}
+	});
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	#[test]
+	fn test_parse_oom_kill() {
+		assert_eq!(parse_oom_kill("oom_kill_disable 0\nunder_oom 0\noom_kill 3\n"), Some(3));
+		assert_eq!(parse_oom_kill("low 0\nhigh 0\nmax 1\noom 2\noom_kill 1\n"), Some(1));
+		assert_eq!(parse_oom_kill("oom_kill_disable 0\nunder_oom 0\n"), None);
+	}
+
+	#[test]
+	fn test_notify_memory_pressure() {
+		let psi = PsiTrigger { full: false, stall_us: 150000, window_us: 1000000 };
+		assert_eq!(psi.trigger(), "some 150000 1000000");
+		assert!(notify_memory_pressure("ctr", "/nonexistent", "high", &psi).is_err());
+		assert!(notify_memory_pressure("ctr", "/nonexistent", PRESSURE_LOW, &psi).is_err());
+	}
+
+	#[test]
+	fn test_notify_oom_v2() {
+		let dir = std::env::temp_dir().join(format!("rustjail-oom-{}", std::process::id()));
+		let _ = fs::remove_dir_all(&dir);
+		fs::create_dir_all(&dir).unwrap();
+		let events = dir.join(MEMORY_EVENTS);
+		fs::write(&events, "oom 1\noom_kill 1\n").unwrap();
+
+		let rx = notify_oom("ctr", dir.to_str().unwrap()).unwrap();
+		let timeout = Duration::from_secs(5);
+
+		fs::write(&events, "oom 3\noom_kill 3\n").unwrap();
+		assert_eq!(rx.recv_timeout(timeout).unwrap(), "ctr");
+		assert_eq!(rx.recv_timeout(timeout).unwrap(), "ctr");
+		assert_eq!(oom_kill_count(dir.to_str().unwrap()), Some(3));
+
+		// the watch ends with the cgroup
+		fs::remove_dir_all(&dir).unwrap();
+		assert!(rx.recv_timeout(timeout).is_err());
+		assert!(rx.try_recv().is_err());
+	}
+}
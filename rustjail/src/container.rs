@@ -13,7 +13,8 @@ use lazy_static;
 #[macro_use]
 use error_chain;
 use protocols::oci::{self, Spec, Linux, LinuxNamespace, LinuxResources, POSIXRlimit};
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
+use std::thread;
 use std::sync::mpsc::{Sender, Receiver};
 use std::sync::mpsc::channel;
 use std::sync::Mutex;
@@ -43,7 +44,9 @@ use crate::specconv::CreateOpts;
 use crate::errors::*;
 // use crate::stats::Stats;
 use crate::mount;
+use crate::trace;
 use crate::cgroups::fs::{Manager as FsManager, self as fscgroup};
+use crate::cgroups::notify::{self, PsiTrigger};
 use crate::capabilities::{self, CAPSMAP};
 
 use protocols::agent::{StatsContainerResponse};
@@ -66,7 +69,7 @@ use libc::{self, VMIN, VTIME};
 use protobuf::{UnknownFields, CachedSize, SingularPtrField};
 
 use std::io::{Error as IOError};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use scopeguard;
 use ::oci::{State as OCIState};
 
@@ -236,6 +239,11 @@ pub struct LinuxContainer
 	pub uid_map_path: String,
 	pub gid_map_path: String,
 	pub processes: HashMap<pid_t, Process>,
+	// exit code and core dump flag of reaped processes, by exec id,
+	// so that every waiter of a process gets the same answer; only the
+	// last MAX_EXITED_PROCESSES are kept, see add_exited_process()
+	pub exited_processes: HashMap<String, (i32, bool)>,
+	exited_order: VecDeque<String>,
 	pub status: Status,
 	pub created: SystemTime,
 }
@@ -264,10 +272,16 @@ pub struct SyncPC {
 pub trait Container: BaseContainer {
 //	fn checkpoint(&self, opts: &CriuOpts) -> Result<()>;
 //	fn restore(&self, p: &Process, opts: &CriuOpts) -> Result<()>;
-	fn pause(&self) -> Result<()>;
-	fn resume(&self) -> Result<()>;
-//	fn notify_oom(&self) -> Result<(Sender, Receiver)>;
-//	fn notify_memory_pressure(&self, lvl: PressureLevel) -> Result<(Sender, Receiver)>;
+	fn pause(&mut self) -> Result<()>;
+	fn resume(&mut self) -> Result<()>;
+	fn stop(&mut self, timeout: Duration) -> Result<()>;
+	// what stop() needs, to do the waiting with the container unlocked
+	fn stopper(&mut self) -> Result<Stopper>;
+	// the id of the container, once for every OOM in its memory cgroup
+	fn notify_oom(&self) -> Result<Receiver<String>>;
+	// the id of the container, whenever its memory pressure reaches level,
+	// or psi on cgroup v2
+	fn notify_memory_pressure(&self, level: &str, psi: &PsiTrigger) -> Result<Receiver<String>>;
 }
 
 impl BaseContainer for LinuxContainer
@@ -303,7 +317,7 @@ impl BaseContainer for LinuxContainer
 			}
 		}
 
-		Err(ErrorKind::ErrorCode(format!("invalid eid {}", eid)).into())
+		Err(ErrorKind::NotFound(format!("invalid eid {}", eid)).into())
 	}
 
 	fn stats(&self) -> Result<StatsContainerResponse> {
@@ -313,7 +327,7 @@ impl BaseContainer for LinuxContainer
 			r.cgroup_stats = SingularPtrField::some(self.cgroup_manager.as_ref().unwrap().get_stats()?);
 		}
 
-		// what about network interface stats?
+		// network_stats are for the agent to fill, from the sandbox netns
 
 		Ok(r)
 	}
@@ -328,6 +342,8 @@ impl BaseContainer for LinuxContainer
 	}
 
 	fn start(&mut self, mut p: Process) -> Result<()> {
+		let mut span = trace::span("start_process");
+		span.tag("exec_id", p.exec_id.as_str());
 		let fifo_file = format!("{}/{}", &self.root, EXEC_FIFO_FILENAME);
 		info!("enter contianer.start!");
 		let mut fifofd: RawFd = -1;
@@ -406,6 +422,8 @@ impl BaseContainer for LinuxContainer
 
 		let mut parent: u32 = 0;
 
+		// only ever closed by the parent, see below
+		let ns_span = trace::span("join_namespaces");
 		let (child, cfd) = match join_namespaces(&spec,
 			to_new, &to_join, pidns, userns, p.init, self.config.no_pivot_root,self.cgroup_manager.as_ref().unwrap(), &mut parent) {
 			Ok((u, v)) => (u, v),
@@ -425,6 +443,7 @@ impl BaseContainer for LinuxContainer
 		info!("entered namespaces!");
 		if child != Pid::from_raw(-1) {
 			// parent
+			drop(ns_span);
 			p.pid = child.as_raw();
 			self.status = Some("created".to_string());
 			if p.init {
@@ -582,6 +601,12 @@ impl BaseContainer for LinuxContainer
 
 	fn run(&mut self, mut p: Process) -> Result<()> {
 		let init = p.init;
+
+		if !init && self.status == Some("paused".to_string()) {
+			return Err(ErrorKind::FailedPrecondition(format!(
+				"container {} is paused, cannot exec", &self.id)).into());
+		}
+
 		self.start(p)?;
 
 		if init {
@@ -616,6 +641,7 @@ impl BaseContainer for LinuxContainer
 	}
 
 	fn exec(&mut self) -> Result<()> {
+		let _span = trace::span("exec");
 		let fifo = format!("{}/{}", &self.root, EXEC_FIFO_FILENAME);
 		let fd = fcntl::open(fifo.as_str(), OFlag::O_WRONLY,
 				Mode::from_bits_truncate(0))?;
@@ -633,6 +659,211 @@ impl BaseContainer for LinuxContainer
 	}
 }
 
+impl Container for LinuxContainer
+{
+	fn pause(&mut self) -> Result<()> {
+		if self.status != Some("running".to_string()) {
+			return Err(ErrorKind::FailedPrecondition(format!(
+				"container {} is not running", &self.id)).into());
+		}
+
+		if self.cgroup_manager.is_some() {
+			self.cgroup_manager.as_ref().unwrap().freeze(fscgroup::FROZEN)?;
+			self.status = Some("paused".to_string());
+			return Ok(());
+		}
+
+		Err(ErrorKind::ErrorCode("failed to get container's cgroup manager".to_string()).into())
+	}
+
+	fn resume(&mut self) -> Result<()> {
+		if self.status != Some("paused".to_string()) {
+			return Err(ErrorKind::FailedPrecondition(format!(
+				"container {} is not paused", &self.id)).into());
+		}
+
+		if self.cgroup_manager.is_some() {
+			self.cgroup_manager.as_ref().unwrap().freeze(fscgroup::THAWED)?;
+			self.status = Some("running".to_string());
+			return Ok(());
+		}
+
+		Err(ErrorKind::ErrorCode("failed to get container's cgroup manager".to_string()).into())
+	}
+
+	fn stop(&mut self, timeout: Duration) -> Result<()> {
+		self.stopper()?.stop(timeout)?;
+		self.status = Some("stopped".to_string());
+		Ok(())
+	}
+
+	fn stopper(&mut self) -> Result<Stopper> {
+		// frozen processes would not see the signals
+		if self.status == Some("paused".to_string()) {
+			self.resume()?;
+		}
+
+		Ok(Stopper {
+			id: self.id.clone(),
+			cgroup_manager: self.cgroup_manager.clone(),
+			pids: self.processes.keys().cloned().collect(),
+		})
+	}
+
+	fn notify_oom(&self) -> Result<Receiver<String>> {
+		let dir = self.memory_cgroup()?;
+		notify::notify_oom(self.id.as_str(), dir.as_str())
+	}
+
+	fn notify_memory_pressure(&self, level: &str, psi: &PsiTrigger) -> Result<Receiver<String>> {
+		let dir = self.memory_cgroup()?;
+		notify::notify_memory_pressure(self.id.as_str(), dir.as_str(), level, psi)
+	}
+}
+
+const STOP_POLL_INTERVAL_MS: u64 = 10;
+const KILL_TIMEOUT_SECS: u64 = 10;
+// enough for waiters that come late, not growing with every exec
+const MAX_EXITED_PROCESSES: usize = 1024;
+
+// the reaper may not have got to it yet, so a zombie counts as gone
+fn pid_alive(pid: i32) -> bool {
+	if signal::kill(Pid::from_raw(pid), None).is_err() {
+		return false;
+	}
+
+	match fs::read_to_string(format!("/proc/{}/stat", pid)) {
+		// the state follows the command name, which may contain ')'
+		Ok(stat) => match stat.rfind(')') {
+			Some(i) => stat[i + 1..].trim_start().chars().next() != Some('Z'),
+			None => true,
+		},
+		Err(_) => false,
+	}
+}
+
+impl LinuxContainer {
+	fn memory_cgroup(&self) -> Result<String> {
+		self.cgroup_manager.as_ref()
+			.and_then(|cm| cm.memory_path())
+			.ok_or_else(|| ErrorKind::NotFound(format!(
+				"no memory cgroup for container {}", &self.id)).into())
+	}
+
+	// the OOM kills in the container so far, 0 when the kernel does not
+	// count them
+	pub fn oom_kill_count(&self) -> u64 {
+		self.memory_cgroup().ok()
+			.and_then(|dir| notify::oom_kill_count(dir.as_str()))
+			.unwrap_or(0)
+	}
+
+	// the oldest ones go first
+	pub fn add_exited_process(&mut self, eid: &str, code: i32, core_dumped: bool) {
+		self.exited_order.retain(|e| e != eid);
+		self.exited_order.push_back(eid.to_string());
+		self.exited_processes.insert(eid.to_string(), (code, core_dumped));
+
+		while self.exited_order.len() > MAX_EXITED_PROCESSES {
+			if let Some(e) = self.exited_order.pop_front() {
+				self.exited_processes.remove(&e);
+			}
+		}
+	}
+}
+
+// the processes of a container as they were when it was taken, enough to
+// stop them without holding on to the container
+#[derive(Debug, Clone)]
+pub struct Stopper {
+	id: String,
+	cgroup_manager: Option<FsManager>,
+	// the processes started by us, for a container without cgroup
+	pids: Vec<i32>,
+}
+
+impl Stopper {
+	// SIGTERM everything in the container and give it timeout to go
+	// away, then SIGKILL what is left and give that KILL_TIMEOUT_SECS.
+	// A zero timeout skips the SIGTERM.
+	pub fn stop(&self, timeout: Duration) -> Result<()> {
+		if timeout != Duration::from_secs(0) {
+			self.signal_pids(Signal::SIGTERM)?;
+
+			if self.wait_pids_gone(timeout) {
+				return Ok(());
+			}
+
+			info!("container {} still running after {:?}, killing it", &self.id, timeout);
+		}
+
+		// nothing can fork away from a frozen cgroup, the kill
+		// lands when it is thawed
+		let frozen = match self.cgroup_manager.as_ref() {
+			Some(cm) => cm.freeze(fscgroup::FROZEN).is_ok(),
+			None => false,
+		};
+
+		let killed = self.signal_pids(Signal::SIGKILL);
+
+		if frozen {
+			self.cgroup_manager.as_ref().unwrap().freeze(fscgroup::THAWED)?;
+		}
+		killed?;
+
+		// processes stuck in D state never go, do not wait for them for ever
+		if !self.wait_pids_gone(Duration::from_secs(KILL_TIMEOUT_SECS)) {
+			return Err(ErrorKind::DeadlineExceeded(format!(
+				"processes of container {} survived SIGKILL", &self.id)).into());
+		}
+
+		Ok(())
+	}
+
+	// the cgroup also knows about the processes forked behind our back;
+	// the pids kept without it may have been reused in the meantime, so
+	// they only stand in for a container without cgroup
+	fn live_pids(&self) -> Vec<i32> {
+		let mut pids = match self.cgroup_manager.as_ref() {
+			Some(cm) => cm.get_all_pids().unwrap_or_default(),
+			None => self.pids.clone(),
+		};
+
+		// a process not started yet has no pid, never signal 0
+		pids.retain(|pid| *pid > 0 && pid_alive(*pid));
+		pids
+	}
+
+	fn signal_pids(&self, sig: Signal) -> Result<()> {
+		for pid in self.live_pids() {
+			match signal::kill(Pid::from_raw(pid), Some(sig)) {
+				// gone in the meantime
+				Err(Error::Sys(Errno::ESRCH)) => {}
+				Err(e) => return Err(e.into()),
+				Ok(_) => {}
+			}
+		}
+
+		Ok(())
+	}
+
+	fn wait_pids_gone(&self, timeout: Duration) -> bool {
+		let start = Instant::now();
+
+		loop {
+			if self.live_pids().is_empty() {
+				return true;
+			}
+
+			if start.elapsed() >= timeout {
+				return false;
+			}
+
+			thread::sleep(Duration::from_millis(STOP_POLL_INTERVAL_MS));
+		}
+	}
+}
+
 use std::env;
 
 fn do_exec(path: &str, args: &[String], env: &[String]) -> Result<()> {
@@ -813,15 +1044,19 @@ fn join_namespaces(spec: &Spec, to_new: CloneFlags, to_join: &Vec<(CloneFlags, R
 			}
 
 			// apply cgroups
-			if init {
-				if res.is_some() {
-					info!("apply cgroups!");
-					cm.set(res.unwrap(), false)?;
+			{
+				let _span = trace::span("apply_cgroups");
+
+				if init {
+					if res.is_some() {
+						info!("apply cgroups!");
+						cm.set(res.unwrap(), false)?;
+					}
 				}
-			}
 
-			if res.is_some() {
-				cm.apply(child.as_raw())?;
+				if res.is_some() {
+					cm.apply(child.as_raw())?;
+				}
 			}
 
 			write_json(pwfd, &SyncPC{pid: 0})?;
@@ -1227,6 +1462,8 @@ impl LinuxContainer
 			gid_map_path: "".to_string(),
 			config,
 			processes: HashMap::new(),
+			exited_processes: HashMap::new(),
+			exited_order: VecDeque::new(),
 			created: SystemTime::now(),
 			init_process_pid: -1,
 			init_process_start_time: SystemTime::now()
@@ -28,5 +28,28 @@ error_chain! {
 			description("Error Code")
 			display("Error Code: '{}'", t)
 		}
+		// kinds below are surfaced to the client as the
+		// matching grpc status code
+		NotFound(t: String) {
+			description("Not Found")
+			display("Not Found: '{}'", t)
+		}
+		InvalidArgument(t: String) {
+			description("Invalid Argument")
+			display("Invalid Argument: '{}'", t)
+		}
+		FailedPrecondition(t: String) {
+			description("Failed Precondition")
+			display("Failed Precondition: '{}'", t)
+		}
+		DeadlineExceeded(t: String) {
+			description("Deadline Exceeded")
+			display("Deadline Exceeded: '{}'", t)
+		}
+		// left out of this build of the agent
+		Unimplemented(t: String) {
+			description("Unimplemented")
+			display("Unimplemented: '{}'", t)
+		}
 	}
 }
@@ -35,6 +35,7 @@ pub mod mount;
 pub mod specconv;
 // pub mod sync;
 pub mod capabilities;
+pub mod trace;
 
 // pub mod factory;
 //pub mod configs;
@@ -44,7 +45,7 @@ pub mod capabilities;
 // pub mod capabilities;
 // pub mod console;
 // pub mod stats;
-// pub mod user;
+pub mod user;
 //pub mod intelrdt;
 
 // construtc ociSpec from grpcSpec, which is needed for hook
@@ -53,7 +54,7 @@ pub mod capabilities;
 use std::mem;
 use std::collections::HashMap;
 
-use protocols::oci::{Spec as grpcSpec, Process as grpcProcess, Root as grpcRoot, Mount as grpcMount, Hooks as grpcHooks, Linux as grpcLinux, Box as grpcBox, User as grpcUser, POSIXRlimit as grpcPOSIXRlimit};
+use protocols::oci::{Spec as grpcSpec, Process as grpcProcess, Root as grpcRoot, Mount as grpcMount, Hooks as grpcHooks, Linux as grpcLinux, Box as grpcBox, User as grpcUser, POSIXRlimit as grpcPOSIXRlimit, LinuxCapabilities as grpcLinuxCapabilities};
 use oci::{Spec as ociSpec, Process as ociProcess, Root as ociRoot, Mount as ociMount, Hooks as ociHooks, Linux as ociLinux, Box as ociBox, User as ociUser, POSIXRlimit as ociPOSIXRlimit, LinuxCapabilities as ociLinuxCapabilities};
 
 fn process_grpc_to_oci(p: &grpcProcess) -> ociProcess {
@@ -121,9 +122,64 @@ fn process_grpc_to_oci(p: &grpcProcess) -> ociProcess {
 	}
 }
 
-fn process_oci_to_grpc(p: ociProcess) -> grpcProcess {
-	// dont implement it for now
-	unsafe { mem::zeroed::<grpcProcess>() } 
+fn process_oci_to_grpc(p: &ociProcess) -> grpcProcess {
+	let console_size = match p.console_size.as_ref() {
+		Some(c) => SingularPtrField::some(grpcBox {
+			Height: c.height,
+			Width: c.width,
+			..Default::default()
+		}),
+		None => SingularPtrField::none(),
+	};
+
+	let user = grpcUser {
+		UID: p.user.uid,
+		GID: p.user.gid,
+		AdditionalGids: p.user.additional_gids.clone(),
+		Username: p.user.username.clone(),
+		..Default::default()
+	};
+
+	let capabilities = match p.capabilities.as_ref() {
+		Some(cap) => SingularPtrField::some(grpcLinuxCapabilities {
+			Bounding: RepeatedField::from_vec(cap.bounding.clone()),
+			Effective: RepeatedField::from_vec(cap.effective.clone()),
+			Inheritable: RepeatedField::from_vec(cap.inheritable.clone()),
+			Permitted: RepeatedField::from_vec(cap.permitted.clone()),
+			Ambient: RepeatedField::from_vec(cap.ambient.clone()),
+			..Default::default()
+		}),
+		None => SingularPtrField::none(),
+	};
+
+	let rlimits = {
+		let mut r = Vec::new();
+		for lm in p.rlimits.iter() {
+			r.push(grpcPOSIXRlimit {
+				Type: lm.r#type.clone(),
+				Hard: lm.hard,
+				Soft: lm.soft,
+				..Default::default()
+			});
+		}
+		r
+	};
+
+	grpcProcess {
+		Terminal: p.terminal,
+		ConsoleSize: console_size,
+		User: SingularPtrField::some(user),
+		Args: RepeatedField::from_vec(p.args.clone()),
+		Env: RepeatedField::from_vec(p.env.clone()),
+		Cwd: p.cwd.clone(),
+		Capabilities: capabilities,
+		Rlimits: RepeatedField::from_vec(rlimits),
+		NoNewPrivileges: p.no_new_privileges,
+		ApparmorProfile: p.apparmor_profile.clone(),
+		OOMScoreAdj: p.oom_score_adj.unwrap_or(0) as i64,
+		SelinuxLabel: p.selinux_label.clone(),
+		..Default::default()
+	}
 }
 
 fn root_grpc_to_oci(root: &grpcRoot) -> ociRoot {
@@ -134,7 +190,11 @@ fn root_grpc_to_oci(root: &grpcRoot) -> ociRoot {
 }
 
 fn root_oci_to_grpc(root: &ociRoot) -> grpcRoot {
-	unsafe { mem::zeroed::<grpcRoot>() }
+	grpcRoot {
+		Path: root.path.clone(),
+		Readonly: root.readonly,
+		..Default::default()
+	}
 }
 
 fn mount_grpc_to_oci(m: &grpcMount) -> ociMount {
@@ -147,7 +207,13 @@ fn mount_grpc_to_oci(m: &grpcMount) -> ociMount {
 }
 
 fn mount_oci_to_grpc(m: &ociMount) -> grpcMount {
-	unsafe { mem::zeroed::<grpcMount>() }
+	grpcMount {
+		destination: m.destination.clone(),
+		source: m.source.clone(),
+		field_type: m.r#type.clone(),
+		options: RepeatedField::from_vec(m.options.clone()),
+		..Default::default()
+	}
 }
 
 use protocols::oci::{Hook as grpcHook};
@@ -181,8 +247,27 @@ fn hooks_grpc_to_oci(h: &grpcHooks) -> ociHooks {
 	}
 }
 
+fn hook_oci_to_grpc(h: &[ociHook]) -> RepeatedField<grpcHook> {
+	let mut r = Vec::new();
+	for e in h.iter() {
+		r.push(grpcHook {
+			Path: e.path.clone(),
+			Args: RepeatedField::from_vec(e.args.clone()),
+			Env: RepeatedField::from_vec(e.env.clone()),
+			Timeout: e.timeout.unwrap_or(0) as i64,
+			..Default::default()
+		});
+	}
+	RepeatedField::from_vec(r)
+}
+
 fn hooks_oci_to_grpc(h: &ociHooks) -> grpcHooks {
-	unsafe { mem::zeroed::<grpcHooks>() }
+	grpcHooks {
+		Prestart: hook_oci_to_grpc(h.prestart.as_ref()),
+		Poststart: hook_oci_to_grpc(h.poststart.as_ref()),
+		Poststop: hook_oci_to_grpc(h.poststop.as_ref()),
+		..Default::default()
+	}
 }
 
 use protocols::oci::{LinuxIDMapping as grpcLinuxIDMapping, LinuxResources as grpcLinuxResources, LinuxNamespace as grpcLinuxNamespace, LinuxDevice as grpcLinuxDevice, LinuxSeccomp as grpcLinuxSeccomp, LinuxIntelRdt as grpcLinuxIntelRdt};
@@ -204,6 +289,19 @@ fn idmaps_grpc_to_oci(ims: &[grpcLinuxIDMapping]) -> Vec<ociLinuxIDMapping> {
 	r
 }
 
+fn idmaps_oci_to_grpc(ims: &[ociLinuxIDMapping]) -> RepeatedField<grpcLinuxIDMapping> {
+	let mut r = Vec::new();
+	for im in ims.iter() {
+		r.push(grpcLinuxIDMapping {
+			HostID: im.host_id,
+			ContainerID: im.container_id,
+			Size: im.size,
+			..Default::default()
+		});
+	}
+	RepeatedField::from_vec(r)
+}
+
 use protocols::oci::{LinuxDeviceCgroup as grpcLinuxDeviceCgroup, LinuxMemory as grpcLinuxMemory, LinuxCPU as grpcLinxCPU, LinuxPids as grpcLinuxPids, LinuxBlockIO as grpcLinuxBlockIO, LinuxHugepageLimit as grpcLinuxHugepageLimit, LinuxNetwork as grpcLinuxNetwork, LinuxInterfacePriority as grpcLinuxInterfacePriority, LinuxWeightDevice as grpcLinuxWeightDevice, LinuxThrottleDevice as grpcLinuxThrottleDevice};
 use oci::{LinuxDeviceCgroup as ociLinuxDeviceCgroup, LinuxMemory as ociLinuxMemory, LinuxCPU as ociLinuxCPU, LinuxPids as ociLinuxPids, LinuxBlockIO as ociLinuxBlockIO, LinuxHugepageLimit as ociLinuxHugepageLimit, LinuxNetwork as ociLinuxNetwork, LinuxInterfacePriority as ociLinuxInterfacePriority, LinuxWeightDevice as ociLinuxWeightDevice, LinuxThrottleDevice as ociLinuxThrottleDevice, LinuxBlockIODevice as ociLinuxBlockIODevice};
 
@@ -370,6 +468,148 @@ fn resources_grpc_to_oci(res: &grpcLinuxResources) -> ociLinuxResources {
 	}
 }
 
+fn throttle_devices_oci_to_grpc(tds: &[ociLinuxThrottleDevice]) -> RepeatedField<grpcLinuxThrottleDevice> {
+	let mut r = Vec::new();
+	for td in tds.iter() {
+		r.push(grpcLinuxThrottleDevice {
+			Major: td.blk.major,
+			Minor: td.blk.minor,
+			Rate: td.rate,
+			..Default::default()
+		});
+	}
+	RepeatedField::from_vec(r)
+}
+
+fn blockio_oci_to_grpc(blk: &ociLinuxBlockIO) -> grpcLinuxBlockIO {
+	let weight_device = {
+		let mut r = Vec::new();
+		for wd in blk.weight_device.iter() {
+			r.push(grpcLinuxWeightDevice {
+				Major: wd.blk.major,
+				Minor: wd.blk.minor,
+				Weight: wd.weight.unwrap_or(0) as u32,
+				LeafWeight: wd.leaf_weight.unwrap_or(0) as u32,
+				..Default::default()
+			});
+		}
+		r
+	};
+
+	grpcLinuxBlockIO {
+		Weight: blk.weight.unwrap_or(0) as u32,
+		LeafWeight: blk.leaf_weight.unwrap_or(0) as u32,
+		WeightDevice: RepeatedField::from_vec(weight_device),
+		ThrottleReadBpsDevice: throttle_devices_oci_to_grpc(blk.throttle_read_bps_device.as_ref()),
+		ThrottleWriteBpsDevice: throttle_devices_oci_to_grpc(blk.throttle_write_bps_device.as_ref()),
+		ThrottleReadIOPSDevice: throttle_devices_oci_to_grpc(blk.throttle_read_iops_device.as_ref()),
+		ThrottleWriteIOPSDevice: throttle_devices_oci_to_grpc(blk.throttle_write_iops_device.as_ref()),
+		..Default::default()
+	}
+}
+
+fn resources_oci_to_grpc(res: &ociLinuxResources) -> grpcLinuxResources {
+	let devices = {
+		let mut d = Vec::new();
+		for dev in res.devices.iter() {
+			d.push(grpcLinuxDeviceCgroup {
+				Allow: dev.allow,
+				Type: dev.r#type.clone(),
+				// -1 stands for all, like in grpc_to_oci()
+				Major: dev.major.unwrap_or(-1),
+				Minor: dev.minor.unwrap_or(-1),
+				Access: dev.access.clone(),
+				..Default::default()
+			});
+		}
+		d
+	};
+
+	let memory = match res.memory.as_ref() {
+		Some(mem) => SingularPtrField::some(grpcLinuxMemory {
+			Limit: mem.limit.unwrap_or(0),
+			Reservation: mem.reservation.unwrap_or(0),
+			Swap: mem.swap.unwrap_or(0),
+			Kernel: mem.kernel.unwrap_or(0),
+			KernelTCP: mem.kernel_tcp.unwrap_or(0),
+			Swappiness: mem.swapiness.unwrap_or(0) as u64,
+			DisableOOMKiller: mem.disable_oom_killer.unwrap_or(false),
+			..Default::default()
+		}),
+		None => SingularPtrField::none(),
+	};
+
+	let cpu = match res.cpu.as_ref() {
+		Some(c) => SingularPtrField::some(grpcLinxCPU {
+			Shares: c.shares.unwrap_or(0),
+			Quota: c.quota.unwrap_or(0),
+			Period: c.period.unwrap_or(0),
+			RealtimeRuntime: c.realtime_runtime.unwrap_or(0),
+			RealtimePeriod: c.realtime_period.unwrap_or(0),
+			Cpus: c.cpus.clone(),
+			Mems: c.mems.clone(),
+			..Default::default()
+		}),
+		None => SingularPtrField::none(),
+	};
+
+	let pids = match res.pids.as_ref() {
+		Some(p) => SingularPtrField::some(grpcLinuxPids {
+			Limit: p.limit,
+			..Default::default()
+		}),
+		None => SingularPtrField::none(),
+	};
+
+	let block_io = match res.block_io.as_ref() {
+		Some(blk) => SingularPtrField::some(blockio_oci_to_grpc(blk)),
+		None => SingularPtrField::none(),
+	};
+
+	let hugepage_limits = {
+		let mut r = Vec::new();
+		for hl in res.hugepage_limits.iter() {
+			r.push(grpcLinuxHugepageLimit {
+				Pagesize: hl.page_size.clone(),
+				Limit: hl.limit,
+				..Default::default()
+			});
+		}
+		r
+	};
+
+	let network = match res.network.as_ref() {
+		Some(net) => {
+			let mut priorities = Vec::new();
+			for pr in net.priorities.iter() {
+				priorities.push(grpcLinuxInterfacePriority {
+					Name: pr.name.clone(),
+					Priority: pr.priority,
+					..Default::default()
+				});
+			}
+
+			SingularPtrField::some(grpcLinuxNetwork {
+				ClassID: net.class_id.unwrap_or(0),
+				Priorities: RepeatedField::from_vec(priorities),
+				..Default::default()
+			})
+		}
+		None => SingularPtrField::none(),
+	};
+
+	grpcLinuxResources {
+		Devices: RepeatedField::from_vec(devices),
+		Memory: memory,
+		CPU: cpu,
+		Pids: pids,
+		BlockIO: block_io,
+		HugepageLimits: RepeatedField::from_vec(hugepage_limits),
+		Network: network,
+		..Default::default()
+	}
+}
+
 use protocols::oci::{LinuxSyscall as grpcLinuxSyscall, LinuxSeccompArg as grpcLinuxSeccompArg};
 use oci::{LinuxSyscall as ociLinuxSyscall, LinuxSeccompArg as ociLinuxSeccompArg};
 
@@ -477,8 +717,107 @@ fn linux_grpc_to_oci(l: &grpcLinux) -> ociLinux {
 	}
 }
 
+fn seccomp_oci_to_grpc(sec: &ociLinuxSeccomp) -> grpcLinuxSeccomp {
+	let syscalls = {
+		let mut r = Vec::new();
+
+		for sys in sec.syscalls.iter() {
+			let mut args = Vec::new();
+
+			for arg in sys.args.iter() {
+				args.push(grpcLinuxSeccompArg {
+					Index: arg.index as u64,
+					Value: arg.value,
+					ValueTwo: arg.value_two,
+					Op: arg.op.clone(),
+					..Default::default()
+				});
+			}
+
+			r.push(grpcLinuxSyscall {
+				Names: RepeatedField::from_vec(sys.names.clone()),
+				Action: sys.action.clone(),
+				Args: RepeatedField::from_vec(args),
+				..Default::default()
+			});
+		}
+		r
+	};
+
+	grpcLinuxSeccomp {
+		DefaultAction: sec.default_action.clone(),
+		Architectures: RepeatedField::from_vec(sec.architectures.clone()),
+		Syscalls: RepeatedField::from_vec(syscalls),
+		..Default::default()
+	}
+}
+
 fn linux_oci_to_grpc(l: &ociLinux) -> grpcLinux {
-	grpcLinux::default()
+	let namespaces = {
+		let mut r = Vec::new();
+
+		for ns in l.namespaces.iter() {
+			r.push(grpcLinuxNamespace {
+				Type: ns.r#type.clone(),
+				Path: ns.path.clone(),
+				..Default::default()
+			});
+		}
+		r
+	};
+
+	let devices = {
+		let mut r = Vec::new();
+
+		for d in l.devices.iter() {
+			r.push(grpcLinuxDevice {
+				Path: d.path.clone(),
+				Type: d.r#type.clone(),
+				Major: d.major,
+				Minor: d.minor,
+				FileMode: d.file_mode.unwrap_or(0),
+				UID: d.uid.unwrap_or(0),
+				GID: d.gid.unwrap_or(0),
+				..Default::default()
+			});
+		}
+		r
+	};
+
+	let resources = match l.resources.as_ref() {
+		Some(res) => SingularPtrField::some(resources_oci_to_grpc(res)),
+		None => SingularPtrField::none(),
+	};
+
+	let seccomp = match l.seccomp.as_ref() {
+		Some(sec) => SingularPtrField::some(seccomp_oci_to_grpc(sec)),
+		None => SingularPtrField::none(),
+	};
+
+	let intel_rdt = match l.intel_rdt.as_ref() {
+		Some(rdt) => SingularPtrField::some(grpcLinuxIntelRdt {
+			L3CacheSchema: rdt.l3_cache_schema.clone(),
+			..Default::default()
+		}),
+		None => SingularPtrField::none(),
+	};
+
+	grpcLinux {
+		UIDMappings: idmaps_oci_to_grpc(l.uid_mappings.as_ref()),
+		GIDMappings: idmaps_oci_to_grpc(l.gid_mappings.as_ref()),
+		Sysctl: l.sysctl.clone(),
+		Resources: resources,
+		CgroupsPath: l.cgroups_path.clone(),
+		Namespaces: RepeatedField::from_vec(namespaces),
+		Devices: RepeatedField::from_vec(devices),
+		Seccomp: seccomp,
+		RootfsPropagation: l.rootfs_propagation.clone(),
+		MaskedPaths: RepeatedField::from_vec(l.masked_paths.clone()),
+		ReadonlyPaths: RepeatedField::from_vec(l.readonly_paths.clone()),
+		MountLabel: l.mount_label.clone(),
+		IntelRdt: intel_rdt,
+		..Default::default()
+	}
 }
 
 pub fn grpc_to_oci(grpc: &grpcSpec) -> ociSpec {
@@ -534,15 +873,96 @@ pub fn grpc_to_oci(grpc: &grpcSpec) -> ociSpec {
 	}
 }
 
+// the solaris, windows and vm parts have no use in the guest
 pub fn oci_to_grpc(oci: &ociSpec) -> grpcSpec {
-	unsafe { mem::zeroed::<grpcSpec>() }
+	let process = match oci.process.as_ref() {
+		Some(p) => SingularPtrField::some(process_oci_to_grpc(p)),
+		None => SingularPtrField::none(),
+	};
+
+	let root = match oci.root.as_ref() {
+		Some(r) => SingularPtrField::some(root_oci_to_grpc(r)),
+		None => SingularPtrField::none(),
+	};
+
+	let mounts = {
+		let mut r = Vec::new();
+		for m in oci.mounts.iter() {
+			r.push(mount_oci_to_grpc(m));
+		}
+		r
+	};
+
+	let hooks = match oci.hooks.as_ref() {
+		Some(h) => SingularPtrField::some(hooks_oci_to_grpc(h)),
+		None => SingularPtrField::none(),
+	};
+
+	let linux = match oci.linux.as_ref() {
+		Some(l) => SingularPtrField::some(linux_oci_to_grpc(l)),
+		None => SingularPtrField::none(),
+	};
+
+	grpcSpec {
+		Version: oci.version.clone(),
+		Process: process,
+		Root: root,
+		Hostname: oci.hostname.clone(),
+		Mounts: RepeatedField::from_vec(mounts),
+		Hooks: hooks,
+		Annotations: oci.annotations.clone(),
+		Linux: linux,
+		..Default::default()
+	}
 }
 
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_oci_to_grpc() {
+        let spec: ociSpec = serde_json::from_str(r#"{
+            "ociVersion": "1.0.1",
+            "process": {
+                "terminal": true,
+                "user": {"uid": 1000, "gid": 1000},
+                "args": ["sh"],
+                "cwd": "/"
+            },
+            "root": {"path": "rootfs", "readonly": true},
+            "hostname": "box",
+            "mounts": [{"destination": "/proc", "type": "proc", "source": "proc"}],
+            "linux": {
+                "namespaces": [{"type": "pid"}, {"type": "mount"}],
+                "resources": {
+                    "devices": [{"allow": false, "access": "rwm"}],
+                    "memory": {"limit": 1048576}
+                },
+                "maskedPaths": ["/proc/kcore"]
+            }
+        }"#).unwrap();
+
+        let grpc = oci_to_grpc(&spec);
+        assert_eq!(grpc.Hostname, "box");
+        assert_eq!(grpc.Process.as_ref().unwrap().User.as_ref().unwrap().UID, 1000);
+        assert_eq!(grpc.Root.as_ref().unwrap().Path, "rootfs");
+        assert_eq!(grpc.Mounts[0].field_type, "proc");
+        let linux = grpc.Linux.as_ref().unwrap();
+        assert_eq!(linux.Namespaces.len(), 2);
+        let res = linux.Resources.as_ref().unwrap();
+        assert_eq!(res.Devices[0].Major, -1);
+        assert_eq!(res.Memory.as_ref().unwrap().Limit, 1048576);
+
+        // and back
+        let oci = grpc_to_oci(&grpc);
+        assert_eq!(oci.process.unwrap().args, vec!["sh".to_string()]);
+        assert_eq!(oci.linux.unwrap().masked_paths, vec!["/proc/kcore".to_string()]);
+    }
 }
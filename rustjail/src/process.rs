@@ -60,6 +60,7 @@ pub struct Process {
 	pub pid: pid_t,
 
 	pub exit_code: i32,
+	pub core_dumped: bool,
 	pub oci: OCIProcess,
 }
 
@@ -112,6 +113,7 @@ impl Process {
 			init,
 			pid: -1,
 			exit_code: 0,
+			core_dumped: false,
 			oci: ocip.clone(),
 		};
 
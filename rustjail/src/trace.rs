@@ -0,0 +1,161 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// span recording shared by the agent and rustjail. spans are only
+// kept while tracing is enabled, shipping them out is up to the agent.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ID_STATE: AtomicU64 = AtomicU64::new(0);
+
+lazy_static! {
+	// every span of a tracing session belongs to the same trace
+	static ref TRACE_ID: Mutex<(u64, u64)> = Mutex::new((0, 0));
+	static ref FINISHED: Mutex<Vec<SpanData>> = Mutex::new(Vec::new());
+}
+
+thread_local! {
+	// spans opened on this thread, innermost last
+	static CURRENT: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SpanData {
+	pub trace_id_high: u64,
+	pub trace_id_low: u64,
+	pub span_id: u64,
+	// 0 for the root spans of a trace
+	pub parent_id: u64,
+	pub operation: String,
+	// micro seconds since the epoch
+	pub start: u64,
+	pub duration: u64,
+	pub tags: Vec<(String, String)>,
+}
+
+fn now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs() * 1_000_000 + d.subsec_micros() as u64)
+		.unwrap_or(0)
+}
+
+// xorshift over a time and pid seeded state, ids only need to be
+// unique within the trace
+fn new_id() -> u64 {
+	let mut x = ID_STATE.load(Ordering::SeqCst);
+	loop {
+		let mut n = if x == 0 {
+			now() ^ ((std::process::id() as u64) << 32) | 1
+		} else {
+			x
+		};
+		n ^= n << 13;
+		n ^= n >> 7;
+		n ^= n << 17;
+
+		match ID_STATE.compare_exchange(x, n, Ordering::SeqCst, Ordering::SeqCst) {
+			Ok(_) => return n,
+			Err(v) => x = v,
+		}
+	}
+}
+
+pub fn enable() {
+	*TRACE_ID.lock().unwrap() = (new_id(), new_id());
+	ENABLED.store(true, Ordering::SeqCst);
+}
+
+pub fn disable() {
+	ENABLED.store(false, Ordering::SeqCst);
+}
+
+pub fn enabled() -> bool {
+	ENABLED.load(Ordering::SeqCst)
+}
+
+// spans finished since the last call
+pub fn take_finished() -> Vec<SpanData> {
+	FINISHED.lock().unwrap().drain(..).collect()
+}
+
+// a span is closed when dropped
+pub struct Span {
+	data: Option<SpanData>,
+	// a forked child must not touch the locks of its parent
+	pid: u32,
+}
+
+impl Span {
+	pub fn tag(&mut self, key: &str, value: &str) {
+		if let Some(d) = self.data.as_mut() {
+			d.tags.push((key.to_string(), value.to_string()));
+		}
+	}
+}
+
+impl Drop for Span {
+	fn drop(&mut self) {
+		let mut d = match self.data.take() {
+			Some(v) => v,
+			None => return,
+		};
+
+		if std::process::id() != self.pid {
+			return;
+		}
+
+		CURRENT.with(|c| {
+			let mut c = c.borrow_mut();
+			if let Some(i) = c.iter().rposition(|id| *id == d.span_id) {
+				c.remove(i);
+			}
+		});
+
+		// spans still open when tracing stops are dropped
+		if !enabled() {
+			return;
+		}
+
+		d.duration = now().saturating_sub(d.start);
+		FINISHED.lock().unwrap().push(d);
+	}
+}
+
+// open a span, child of the innermost span open on this thread
+pub fn span(operation: &str) -> Span {
+	let pid = std::process::id();
+
+	if !enabled() {
+		return Span { data: None, pid };
+	}
+
+	let (high, low) = *TRACE_ID.lock().unwrap();
+	let span_id = new_id();
+	let parent_id = CURRENT.with(|c| {
+		let mut c = c.borrow_mut();
+		let parent = c.last().cloned().unwrap_or(0);
+		c.push(span_id);
+		parent
+	});
+
+	Span {
+		data: Some(SpanData {
+			trace_id_high: high,
+			trace_id_low: low,
+			span_id,
+			parent_id,
+			operation: operation.to_string(),
+			start: now(),
+			duration: 0,
+			tags: Vec::new(),
+		}),
+		pid,
+	}
+}
@@ -0,0 +1,315 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// User and group names of a container process, looked up in the
+// /etc/passwd and /etc/group of its rootfs the way runc does, before the
+// process is started.
+
+use protocols::oci::{Process as OCIProcess, User};
+use protobuf::SingularPtrField;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::errors::*;
+
+const PASSWD: &'static str = "/etc/passwd";
+const GROUP: &'static str = "/etc/group";
+const DEFAULT_HOME: &'static str = "/";
+// the limit of the kernel, MAXSYMLINKS
+const MAX_SYMLINKS: u32 = 40;
+
+#[derive(Debug, PartialEq)]
+struct Passwd {
+	name: String,
+	uid: u32,
+	gid: u32,
+	home: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Group {
+	name: String,
+	gid: u32,
+	members: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExecUser {
+	pub uid: u32,
+	pub gid: u32,
+	pub sgids: Vec<u32>,
+	pub home: String,
+}
+
+// root joined with path, every symlink on the way resolved as if root
+// were /, so that none leads out of it
+pub fn secure_join(root: &Path, path: &str) -> Result<PathBuf> {
+	let mut resolved = PathBuf::new();
+	let mut pending: Vec<String> = path.split('/').rev().map(|c| c.to_string()).collect();
+	let mut links = 0;
+
+	while let Some(c) = pending.pop() {
+		match c.as_str() {
+			"" | "." => continue,
+			".." => {
+				resolved.pop();
+				continue;
+			}
+			_ => {}
+		}
+
+		let next = resolved.join(c.as_str());
+		let full = root.join(&next);
+
+		match fs::symlink_metadata(&full) {
+			Ok(ref m) if m.file_type().is_symlink() => {
+				links += 1;
+				if links > MAX_SYMLINKS {
+					return Err(ErrorKind::InvalidArgument(format!(
+						"too many symlinks in {} below {}", path, root.display())).into());
+				}
+
+				let target = fs::read_link(&full)?;
+				let target = target.to_string_lossy();
+				if target.starts_with('/') {
+					resolved = PathBuf::new();
+				}
+				pending.extend(target.split('/').rev().map(|c| c.to_string()));
+			}
+			// what is not there is taken as it is
+			_ => resolved = next,
+		}
+	}
+
+	Ok(root.join(resolved))
+}
+
+fn read_in_root(root: &Path, path: &str) -> Result<Option<String>> {
+	match fs::read_to_string(secure_join(root, path)?) {
+		Ok(s) => Ok(Some(s)),
+		Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+		Err(e) => Err(e.into()),
+	}
+}
+
+// name:password:uid:gid:gecos:home:shell, broken lines are skipped
+fn parse_passwd(content: &str) -> Vec<Passwd> {
+	content.lines()
+		.filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+		.filter_map(|l| {
+			let f: Vec<&str> = l.split(':').collect();
+			if f.len() < 6 {
+				return None;
+			}
+
+			Some(Passwd {
+				name: f[0].to_string(),
+				uid: f[2].parse().ok()?,
+				gid: f[3].parse().ok()?,
+				home: f[5].to_string(),
+			})
+		})
+		.collect()
+}
+
+// name:password:gid:member,member
+fn parse_group(content: &str) -> Vec<Group> {
+	content.lines()
+		.filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+		.filter_map(|l| {
+			let f: Vec<&str> = l.split(':').collect();
+			if f.len() < 4 {
+				return None;
+			}
+
+			Some(Group {
+				name: f[0].to_string(),
+				gid: f[2].parse().ok()?,
+				members: f[3].split(',').filter(|m| !m.is_empty()).map(|m| m.to_string()).collect(),
+			})
+		})
+		.collect()
+}
+
+fn find_group(groups: &[Group], group: &str) -> Result<u32> {
+	if let Some(g) = groups.iter().find(|g| g.name == group) {
+		return Ok(g.gid);
+	}
+
+	group.parse::<u32>().map_err(|_| ErrorKind::InvalidArgument(format!(
+		"no group {} in the {} of the container", group, GROUP)).into())
+}
+
+// user and group are names or ids, user empty for default_uid and
+// default_gid. A user without a group gets its primary group and those it
+// is a member of; like the uid:gid runc passes, the ids of the spec come
+// with their group, only the home is looked up for them.
+pub fn get_exec_user(rootfs: &Path, user: &str, group: &str, additional: &[String],
+	default_uid: u32, default_gid: u32) -> Result<ExecUser> {
+	let passwd = parse_passwd(read_in_root(rootfs, PASSWD)?.unwrap_or_default().as_str());
+	let groups = parse_group(read_in_root(rootfs, GROUP)?.unwrap_or_default().as_str());
+
+	let mut u = ExecUser {
+		uid: default_uid,
+		gid: default_gid,
+		sgids: Vec::new(),
+		home: DEFAULT_HOME.to_string(),
+	};
+
+	let entry = if user.is_empty() {
+		passwd.iter().find(|p| p.uid == default_uid)
+	} else if let Ok(uid) = user.parse::<u32>() {
+		u.uid = uid;
+		passwd.iter().find(|p| p.uid == uid)
+	} else {
+		match passwd.iter().find(|p| p.name == user) {
+			Some(p) => Some(p),
+			None => return Err(ErrorKind::InvalidArgument(format!(
+				"no user {} in the {} of the container", user, PASSWD)).into()),
+		}
+	};
+
+	if let Some(p) = entry {
+		u.uid = p.uid;
+		u.home = p.home.clone();
+	}
+
+	if !group.is_empty() {
+		u.gid = find_group(&groups, group)?;
+	} else if let Some(p) = entry.filter(|_| !user.is_empty()) {
+		u.gid = p.gid;
+		u.sgids = groups.iter()
+			.filter(|g| g.members.iter().any(|m| *m == p.name))
+			.map(|g| g.gid)
+			.collect();
+	}
+
+	for g in additional.iter() {
+		let gid = find_group(&groups, g.as_str())?;
+		if !u.sgids.contains(&gid) {
+			u.sgids.push(gid);
+		}
+	}
+
+	Ok(u)
+}
+
+// fills in the ids of ocip from the names, the supplementary groups and
+// HOME when the environment has none
+pub fn setup_user(rootfs: &Path, ocip: &mut OCIProcess, user: &str, group: &str,
+	additional: &[String]) -> Result<()> {
+	let (uid, gid, mut gids) = match ocip.User.as_ref() {
+		Some(u) => (u.UID, u.GID, u.AdditionalGids.clone()),
+		None => (0, 0, Vec::new()),
+	};
+
+	let exec_user = get_exec_user(rootfs, user, group, additional, uid, gid)?;
+
+	for g in exec_user.sgids.iter() {
+		if !gids.contains(g) {
+			gids.push(*g);
+		}
+	}
+
+	let mut u = ocip.User.take().unwrap_or_else(User::new);
+	u.UID = exec_user.uid;
+	u.GID = exec_user.gid;
+	u.AdditionalGids = gids;
+	ocip.User = SingularPtrField::some(u);
+
+	if !ocip.Env.iter().any(|e| e.starts_with("HOME=")) {
+		ocip.Env.push(format!("HOME={}", exec_user.home));
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::os::unix::fs::symlink;
+
+	const PASSWD_CONTENT: &'static str = "root:x:0:0:root:/root:/bin/sh\n\
+		app:x:1000:1000:App:/home/app:/bin/sh\n\
+		broken:x:nan:1\n";
+	const GROUP_CONTENT: &'static str = "root:x:0:\n\
+		app:x:1000:\n\
+		staff:x:50:app,other\n\
+		wheel:x:10:root\n";
+
+	fn rootfs(name: &str) -> PathBuf {
+		let root = std::env::temp_dir().join(format!("rustjail-user-{}-{}", name, std::process::id()));
+		let _ = fs::remove_dir_all(&root);
+		fs::create_dir_all(root.join("etc")).unwrap();
+		root
+	}
+
+	#[test]
+	fn test_get_exec_user() {
+		let root = rootfs("exec");
+		fs::write(root.join("etc/passwd"), PASSWD_CONTENT).unwrap();
+		fs::write(root.join("etc/group"), GROUP_CONTENT).unwrap();
+
+		let u = get_exec_user(&root, "app", "", &[], 0, 0).unwrap();
+		assert_eq!(u, ExecUser { uid: 1000, gid: 1000, sgids: vec![50], home: "/home/app".to_string() });
+
+		let u = get_exec_user(&root, "app", "staff", &["wheel".to_string()], 0, 0).unwrap();
+		assert_eq!((u.uid, u.gid, u.sgids), (1000, 50, vec![10]));
+
+		// unknown ids are fine, unknown names are not
+		let u = get_exec_user(&root, "2000", "", &[], 0, 0).unwrap();
+		assert_eq!((u.uid, u.gid, u.home.as_str()), (2000, 0, "/"));
+		assert!(get_exec_user(&root, "nobody", "", &[], 0, 0).is_err());
+		assert!(get_exec_user(&root, "app", "nogroup", &[], 0, 0).is_err());
+
+		let u = get_exec_user(&root, "", "", &[], 0, 0).unwrap();
+		assert_eq!((u.uid, u.sgids, u.home.as_str()), (0, vec![], "/root"));
+
+		// runAsUser 1000 and runAsGroup 3000 stay as they are
+		let u = get_exec_user(&root, "", "", &[], 1000, 3000).unwrap();
+		assert_eq!(u, ExecUser { uid: 1000, gid: 3000, sgids: vec![], home: "/home/app".to_string() });
+
+		let mut ocip = OCIProcess::new();
+		let mut user = User::new();
+		user.UID = 1000;
+		user.GID = 3000;
+		ocip.User = SingularPtrField::some(user);
+		setup_user(&root, &mut ocip, "", "", &[]).unwrap();
+		let user = ocip.User.as_ref().unwrap();
+		assert_eq!((user.UID, user.GID, user.AdditionalGids.clone()), (1000, 3000, vec![]));
+
+		let mut ocip = OCIProcess::new();
+		ocip.Env.push("PATH=/bin".to_string());
+		setup_user(&root, &mut ocip, "app", "", &[]).unwrap();
+		let user = ocip.User.as_ref().unwrap();
+		assert_eq!((user.UID, user.GID, user.AdditionalGids.clone()), (1000, 1000, vec![50]));
+		assert!(ocip.Env.contains(&"HOME=/home/app".to_string()));
+
+		let _ = fs::remove_dir_all(&root);
+	}
+
+	#[test]
+	fn test_secure_join() {
+		let root = rootfs("join");
+		let outside = root.with_file_name(format!("rustjail-user-outside-{}", std::process::id()));
+		fs::write(&outside, "root:x:0:0::/:/bin/sh\n").unwrap();
+
+		symlink("/etc/shadow", root.join("etc/passwd")).unwrap();
+		symlink("../../../..", root.join("etc/up")).unwrap();
+		symlink(&outside, root.join("etc/group")).unwrap();
+
+		assert_eq!(secure_join(&root, "/etc/passwd").unwrap(), root.join("etc/shadow"));
+		assert_eq!(secure_join(&root, "/etc/up/etc/passwd").unwrap(), root.join("etc/shadow"));
+		assert!(secure_join(&root, "/etc/group").unwrap().starts_with(&root));
+
+		// none of it reads the files outside
+		let u = get_exec_user(&root, "", "", &[], 0, 0).unwrap();
+		assert_eq!(u.home, "/");
+
+		let _ = fs::remove_dir_all(&root);
+		let _ = fs::remove_file(&outside);
+	}
+}
@@ -0,0 +1,265 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Per process output capture. The stdout and stderr pipes (or the
+// terminal) of every process are drained as soon as the process starts,
+// so a slow host never blocks the container, and the output is kept in
+// a bounded ring buffer that readers can replay from any offset. The
+// capture goes once the process is waited and its output read to the end.
+
+use nix::errno::Errno;
+use nix::fcntl::{self, FcntlArg};
+use nix::unistd;
+use rustjail::errors::*;
+use rustjail::process::Process;
+use std::collections::VecDeque;
+use std::os::unix::io::RawFd;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+pub const DEFAULT_BUFFER_SIZE: usize = 256 * 1024;
+// for each of stdout and stderr, the host cannot ask for more
+pub const MAX_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+const READ_BUF_SIZE: usize = 32 * 1024;
+
+#[derive(Debug)]
+struct RingBuffer {
+	data: VecDeque<u8>,
+	size: usize,
+	// offset of the first byte still in data
+	start: u64,
+	eof: bool,
+	// a reader got to the end after eof
+	consumed: bool,
+}
+
+impl RingBuffer {
+	fn write(&mut self, buf: &[u8]) {
+		let buf = if buf.len() > self.size {
+			let skip = buf.len() - self.size;
+			self.start += (self.data.len() + skip) as u64;
+			self.data.clear();
+			&buf[skip..]
+		} else {
+			buf
+		};
+
+		let over = (self.data.len() + buf.len()).saturating_sub(self.size);
+		self.data.drain(..over);
+		self.start += over as u64;
+		self.data.extend(buf);
+	}
+
+	fn end(&self) -> u64 {
+		self.start + self.data.len() as u64
+	}
+}
+
+#[derive(Debug, Default)]
+pub struct Chunk {
+	pub data: Vec<u8>,
+	pub offset: u64,
+	pub dropped: u64,
+	pub eof: bool,
+}
+
+#[derive(Debug)]
+pub struct OutputStream {
+	ring: Mutex<RingBuffer>,
+	cond: Condvar,
+}
+
+impl OutputStream {
+	fn new(size: usize) -> OutputStream {
+		OutputStream {
+			// grows with the output, up to size
+			ring: Mutex::new(RingBuffer {
+				data: VecDeque::new(),
+				size,
+				start: 0,
+				eof: false,
+				consumed: false,
+			}),
+			cond: Condvar::new(),
+		}
+	}
+
+	fn write(&self, buf: &[u8]) {
+		self.ring.lock().unwrap().write(buf);
+		self.cond.notify_all();
+	}
+
+	fn close(&self) {
+		self.ring.lock().unwrap().eof = true;
+		self.cond.notify_all();
+	}
+
+	// read at most len bytes starting at offset, blocks until some
+	// output past offset is available or the stream is closed
+	pub fn read(&self, offset: u64, len: usize) -> Chunk {
+		let mut ring = self.ring.lock().unwrap();
+
+		while offset >= ring.end() && !ring.eof {
+			ring = self.cond.wait(ring).unwrap();
+		}
+
+		let from = if offset < ring.start { ring.start } else { offset };
+		let skip = (from - ring.start) as usize;
+		let n = len.min(ring.data.len().saturating_sub(skip));
+		let eof = ring.eof && from + n as u64 >= ring.end();
+		ring.consumed |= eof;
+
+		Chunk {
+			data: ring.data.iter().skip(skip).take(n).cloned().collect(),
+			offset: from,
+			dropped: from - offset.min(from),
+			eof,
+		}
+	}
+
+	// closed, and nothing in it anybody still has to read
+	fn done(&self) -> bool {
+		let ring = self.ring.lock().unwrap();
+		ring.eof && (ring.consumed || ring.end() == 0)
+	}
+}
+
+#[derive(Debug)]
+pub struct Capture {
+	pub stdout: Arc<OutputStream>,
+	pub stderr: Arc<OutputStream>,
+	// position of the legacy ReadStdout/ReadStderr readers, held across
+	// a read so that concurrent readers never get the same bytes
+	stdout_cursor: Mutex<u64>,
+	stderr_cursor: Mutex<u64>,
+	waited: AtomicBool,
+}
+
+impl Capture {
+	pub fn stream(&self, stderr: bool) -> Arc<OutputStream> {
+		if stderr {
+			Arc::clone(&self.stderr)
+		} else {
+			Arc::clone(&self.stdout)
+		}
+	}
+
+	pub fn cursor(&self, stderr: bool) -> &Mutex<u64> {
+		if stderr {
+			&self.stderr_cursor
+		} else {
+			&self.stdout_cursor
+		}
+	}
+
+	pub fn set_waited(&self) {
+		self.waited.store(true, Ordering::SeqCst);
+	}
+
+	// nobody needs it any more
+	pub fn done(&self) -> bool {
+		self.waited.load(Ordering::SeqCst) && self.stdout.done() && self.stderr.done()
+	}
+}
+
+// the ring buffer size the host asked for, 0 means DEFAULT_BUFFER_SIZE
+pub fn buffer_size(size: u32) -> Result<usize> {
+	match size as usize {
+		0 => Ok(DEFAULT_BUFFER_SIZE),
+		s if s > MAX_BUFFER_SIZE => Err(ErrorKind::InvalidArgument(format!(
+			"output buffer size {} is over {}", s, MAX_BUFFER_SIZE)).into()),
+		s => Ok(s),
+	}
+}
+
+fn drain(fd: RawFd, out: Arc<OutputStream>) {
+	thread::spawn(move || {
+		let mut buf = vec![0u8; READ_BUF_SIZE];
+
+		loop {
+			match unistd::read(fd, buf.as_mut_slice()) {
+				Ok(0) => break,
+				Ok(n) => out.write(&buf[..n]),
+				Err(nix::Error::Sys(Errno::EINTR)) => continue,
+				// EIO once the slave side of a terminal is closed
+				Err(_) => break,
+			}
+		}
+
+		out.close();
+		let _ = unistd::close(fd);
+	});
+}
+
+// start draining the output of a started process, size is the
+// capacity of each ring buffer, from buffer_size()
+pub fn start(p: &Process, size: usize) -> Result<Capture> {
+	let c = Capture {
+		stdout: Arc::new(OutputStream::new(size)),
+		stderr: Arc::new(OutputStream::new(size)),
+		stdout_cursor: Mutex::new(0),
+		stderr_cursor: Mutex::new(0),
+		waited: AtomicBool::new(false),
+	};
+
+	// the drainers own a copy of the fds, the process ones may be
+	// closed by CloseStdin or WaitProcess at any time
+	let (stdout, stderr) = if p.term_master.is_some() {
+		(p.term_master, None)
+	} else {
+		(p.parent_stdout, p.parent_stderr)
+	};
+
+	match stdout {
+		Some(fd) => drain(fcntl::fcntl(fd, FcntlArg::F_DUPFD_CLOEXEC(0))?, Arc::clone(&c.stdout)),
+		None => c.stdout.close(),
+	}
+
+	match stderr {
+		Some(fd) => drain(fcntl::fcntl(fd, FcntlArg::F_DUPFD_CLOEXEC(0))?, Arc::clone(&c.stderr)),
+		None => c.stderr.close(),
+	}
+
+	Ok(c)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::capture::{buffer_size, OutputStream, DEFAULT_BUFFER_SIZE, MAX_BUFFER_SIZE};
+
+	#[test]
+	fn test_ring_buffer() {
+		let out = OutputStream::new(4);
+		out.write(b"abc");
+		out.write(b"def");
+
+		let c = out.read(0, 10);
+		assert_eq!(c.data, b"cdef");
+		assert_eq!(c.offset, 2);
+		assert_eq!(c.dropped, 2);
+
+		out.write(b"0123456789");
+		out.close();
+		let c = out.read(5, 2);
+		assert_eq!(c.data, b"67");
+		assert_eq!(c.dropped, 7);
+		assert!(!c.eof);
+
+		assert!(!out.done());
+		let c = out.read(14, 10);
+		assert_eq!(c.data, b"89");
+		assert!(c.eof);
+		assert!(out.done());
+	}
+
+	#[test]
+	fn test_buffer_size() {
+		assert_eq!(buffer_size(0).unwrap(), DEFAULT_BUFFER_SIZE);
+		assert_eq!(buffer_size(4096).unwrap(), 4096);
+		assert_eq!(buffer_size(MAX_BUFFER_SIZE as u32).unwrap(), MAX_BUFFER_SIZE);
+		assert!(buffer_size(0xFFFFFFFF).is_err());
+	}
+}
@@ -0,0 +1,434 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Agent configuration. The settings come from, lowest precedence first:
+// the built-in defaults, a JSON config file, the agent.* parameters of
+// the kernel command line and, unless running as init, the command line
+// flags. The same keys are used everywhere:
+//
+//   agent.log=debug                       --log debug
+//   agent.server_addr=vsock://-1:1024     --server-addr vsock://-1:1024
+//                    serial:///dev/virtio-ports/agent.channel.0
+//                    unix:///run/kata-agent.sock, tcp://127.0.0.1:5000
+//   agent.debug_console                   --debug-console
+//   agent.debug_console_vport=1026        --debug-console-vport 1026
+//   agent.hotplug_timeout=3               --hotplug-timeout 3
+//   agent.container_base=/run/agent       --container-base /run/agent
+//   agent.file_paths=/var/log:/etc        --file-paths /var/log:/etc
+//   agent.trace                           --trace
+//   agent.trace_endpoint=vsock://2:10240  --trace-endpoint vsock://2:10240
+//   agent.config_file=/path/agent.json    --config-file /path/agent.json
+
+use log::LevelFilter;
+use rustjail::errors::*;
+use serde_json::Value;
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::Duration;
+
+const KERNEL_CMDLINE: &'static str = "/proc/cmdline";
+const CMDLINE_PREFIX: &'static str = "agent.";
+const DEFAULT_CONFIG_FILE: &'static str = "/etc/kata-containers/agent.json";
+
+const DEFAULT_SERVER_ADDR: &'static str = "vsock://-1:1024";
+const DEFAULT_HOTPLUG_TIMEOUT_SECS: u64 = 3;
+const DEFAULT_CONTAINER_BASE: &'static str = "/run/agent";
+
+const LOG_KEY: &'static str = "log";
+const SERVER_ADDR_KEY: &'static str = "server_addr";
+const DEBUG_CONSOLE_KEY: &'static str = "debug_console";
+const DEBUG_CONSOLE_VPORT_KEY: &'static str = "debug_console_vport";
+const HOTPLUG_TIMEOUT_KEY: &'static str = "hotplug_timeout";
+const CONTAINER_BASE_KEY: &'static str = "container_base";
+const FILE_PATHS_KEY: &'static str = "file_paths";
+const TRACE_KEY: &'static str = "trace";
+const TRACE_ENDPOINT_KEY: &'static str = "trace_endpoint";
+const CONFIG_FILE_KEY: &'static str = "config_file";
+
+// a bare key turns these on
+const BOOL_KEYS: &'static [&'static str] = &[DEBUG_CONSOLE_KEY, TRACE_KEY];
+
+// where the agent API is served
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerAddr {
+    // the host and port the way grpc binds them
+    Vsock(String, u16),
+    // a virtio-serial port, multiplexed with yamux
+    Serial(String),
+    // the agent runs on a plain host, for testing
+    Unix(String),
+    // loopback only, the API is not authenticated
+    Tcp(String, u16),
+}
+
+impl ServerAddr {
+    // outside a VM there is neither an init to play nor any
+    // hotplugged hardware to wait for
+    pub fn is_local(&self) -> bool {
+        match self {
+            ServerAddr::Unix(_) | ServerAddr::Tcp(_, _) => true,
+            _ => false,
+        }
+    }
+}
+
+lazy_static! {
+    pub static ref AGENT_CONFIG: RwLock<AgentConfig> = RwLock::new(AgentConfig::default());
+}
+
+pub fn local_mode() -> bool {
+    AGENT_CONFIG.read().unwrap().server().is_local()
+}
+
+#[derive(Debug, Clone)]
+pub struct AgentConfig {
+    pub log_level: LevelFilter,
+    pub server_addr: String,
+    pub debug_console: bool,
+    // 0 for the serial console
+    pub debug_console_vport: u32,
+    pub hotplug_timeout: Duration,
+    pub container_base: String,
+    // where CopyFile and ReadFile may go besides the container base
+    pub file_paths: Vec<String>,
+    pub trace: bool,
+    // None keeps the tracer default
+    pub trace_endpoint: Option<String>,
+}
+
+impl Default for AgentConfig {
+    fn default() -> AgentConfig {
+        AgentConfig {
+            log_level: LevelFilter::Info,
+            server_addr: DEFAULT_SERVER_ADDR.to_string(),
+            debug_console: false,
+            debug_console_vport: 0,
+            hotplug_timeout: Duration::from_secs(DEFAULT_HOTPLUG_TIMEOUT_SECS),
+            container_base: DEFAULT_CONTAINER_BASE.to_string(),
+            file_paths: Vec::new(),
+            trace: false,
+            trace_endpoint: None,
+        }
+    }
+}
+
+impl AgentConfig {
+    fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            LOG_KEY => self.log_level = parse_log_level(value)?,
+            SERVER_ADDR_KEY => {
+                parse_server_addr(value)?;
+                self.server_addr = value.to_string();
+            }
+            DEBUG_CONSOLE_KEY => self.debug_console = parse_bool(key, value)?,
+            DEBUG_CONSOLE_VPORT_KEY => {
+                self.debug_console_vport = value.parse::<u32>().map_err(|_| invalid(key, value))?;
+            }
+            HOTPLUG_TIMEOUT_KEY => {
+                let secs = value.parse::<u64>().map_err(|_| invalid(key, value))?;
+                self.hotplug_timeout = Duration::from_secs(secs);
+            }
+            CONTAINER_BASE_KEY => {
+                if !value.starts_with('/') {
+                    return Err(invalid(key, value));
+                }
+                self.container_base = value.to_string();
+            }
+            FILE_PATHS_KEY => {
+                let paths: Vec<String> = value.split(':')
+                    .filter(|p| !p.is_empty()).map(|p| p.to_string()).collect();
+                if paths.iter().any(|p| !p.starts_with('/')) {
+                    return Err(invalid(key, value));
+                }
+                self.file_paths = paths;
+            }
+            TRACE_KEY => self.trace = parse_bool(key, value)?,
+            TRACE_ENDPOINT_KEY => self.trace_endpoint = Some(value.to_string()),
+            // already handled by load()
+            CONFIG_FILE_KEY => {}
+            _ => {
+                return Err(ErrorKind::InvalidArgument(
+                    format!("unknown config key {}", key)).into())
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn server(&self) -> ServerAddr {
+        // validated when set
+        parse_server_addr(self.server_addr.as_str()).unwrap()
+    }
+
+    fn load_file(&mut self, path: &str) -> Result<()> {
+        let content = fs::read_to_string(path)
+            .chain_err(|| format!("cannot read config file {}", path))?;
+
+        let v: Value = serde_json::from_str(content.as_str())
+            .map_err(|e| ErrorKind::InvalidArgument(
+                format!("bad config file {}: {}", path, e)))?;
+
+        let map = match v.as_object() {
+            Some(m) => m,
+            None => return Err(ErrorKind::InvalidArgument(
+                format!("config file {} is not a JSON object", path)).into()),
+        };
+
+        for (key, value) in map.iter() {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                Value::Bool(_) | Value::Number(_) => value.to_string(),
+                _ => return Err(invalid(key, value.to_string().as_str())),
+            };
+
+            self.set(key.as_str(), value.as_str())
+                .chain_err(|| format!("bad config file {}", path))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid(key: &str, value: &str) -> Error {
+    ErrorKind::InvalidArgument(format!("invalid value {:?} for {}", value, key)).into()
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool> {
+    match value {
+        "true" | "1" | "yes" | "on" => Ok(true),
+        "false" | "0" | "no" | "off" => Ok(false),
+        _ => Err(invalid(key, value)),
+    }
+}
+
+fn parse_log_level(value: &str) -> Result<LevelFilter> {
+    match value {
+        "off" => Ok(LevelFilter::Off),
+        "error" => Ok(LevelFilter::Error),
+        "warn" | "warning" => Ok(LevelFilter::Warn),
+        "info" => Ok(LevelFilter::Info),
+        "debug" => Ok(LevelFilter::Debug),
+        "trace" => Ok(LevelFilter::Trace),
+        _ => Err(invalid(LOG_KEY, value)),
+    }
+}
+
+// vsock://<cid>:<port>, cid -1 being any, serial://<port path>,
+// unix://<socket path> or tcp://<loopback address>:<port>
+fn parse_server_addr(addr: &str) -> Result<ServerAddr> {
+    if addr.starts_with("vsock://") {
+        if let Some(i) = addr.rfind(':') {
+            let cid = &addr["vsock://".len()..i];
+            if let (Ok(_), Ok(port)) = (cid.parse::<i64>(), addr[i + 1..].parse::<u16>()) {
+                return Ok(ServerAddr::Vsock(addr[..i].to_string(), port));
+            }
+        }
+    } else if addr.starts_with("serial://") {
+        let path = &addr["serial://".len()..];
+        if path.starts_with('/') {
+            return Ok(ServerAddr::Serial(path.to_string()));
+        }
+    } else if addr.starts_with("unix://") {
+        let path = &addr["unix://".len()..];
+        if path.starts_with('/') {
+            return Ok(ServerAddr::Unix(path.to_string()));
+        }
+    } else if addr.starts_with("tcp://") {
+        let hostport = &addr["tcp://".len()..];
+        if let Some(i) = hostport.rfind(':') {
+            let host = &hostport[..i];
+            let loopback = host == "localhost" || host.trim_start_matches('[')
+                .trim_end_matches(']').parse::<IpAddr>().map_or(false, |ip| ip.is_loopback());
+            if let (true, Ok(port)) = (loopback, hostport[i + 1..].parse::<u16>()) {
+                return Ok(ServerAddr::Tcp(host.to_string(), port));
+            }
+        }
+    }
+
+    Err(invalid(SERVER_ADDR_KEY, addr))
+}
+
+// the agent.* parameters, a bare parameter is a boolean switch
+fn parse_cmdline(cmdline: &str) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+
+    for p in cmdline.split_whitespace() {
+        if !p.starts_with(CMDLINE_PREFIX) {
+            continue;
+        }
+
+        let p = &p[CMDLINE_PREFIX.len()..];
+        let (key, value) = match p.find('=') {
+            Some(i) => (&p[..i], &p[i + 1..]),
+            None => (p, "true"),
+        };
+
+        params.push((key.to_string(), value.to_string()));
+    }
+
+    params
+}
+
+// --some-key value, --some-key=value or --some-key for the booleans
+fn parse_flags(args: &[String]) -> Result<Vec<(String, String)>> {
+    let mut params = Vec::new();
+    let mut i = 0;
+
+    while i < args.len() {
+        let arg = args[i].as_str();
+        i += 1;
+
+        if !arg.starts_with("--") {
+            return Err(ErrorKind::InvalidArgument(
+                format!("unexpected argument {}", arg)).into());
+        }
+
+        let arg = &arg[2..];
+        let (key, value) = match arg.find('=') {
+            Some(n) => (arg[..n].replace('-', "_"), Some(arg[n + 1..].to_string())),
+            None => (arg.replace('-', "_"), None),
+        };
+
+        let value = match value {
+            Some(v) => v,
+            None if BOOL_KEYS.contains(&key.as_str()) => "true".to_string(),
+            None if i < args.len() => {
+                i += 1;
+                args[i - 1].clone()
+            }
+            None => return Err(ErrorKind::InvalidArgument(
+                format!("missing value for --{}", arg)).into()),
+        };
+
+        params.push((key, value));
+    }
+
+    Ok(params)
+}
+
+fn config_file(params: &[(String, String)]) -> Option<String> {
+    params.iter().rev()
+        .find(|(k, _)| k.as_str() == CONFIG_FILE_KEY)
+        .map(|(_, v)| v.clone())
+}
+
+// args are the command line arguments but the program name, they are
+// ignored when running as init since the kernel hands us whatever it
+// did not understand on its command line
+pub fn load(args: &[String], init: bool) -> Result<AgentConfig> {
+    let cmdline = parse_cmdline(
+        fs::read_to_string(KERNEL_CMDLINE).unwrap_or_default().as_str());
+    let flags = if init { Vec::new() } else { parse_flags(args)? };
+
+    let mut config = AgentConfig::default();
+
+    let file = config_file(&flags).or(config_file(&cmdline))
+        .or_else(|| Some(DEFAULT_CONFIG_FILE.to_string()).filter(|f| Path::new(f).exists()));
+
+    if let Some(f) = file {
+        let mut loaded = AgentConfig::default();
+        match loaded.load_file(f.as_str()) {
+            Ok(_) => config = loaded,
+            // as init, a missing or broken file must not keep the guest
+            // from booting either, the defaults are used instead
+            Err(e) if init => error!("ignoring config file {}: {}", f, e),
+            Err(e) => return Err(e),
+        }
+    }
+
+    // a typo on the kernel command line must not keep the guest from
+    // booting, the parameter is skipped
+    for (key, value) in cmdline.iter() {
+        if let Err(e) = config.set(key.as_str(), value.as_str()) {
+            warn!("ignoring kernel parameter {}{}={}: {}", CMDLINE_PREFIX, key, value, e);
+        }
+    }
+
+    for (key, value) in flags.iter() {
+        config.set(key.as_str(), value.as_str())?;
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cmdline() {
+        let params = parse_cmdline("console=hvc0 agent.log=debug quiet agent.debug_console \
+            agent.hotplug_timeout=10 systemd.unit=kata-containers.target");
+        assert_eq!(params, vec![
+            ("log".to_string(), "debug".to_string()),
+            ("debug_console".to_string(), "true".to_string()),
+            ("hotplug_timeout".to_string(), "10".to_string()),
+        ]);
+
+        let mut config = AgentConfig::default();
+        for (k, v) in params.iter() {
+            config.set(k, v).unwrap();
+        }
+        assert_eq!(config.log_level, LevelFilter::Debug);
+        assert!(config.debug_console);
+        assert_eq!(config.hotplug_timeout, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        let args: Vec<String> = vec!["--trace", "--server-addr", "vsock://3:2048", "--log=warn"]
+            .iter().map(|s| s.to_string()).collect();
+        let mut config = AgentConfig::default();
+        for (k, v) in parse_flags(&args).unwrap().iter() {
+            config.set(k, v).unwrap();
+        }
+        assert!(config.trace);
+        assert_eq!(config.log_level, LevelFilter::Warn);
+        assert_eq!(config.server(), ServerAddr::Vsock("vsock://3".to_string(), 2048));
+
+        assert!(parse_flags(&["--log".to_string()]).is_err());
+        assert!(parse_flags(&["log".to_string()]).is_err());
+        assert!(config.set("server_addr", "vsock://3").is_err());
+        assert!(config.set("no_such_key", "1").is_err());
+        assert_eq!(AgentConfig::default().server(), ServerAddr::Vsock("vsock://-1".to_string(), 1024));
+
+        config.set("server_addr", "serial:///dev/virtio-ports/agent.channel.0").unwrap();
+        assert_eq!(config.server(),
+            ServerAddr::Serial("/dev/virtio-ports/agent.channel.0".to_string()));
+        assert!(config.set("server_addr", "serial://agent.channel.0").is_err());
+
+        config.set("server_addr", "unix:///tmp/kata-agent.sock").unwrap();
+        assert_eq!(config.server(), ServerAddr::Unix("/tmp/kata-agent.sock".to_string()));
+        assert!(config.server().is_local());
+        config.set("server_addr", "tcp://[::1]:5000").unwrap();
+        assert_eq!(config.server(), ServerAddr::Tcp("[::1]".to_string(), 5000));
+        config.set("server_addr", "tcp://127.0.0.1:5000").unwrap();
+        assert!(config.set("server_addr", "tcp://0.0.0.0:5000").is_err());
+        assert!(config.set("server_addr", "tcp://127.0.0.1").is_err());
+
+        config.set("file_paths", "/var/log:/etc").unwrap();
+        assert_eq!(config.file_paths, vec!["/var/log", "/etc"]);
+        assert!(config.set("file_paths", "/var/log:log").is_err());
+    }
+
+    #[test]
+    fn test_load_file() {
+        let path = std::env::temp_dir().join(format!("agent.json.{}", std::process::id()));
+        let path = path.to_str().unwrap();
+        fs::write(path, r#"{"log": "error", "trace": true, "hotplug_timeout": 5}"#).unwrap();
+
+        let mut config = AgentConfig::default();
+        config.load_file(path).unwrap();
+        assert_eq!(config.log_level, LevelFilter::Error);
+        assert!(config.trace);
+        assert_eq!(config.hotplug_timeout, Duration::from_secs(5));
+
+        fs::write(path, r#"{"hotplug_timeout": "soon"}"#).unwrap();
+        assert!(config.load_file(path).is_err());
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Debug console, a shell on a pty proxied over a vsock port or the
+// serial console. It only exists when enabled by agent.debug_console.
+//
+// The pty is resized by sending the xterm "resize text area" sequence,
+// ESC [ 8 ; <rows> ; <cols> t, in the input stream, for instance with
+//
+//   socat -,raw,echo=0 vsock-connect:<cid>:<port> after a
+//   printf '\033[8;%d;%dt' $(tput lines) $(tput cols)
+
+use libc::{self, winsize, TIOCSWINSZ};
+use nix::errno::Errno;
+use nix::fcntl::{self, FcntlArg, FdFlag, OFlag};
+use nix::poll::{self, EventFlags, PollFd};
+use nix::pty;
+use nix::sys::signal::{self, Signal};
+use nix::sys::stat::Mode;
+use nix::sys::termios::{self, SetArg};
+use nix::unistd::{self, Pid};
+use rustjail::errors::*;
+use std::fs::{self, File};
+use std::io::{ErrorKind as IoErrorKind, Read, Write};
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use crate::vsock;
+
+const SHELLS: &'static [&'static str] = &["/bin/bash", "/bin/sh"];
+// the consoles the kernel writes to, /dev/console being the last one
+const ACTIVE_CONSOLES: &'static str = "/sys/class/tty/console/active";
+const DEFAULT_CONSOLE: &'static str = "/dev/console";
+const CONSOLE_BUF_SIZE: usize = 4096;
+const RETRY_INTERVAL_SECS: u64 = 1;
+
+const ESC: u8 = 0x1b;
+const RESIZE_PREFIX: &'static [u8] = b"\x1b[8;";
+// ESC [ 8 ; 65535 ; 65535 t
+const RESIZE_MAX_LEN: usize = 16;
+
+// vport 0 is the serial console
+pub fn start(vport: u32) {
+	thread::spawn(move || {
+		info!("debug console on {}", if vport == 0 {
+			"the serial console".to_string()
+		} else {
+			format!("vsock port {}", vport)
+		});
+
+		loop {
+			let r = if vport == 0 { serve_serial() } else { serve_vsock(vport) };
+
+			if let Err(e) = r {
+				warn!("debug console: {}", e);
+			}
+
+			thread::sleep(Duration::from_secs(RETRY_INTERVAL_SECS));
+		}
+	});
+}
+
+fn serve_vsock(port: u32) -> Result<()> {
+	let listener = vsock::listen(vsock::VMADDR_CID_ANY, port, 1)?;
+
+	// one session at a time
+	loop {
+		let conn = vsock::accept(&listener)?;
+		info!("debug console connected");
+
+		if let Err(e) = serve(&conn) {
+			warn!("debug console session: {}", e);
+		}
+	}
+}
+
+fn serve_serial() -> Result<()> {
+	let path = console_path();
+	let fd = fcntl::open(path.as_str(), OFlag::O_RDWR | OFlag::O_NOCTTY | OFlag::O_CLOEXEC,
+		Mode::empty())?;
+	let conn = unsafe { File::from_raw_fd(fd) };
+
+	// line editing and echo are left to the pty
+	let mut term = termios::tcgetattr(fd)?;
+	termios::cfmakeraw(&mut term);
+	termios::tcsetattr(fd, SetArg::TCSANOW, &term)?;
+
+	serve(&conn)
+}
+
+fn console_path() -> String {
+	let active = fs::read_to_string(ACTIVE_CONSOLES).unwrap_or_default();
+
+	match active.split_whitespace().last() {
+		Some(name) if Path::new("/dev").join(name).exists() => format!("/dev/{}", name),
+		_ => DEFAULT_CONSOLE.to_string(),
+	}
+}
+
+enum Exit {
+	Shell,
+	Peer,
+}
+
+// a new shell every time the previous one exits, until the peer goes away
+fn serve(conn: &File) -> Result<()> {
+	let mut filter = ResizeFilter::default();
+
+	loop {
+		let (master, pid) = spawn_shell()?;
+		let r = proxy(conn, &master, &mut filter);
+
+		// the shell may still hold the pty, nothing else must be left
+		// running behind a closed session
+		let _ = signal::kill(Pid::from_raw(-pid), Signal::SIGKILL);
+
+		match r? {
+			Exit::Shell => info!("debug console shell exited, respawning"),
+			Exit::Peer => return Ok(()),
+		}
+	}
+}
+
+fn find_shell() -> Result<&'static str> {
+	for s in SHELLS {
+		if Path::new(s).exists() {
+			return Ok(*s);
+		}
+	}
+
+	Err(ErrorKind::ErrorCode("no shell for the debug console".to_string()).into())
+}
+
+// the shell leads its own session on the pty slave. It is not waited
+// for here, the agent reaper collects it like any orphan.
+fn spawn_shell() -> Result<(File, i32)> {
+	let shell = find_shell()?;
+	let p = pty::openpty(None, None)?;
+	let master = unsafe { File::from_raw_fd(p.master) };
+	let slave = unsafe { File::from_raw_fd(p.slave) };
+	for fd in &[p.master, p.slave] {
+		fcntl::fcntl(*fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC))?;
+	}
+
+	let mut cmd = Command::new(shell);
+	cmd.stdin(Stdio::from(slave.try_clone()?))
+		.stdout(Stdio::from(slave.try_clone()?))
+		.stderr(Stdio::from(slave))
+		.env("TERM", "vt100");
+
+	unsafe {
+		cmd.pre_exec(|| {
+			unistd::setsid().map_err(|e| std::io::Error::from_raw_os_error(errno(e)))?;
+			if libc::ioctl(0, libc::TIOCSCTTY, 0) < 0 {
+				return Err(std::io::Error::last_os_error());
+			}
+			Ok(())
+		});
+	}
+
+	let child = cmd.spawn()?;
+	Ok((master, child.id() as i32))
+}
+
+fn errno(e: nix::Error) -> i32 {
+	match e {
+		nix::Error::Sys(errno) => errno as i32,
+		_ => libc::EINVAL,
+	}
+}
+
+fn proxy(conn: &File, master: &File, filter: &mut ResizeFilter) -> Result<Exit> {
+	let mut buf = [0u8; CONSOLE_BUF_SIZE];
+	let mut fds = [
+		PollFd::new(conn.as_raw_fd(), EventFlags::POLLIN),
+		PollFd::new(master.as_raw_fd(), EventFlags::POLLIN),
+	];
+
+	loop {
+		match poll::poll(&mut fds, -1) {
+			Err(nix::Error::Sys(Errno::EINTR)) => continue,
+			Err(e) => return Err(e.into()),
+			Ok(_) => {}
+		}
+
+		if ready(&fds[0]) {
+			let n = match read(conn, &mut buf)? {
+				0 => return Ok(Exit::Peer),
+				n => n,
+			};
+
+			let (input, sizes) = filter.filter(&buf[..n]);
+			for (rows, cols) in sizes {
+				resize(master.as_raw_fd(), rows, cols)?;
+			}
+			(&*master).write_all(input.as_slice())?;
+		}
+
+		if ready(&fds[1]) {
+			// EIO once the last slave fd is closed, the shell is gone
+			let n = match read(master, &mut buf) {
+				Ok(0) | Err(_) => return Ok(Exit::Shell),
+				Ok(n) => n,
+			};
+
+			if let Err(e) = (&*conn).write_all(&buf[..n]) {
+				info!("debug console peer went away: {}", e);
+				return Ok(Exit::Peer);
+			}
+		}
+	}
+}
+
+fn ready(fd: &PollFd) -> bool {
+	fd.revents().map_or(false, |r| !r.is_empty())
+}
+
+fn read(f: &File, buf: &mut [u8]) -> Result<usize> {
+	loop {
+		match (&*f).read(buf) {
+			Err(ref e) if e.kind() == IoErrorKind::Interrupted => continue,
+			r => return Ok(r?),
+		}
+	}
+}
+
+fn resize(fd: RawFd, rows: u16, cols: u16) -> Result<()> {
+	let win = winsize {
+		ws_row: rows,
+		ws_col: cols,
+		ws_xpixel: 0,
+		ws_ypixel: 0,
+	};
+
+	let ret = unsafe { libc::ioctl(fd, TIOCSWINSZ, &win) };
+	Errno::result(ret).map(drop)?;
+
+	Ok(())
+}
+
+enum Sequence {
+	Partial,
+	Resize(u16, u16),
+	NoMatch,
+}
+
+fn parse_resize(seq: &[u8]) -> Sequence {
+	if seq.len() <= RESIZE_PREFIX.len() {
+		return if RESIZE_PREFIX.starts_with(seq) { Sequence::Partial } else { Sequence::NoMatch };
+	}
+
+	if !seq.starts_with(RESIZE_PREFIX) || seq.len() > RESIZE_MAX_LEN {
+		return Sequence::NoMatch;
+	}
+
+	let args = &seq[RESIZE_PREFIX.len()..];
+	match args.last() {
+		Some(b't') => {}
+		Some(b) if b.is_ascii_digit() || *b == b';' => return Sequence::Partial,
+		_ => return Sequence::NoMatch,
+	}
+
+	let args = String::from_utf8_lossy(&args[..args.len() - 1]).to_string();
+	let v: Vec<&str> = args.split(';').collect();
+	if v.len() == 2 {
+		if let (Ok(rows), Ok(cols)) = (v[0].parse::<u16>(), v[1].parse::<u16>()) {
+			return Sequence::Resize(rows, cols);
+		}
+	}
+
+	Sequence::NoMatch
+}
+
+// takes the resize sequences out of the console input, they may be
+// split across reads
+#[derive(Default)]
+struct ResizeFilter {
+	pending: Vec<u8>,
+}
+
+impl ResizeFilter {
+	fn filter(&mut self, input: &[u8]) -> (Vec<u8>, Vec<(u16, u16)>) {
+		let mut out = Vec::with_capacity(input.len());
+		let mut sizes = Vec::new();
+
+		for &b in input {
+			if self.pending.is_empty() {
+				if b == ESC {
+					self.pending.push(b);
+				} else {
+					out.push(b);
+				}
+				continue;
+			}
+
+			self.pending.push(b);
+			match parse_resize(self.pending.as_slice()) {
+				Sequence::Partial => {}
+				Sequence::Resize(rows, cols) => {
+					sizes.push((rows, cols));
+					self.pending.clear();
+				}
+				Sequence::NoMatch => {
+					// not ours, pass it on
+					self.pending.pop();
+					out.extend(self.pending.drain(..));
+					if b == ESC {
+						self.pending.push(b);
+					} else {
+						out.push(b);
+					}
+				}
+			}
+		}
+
+		// a lone escape at the end of a read is a key press, holding it
+		// back until the next key would be noticed
+		if self.pending.as_slice() == &[ESC] {
+			out.push(ESC);
+			self.pending.clear();
+		}
+
+		(out, sizes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_resize_filter() {
+		let mut f = ResizeFilter::default();
+
+		let (out, sizes) = f.filter(b"ls\x1b[8;40;120tpwd\n");
+		assert_eq!(out, b"ls".iter().chain(b"pwd\n").cloned().collect::<Vec<u8>>());
+		assert_eq!(sizes, vec![(40, 120)]);
+
+		// split across reads
+		let (out, sizes) = f.filter(b"a\x1b[8;2");
+		assert_eq!(out, b"a".to_vec());
+		assert!(sizes.is_empty());
+		let (out, sizes) = f.filter(b"4;80t");
+		assert!(out.is_empty());
+		assert_eq!(sizes, vec![(24, 80)]);
+
+		// other sequences and escapes go through untouched
+		let (out, sizes) = f.filter(b"\x1b[A\x1b\x1b[8;x");
+		assert_eq!(out, b"\x1b[A\x1b\x1b[8;x".to_vec());
+		assert!(sizes.is_empty());
+		let (out, _) = f.filter(b"\x1b");
+		assert_eq!(out, b"\x1b".to_vec());
+	}
+}
@@ -4,19 +4,20 @@
 //
 
 use rustjail::errors::*;
+use rustjail::trace;
 use std::fs::{self, DirEntry, File, OpenOptions};
 use std::io::Write;
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
 use std::path::Path;
 use std::collections::HashMap;
 use std::os::unix::fs::MetadataExt;
 use libc::{major, minor, c_uint};
 
-use crate::mount::{TIMEOUT_HOTPLUG, DRIVERBLKTYPE, DRIVERMMIOBLKTYPE, DRIVERNVDIMMTYPE, DRIVERSCSITYPE};
+use crate::mount::{DRIVERBLKTYPE, DRIVERMMIOBLKTYPE, DRIVERNVDIMMTYPE, DRIVERSCSITYPE};
 use crate::sandbox::Sandbox;
 use crate::GLOBAL_DEVICE_WATCHER;
+use crate::config::{self, AGENT_CONFIG};
 use protocols::agent::Device;
 use protocols::oci::{Spec, LinuxDevice, LinuxDeviceCgroup, LinuxResources};
 
@@ -61,15 +62,26 @@ lazy_static! {
         m.insert(DRIVERBLKTYPE, blk);
     let virtiommio: DeviceHandler = virtiommio_blk_device_handler;
         m.insert(DRIVERMMIOBLKTYPE, virtiommio);
+    #[cfg(feature = "nvdimm")]
+    {
     let local: DeviceHandler = virtio_nvdimm_device_handler;
         m.insert(DRIVERNVDIMMTYPE, local);
+    }
+    #[cfg(feature = "scsi")]
+    {
     let scsi: DeviceHandler = virtio_scsi_device_handler;
         m.insert(DRIVERSCSITYPE, scsi);
+    }
         m
     };
 }
 
 pub fn rescan_pci_bus() -> Result<()> {
+    // the bus of the host is left alone
+    if config::local_mode() {
+        return Ok(());
+    }
+
     online_device(PCI_BUS_RESCAN_FILE)
 }
 
@@ -134,6 +146,14 @@ pub fn get_device_pci_address(pci_id: &str) -> Result<String> {
 }
 
 pub fn get_device_name(sandbox: Arc<Mutex<Sandbox>>, dev_addr: &str, ) -> Result<String> {
+    // no uevents are watched, the wait could only time out
+    if config::local_mode() {
+        return Err(ErrorKind::FailedPrecondition(format!(
+            "cannot wait for device {} when running outside a VM", dev_addr)).into());
+    }
+
+    let mut span = trace::span("wait_device");
+    span.tag("address", dev_addr);
     let mut dev_name: String = String::default();
     let (tx, rx) = mpsc::channel::<String>();
 
@@ -165,7 +185,8 @@ pub fn get_device_name(sandbox: Arc<Mutex<Sandbox>>, dev_addr: &str, ) -> Result
     if dev_name == "" {
         info!("Waiting on channel for device notification\n");
 
-        match rx.recv_timeout(Duration::from_secs(TIMEOUT_HOTPLUG)) {
+        let timeout = AGENT_CONFIG.read().unwrap().hotplug_timeout;
+        match rx.recv_timeout(timeout) {
             Ok(name) => dev_name = name,
             Err(e) => {
                 let watcher = GLOBAL_DEVICE_WATCHER.clone();
@@ -173,8 +194,8 @@ pub fn get_device_name(sandbox: Arc<Mutex<Sandbox>>, dev_addr: &str, ) -> Result
                 w.remove_entry(dev_addr);
 
                 return Err(ErrorKind::ErrorCode(format!(
-                    "Timeout reached after {} waiting for device {}",
-                    TIMEOUT_HOTPLUG, dev_addr
+                    "Timeout reached after {:?} waiting for device {}",
+                    timeout, dev_addr
                 ))
                     .into());
             }
@@ -184,6 +205,7 @@ pub fn get_device_name(sandbox: Arc<Mutex<Sandbox>>, dev_addr: &str, ) -> Result
     Ok(format!("{}/{}", SYSTEM_DEV_PATH, &dev_name))
 }
 
+#[cfg(feature = "scsi")]
 pub fn get_scsi_device_name(sandbox: Arc<Mutex<Sandbox>>, scsi_addr: &str) -> Result<String> {
     scan_scsi_bus(scsi_addr)?;
 
@@ -201,6 +223,7 @@ pub fn get_pci_device_name(sandbox: Arc<Mutex<Sandbox>>, pci_id: &str) -> Result
 }
 
 // scan_scsi_bus scans SCSI bus for the given SCSI address(SCSI-Id and LUN)
+#[cfg(feature = "scsi")]
 pub fn scan_scsi_bus(scsi_addr: &str) -> Result<()> {
     let tokens: Vec<&str> = scsi_addr.split(":").collect();
     if tokens.len() != 2 {
@@ -315,6 +338,7 @@ fn virtio_blk_device_handler(device: &Device, spec: &mut Spec, sandbox: Arc<Mute
 }
 
 // device.Id should be the SCSI address of the disk in the format "scsiID:lunID"
+#[cfg(feature = "scsi")]
 fn virtio_scsi_device_handler(device: &Device, spec: &mut Spec, sandbox: Arc<Mutex<Sandbox>>) -> Result<()> {
     let dev_path = get_scsi_device_name(sandbox, device.id.as_str())?;
 
@@ -324,6 +348,7 @@ fn virtio_scsi_device_handler(device: &Device, spec: &mut Spec, sandbox: Arc<Mut
     update_spec_device_list(&dev, spec)
 }
 
+#[cfg(feature = "nvdimm")]
 fn virtio_nvdimm_device_handler(device: &Device, spec: &mut Spec, sandbox: Arc<Mutex<Sandbox>>) -> Result<()> {
     update_spec_device_list(device, spec)
 }
@@ -355,7 +380,8 @@ fn add_device(device: &Device, spec: &mut Spec, sandbox: Arc<Mutex<Sandbox>>) ->
     }
 
     let dev_handler = match DEVICEHANDLERLIST.get(device.field_type.as_str()) {
-        None => return Err(ErrorKind::Msg(format!("Unknown device type {}", device.field_type)).into()),
+        // unknown, or left out of this build
+        None => return Err(ErrorKind::Unimplemented(format!("Unknown device type {}", device.field_type)).into()),
         Some(t) => t
     };
 
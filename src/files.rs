@@ -0,0 +1,314 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// CopyFile and ReadFile, files going into and out of the guest. Both stay
+// below the container base and the agent.file_paths directories.
+
+use nix::unistd::{self, Gid, Uid};
+use protobuf::RepeatedField;
+use protocols::agent::{CopyFileRequest, CopyFileResponse, DirEntry, ReadFileRequest, ReadFileResponse};
+use rustjail::errors::*;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File, OpenOptions, Permissions};
+use std::io::Read;
+use std::os::unix::fs::{FileExt, MetadataExt, PermissionsExt};
+use std::path::{Component, Path, PathBuf};
+
+use crate::config::AGENT_CONFIG;
+
+// well below the message limit of both transports
+const READ_FILE_MAX_LEN: u32 = 1 << 20;
+const DIGEST_BUF_SIZE: usize = 64 * 1024;
+
+fn invalid_path(path: &str) -> Error {
+	ErrorKind::InvalidArgument(format!(
+		"{} is not below the container base or agent.file_paths", path)).into()
+}
+
+fn allowed_roots() -> Vec<PathBuf> {
+	let config = AGENT_CONFIG.read().unwrap();
+	let mut roots = vec![config.container_base.clone()];
+	roots.extend(config.file_paths.iter().cloned());
+
+	// symlinks are resolved on both sides
+	roots.iter()
+		.map(|r| fs::canonicalize(r).unwrap_or_else(|_| PathBuf::from(r)))
+		.collect()
+}
+
+// absolute, without "..", and below one of the roots once the symlinks of
+// the part that exists are resolved; the rest is created by CopyFile
+fn check_path(path: &str) -> Result<PathBuf> {
+	let p = Path::new(path);
+	if !p.is_absolute() || p.components().any(|c| c == Component::ParentDir) {
+		return Err(invalid_path(path));
+	}
+
+	// a dangling symlink counts as there, and fails to resolve
+	let mut existing = p.to_path_buf();
+	let mut rest = Vec::new();
+	while existing.symlink_metadata().is_err() {
+		match (existing.file_name().map(|n| n.to_os_string()), existing.parent()) {
+			(Some(name), Some(parent)) => {
+				rest.push(name);
+				existing = parent.to_path_buf();
+			}
+			_ => break,
+		}
+	}
+
+	let mut resolved = fs::canonicalize(&existing)?;
+	for name in rest.iter().rev() {
+		resolved.push(name);
+	}
+
+	if !allowed_roots().iter().any(|r| resolved.starts_with(r)) {
+		return Err(invalid_path(path));
+	}
+
+	Ok(resolved)
+}
+
+// <path>.tmp, with the extension kept so that a.json and a.txt differ
+fn tmp_path(path: &Path) -> PathBuf {
+	let mut tmp = path.as_os_str().to_os_string();
+	tmp.push(".tmp");
+	PathBuf::from(tmp)
+}
+
+fn sha256(path: &Path) -> Result<Vec<u8>> {
+	let mut f = File::open(path)?;
+	let mut hasher = Sha256::new();
+	let mut buf = vec![0u8; DIGEST_BUF_SIZE];
+
+	loop {
+		let n = f.read(buf.as_mut_slice())?;
+		if n == 0 {
+			break;
+		}
+		hasher.input(&buf[..n]);
+	}
+
+	Ok(hasher.result().to_vec())
+}
+
+// chunks go into <path>.tmp, which only takes the place of path once it
+// has file_size bytes and the digest matches; a request without data
+// only asks how much of the file is there
+pub fn copy_file(req: &CopyFileRequest) -> Result<CopyFileResponse> {
+	let path = check_path(req.path.as_str())?;
+	if req.file_size < 0 || req.offset < 0 {
+		return Err(ErrorKind::InvalidArgument(format!(
+			"bad size {} or offset {} for {}", req.file_size, req.offset, req.path)).into());
+	}
+
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir)?;
+		fs::set_permissions(dir, Permissions::from_mode(req.dir_mode))?;
+	}
+
+	let tmp = tmp_path(&path);
+	// a query creates nothing; without <path>.tmp, either path is the
+	// whole file already or the copy starts over
+	if req.data.is_empty() && req.file_size > 0 && !tmp.exists() {
+		let mut resp = CopyFileResponse::new();
+		if let Ok(meta) = fs::metadata(&path) {
+			if meta.len() as i64 == req.file_size && (req.sha256.is_empty() || sha256(&path)? == req.sha256) {
+				resp.offset = req.file_size;
+				resp.done = true;
+			}
+		}
+		return Ok(resp);
+	}
+
+	let file = OpenOptions::new()
+		.write(true)
+		.create(true)
+		.truncate(false)
+		.open(&tmp)?;
+
+	if !req.data.is_empty() {
+		let size = file.metadata()?.len() as i64;
+		// there would be a hole otherwise
+		if req.offset > size {
+			return Err(ErrorKind::FailedPrecondition(format!(
+				"{} has {} bytes, cannot write at {}", req.path, size, req.offset)).into());
+		}
+
+		if req.offset == 0 {
+			file.set_len(0)?;
+		}
+		file.write_all_at(req.data.as_slice(), req.offset as u64)?;
+	}
+
+	let size = file.metadata()?.len() as i64;
+	let mut resp = CopyFileResponse::new();
+	resp.offset = size;
+
+	if size < req.file_size {
+		return Ok(resp);
+	}
+
+	if size > req.file_size {
+		let _ = fs::remove_file(&tmp);
+		return Err(ErrorKind::InvalidArgument(format!(
+			"{} bytes sent for {}, {} expected", size, req.path, req.file_size)).into());
+	}
+
+	if !req.sha256.is_empty() && sha256(&tmp)? != req.sha256 {
+		let _ = fs::remove_file(&tmp);
+		return Err(ErrorKind::InvalidArgument(format!(
+			"sha256 of {} does not match", req.path)).into());
+	}
+
+	fs::set_permissions(&tmp, Permissions::from_mode(req.file_mode))?;
+	unistd::chown(tmp.as_path(), Some(Uid::from_raw(req.uid as u32)), Some(Gid::from_raw(req.gid as u32)))?;
+	fs::rename(&tmp, &path)?;
+
+	resp.done = true;
+	Ok(resp)
+}
+
+// a part of a file, or the listing of a directory
+pub fn read_file(req: &ReadFileRequest) -> Result<ReadFileResponse> {
+	let path = check_path(req.path.as_str())?;
+	let mut resp = ReadFileResponse::new();
+
+	if fs::metadata(&path)?.is_dir() {
+		let mut entries = Vec::new();
+
+		for e in fs::read_dir(&path)? {
+			let e = e?;
+			// gone since the listing, or symlinks, shown as themselves
+			let m = match e.metadata() {
+				Ok(m) => m,
+				Err(_) => continue,
+			};
+
+			let mut entry = DirEntry::new();
+			entry.name = e.file_name().to_string_lossy().to_string();
+			entry.dir = m.is_dir();
+			entry.size = m.len() as i64;
+			entry.mode = m.mode();
+			entry.mtime = m.mtime();
+			entries.push(entry);
+		}
+
+		entries.sort_by(|a, b| a.name.cmp(&b.name));
+		resp.entries = RepeatedField::from_vec(entries);
+		resp.dir = true;
+
+		return Ok(resp);
+	}
+
+	if req.offset < 0 {
+		return Err(ErrorKind::InvalidArgument(format!(
+			"bad offset {} for {}", req.offset, req.path)).into());
+	}
+
+	let len = if req.len == 0 || req.len > READ_FILE_MAX_LEN {
+		READ_FILE_MAX_LEN
+	} else {
+		req.len
+	};
+
+	let file = File::open(&path)?;
+	let mut buf = vec![0u8; len as usize];
+	let mut n = 0;
+
+	// procfs and sysfs hand out less than asked for
+	while n < buf.len() {
+		let r = file.read_at(&mut buf[n..], req.offset as u64 + n as u64)?;
+		if r == 0 {
+			break;
+		}
+		n += r;
+	}
+	buf.truncate(n);
+
+	resp.data = buf;
+	resp.file_size = file.metadata()?.len() as i64;
+
+	Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_copy_and_read_file() {
+		let base = std::env::temp_dir().join(format!("kata-files-{}", std::process::id()));
+		fs::create_dir_all(&base).unwrap();
+		let base = fs::canonicalize(&base).unwrap();
+		AGENT_CONFIG.write().unwrap().container_base = base.to_string_lossy().to_string();
+
+		let path = base.join("dir/file.txt");
+		let mut req = CopyFileRequest::new();
+		req.path = path.to_string_lossy().to_string();
+		req.file_size = 11;
+		req.file_mode = 0o640;
+		req.dir_mode = 0o750;
+		req.uid = unistd::getuid().as_raw() as i32;
+		req.gid = unistd::getgid().as_raw() as i32;
+		req.sha256 = Sha256::digest(b"hello world").to_vec();
+
+		// asking leaves nothing behind
+		let resp = copy_file(&req).unwrap();
+		assert_eq!((resp.offset, resp.done), (0, false));
+		assert!(!tmp_path(&path).exists());
+
+		req.data = b"hello".to_vec();
+		let resp = copy_file(&req).unwrap();
+		assert_eq!((resp.offset, resp.done), (5, false));
+
+		// a gap, then how far it got
+		req.offset = 8;
+		req.data = b"rld".to_vec();
+		assert!(copy_file(&req).is_err());
+		req.data = Vec::new();
+		assert_eq!(copy_file(&req).unwrap().offset, 5);
+
+		req.offset = 5;
+		req.data = b" world".to_vec();
+		let resp = copy_file(&req).unwrap();
+		assert_eq!((resp.offset, resp.done), (11, true));
+		assert_eq!(fs::read_to_string(&path).unwrap(), "hello world");
+		assert!(!tmp_path(&path).exists());
+
+		// the file is there already
+		req.offset = 0;
+		req.data = Vec::new();
+		let resp = copy_file(&req).unwrap();
+		assert_eq!((resp.offset, resp.done), (11, true));
+		assert!(!tmp_path(&path).exists());
+
+		// a wrong digest leaves the destination alone
+		req.offset = 0;
+		req.data = b"hello there".to_vec();
+		assert!(copy_file(&req).is_err());
+		assert_eq!(fs::read_to_string(&path).unwrap(), "hello world");
+
+		let mut rreq = ReadFileRequest::new();
+		rreq.path = req.path.clone();
+		rreq.offset = 6;
+		let resp = read_file(&rreq).unwrap();
+		assert_eq!((resp.data.as_slice(), resp.file_size), (&b"world"[..], 11));
+
+		rreq.path = base.join("dir").to_string_lossy().to_string();
+		let resp = read_file(&rreq).unwrap();
+		assert!(resp.dir);
+		assert_eq!(resp.entries.len(), 1);
+		assert_eq!(resp.entries[0].name, "file.txt");
+		assert_eq!(resp.entries[0].mode & 0o777, 0o640);
+
+		rreq.path = format!("{}/../etc/passwd", base.display());
+		assert!(read_file(&rreq).is_err());
+		rreq.path = "/etc/passwd".to_string();
+		assert!(read_file(&rreq).is_err());
+
+		let _ = fs::remove_dir_all(&base);
+	}
+}
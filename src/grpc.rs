@@ -3,25 +3,29 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+#[cfg(feature = "grpc")]
 use futures::*;
-use grpcio::{EnvBuilder, Server, ServerBuilder};
-use grpcio::{RpcStatus, RpcStatusCode};
+#[cfg(feature = "grpc")]
+use grpcio::{EnvBuilder, Server, ServerBuilder, WriteFlags};
+#[cfg(feature = "ttrpc")]
+use protocols::ttrpc::{self, TtrpcContext};
 use std::sync::{Arc, Mutex};
 
 use lazy_static;
 use rustjail::cgroups::fs::Manager as FsManager;
-use rustjail::container::{BaseContainer, LinuxContainer};
+use rustjail::container::{BaseContainer, Container, LinuxContainer};
 use rustjail::cgroups::Manager as CgroupManager;
 use rustjail::process::Process;
 use rustjail::specconv::CreateOpts;
 use rustjail::errors::*;
+use rustjail::trace;
 use rustjail;
 use protocols::empty::Empty;
 use protocols::agent::{WriteStreamResponse, ReadStreamResponse, GuestDetailsResponse, AgentDetails, WaitProcessResponse, ListProcessesResponse};
-use protocols::health::{HealthCheckResponse_ServingStatus, HealthCheckResponse};
+use protocols::health::HealthCheckResponse;
 use protobuf::{RepeatedField, SingularPtrField};
 use protocols::oci::{self, Spec, Linux, LinuxNamespace};
-use protocols::agent::{CopyFileRequest};
+use protocols::agent::{ReadOutputResponse, StringUser, OOMEvent, Metrics};
 
 use std::collections::HashMap;
 
@@ -35,92 +39,100 @@ use rustjail::process::ProcessOperations;
 use crate::mount::{add_storages, remove_mounts, STORAGEHANDLERLIST};
 use crate::sandbox::Sandbox;
 use crate::version::{AGENT_VERSION, API_VERSION};
+#[cfg(feature = "netlink")]
 use crate::netlink::{RtnlHandle, NETLINK_ROUTE};
+use crate::network::{self, RESOLV_CONF_DEST, RESOLV_CONF_PATH};
 use crate::namespace::{NSTYPEIPC, NSTYPEUTS, NSTYPEPID};
-use crate::device::{rescan_pci_bus, add_devices};
+use crate::device::{rescan_pci_bus, add_devices, DEVICEHANDLERLIST};
 use crate::random;
+use crate::files;
+use crate::health;
+use crate::metrics;
+use crate::oom::OomEvents;
+use crate::pressure::{self, PressureEvents};
+use crate::ps;
+#[cfg(feature = "grpc")]
+use crate::stdio;
+use crate::capture::{self, Capture};
+#[cfg(feature = "grpc")]
+use crate::rpc::{reply, reply_wait};
+#[cfg(feature = "ttrpc")]
+use crate::rpc::ttrpc_reply;
+#[cfg(feature = "tracing")]
+use crate::tracer;
+use crate::config::{AGENT_CONFIG, ServerAddr};
 
 use std::fs;
 use libc::{self, pid_t, TIOCSWINSZ, winsize, c_ushort};
 use std::os::unix::io::RawFd;
-use std::process::{Command, Stdio};
 use serde_json;
+#[cfg(feature = "grpc")]
 use std::thread;
-use std::sync::mpsc;
 use std::time::Duration;
 
-use std::fs::{File, OpenOptions};
+use std::fs::File;
 use std::io::{BufRead, BufReader};
-use std::os::unix::fs::FileExt;
-use std::path::PathBuf;
-use nix::unistd::{Uid, Gid};
 use ::oci::{Spec as OCISpec};
 
 const SYSFS_MEMORY_BLOCK_SIZE_PATH: &'static str = "/sys/devices/system/memory/block_size_bytes";
 const SYSFS_MEMORY_HOTPLUG_PROBE_PATH: &'static str = "/sys/devices/system/memory/probe";
 pub const SYSFS_MEMORY_ONLINE_PATH: &'static str = "/sys/devices/system/memory";
-const CONTAINER_BASE: &'static str = "/run/agent";
+const PROC_MOUNTS: &'static str = "/proc/self/mounts";
+const PROC_CGROUPS: &'static str = "/proc/cgroups";
+const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+const CGROUP2_CONTROLLERS: &'static str = "/sys/fs/cgroup/cgroup.controllers";
+
+// from linux/seccomp.h, not in every libc
+const SECCOMP_MODE_FILTER: libc::c_ulong = 2;
+
+// rustjail drops the seccomp section of the spec, nothing is enforced
+const SECCOMP_PROFILES_APPLIED: bool = false;
 
 #[derive(Clone, Default)]
 struct agentService {
     sandbox: Arc<Mutex<Sandbox>>,
-    test: u32,
 }
 
-impl protocols::agent_grpc::AgentService for agentService {
-    fn create_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::CreateContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-        let cid = req.container_id.clone();
-        let eid = req.exec_id.clone();
+impl agentService {
+	fn do_create_container(&self, req: protocols::agent::CreateContainerRequest) -> Result<()> {
+		let cid = req.container_id.clone();
+		let eid = req.exec_id.clone();
+
+		let mut oci_spec = req.OCI.clone();
 
-        let mut oci_spec = req.OCI.clone();
+		let oci = match oci_spec.as_mut() {
+			Some(spec) => spec,
+			None => return Err(ErrorKind::InvalidArgument(
+				"no oci spec in the request".to_string()).into()),
+		};
 
-		let sandbox;
-		let mut s;
+		info!("receive createcontainer {}\n", &cid);
 
-		let mut oci = oci_spec.as_mut().unwrap();
+		// nothing is set up yet for a bad threshold or buffer size
+		pressure::psi_triggers(req.memory_pressure.as_slice())?;
+		let buffer_size = capture::buffer_size(req.output_buffer_size)?;
 
-        info!("receive createcontainer {}\n", &cid);
+		// no process can be started in the shared pid namespace once
+		// its init is gone
+		{
+			let s = self.sandbox.lock().unwrap();
+			if s.sandbox_pid_ns && !s.pause_alive() {
+				return Err(ErrorKind::FailedPrecondition(
+					"the pause process of the sandbox is gone".to_string()).into());
+			}
+		}
 
 		// re-scan PCI bus
 		// looking for hidden devices
-
-		match rescan_pci_bus().chain_err(|| "Could not rescan PCI bus") {
-			Ok(_) => (),
-			Err(e) => {
-				let f = sink
-					.fail(RpcStatus::new(
-						RpcStatusCode::Internal,
-						Some(e.to_string()),
-					))
-					.map_err(move |e| error!("fail to reply {:?}", req));
-				ctx.spawn(f);
-				return;
-			}
-		};
+		rescan_pci_bus().chain_err(|| "Could not rescan PCI bus")?;
 
 		// Some devices need some extra processing (the ones invoked with
 		// --device for instance), and that's what this call is doing. It
 		// updates the devices listed in the OCI spec, so that they actually
 		// match real devices inside the VM. This step is necessary since we
 		// cannot predict everything from the caller.
-		match add_devices(req.devices.to_vec(), oci, self.sandbox.clone()) {
-			Ok(_) => (),
-			Err(e) => {
-				let f = sink
-					.fail(RpcStatus::new(
-						RpcStatusCode::Internal,
-						Some(format!("failed to add devices to container: {:?}", e)),
-					))
-					.map_err(move |e| error!("fail to reply {:?}", req));
-				ctx.spawn(f);
-				return;
-			}
-		};
+		add_devices(req.devices.to_vec(), oci, self.sandbox.clone())
+			.chain_err(|| "failed to add devices to container")?;
 
 		// Both rootfs and volumes (invoked with --volume for instance) will
 		// be processed the same way. The idea is to always mount any provided
@@ -129,246 +141,110 @@ impl protocols::agent_grpc::AgentService for agentService {
 		// After all those storages have been processed, no matter the order
 		// here, the agent will rely on rustjail (using the oci.Mounts
 		// list) to bind mount all of them inside the container.
-		match add_storages(req.storages.to_vec(), self.sandbox.clone()) {
-			Ok(m) => {
-				sandbox = self.sandbox.clone();
-				s = sandbox.lock().unwrap();
-				s.container_mounts.insert(cid.clone(), m);
-			},
-			Err(e) => {
-				let f = sink
-					.fail(RpcStatus::new(
-						RpcStatusCode::Internal,
-						Some(format!("failed to add storage to container: {:?}", e)),
-					))
-					.map_err(move |e| error!("fail to reply {:?}", req));
-				ctx.spawn(f);
-				return;
-			}
-		};
+		let m = add_storages(req.storages.to_vec(), self.sandbox.clone())
+			.chain_err(|| "failed to add storage to container")?;
+
+		let sandbox = self.sandbox.clone();
+		let mut s = sandbox.lock().unwrap();
+		s.container_mounts.insert(cid.clone(), m);
 
-		update_container_namespaces(&s, oci);
+		update_container_namespaces(&s, oci)?;
+		update_container_dns(&s, oci);
 
 		// write spec to bundle path, hooks might
 		// read ocispec
 		let _ = setup_bundle(oci);
 
-        let opts = CreateOpts {
-            cgroup_name: "".to_string(),
-            use_systemd_cgroup: false,
-            no_pivot_root: s.no_pivot_root,
-            no_new_keyring: false,
-            spec: Some(oci.clone()),
-            rootless_euid: false,
-            rootless_cgroup: false,
-        };
-
-        let mut ctr: LinuxContainer = match LinuxContainer::new(cid.as_str(), CONTAINER_BASE, opts) {
-            Ok(v) => v,
-            Err(_) => {
-                info!("create contianer failed!\n");
-                let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some(format!("fail to create container {}", cid)),
-                    ))
-                    .map_err(move |e| error!("fail to reply {:?}", req));
-                ctx.spawn(f);
-                return;
-            }
-        };
-
-        let p = if oci.Process.is_some() {
-            let tp = match Process::new(oci.get_Process(), eid.as_str(),true) {
-                Ok(v) => v,
-                Err(_) => {
-                    info!("fail to create process!\n");
-                    let f = sink
-                        .fail(RpcStatus::new(
-                            RpcStatusCode::Internal,
-                            Some("fail to create process".to_string()),
-                        ))
-                        .map_err(|e| error!("process create fail"));
-                    ctx.spawn(f);
-                    return;
-                }
-            };
-            tp
-        } else {
-            info!("no process configurations!\n");
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("fail to create process".to_string()),
-                ))
-                .map_err(|e| error!("process create fail"));
-            ctx.spawn(f);
-            return;
-        };
-
-        if let Err(_) = ctr.start(p) {
-            info!("fail to start process!\n");
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(format!("fail to start init process {}", eid)),
-                ))
-                .map_err(move |e| error!("fail to start {}", eid));
-            ctx.spawn(f);
-            return;
-        }
+		let opts = CreateOpts {
+			cgroup_name: "".to_string(),
+			use_systemd_cgroup: false,
+			no_pivot_root: s.no_pivot_root,
+			no_new_keyring: false,
+			spec: Some(oci.clone()),
+			rootless_euid: false,
+			rootless_cgroup: false,
+		};
 
-        s.add_container(ctr);
-        info!("created container!\n");
+		let mut ctr = LinuxContainer::new(cid.as_str(), container_base().as_str(), opts)
+			.chain_err(|| format!("fail to create container {}", cid))?;
 
-        let resp = Empty::new();
-        let f = sink
-            .success(resp)
-            .map_err(move |e| error!("fail to create container {}", cid));
-        ctx.spawn(f);
-    }
+		let rootfs = match oci.Root.as_ref() {
+			Some(root) => root.Path.clone(),
+			None => return Err(ErrorKind::InvalidArgument(
+				"no root in the oci spec".to_string()).into()),
+		};
 
-    fn start_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::StartContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-        let cid = req.container_id.clone();
+		let p = match oci.Process.as_mut() {
+			Some(ocip) => {
+				resolve_user(rootfs.as_str(), ocip, req.string_user.as_ref())?;
+				Process::new(ocip, eid.as_str(), true)
+					.chain_err(|| "fail to create process")?
+			}
+			None => return Err(ErrorKind::InvalidArgument(
+				"no process configurations".to_string()).into()),
+		};
 
-        let sandbox = self.sandbox.clone();
-        let mut s = sandbox.lock().unwrap();
+		ctr.start(p).chain_err(|| format!("fail to start init process {}", eid))?;
 
-        let mut ctr: &mut LinuxContainer = match s.get_container(cid.as_str()) {
-            Some(cr) => cr,
-            None => {
-                let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("fail to find container".to_string()),
-                    ))
-                    .map_err(move |e| error!("get container fail {}", cid.clone()));
-                ctx.spawn(f);
-                return;
-            }
-        };
+		// start draining the init process output right away
+		match ctr.get_process(eid.as_str())
+				.and_then(|p| capture::start(p, buffer_size)) {
+			Ok(c) => s.add_capture(cid.as_str(), eid.as_str(), c),
+			Err(e) => warn!("cannot capture output of {}/{}: {}", cid, eid, e),
+		}
 
-        let _ = ctr.exec();
+		// a container without memory cgroup still runs, without events
+		if let Err(e) = OomEvents::watch(&s.oom_events, &ctr) {
+			warn!("no OOM events for container {}: {}", cid, e);
+		}
+		if let Err(e) = PressureEvents::watch(&s.pressure_events, &ctr, req.memory_pressure.as_slice()) {
+			warn!("no memory pressure events for container {}: {}", cid, e);
+		}
 
-        info!("exec process!\n");
+		s.add_container(ctr);
+		info!("created container!\n");
 
-        let resp = Empty::new();
-        let f = sink
-            .success(resp)
-            .map_err(move |e| error!("fail to create container {}", cid));
-        ctx.spawn(f);
-    }
+		Ok(())
+	}
 
-    fn remove_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::RemoveContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
+	fn do_start_container(&self, req: protocols::agent::StartContainerRequest) -> Result<()> {
 		let cid = req.container_id.clone();
-		let resp = Empty::new();
-		let mut cmounts: Vec<String> = vec![];
-
-		if req.timeout == 0 {
-			let s = Arc::clone(&self.sandbox);
-			let mut sandbox = s.lock().unwrap();
-			let ctr = sandbox.get_container(cid.as_str()).unwrap();
-
-			ctr.destroy().unwrap();
-
-			// Find the sandbox storage used by this container
-			let mounts = sandbox.container_mounts.get(&cid);
-			if mounts.is_some() {
-				let mounts =  mounts.unwrap();
-
-				match remove_mounts(&mounts) {
-					Ok(_) => (),
-					Err(e) => {
-						let f = sink
-							.fail(RpcStatus::new(
-								RpcStatusCode::Internal,
-								Some(format!("fail to umount container mounts: {:?}", e)),
-							))
-							.map_err(move |e| error!("get container fail {}", cid.clone()));
-						ctx.spawn(f);
-						return;
-					}
-				}
 
-				for m in mounts.iter() {
-					if sandbox.storages.get(m).is_some() {
-						cmounts.push(m.to_string());
-					}
-				}
-			}
+		let mut s = self.sandbox.lock().unwrap();
+		let ctr = get_container(&mut s, cid.as_str())?;
 
-			for m in cmounts.iter() {
-				match sandbox.unset_and_remove_sandbox_storage(m) {
-					Ok(_) => (),
-					Err(e) => {
-						let f = sink
-							.fail(RpcStatus::new(
-								RpcStatusCode::Internal,
-								Some(format!("fail to remove container storage: {:?}", e)),
-							))
-							.map_err(move |e| error!("get container fail {}", cid.clone()));
-						ctx.spawn(f);
-						return;
-					}
-				}
-			}
+		ctr.exec().chain_err(|| format!("fail to start container {}", cid))?;
 
-			sandbox.container_mounts.remove(cid.as_str());
-			sandbox.containers.remove(cid.as_str());
+		info!("exec process!\n");
 
-			let f = sink.success(resp)
-				.map_err(|_e| error!("cannot destroy container"));
-			ctx.spawn(f);
-			return;
-		}
+		Ok(())
+	}
 
-		// timeout != 0
-		let s = Arc::clone(&self.sandbox);
-		let cid2 = cid.clone();
-		let (tx, rx) = mpsc::channel();
+	fn do_remove_container(&self, req: protocols::agent::RemoveContainerRequest) -> Result<()> {
+		let cid = req.container_id.clone();
+		let mut cmounts: Vec<String> = vec![];
 
-		let handle = thread::spawn(move || {
-			let mut sandbox = s.lock().unwrap();
-			let ctr = sandbox.get_container(cid2.as_str()).unwrap();
+		let stopper = {
+			let mut sandbox = self.sandbox.lock().unwrap();
+			get_container(&mut sandbox, cid.as_str())?.stopper()?
+		};
 
-			ctr.destroy().unwrap();
-			tx.send(1).unwrap();
-		});
+		// the waits take as long as the processes do, the reaper and every
+		// other call need the sandbox meanwhile
+		let stopped = stopper.stop(Duration::from_secs(req.timeout as u64));
 
-		rx.recv_timeout(Duration::from_secs(req.timeout as u64)).unwrap();
-		handle.join().unwrap();
+		// when some processes refuse to die, the mounts still go away
+		// and the container is kept around so that removal can be retried
+		match stopped {
+			Ok(_) | Err(Error(ErrorKind::DeadlineExceeded(_), _)) => {}
+			Err(e) => return Err(e),
+		}
 
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
+		let mut sandbox = self.sandbox.lock().unwrap();
 
 		// Find the sandbox storage used by this container
-		let mounts = sandbox.container_mounts.get(&cid);
-		if mounts.is_some() {
-			let mounts =  mounts.unwrap();
-
-			match remove_mounts(&mounts) {
-				Ok(_) => (),
-				Err(e) => {
-					let f = sink
-						.fail(RpcStatus::new(
-							RpcStatusCode::Internal,
-							Some(format!("fail to umount container mounts: {:?}", e)),
-						))
-						.map_err(move |e| error!("get container fail {}", cid.clone()));
-					ctx.spawn(f);
-					return;
-				}
-			}
+		if let Some(mounts) = sandbox.container_mounts.get(&cid) {
+			remove_mounts(mounts).chain_err(|| "fail to umount container mounts")?;
 
 			for m in mounts.iter() {
 				if sandbox.storages.get(m).is_some() {
@@ -378,123 +254,73 @@ impl protocols::agent_grpc::AgentService for agentService {
 		}
 
 		for m in cmounts.iter() {
-			match sandbox.unset_and_remove_sandbox_storage(m) {
-				Ok(_) => (),
-				Err(e) => {
-					let f = sink
-						.fail(RpcStatus::new(
-							RpcStatusCode::Internal,
-							Some(format!("fail to remove container storage: {:?}", e)),
-						))
-						.map_err(move |e| error!("get container fail {}", cid.clone()));
-					ctx.spawn(f);
-					return;
-				}
-			}
+			sandbox.unset_and_remove_sandbox_storage(m)
+				.chain_err(|| "fail to remove container storage")?;
 		}
 
-		sandbox.container_mounts.remove(&cid);
+		sandbox.container_mounts.remove(cid.as_str());
+
+		stopped?;
+
 		sandbox.containers.remove(cid.as_str());
+		sandbox.captures.remove(cid.as_str());
+		sandbox.oom_events.forget(cid.as_str());
 
-		let f = sink.success(resp)
-			.map_err(|_e| error!("remove container failed"));
-		ctx.spawn(f);
-    }
-    fn exec_process(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::ExecProcessRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
+		Ok(())
+	}
+
+	fn do_exec_process(&self, req: protocols::agent::ExecProcessRequest) -> Result<()> {
 		let cid = req.container_id.clone();
 		let exec_id = req.exec_id.clone();
 
 		info!("cid: {} eid: {}", cid.clone(), exec_id.clone());
 
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
+		let buffer_size = capture::buffer_size(req.output_buffer_size)?;
+		let mut sandbox = self.sandbox.lock().unwrap();
 
-		// ignore string_user, not sure what it is
-		let ocip = if req.process.is_some() {
-			req.process.as_ref().unwrap()
-		} else {
-			let f = sink.fail(RpcStatus::new(
-			RpcStatusCode::InvalidArgument,
-			Some(String::from("No process configuration!"))))
-			.map_err(|e| error!("Invalid execprocessrequest!"));
-			ctx.spawn(f);
-			return;
+		let mut ocip = match req.process.as_ref() {
+			Some(v) => v.clone(),
+			None => return Err(ErrorKind::InvalidArgument(
+				"No process configuration!".to_string()).into()),
 		};
 
-		let p = match Process::new(ocip, exec_id.as_str(),false) {
-			Ok(v) => v,
-			Err(_) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some(String::from("fail while creating process!"))))
-				.map_err(|e| error!("fail to create process!"));
-				ctx.spawn(f);
-				return;
-			}
-		};
+		let ctr = get_container(&mut sandbox, cid.as_str())?;
 
-		let mut ctr = match sandbox.get_container(cid.as_str()) {
-			Some(v) => v,
-			None => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some(String::from("no container"))))
-				.map_err(move |e| error!("no container {}", cid.clone()));
-				ctx.spawn(f);
-				return;
-			}
+		let rootfs = match ctr.config.spec.as_ref().and_then(|s| s.Root.as_ref()) {
+			Some(root) => root.Path.clone(),
+			None => return Err(ErrorKind::FailedPrecondition(
+				format!("container {} has no root", cid)).into()),
 		};
+		resolve_user(rootfs.as_str(), &mut ocip, req.string_user.as_ref())?;
 
-		match ctr.run(p) {
-			Err(e) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some(e.to_string())))
-					.map_err(move |e| error!("connt exec process {}", cid.clone()));
-				ctx.spawn(f);
-				return;
-			},
-			Ok(_) => ()
-		};
+		let p = Process::new(&ocip, exec_id.as_str(), false)
+			.chain_err(|| "fail while creating process!")?;
 
-		let resp = Empty::new();
-		let f = sink.success(resp)
-				.map_err(move |e| error!("connot exec process {}",
-					exec_id.clone()));
-		ctx.spawn(f);
-    }
-    fn signal_process(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::SignalProcessRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
+		ctr.run(p)?;
+
+		match ctr.get_process(exec_id.as_str())
+				.and_then(|p| capture::start(p, buffer_size)) {
+			Ok(c) => sandbox.add_capture(cid.as_str(), exec_id.as_str(), c),
+			Err(e) => warn!("cannot capture output of {}/{}: {}", cid, exec_id, e),
+		}
+
+		Ok(())
+	}
+
+	fn do_signal_process(&self, req: protocols::agent::SignalProcessRequest) -> Result<()> {
 		let cid = req.container_id.clone();
 		let eid = req.exec_id.clone();
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
+		let mut sandbox = self.sandbox.lock().unwrap();
 
 		info!("signal process: {}/{}", cid.clone(), eid.clone());
-		let p = match find_process(&mut sandbox, cid.as_str(),
-				eid.as_str(), true) {
+		let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), true)?;
+
+		let mut signal = match Signal::from_c_int(req.signal as i32) {
 			Ok(v) => v,
-			Err(_) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::InvalidArgument,
-					Some(String::from("invalid argument"))))
-				.map_err(|_e| error!("invalid argument"));
-				ctx.spawn(f);
-				return;
-			}
+			Err(_) => return Err(ErrorKind::InvalidArgument(
+				format!("invalid signal {}", req.signal)).into()),
 		};
 
-		let mut signal = Signal::from_c_int(req.signal as i32).unwrap();
-
 		// For container initProcess, if it hasn't installed handler for "SIGTERM" signal,
 		// it will ignore the "SIGTERM" signal sent to it, thus send it "SIGKILL" signal
 		// instead of "SIGTERM" to terminate it.
@@ -504,122 +330,129 @@ impl protocols::agent_grpc::AgentService for agentService {
 
 		let _ = p.signal(signal);
 
-		let resp = Empty::new();
-		let f = sink.success(resp)
-			.map_err(|_e| error!("cannot signal process"));
-		ctx.spawn(f);
-    }
-    fn wait_process(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::WaitProcessRequest,
-        sink: ::grpcio::UnarySink<protocols::agent::WaitProcessResponse>,
-    ) {
+		Ok(())
+	}
+
+	fn do_wait_process(&self, req: protocols::agent::WaitProcessRequest) -> Result<WaitProcessResponse> {
 		let cid = req.container_id.clone();
 		let eid = req.exec_id.clone();
 		let s = Arc::clone(&self.sandbox);
 		let mut resp = WaitProcessResponse::new();
-        let mut pid: pid_t = -1;
-        let mut exit_pipe_r: RawFd = -1;
-        let mut buf: Vec<u8> = vec![0,1];
+		let mut pid: pid_t = -1;
+		let mut exit_pipe_r: RawFd = -1;
+		let mut buf: Vec<u8> = vec![0,1];
 
 		info!("wait process: {}/{}", cid.clone(), eid.clone());
 
-        {
-            let mut sandbox = s.lock().unwrap();
-
-            let p = match find_process(&mut sandbox, cid.as_str(),
-                                       eid.as_str(), false) {
-                Ok(v) => v,
-                Err(_) => {
-                    let f = sink.fail(RpcStatus::new(
-                        RpcStatusCode::InvalidArgument,
-                        Some(String::from("invalid argument"))))
-                        .map_err(|_e| error!("invalid argument"));
-                    ctx.spawn(f);
-                    return;
-                }
-            };
-            if p.exit_pipe_r.is_some() {
-                exit_pipe_r = p.exit_pipe_r.unwrap();
-            }
-            pid = p.pid;
-        }
+		{
+			let mut sandbox = s.lock().unwrap();
 
-        if exit_pipe_r != -1 {
-            let _ = unistd::read(exit_pipe_r, buf.as_mut_slice());
-        }
+			match find_process(&mut sandbox, cid.as_str(), eid.as_str(), false) {
+				Ok(p) => {
+					// every waiter reads from its own copy of the exit pipe,
+					// the original one is closed by whoever reaps the process
+					// out of fds is no reason to take the process for gone
+					if p.exit_pipe_r.is_some() {
+						exit_pipe_r = unistd::dup(p.exit_pipe_r.unwrap())
+							.chain_err(|| format!("cannot wait for {}/{}", cid, eid))?;
+					}
+					pid = p.pid;
+				}
+				Err(e) => {
+					// the process may have been reaped by a concurrent waiter
+					let status = sandbox.get_container(cid.as_str())
+						.and_then(|c| c.exited_processes.get(&eid).cloned()
+							.map(|s| (s, c.oom_kill_count())));
+
+					match status {
+						Some(((code, core), kills)) => {
+							resp.status = code;
+							resp.core_dumped = core;
+							resp.oom_killed = sandbox.oom_events.oom_killed(cid.as_str(), eid.as_str(), code, kills);
+							sandbox.capture_waited(cid.as_str(), eid.as_str());
+							return Ok(resp);
+						}
+						None => return Err(e),
+					}
+				}
+			};
+		}
+
+		if exit_pipe_r != -1 {
+			loop {
+				match unistd::read(exit_pipe_r, buf.as_mut_slice()) {
+					Err(nix::Error::Sys(Errno::EINTR)) => continue,
+					_ => break,
+				}
+			}
+			let _ = unistd::close(exit_pipe_r);
+		}
 
 		let mut sandbox = s.lock().unwrap();
-		let mut ctr = sandbox.get_container(cid.as_str()).unwrap();
-		// need to close all fds
-		let mut p = ctr.processes.get_mut(&pid).unwrap();
+		let oom_events = Arc::clone(&sandbox.oom_events);
+		let ctr = get_container(&mut sandbox, cid.as_str())?;
 
-		if p.parent_stdin.is_some() {
-			let _ = unistd::close(p.parent_stdin.unwrap());
-		}
+		// need to close all fds, unless another waiter got here first
+		if let Some(p) = ctr.processes.remove(&pid) {
+			if p.parent_stdin.is_some() {
+				let _ = unistd::close(p.parent_stdin.unwrap());
+			}
 
-		if p.parent_stdout.is_some() {
-			let _ = unistd::close(p.parent_stdout.unwrap());
-		}
+			if p.parent_stdout.is_some() {
+				let _ = unistd::close(p.parent_stdout.unwrap());
+			}
 
-		if p.parent_stderr.is_some() {
-			let _ = unistd::close(p.parent_stderr.unwrap());
-		}
+			if p.parent_stderr.is_some() {
+				let _ = unistd::close(p.parent_stderr.unwrap());
+			}
 
-		if p.term_master.is_some() {
-			let _ = unistd::close(p.term_master.unwrap());
+			if p.term_master.is_some() {
+				let _ = unistd::close(p.term_master.unwrap());
+			}
+
+			if p.exit_pipe_r.is_some() {
+				let _ = unistd::close(p.exit_pipe_r.unwrap());
+			}
+
+			ctr.add_exited_process(eid.as_str(), p.exit_code, p.core_dumped);
 		}
 
-        if p.exit_pipe_r.is_some() {
-            let _ = unistd::close(p.exit_pipe_r.unwrap());
-        }
+		if let Some((code, core)) = ctr.exited_processes.get(&eid) {
+			resp.status = *code;
+			resp.core_dumped = *core;
+			resp.oom_killed = oom_events.oom_killed(cid.as_str(), eid.as_str(), *code, ctr.oom_kill_count());
+		}
 
-		p.parent_stdin = None;
-		p.parent_stdout = None;
-		p.parent_stderr = None;
-		p.term_master = None;
+		sandbox.capture_waited(cid.as_str(), eid.as_str());
 
-		ctr.processes.remove(&pid);
+		Ok(resp)
+	}
 
-		let f = sink.success(resp)
-			.map_err(|_e| error!("cannot wait process"));
-		ctx.spawn(f);
-    }
-    fn list_processes(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::ListProcessesRequest,
-        sink: ::grpcio::UnarySink<protocols::agent::ListProcessesResponse>,
-    ) {
+	fn do_list_processes(&self, req: protocols::agent::ListProcessesRequest) -> Result<ListProcessesResponse> {
 		let cid = req.container_id.clone();
 		let format = req.format.clone();
 		let mut args  = req.args.clone().into_vec();
 		let mut resp = ListProcessesResponse::new();
 
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
+		let mut sandbox = self.sandbox.lock().unwrap();
+		let ctr = get_container(&mut sandbox, cid.as_str())?;
 
-		let ctr = sandbox.get_container(cid.as_str()).unwrap();
-		let pids = ctr.processes().unwrap();
+		// every process in the container cgroup, not only the
+		// ones started by the agent
+		let pids = match ctr.cgroup_manager.as_ref() {
+			Some(m) => m.get_all_pids()?,
+			None => return Err(ErrorKind::FailedPrecondition(
+				format!("container {} has no cgroup manager", cid)).into()),
+		};
 
 		match format.as_str() {
 			"table" => {}
 			"json" => {
-				resp.process_list = serde_json::to_vec(&pids).unwrap();
-				let f = sink.success(resp)
-					.map_err(|_e| error!("cannot handle json resp"));
-				ctx.spawn(f);
-				return;
-			}
-			_ => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::InvalidArgument,
-					Some(String::from("invalid format"))))
-					.map_err(|_e| error!("invalid format!"));
-				ctx.spawn(f);
-				return;
+				resp.process_list = serde_json::to_vec(&pids)?;
+				return Ok(resp);
 			}
+			_ => return Err(ErrorKind::InvalidArgument(
+				format!("invalid format {}", format)).into()),
 		}
 
 		// format "table"
@@ -628,221 +461,576 @@ impl protocols::agent_grpc::AgentService for agentService {
 			args = vec!["-ef".to_string()];
 		}
 
-		let output = Command::new("ps")
-					.args(args.as_slice())
-					.stdout(Stdio::piped())
-					.output()
-					.expect("ps failed");
+		let result = ps::render_table(pids.as_slice(), args.as_slice())
+			.map_err(|e| Error::with_chain(e, ErrorKind::InvalidArgument(
+				"cannot list processes".to_string())))?;
 
-		let out: String = String::from_utf8(output.stdout).unwrap();
-		let mut lines: Vec<String> = out.split('\n')
-					.map(|v| v.to_string())
-					.collect();
+		resp.process_list = Vec::from(result);
 
-		let predicate = |v| if v == "PID" {
-				return true;
-		} else {
-			return false;
-		};
+		Ok(resp)
+	}
 
-		let pid_index = lines[0].split_whitespace()
-					.position(predicate).unwrap();
+	fn do_update_container(&self, req: protocols::agent::UpdateContainerRequest) -> Result<()> {
+		let cid = req.container_id.clone();
+		let mut sandbox = self.sandbox.lock().unwrap();
 
-		let mut result = String::new();
-		result.push_str(lines[0].as_str());
+		let ctr = get_container(&mut sandbox, cid.as_str())?;
 
-		lines.remove(0);
-		for line in &lines {
-			if line.trim().is_empty() {
-				continue;
-			}
+		if let Some(res) = req.resources.into_option() {
+			ctr.set(res)?;
+		}
 
-			let fields: Vec<String> = line
-				.split_whitespace()
-				.map(|v| v.to_string())
-				.collect();
+		Ok(())
+	}
 
-			if fields.len() < pid_index + 1 {
-				warn!("corrupted output?");
-				continue;
-			}
-			let pid = fields[pid_index].trim().parse::<i32>().unwrap();
+	fn do_stats_container(&self, req: protocols::agent::StatsContainerRequest) -> Result<protocols::agent::StatsContainerResponse> {
+		let cid = req.container_id.clone();
+		let mut sandbox = self.sandbox.lock().unwrap();
 
-			for p in &pids {
-				if pid == *p {
-					result.push_str(line.as_str());
-				}
-			}
-		}
+		let mut resp = get_container(&mut sandbox, cid.as_str())?.stats()?;
+		resp.network_stats = RepeatedField::from_vec(network_stats(&mut sandbox));
 
-		resp.process_list = Vec::from(result);
+		Ok(resp)
+	}
 
-		let f = sink.success(resp)
-				.map_err(|_e| error!("list processes failed"));
-		ctx.spawn(f);
-    }
-    fn update_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::UpdateContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
+	fn do_write_stdin(&self, req: protocols::agent::WriteStreamRequest) -> Result<WriteStreamResponse> {
 		let cid = req.container_id.clone();
-		let res = req.resources.clone();
+		let eid = req.exec_id.clone();
 
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
+		info!("write stdin for {}/{}", cid.clone(), eid.clone());
 
-		let ctr = sandbox.get_container(cid.as_str()).unwrap();
+		let mut sandbox = self.sandbox.lock().unwrap();
+		let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
 
-		let resp = Empty::new();
+		// use ptmx io, or piped io
+		let fd = match p.term_master.or(p.parent_stdin) {
+			Some(fd) => fd,
+			None => return Err(ErrorKind::FailedPrecondition(
+				format!("stdin of {}/{} is closed", cid, eid)).into()),
+		};
 
-		if res.is_some() {
-			match ctr.set(res.unwrap()) {
-				Err(e) => {
-					let f = sink.fail(RpcStatus::new(
-						RpcStatusCode::Internal,
-						Some("internal error".to_string())))
-						.map_err(|_e| error!("internal error!"));
-					ctx.spawn(f);
-					return;
+		let mut l = req.data.len();
+		match unistd::write(fd, req.data.as_slice()) {
+			Ok(v) => {
+				if v < l {
+					info!("write {} bytes", v);
+					l = v;
 				}
-
-				Ok(()) => {}
 			}
+			Err(nix::Error::Sys(Errno::EAGAIN)) => l = 0,
+			Err(e) => return Err(e).chain_err(|| "write error"),
 		}
 
-		let f = sink.success(resp)
-			.map_err(|_e| error!("update container failed!"));
+		let mut resp = WriteStreamResponse::new();
+		resp.set_len(l as u32);
 
-		ctx.spawn(f);
-    }
-    fn stats_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::StatsContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::agent::StatsContainerResponse>,
-    ) {
+		Ok(resp)
+	}
+
+	fn do_read_stream(&self, req: protocols::agent::ReadStreamRequest, stderr: bool) -> Result<ReadStreamResponse> {
 		let cid = req.container_id.clone();
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
+		let eid = req.exec_id.clone();
 
-		let ctr = sandbox.get_container(cid.as_str()).unwrap();
+		// the output is drained into the capture, read it from there
+		let capture = {
+			let sandbox = self.sandbox.lock().unwrap();
+			sandbox.get_capture(cid.as_str(), eid.as_str())
+		};
 
-		let resp = match ctr.stats() {
-			Err(e) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some("internal error!".to_string())))
-					.map_err(|_e| error!("internal error!"));
-				ctx.spawn(f);
-				return;
-			}
+		let vector = match capture {
+			Some(c) => read_capture(&c, stderr, req.len as usize)?,
+			None => {
+				let fd = {
+					let mut sandbox = self.sandbox.lock().unwrap();
+					let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
+
+					if p.term_master.is_some() {
+						p.term_master
+					} else if stderr {
+						p.parent_stderr
+					} else {
+						p.parent_stdout
+					}
+				};
 
-			Ok(r) => r,
+				let fd = match fd {
+					Some(fd) => fd,
+					None => return Err(ErrorKind::FailedPrecondition(
+						format!("output of {}/{} is closed", cid, eid)).into()),
+				};
+
+				read_stream(fd, cid.as_str(), eid.as_str(), req.len as usize)?
+			}
 		};
 
-		let f = sink.success(resp)
-				.map_err(|_e| error!("stats containers failed!"));
-		ctx.spawn(f);
-    }
-    fn pause_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::PauseContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-    }
-    fn resume_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::ResumeContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-    }
-    fn write_stdin(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::WriteStreamRequest,
-        sink: ::grpcio::UnarySink<protocols::agent::WriteStreamResponse>,
-    ) {
+		let mut resp = ReadStreamResponse::new();
+		resp.set_data(vector);
+
+		Ok(resp)
+	}
+
+	fn do_close_stdin(&self, req: protocols::agent::CloseStdinRequest) -> Result<()> {
 		let cid = req.container_id.clone();
 		let eid = req.exec_id.clone();
+		let mut sandbox = self.sandbox.lock().unwrap();
 
-		info!("write stdin for {}/{}", cid.clone(), eid.clone());
+		let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
 
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-		let ctr = match sandbox.get_container(cid.as_str()) {
-			Some(v) => v,
-			None => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::InvalidArgument,
-					Some(String::from("invalid cid"))))
-				.map_err(move |e| error!("invalid cid {}", cid.clone()));
-				ctx.spawn(f);
-				return;
-			}
+		if p.term_master.is_some() {
+			let _ = unistd::close(p.term_master.unwrap());
+			p.term_master = None;
+		}
+
+		if p.parent_stdin.is_some() {
+			let _ = unistd::close(p.parent_stdin.unwrap());
+			p.parent_stdin = None;
+		}
+
+		Ok(())
+	}
+
+	fn do_tty_win_resize(&self, req: protocols::agent::TtyWinResizeRequest) -> Result<()> {
+		let cid = req.container_id.clone();
+		let eid = req.exec_id.clone();
+		let mut sandbox = self.sandbox.lock().unwrap();
+
+		let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
+
+		let fd = match p.term_master {
+			Some(fd) => fd,
+			None => return Err(ErrorKind::FailedPrecondition(
+				format!("{}/{} has no tty", cid, eid)).into()),
 		};
 
-		let p = match ctr.get_process(eid.as_str()) {
-			Ok(v) => v,
-			Err(_) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::InvalidArgument,
-					Some(format!("invalid eid {}", eid.as_str()))))
-				.map_err(move |e| error!("invalid eid {}", eid.clone()));
-				ctx.spawn(f);
-				return;
-			}
+		let win = winsize {
+			ws_row: req.row as c_ushort,
+			ws_col: req.column as c_ushort,
+			ws_xpixel: 0,
+			ws_ypixel: 0,
 		};
 
-		// use ptmx io
-		let fd = if p.term_master.is_some() {
-			p.term_master.unwrap()
-		} else {
-			// use piped io
-			p.parent_stdin.unwrap()
+		let err = unsafe { libc::ioctl(fd, TIOCSWINSZ, &win) };
+		Errno::result(err).map(drop).chain_err(|| "ioctl error")?;
+
+		Ok(())
+	}
+
+	#[cfg(feature = "netlink")]
+	fn do_update_interface(&self, req: protocols::agent::UpdateInterfaceRequest) -> Result<protocols::types::Interface> {
+		let interface = match req.interface.as_ref() {
+			Some(v) => v,
+			None => return Err(ErrorKind::InvalidArgument(
+				"no interface in the request".to_string()).into()),
 		};
 
-		let mut l = req.data.len();
-		match unistd::write(fd, req.data.as_slice()) {
-			Ok(v) => {
-				if v < l {
-					/*
-					let f = sink.fail(RpcStatus::new(
-						RpcStatusCode::InvalidArgument,
-						Some(format!("write error"))))
-					.map_err(|_e| error!("write error"));
-					ctx.spawn(f);
-					return;
-					*/
-					info!("write {} bytes", v);
-					l = v;
-				}
+		let mut sandbox = self.sandbox.lock().unwrap();
+
+		get_rtnl(&mut sandbox)?.update_interface(interface)
+	}
+
+	#[cfg(feature = "netlink")]
+	fn do_update_routes(&self, req: protocols::agent::UpdateRoutesRequest) -> Result<protocols::agent::Routes> {
+		let mut routes = protocols::agent::Routes::new();
+		let rs = match req.routes.as_ref() {
+			Some(v) => v.Routes.clone().into_vec(),
+			None => return Err(ErrorKind::InvalidArgument(
+				"no routes in the request".to_string()).into()),
+		};
+
+		let mut sandbox = self.sandbox.lock().unwrap();
+		let v = get_rtnl(&mut sandbox)?.update_routes(rs.as_ref())?;
+
+		routes.set_Routes(RepeatedField::from_vec(v));
+
+		Ok(routes)
+	}
+
+	fn do_update_dns(&self, req: protocols::agent::UpdateDNSRequest) -> Result<()> {
+		let mut sandbox = self.sandbox.lock().unwrap();
+
+		sandbox.network.set_dns(req.dns.to_vec())?;
+		sandbox.network.setup_dns().chain_err(|| "cannot write resolv.conf")?;
+
+		// the containers running keep the file they mounted
+		let content = sandbox.network.resolv_conf();
+		let mut failed = Vec::new();
+
+		for (cid, ctr) in sandbox.containers.iter() {
+			let shared = ctr.config.spec.as_ref()
+				.map(|s| s.Mounts.iter().any(|m| m.destination == RESOLV_CONF_DEST && m.source == RESOLV_CONF_PATH))
+				.unwrap_or(false);
+			if !shared || !ctr.processes.contains_key(&ctr.init_process_pid) {
+				continue;
 			}
-			Err(e) => {
-				match e {
-					nix::Error::Sys(nix::errno::Errno::EAGAIN) => l = 0,
-					_ => {
-					let f = sink.fail(RpcStatus::new(
-						RpcStatusCode::InvalidArgument,
-						Some(format!("write error"))))
-					.map_err(|_e| error!("write error"));
-					ctx.spawn(f);
-					return;
+
+			if let Err(e) = network::rebind_resolv_conf(ctr.init_process_pid, content.as_str()) {
+				warn!("cannot update the resolv.conf of container {}: {}", cid, e);
+				failed.push(cid.clone());
+			}
+		}
+
+		if !failed.is_empty() {
+			return Err(ErrorKind::ErrorCode(format!(
+				"resolv.conf not updated in containers {}", failed.join(", "))).into());
+		}
+
+		Ok(())
+	}
+
+	#[cfg(feature = "netlink")]
+	fn do_list_interfaces(&self) -> Result<protocols::agent::Interfaces> {
+		let mut interface = protocols::agent::Interfaces::new();
+		let mut sandbox = self.sandbox.lock().unwrap();
+
+		let v = get_rtnl(&mut sandbox)?.list_interfaces()?;
+
+		interface.set_Interfaces(RepeatedField::from_vec(v));
+
+		Ok(interface)
+	}
+
+	#[cfg(feature = "netlink")]
+	fn do_list_routes(&self) -> Result<protocols::agent::Routes> {
+		let mut routes = protocols::agent::Routes::new();
+		let mut sandbox = self.sandbox.lock().unwrap();
+
+		let v = get_rtnl(&mut sandbox)?.list_routes()?;
+
+		routes.set_Routes(RepeatedField::from_vec(v));
+
+		Ok(routes)
+	}
+
+	// without netlink the host has to set up the guest network itself
+	#[cfg(not(feature = "netlink"))]
+	fn do_update_interface(&self, _req: protocols::agent::UpdateInterfaceRequest) -> Result<protocols::types::Interface> {
+		Err(not_built_in("netlink"))
+	}
+
+	#[cfg(not(feature = "netlink"))]
+	fn do_update_routes(&self, _req: protocols::agent::UpdateRoutesRequest) -> Result<protocols::agent::Routes> {
+		Err(not_built_in("netlink"))
+	}
+
+	#[cfg(not(feature = "netlink"))]
+	fn do_list_interfaces(&self) -> Result<protocols::agent::Interfaces> {
+		Err(not_built_in("netlink"))
+	}
+
+	#[cfg(not(feature = "netlink"))]
+	fn do_list_routes(&self) -> Result<protocols::agent::Routes> {
+		Err(not_built_in("netlink"))
+	}
+
+	fn do_create_sandbox(&self, req: protocols::agent::CreateSandboxRequest) -> Result<()> {
+		{
+			let mut s = self.sandbox.lock().unwrap();
+
+			let _ = fs::remove_dir_all(container_base());
+			let _ = fs::create_dir_all(container_base());
+
+			s.hostname = req.hostname.clone();
+			s.running = true;
+
+			if req.sandbox_id.len() > 0 {
+				s.id = req.sandbox_id.clone();
+			}
+
+			s.sandbox_pid_ns = req.sandbox_pidns;
+
+			s.setup_shared_namespaces()
+				.map_err(|e| Error::with_chain(e, ErrorKind::FailedPrecondition(
+					"cannot setup shared namespaces".to_string())))?;
+
+			if req.dns.len() > 0 {
+				s.network.set_dns(req.dns.to_vec())?;
+				s.network.setup_dns().chain_err(|| "cannot write resolv.conf")?;
+			}
+		}
+
+		let m = add_storages(req.storages.to_vec(), self.sandbox.clone())
+			.map_err(|e| Error::with_chain(e, ErrorKind::FailedPrecondition(
+				"cannot add sandbox storages".to_string())))?;
+
+		self.sandbox.lock().unwrap().mounts = m;
+
+		Ok(())
+	}
+
+	fn do_destroy_sandbox(&self) -> Result<()> {
+		let mut sandbox = self.sandbox.lock().unwrap();
+		// destroy all containers, clean up, notify agent to exit
+		// etc.
+		sandbox.destroy()?;
+
+		if let Some(tx) = sandbox.sender.take() {
+			let _ = tx.send(1);
+		}
+
+		Ok(())
+	}
+
+	#[cfg(feature = "tracing")]
+	fn do_start_tracing(&self) -> Result<()> {
+		tracer::start()?;
+		self.sandbox.lock().unwrap().enable_grpc_trace = true;
+
+		Ok(())
+	}
+
+	#[cfg(feature = "tracing")]
+	fn do_stop_tracing(&self) -> Result<()> {
+		self.sandbox.lock().unwrap().enable_grpc_trace = false;
+
+		// whatever was recorded goes out before we answer
+		tracer::stop()
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	fn do_start_tracing(&self) -> Result<()> {
+		Err(not_built_in("tracing"))
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	fn do_stop_tracing(&self) -> Result<()> {
+		Err(not_built_in("tracing"))
+	}
+
+	fn do_get_guest_details(&self, req: protocols::agent::GuestDetailsRequest) -> Result<GuestDetailsResponse> {
+		info!("get guest details!");
+		let mut resp = GuestDetailsResponse::new();
+		// to get memory block size
+		let (u, v) = get_memory_info(req.mem_block_size, req.mem_hotplug_probe)
+			.chain_err(|| "fail to get memory info")?;
+		resp.mem_block_size_bytes = u;
+		resp.support_mem_hotplug_probe = v;
+
+		// to get agent details
+		let detail = get_agent_details();
+		resp.agent_details = SingularPtrField::some(detail);
+
+		Ok(resp)
+	}
+
+	fn do_pause_container(&self, req: protocols::agent::PauseContainerRequest) -> Result<()> {
+		let cid = req.container_id.clone();
+		let mut sandbox = self.sandbox.lock().unwrap();
+
+		get_container(&mut sandbox, cid.as_str())?.pause()
+	}
+
+	fn do_resume_container(&self, req: protocols::agent::ResumeContainerRequest) -> Result<()> {
+		let cid = req.container_id.clone();
+		let mut sandbox = self.sandbox.lock().unwrap();
+
+		get_container(&mut sandbox, cid.as_str())?.resume()
+	}
+
+	fn do_get_metrics(&self) -> Result<Metrics> {
+		let mut stats = Vec::new();
+
+		// only the cgroups are read with the sandbox locked
+		{
+			let sandbox = self.sandbox.lock().unwrap();
+			for (cid, ctr) in sandbox.containers.iter() {
+				if let Some(cm) = ctr.cgroup_manager.as_ref() {
+					match cm.get_stats() {
+						Ok(s) => stats.push((cid.clone(), s)),
+						Err(e) => warn!("no cgroup stats for container {}: {}", cid, e),
 					}
 				}
 			}
 		}
-		
-		let mut resp = WriteStreamResponse::new();
-		resp.set_len(l as u32);
 
-		let f = sink.success(resp)
-			.map_err(|e| error!("writestream request failed!"));
+		stats.sort_by(|a, b| a.0.cmp(&b.0));
+
+		let mut resp = Metrics::new();
+		resp.metrics = metrics::gather(stats.as_slice());
+
+		Ok(resp)
+	}
+
+	fn do_get_oom_event(&self) -> Result<OOMEvent> {
+		// the sandbox stays unlocked while waiting
+		let events = Arc::clone(&self.sandbox.lock().unwrap().oom_events);
+		let mut resp = OOMEvent::new();
+		resp.container_id = events.next()?;
+
+		Ok(resp)
+	}
+
+	fn do_online_cpu_mem(&self, req: protocols::agent::OnlineCPUMemRequest) -> Result<()> {
+		self.sandbox.lock().unwrap().online_cpu_memory(&req)
+	}
+
+	fn get_output_capture(&self, req: &protocols::agent::ReadOutputRequest) -> Result<Arc<Capture>> {
+		let cid = req.container_id.as_str();
+		let eid = req.exec_id.as_str();
+		let sandbox = self.sandbox.lock().unwrap();
+
+		sandbox.get_capture(cid, eid).ok_or_else(|| ErrorKind::NotFound(
+			format!("no output captured for {}/{}", cid, eid)).into())
+	}
+}
 
-		ctx.spawn(f);
+#[cfg(feature = "grpc")]
+impl protocols::agent_grpc::AgentService for agentService {
+    fn create_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::CreateContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("create_container");
+        let _timer = metrics::rpc_timer("create_container");
+        let r = self.do_create_container(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "create_container");
+    }
+    fn start_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::StartContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("start_container");
+        let _timer = metrics::rpc_timer("start_container");
+        let r = self.do_start_container(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "start_container");
+    }
+    fn remove_container(
+        &mut self,
+        _ctx: ::grpcio::RpcContext,
+        req: protocols::agent::RemoveContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+		let _span = trace::span("remove_container");
+		let timer = metrics::rpc_timer("remove_container");
+		let s = self.clone();
+
+		// the processes get up to the timeout to go, off the grpc workers
+		thread::spawn(move || {
+			let _timer = timer;
+			let r = s.do_remove_container(req).map(|_| Empty::new());
+			reply_wait(sink, r, "remove_container");
+		});
+    }
+    fn exec_process(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ExecProcessRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("exec_process");
+        let _timer = metrics::rpc_timer("exec_process");
+        let r = self.do_exec_process(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "exec_process");
+    }
+    fn signal_process(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::SignalProcessRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("signal_process");
+        let _timer = metrics::rpc_timer("signal_process");
+        let r = self.do_signal_process(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "signal_process");
+    }
+    fn wait_process(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::WaitProcessRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::WaitProcessResponse>,
+    ) {
+        let _span = trace::span("wait_process");
+        let _timer = metrics::rpc_timer("wait_process");
+        let r = self.do_wait_process(req);
+        reply(&ctx, sink, r, "wait_process");
+    }
+    fn list_processes(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ListProcessesRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::ListProcessesResponse>,
+    ) {
+        let _span = trace::span("list_processes");
+        let _timer = metrics::rpc_timer("list_processes");
+        let r = self.do_list_processes(req);
+        reply(&ctx, sink, r, "list_processes");
+    }
+    fn update_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::UpdateContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("update_container");
+        let _timer = metrics::rpc_timer("update_container");
+        let r = self.do_update_container(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "update_container");
+    }
+    fn stats_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::StatsContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::StatsContainerResponse>,
+    ) {
+        let _span = trace::span("stats_container");
+        let _timer = metrics::rpc_timer("stats_container");
+        let r = self.do_stats_container(req);
+        reply(&ctx, sink, r, "stats_container");
+    }
+    fn pause_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::PauseContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("pause_container");
+        let _timer = metrics::rpc_timer("pause_container");
+        let r = self.do_pause_container(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "pause_container");
+    }
+    fn resume_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ResumeContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("resume_container");
+        let _timer = metrics::rpc_timer("resume_container");
+        let r = self.do_resume_container(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "resume_container");
+    }
+    fn get_oom_event(
+        &mut self,
+        _ctx: ::grpcio::RpcContext,
+        _req: protocols::agent::GetOOMEventRequest,
+        sink: ::grpcio::UnarySink<OOMEvent>,
+    ) {
+		let _span = trace::span("get_oom_event");
+		let timer = metrics::rpc_timer("get_oom_event");
+		let events = Arc::clone(&self.sandbox.lock().unwrap().oom_events);
+
+		// there may be no event for ever, nothing waits on the call; the
+		// event goes to the next caller when this one has gone away
+		events.wait(Box::new(move |cid| {
+			let _timer = timer;
+			let mut resp = OOMEvent::new();
+			resp.container_id = cid;
+			reply_wait(sink, Ok(resp), "get_oom_event")
+		}));
+    }
+    fn write_stdin(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::WriteStreamRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::WriteStreamResponse>,
+    ) {
+        let _span = trace::span("write_stdin");
+        let _timer = metrics::rpc_timer("write_stdin");
+        let r = self.do_write_stdin(req);
+        reply(&ctx, sink, r, "write_stdin");
     }
     fn read_stdout(
         &mut self,
@@ -850,66 +1038,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReadStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::ReadStreamResponse>,
     ) {
-		let cid = req.container_id;
-		let eid = req.exec_id;
-
-        let mut fd: RawFd = -1;
-		// info!("read stdout for {}/{}", cid.clone(), eid.clone());
-        {
-            let s = Arc::clone(&self.sandbox);
-            let mut sandbox = s.lock().unwrap();
-
-            let p = match find_process(&mut sandbox, cid.as_str(), eid.as_str(), false) {
-                Ok(v) => v,
-                Err(_) => {
-                    let f = sink.fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some(String::from("invalid argument!"))))
-                        .map_err(move |_e| error!(
-                            "read stream failed"));
-                    ctx.spawn(f);
-                    return;
-                }
-            };
-
-               fd = if p.term_master.is_some() {
-                p.term_master.unwrap()
-            } else if p.parent_stdout.is_some() {
-                p.parent_stdout.unwrap()
-            } else { -1 };
-        }
-
-        if fd == -1 {
-            let f = sink.fail(RpcStatus::new(
-                RpcStatusCode::Internal,
-                Some(String::from("invalid argument!"))))
-                .map_err(move |_e| error!("read stream failed"));
-            ctx.spawn(f);
-            return;
-        }
-
-        let vector = match read_stream(fd, cid.as_str(),
-			eid.as_str(), req.len as usize) {
-			Ok(v) => v,
-			Err(_) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some(String::from("read stream error!"))))
-				.map_err(move |_e| error!(
-				"read stream failed"));
-
-				ctx.spawn(f);
-				return;
-			}
-		};
-
-		let mut resp = ReadStreamResponse::new();
-		resp.set_data(vector);
-
-		let f = sink.success(resp)
-			.map_err(move |_e| error!("read error for container {} process {}", cid, eid));
-
-		ctx.spawn(f);
+        let _span = trace::span("read_stdout");
+        let _timer = metrics::rpc_timer("read_stdout");
+        let r = self.do_read_stream(req, false);
+        reply(&ctx, sink, r, "read_stdout");
     }
     fn read_stderr(
         &mut self,
@@ -917,66 +1049,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReadStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::ReadStreamResponse>,
     ) {
-        let cid = req.container_id;
-        let eid = req.exec_id;
-        let mut fd: RawFd = -1;
-        // info!("read stderr for {}/{}", cid.clone(), eid.clone());
-        {
-            let s = Arc::clone(&self.sandbox);
-            let mut sandbox = s.lock().unwrap();
-
-            let p = match find_process(&mut sandbox, cid.as_str(), eid.as_str(), false) {
-                Ok(v) => v,
-                Err(_) => {
-                    let f = sink.fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some(String::from("invalid argument!"))))
-                        .map_err(move |_e| error!(
-                            "read stream failed"));
-                    ctx.spawn(f);
-                    return;
-                }
-            };
-
-            fd = if p.term_master.is_some() {
-                p.term_master.unwrap()
-            } else if p.parent_stderr.is_some() {
-                p.parent_stderr.unwrap()
-            } else { -1 };
-        }
-
-        if fd == -1 {
-            let f = sink.fail(RpcStatus::new(
-                RpcStatusCode::Internal,
-                Some(String::from("invalid argument!"))))
-                .map_err(move |_e| error!(
-                    "read stream failed"));
-            ctx.spawn(f);
-            return;
-        }
-
-		let vector = match read_stream(fd, cid.as_str(),
-			eid.as_str(), req.len as usize) {
-			Ok(v) => v,
-			Err(_) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some(String::from("read stream error!"))))
-				.map_err(move |_e| error!(
-				"read stream failed"));
-
-				ctx.spawn(f);
-				return;
-			}
-		};
-
-		let mut resp = ReadStreamResponse::new();
-		resp.set_data(vector);
-
-		let f = sink.success(resp)
-			.map_err(move |_e| error!("read error for container {} process {}", cid, eid));
-
-		ctx.spawn(f);
+        let _span = trace::span("read_stderr");
+        let _timer = metrics::rpc_timer("read_stderr");
+        let r = self.do_read_stream(req, true);
+        reply(&ctx, sink, r, "read_stderr");
     }
     fn close_stdin(
         &mut self,
@@ -984,39 +1060,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::CloseStdinRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-		let cid = req.container_id.clone();
-		let eid = req.exec_id.clone();
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-
-		let p = match find_process(&mut sandbox, cid.as_str(),
-				eid.as_str(), false) {
-			Ok(v) => v,
-			Err(_) => {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::InvalidArgument,
-					Some(String::from("invalid argument"))))
-				.map_err(|_e| error!("invalid argument"));
-				ctx.spawn(f);
-				return;
-			}
-		};
-
-		if p.term_master.is_some() {
-			let _ = unistd::close(p.term_master.unwrap());
-			p.term_master = None;
-		}
-
-		if p.parent_stdin.is_some() {
-			let _ = unistd::close(p.parent_stdin.unwrap());
-			p.parent_stdin = None;
-		}
-
-		let resp = Empty::new();
-
-		let f = sink.success(resp)
-			.map_err(|_e| error!("close stdin failed"));
-		ctx.spawn(f);
+        let _span = trace::span("close_stdin");
+        let _timer = metrics::rpc_timer("close_stdin");
+        let r = self.do_close_stdin(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "close_stdin");
     }
 
     fn tty_win_resize(
@@ -1025,46 +1072,45 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::TtyWinResizeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-		let cid = req.container_id.clone();
-		let eid = req.exec_id.clone();
+        let _span = trace::span("tty_win_resize");
+        let _timer = metrics::rpc_timer("tty_win_resize");
+        let r = self.do_tty_win_resize(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "tty_win_resize");
+    }
+    fn stream_io(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        stream: ::grpcio::RequestStream<protocols::agent::StreamIORequest>,
+        sink: ::grpcio::DuplexSink<protocols::agent::StreamIOResponse>,
+    ) {
+		let _span = trace::span("stream_io");
 		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-		let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false).unwrap();
-
-		if p.term_master.is_none() {
-			let f = sink.fail(RpcStatus::new(
-				RpcStatusCode::Unavailable,
-				Some("no tty".to_string())))
-				.map_err(|_e| error!("tty resize"));
-			ctx.spawn(f);
-			return;
-		}
 
-		let fd = p.term_master.unwrap();
-		unsafe {
-			let win = winsize {
-				ws_row: req.row as c_ushort,
-				ws_col: req.column as c_ushort,
-				ws_xpixel: 0,
-				ws_ypixel: 0,
-			};
+		// the call lives as long as the process, keep it off the grpc workers
+		thread::spawn(move || stdio::serve(s, stream, sink));
+    }
+    fn read_output(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ReadOutputRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::ReadOutputResponse>,
+    ) {
+		let _span = trace::span("read_output");
+		let timer = metrics::rpc_timer("read_output");
 
-			let err = libc::ioctl(fd, TIOCSWINSZ, &win);
-			if let Err(_) = Errno::result(err).map(drop) {
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some("ioctl error".to_string())))
-				.map_err(|_e| error!("ioctl error!"));
-				ctx.spawn(f);
+		let c = match self.get_output_capture(&req) {
+			Ok(c) => c,
+			Err(e) => {
+				reply(&ctx, sink, Err(e), "read_output");
 				return;
 			}
-		}
+		};
 
-        let empty = protocols::empty::Empty::new();
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+		// reading may block until the process writes something
+		thread::spawn(move || {
+			let _timer = timer;
+			reply_wait(sink, Ok(read_output(&c, &req)), "read_output");
+		});
     }
     fn update_interface(
         &mut self,
@@ -1072,23 +1118,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::UpdateInterfaceRequest,
         sink: ::grpcio::UnarySink<protocols::types::Interface>,
     ) {
-        let interface = req.interface.clone();
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-
-		if sandbox.rtnl.is_none() {
-			sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-		}
-
-		let rtnl = sandbox.rtnl.as_mut().unwrap();
-
-		let iface = rtnl.update_interface(interface.as_ref().unwrap()).unwrap();
-
-        let f = sink
-            .success(iface)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
-
+        let _span = trace::span("update_interface");
+        let _timer = metrics::rpc_timer("update_interface");
+        let r = self.do_update_interface(req);
+        reply(&ctx, sink, r, "update_interface");
     }
     fn update_routes(
         &mut self,
@@ -1096,26 +1129,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::UpdateRoutesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Routes>,
     ) {
-        let mut routes = protocols::agent::Routes::new();
-		let rs = req.routes.clone().unwrap().Routes.into_vec();
-
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-
-		if sandbox.rtnl.is_none() {
-			sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-		}
-
-		let rtnl = sandbox.rtnl.as_mut().unwrap();
-		let v = rtnl.update_routes(rs.as_ref()).unwrap();
-
-		routes.set_Routes(RepeatedField::from_vec(v));
-
-        let f = sink
-            .success(routes)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-
-        ctx.spawn(f)
+        let _span = trace::span("update_routes");
+        let _timer = metrics::rpc_timer("update_routes");
+        let r = self.do_update_routes(req);
+        reply(&ctx, sink, r, "update_routes");
     }
     fn list_interfaces(
         &mut self,
@@ -1123,23 +1140,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ListInterfacesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Interfaces>,
     ) {
-        let mut interface = protocols::agent::Interfaces::new();
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-
-		if sandbox.rtnl.is_none() {
-			sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-		}
-
-		let rtnl = sandbox.rtnl.as_mut().unwrap();
-		let v = rtnl.list_interfaces().unwrap();
-
-		interface.set_Interfaces(RepeatedField::from_vec(v));
-
-        let f = sink
-            .success(interface)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _span = trace::span("list_interfaces");
+        let _timer = metrics::rpc_timer("list_interfaces");
+        let r = self.do_list_interfaces();
+        reply(&ctx, sink, r, "list_interfaces");
     }
     fn list_routes(
         &mut self,
@@ -1147,24 +1151,45 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ListRoutesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Routes>,
     ) {
-        let mut routes = protocols::agent::Routes::new();
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-
-		if sandbox.rtnl.is_none() {
-			sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-		}
-
-		let rtnl = sandbox.rtnl.as_mut().unwrap();
-
-		let v = rtnl.list_routes().unwrap();
-
-		routes.set_Routes(RepeatedField::from_vec(v));
+        let _span = trace::span("list_routes");
+        let _timer = metrics::rpc_timer("list_routes");
+        let r = self.do_list_routes();
+        reply(&ctx, sink, r, "list_routes");
+    }
+    fn update_dns(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::UpdateDNSRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _span = trace::span("update_dns");
+        let _timer = metrics::rpc_timer("update_dns");
+        let r = self.do_update_dns(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "update_dns");
+    }
+    fn subscribe_memory_pressure(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::SubscribeMemoryPressureRequest,
+        sink: ::grpcio::ServerStreamingSink<protocols::agent::MemoryPressureEvent>,
+    ) {
+		let _span = trace::span("subscribe_memory_pressure");
+		let events = Arc::clone(&self.sandbox.lock().unwrap().pressure_events);
+		let sub = PressureEvents::subscribe(&events, req.container_ids.into_vec());
+
+		// no thread waits on the stream, the events are sent as they come;
+		// the subscription goes with the stream once the caller is gone
+		let events = stream::poll_fn(move || -> Poll<Option<(protocols::agent::MemoryPressureEvent, WriteFlags)>, grpcio::Error> {
+			let task = task::current();
+			match sub.next_or_wake(Box::new(move || task.notify())) {
+				Some(ev) => Ok(Async::Ready(Some((ev, WriteFlags::default())))),
+				None => Ok(Async::NotReady),
+			}
+		});
 
-        let f = sink
-            .success(routes)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+		ctx.spawn(sink.send_all(events)
+			.map(|_| ())
+			.map_err(|e| info!("memory pressure subscription closed: {:?}", e)));
     }
     fn start_tracing(
         &mut self,
@@ -1172,13 +1197,9 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::StartTracingRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        info!("start_tracing {:?} self.test={}", req, self.test);
-        self.test = 2;
-        let empty = protocols::empty::Empty::new();
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        info!("start_tracing {:?}", req);
+        let r = self.do_start_tracing().map(|_| Empty::new());
+        reply(&ctx, sink, r, "start_tracing");
     }
     fn stop_tracing(
         &mut self,
@@ -1186,11 +1207,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::StopTracingRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let empty = protocols::empty::Empty::new();
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let r = self.do_stop_tracing().map(|_| Empty::new());
+        reply(&ctx, sink, r, "stop_tracing");
     }
     fn create_sandbox(
         &mut self,
@@ -1198,61 +1216,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::CreateSandboxRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-		let mut err = "".to_string();
-
-		{
-			let sandbox = self.sandbox.clone();
-			let mut s = sandbox.lock().unwrap();
-
-			let _ = fs::remove_dir_all(CONTAINER_BASE);
-			let _ = fs::create_dir_all(CONTAINER_BASE);
-
-			s.hostname = req.hostname.clone();
-			s.running = true;
-
-			if req.sandbox_id.len() > 0 {
-				s.id = req.sandbox_id.clone();
-			}
-
-			match s.setup_shared_namespaces() {
-				Ok(t) => (),
-				Err(e) => err = e.to_string(),
-			}
-			if err.len() != 0 {
-				let rpc_status =
-					grpcio::RpcStatus::new(grpcio::RpcStatusCode::FailedPrecondition, Some(err));
-				let f = sink
-					.fail(rpc_status)
-					.map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-				ctx.spawn(f);
-				return;
-			}
-		}
-
-        match add_storages(req.storages.to_vec(), self.sandbox.clone()) {
-            Ok(m) => {
-				let sandbox = self.sandbox.clone();
-				let mut s = sandbox.lock().unwrap();
-				s.mounts = m
-			},
-            Err(e) => err = e.to_string(),
-        };
-
-        if err.len() != 0 {
-            let rpc_status =
-                grpcio::RpcStatus::new(grpcio::RpcStatusCode::FailedPrecondition, Some(err));
-            let f = sink
-                .fail(rpc_status)
-                .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-            ctx.spawn(f);
-            return;
-        }
-
-        let empty = protocols::empty::Empty::new();
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _span = trace::span("create_sandbox");
+        let _timer = metrics::rpc_timer("create_sandbox");
+        let r = self.do_create_sandbox(req).map(|_| Empty::new());
+        reply(&ctx, sink, r, "create_sandbox");
     }
     fn destroy_sandbox(
         &mut self,
@@ -1260,47 +1227,23 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::DestroySandboxRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-		let s = Arc::clone(&self.sandbox);
-		let mut sandbox = s.lock().unwrap();
-		// destroy all containers, clean up, notify agent to exit 
-		// etc.
-		sandbox.destroy().unwrap();
-
-		sandbox.sender.as_ref().unwrap().send(1).unwrap();
-		sandbox.sender = None;
-
-        let empty = protocols::empty::Empty::new();
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _span = trace::span("destroy_sandbox");
+        let _timer = metrics::rpc_timer("destroy_sandbox");
+        let r = self.do_destroy_sandbox().map(|_| Empty::new());
+        reply(&ctx, sink, r, "destroy_sandbox");
     }
     fn online_cpu_mem(
         &mut self,
         ctx: ::grpcio::RpcContext,
         req: protocols::agent::OnlineCPUMemRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-		// sleep 5 seconds for debug
-		// thread::sleep(Duration::new(5, 0));
-		let s = Arc::clone(&self.sandbox);
-		let sandbox = s.lock().unwrap();
-        let empty = protocols::empty::Empty::new();
-
-		if let Err(e) = sandbox.online_cpu_memory(&req) {
-			let f = sink.fail(RpcStatus::new(
-				RpcStatusCode::Internal,
-				Some("Internal error".to_string())))
-				.map_err(|_e| error!("cannot online memory/cpu"));
-			ctx.spawn(f);
-			return;
-		} 
-
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-
-        ctx.spawn(f)
+    ) {
+		let _span = trace::span("online_cpu_mem");
+		let _timer = metrics::rpc_timer("online_cpu_mem");
+		// sleep 5 seconds for debug
+		// thread::sleep(Duration::new(5, 0));
+		let r = self.do_online_cpu_mem(req).map(|_| Empty::new());
+		reply(&ctx, sink, r, "online_cpu_mem");
     }
     fn reseed_random_dev(
         &mut self,
@@ -1308,20 +1251,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReseedRandomDevRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let empty = protocols::empty::Empty::new();
-		if let Err(e) = random::reseed_rng(req.data.as_slice()) {
-			let f = sink.fail(RpcStatus::new(
-				RpcStatusCode::Internal,
-				Some("Internal error".to_string())))
-				.map_err(|_e| error!("fail to reseed rng!"));
-			ctx.spawn(f);
-			return;
-		}
-
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _span = trace::span("reseed_random_dev");
+        let _timer = metrics::rpc_timer("reseed_random_dev");
+        let r = random::reseed_rng(req.data.as_slice()).map(|_| Empty::new());
+        reply(&ctx, sink, r, "reseed_random_dev");
     }
     fn get_guest_details(
         &mut self,
@@ -1329,34 +1262,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::GuestDetailsRequest,
         sink: ::grpcio::UnarySink<protocols::agent::GuestDetailsResponse>,
     ) {
-		info!("get guest details!");
-		let mut resp = GuestDetailsResponse::new();
-		// to get memory block size
-		match get_memory_info(req.mem_block_size,
-				req.mem_hotplug_probe) {
-			Ok((u, v)) => {
-				resp.mem_block_size_bytes = u;
-				resp.support_mem_hotplug_probe = v;
-			}
-
-			Err(_) => {
-				info!("fail to get memory info!");
-				let f = sink.fail(RpcStatus::new(
-					RpcStatusCode::Internal,
-					Some(String::from("internal error"))))
-				.map_err(|_e| error!("cannot get memory info!"));
-				ctx.spawn(f);
-				return;
-			}
-		}
-
-		// to get agent details
-		let detail = get_agent_details();
-		resp.agent_details = SingularPtrField::some(detail);
-
-		let f = sink.success(resp)
-			.map_err(|_e| error!("cannot get guest detail"));
-		ctx.spawn(f);
+        let _span = trace::span("get_guest_details");
+        let _timer = metrics::rpc_timer("get_guest_details");
+        let r = self.do_get_guest_details(req);
+        reply(&ctx, sink, r, "get_guest_details");
     }
     fn mem_hotplug_by_probe(
         &mut self,
@@ -1364,21 +1273,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::MemHotplugByProbeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let empty = protocols::empty::Empty::new();
-
-		if let Err(e) = do_mem_hotplug_by_probe(&req.memHotplugProbeAddr) {
-			let f = sink.fail(RpcStatus::new(
-				RpcStatusCode::Internal,
-				Some("internal error!".to_string())))
-				.map_err(|_e| error!("cannont mem hotplug by probe!"));
-			ctx.spawn(f);
-			return;
-		}
-
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _span = trace::span("mem_hotplug_by_probe");
+        let _timer = metrics::rpc_timer("mem_hotplug_by_probe");
+        let r = do_mem_hotplug_by_probe(&req.memHotplugProbeAddr).map(|_| Empty::new());
+        reply(&ctx, sink, r, "mem_hotplug_by_probe");
     }
     fn set_guest_date_time(
         &mut self,
@@ -1386,46 +1284,70 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::SetGuestDateTimeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let empty = protocols::empty::Empty::new();
-		if let Err(e) = do_set_guest_date_time(req.Sec, req.Usec) {
-			let f = sink.fail(RpcStatus::new(
-				RpcStatusCode::Internal,
-				Some("internal error!".to_string())))
-				.map_err(|_e| error!("cannot set guest time!"));
-			ctx.spawn(f);
-			return;
-		}
-
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _span = trace::span("set_guest_date_time");
+        let _timer = metrics::rpc_timer("set_guest_date_time");
+        let r = do_set_guest_date_time(req.Sec, req.Usec).map(|_| Empty::new());
+        reply(&ctx, sink, r, "set_guest_date_time");
     }
     fn copy_file(
         &mut self,
         ctx: ::grpcio::RpcContext,
         req: protocols::agent::CopyFileRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        sink: ::grpcio::UnarySink<protocols::agent::CopyFileResponse>,
     ) {
-        let empty = protocols::empty::Empty::new();
-		if let Err(e) = do_copy_file(&req) {
-			let f = sink.fail(RpcStatus::new(
-				RpcStatusCode::Internal,
-				Some("Internal error!".to_string())))
-				.map_err(|_e| error!("cannot copy file!"));
-			ctx.spawn(f);
-			return;
-		}
-
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _span = trace::span("copy_file");
+        let _timer = metrics::rpc_timer("copy_file");
+        let r = files::copy_file(&req);
+        reply(&ctx, sink, r, "copy_file");
+    }
+    fn read_file(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ReadFileRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::ReadFileResponse>,
+    ) {
+        let _span = trace::span("read_file");
+        let _timer = metrics::rpc_timer("read_file");
+        let r = files::read_file(&req);
+        reply(&ctx, sink, r, "read_file");
+    }
+    fn get_metrics(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        _req: protocols::agent::GetMetricsRequest,
+        sink: ::grpcio::UnarySink<Metrics>,
+    ) {
+        let _span = trace::span("get_metrics");
+        let _timer = metrics::rpc_timer("get_metrics");
+        let r = self.do_get_metrics();
+        reply(&ctx, sink, r, "get_metrics");
     }
 }
 
 #[derive(Clone)]
-struct healthService;
+struct healthService {
+    sandbox: Arc<Mutex<Sandbox>>,
+}
+
+impl healthService {
+	fn do_check(&self, req: protocols::health::CheckRequest) -> Result<HealthCheckResponse> {
+		let mut resp = HealthCheckResponse::new();
+		resp.set_status(health::check(&self.sandbox, req.service.as_str())?);
+
+		Ok(resp)
+	}
+
+	fn do_version(&self, req: protocols::health::CheckRequest) -> Result<protocols::health::VersionCheckResponse> {
+		info!("version {:?}", req);
+		let mut rep = protocols::health::VersionCheckResponse::new();
+		rep.agent_version = AGENT_VERSION.to_string();
+		rep.grpc_version = API_VERSION.to_string();
+
+		Ok(rep)
+	}
+}
+
+#[cfg(feature = "grpc")]
 impl protocols::health_grpc::Health for healthService {
     fn check(
         &mut self,
@@ -1433,14 +1355,9 @@ impl protocols::health_grpc::Health for healthService {
         req: protocols::health::CheckRequest,
         sink: ::grpcio::UnarySink<protocols::health::HealthCheckResponse>,
     ) {
-		let mut resp = HealthCheckResponse::new();
-		resp.set_status(HealthCheckResponse_ServingStatus::SERVING);
-
-		let f = sink.success(resp)
-			.map_err(|_e| error!(
-			"cannot get health status"));
-
-		ctx.spawn(f);
+        let _timer = metrics::rpc_timer("check");
+        let r = self.do_check(req);
+        reply(&ctx, sink, r, "check");
     }
     fn version(
         &mut self,
@@ -1448,14 +1365,381 @@ impl protocols::health_grpc::Health for healthService {
         req: protocols::health::CheckRequest,
         sink: ::grpcio::UnarySink<protocols::health::VersionCheckResponse>,
     ) {
-        info!("version {:?}", req);
-        let mut rep = protocols::health::VersionCheckResponse::new();
-        rep.agent_version = AGENT_VERSION.to_string();
-        rep.grpc_version = API_VERSION.to_string();
-        let f = sink
-            .success(rep)
-            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        let _timer = metrics::rpc_timer("version");
+        let r = self.do_version(req);
+        reply(&ctx, sink, r, "version");
+    }
+}
+
+#[cfg(feature = "ttrpc")]
+impl protocols::agent_ttrpc::AgentService for agentService {
+    fn create_container(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::CreateContainerRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("create_container");
+        let _timer = metrics::rpc_timer("create_container");
+        let r = self.do_create_container(req).map(|_| Empty::new());
+        ttrpc_reply(r, "create_container")
+    }
+    fn start_container(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::StartContainerRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("start_container");
+        let _timer = metrics::rpc_timer("start_container");
+        let r = self.do_start_container(req).map(|_| Empty::new());
+        ttrpc_reply(r, "start_container")
+    }
+    fn remove_container(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::RemoveContainerRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("remove_container");
+        let _timer = metrics::rpc_timer("remove_container");
+        let r = self.do_remove_container(req).map(|_| Empty::new());
+        ttrpc_reply(r, "remove_container")
+    }
+    fn exec_process(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ExecProcessRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("exec_process");
+        let _timer = metrics::rpc_timer("exec_process");
+        let r = self.do_exec_process(req).map(|_| Empty::new());
+        ttrpc_reply(r, "exec_process")
+    }
+    fn signal_process(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::SignalProcessRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("signal_process");
+        let _timer = metrics::rpc_timer("signal_process");
+        let r = self.do_signal_process(req).map(|_| Empty::new());
+        ttrpc_reply(r, "signal_process")
+    }
+    fn wait_process(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::WaitProcessRequest,
+    ) -> ttrpc::Result<protocols::agent::WaitProcessResponse> {
+        let _span = trace::span("wait_process");
+        let _timer = metrics::rpc_timer("wait_process");
+        let r = self.do_wait_process(req);
+        ttrpc_reply(r, "wait_process")
+    }
+    fn list_processes(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ListProcessesRequest,
+    ) -> ttrpc::Result<protocols::agent::ListProcessesResponse> {
+        let _span = trace::span("list_processes");
+        let _timer = metrics::rpc_timer("list_processes");
+        let r = self.do_list_processes(req);
+        ttrpc_reply(r, "list_processes")
+    }
+    fn update_container(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::UpdateContainerRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("update_container");
+        let _timer = metrics::rpc_timer("update_container");
+        let r = self.do_update_container(req).map(|_| Empty::new());
+        ttrpc_reply(r, "update_container")
+    }
+    fn stats_container(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::StatsContainerRequest,
+    ) -> ttrpc::Result<protocols::agent::StatsContainerResponse> {
+        let _span = trace::span("stats_container");
+        let _timer = metrics::rpc_timer("stats_container");
+        let r = self.do_stats_container(req);
+        ttrpc_reply(r, "stats_container")
+    }
+    fn pause_container(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::PauseContainerRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("pause_container");
+        let _timer = metrics::rpc_timer("pause_container");
+        let r = self.do_pause_container(req).map(|_| Empty::new());
+        ttrpc_reply(r, "pause_container")
+    }
+    fn resume_container(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ResumeContainerRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("resume_container");
+        let _timer = metrics::rpc_timer("resume_container");
+        let r = self.do_resume_container(req).map(|_| Empty::new());
+        ttrpc_reply(r, "resume_container")
+    }
+    fn get_oom_event(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: protocols::agent::GetOOMEventRequest,
+    ) -> ttrpc::Result<OOMEvent> {
+        let _span = trace::span("get_oom_event");
+        let _timer = metrics::rpc_timer("get_oom_event");
+        let r = self.do_get_oom_event();
+        ttrpc_reply(r, "get_oom_event")
+    }
+    fn write_stdin(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::WriteStreamRequest,
+    ) -> ttrpc::Result<protocols::agent::WriteStreamResponse> {
+        let _span = trace::span("write_stdin");
+        let _timer = metrics::rpc_timer("write_stdin");
+        let r = self.do_write_stdin(req);
+        ttrpc_reply(r, "write_stdin")
+    }
+    fn read_stdout(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ReadStreamRequest,
+    ) -> ttrpc::Result<protocols::agent::ReadStreamResponse> {
+        let _span = trace::span("read_stdout");
+        let _timer = metrics::rpc_timer("read_stdout");
+        let r = self.do_read_stream(req, false);
+        ttrpc_reply(r, "read_stdout")
+    }
+    fn read_stderr(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ReadStreamRequest,
+    ) -> ttrpc::Result<protocols::agent::ReadStreamResponse> {
+        let _span = trace::span("read_stderr");
+        let _timer = metrics::rpc_timer("read_stderr");
+        let r = self.do_read_stream(req, true);
+        ttrpc_reply(r, "read_stderr")
+    }
+    fn close_stdin(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::CloseStdinRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("close_stdin");
+        let _timer = metrics::rpc_timer("close_stdin");
+        let r = self.do_close_stdin(req).map(|_| Empty::new());
+        ttrpc_reply(r, "close_stdin")
+    }
+    fn tty_win_resize(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::TtyWinResizeRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("tty_win_resize");
+        let _timer = metrics::rpc_timer("tty_win_resize");
+        let r = self.do_tty_win_resize(req).map(|_| Empty::new());
+        ttrpc_reply(r, "tty_win_resize")
+    }
+    fn read_output(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ReadOutputRequest,
+    ) -> ttrpc::Result<protocols::agent::ReadOutputResponse> {
+        let _span = trace::span("read_output");
+        let _timer = metrics::rpc_timer("read_output");
+        // the server runs every call on a thread of its own
+        let r = self.get_output_capture(&req).map(|c| read_output(&c, &req));
+        ttrpc_reply(r, "read_output")
+    }
+    fn update_interface(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::UpdateInterfaceRequest,
+    ) -> ttrpc::Result<protocols::types::Interface> {
+        let _span = trace::span("update_interface");
+        let _timer = metrics::rpc_timer("update_interface");
+        let r = self.do_update_interface(req);
+        ttrpc_reply(r, "update_interface")
+    }
+    fn update_routes(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::UpdateRoutesRequest,
+    ) -> ttrpc::Result<protocols::agent::Routes> {
+        let _span = trace::span("update_routes");
+        let _timer = metrics::rpc_timer("update_routes");
+        let r = self.do_update_routes(req);
+        ttrpc_reply(r, "update_routes")
+    }
+    fn list_interfaces(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: protocols::agent::ListInterfacesRequest,
+    ) -> ttrpc::Result<protocols::agent::Interfaces> {
+        let _span = trace::span("list_interfaces");
+        let _timer = metrics::rpc_timer("list_interfaces");
+        let r = self.do_list_interfaces();
+        ttrpc_reply(r, "list_interfaces")
+    }
+    fn list_routes(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: protocols::agent::ListRoutesRequest,
+    ) -> ttrpc::Result<protocols::agent::Routes> {
+        let _span = trace::span("list_routes");
+        let _timer = metrics::rpc_timer("list_routes");
+        let r = self.do_list_routes();
+        ttrpc_reply(r, "list_routes")
+    }
+    fn update_dns(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::UpdateDNSRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("update_dns");
+        let _timer = metrics::rpc_timer("update_dns");
+        let r = self.do_update_dns(req).map(|_| Empty::new());
+        ttrpc_reply(r, "update_dns")
+    }
+    fn start_tracing(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::StartTracingRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        info!("start_tracing {:?}", req);
+        let r = self.do_start_tracing().map(|_| Empty::new());
+        ttrpc_reply(r, "start_tracing")
+    }
+    fn stop_tracing(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: protocols::agent::StopTracingRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let r = self.do_stop_tracing().map(|_| Empty::new());
+        ttrpc_reply(r, "stop_tracing")
+    }
+    fn create_sandbox(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::CreateSandboxRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("create_sandbox");
+        let _timer = metrics::rpc_timer("create_sandbox");
+        let r = self.do_create_sandbox(req).map(|_| Empty::new());
+        ttrpc_reply(r, "create_sandbox")
+    }
+    fn destroy_sandbox(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: protocols::agent::DestroySandboxRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("destroy_sandbox");
+        let _timer = metrics::rpc_timer("destroy_sandbox");
+        let r = self.do_destroy_sandbox().map(|_| Empty::new());
+        ttrpc_reply(r, "destroy_sandbox")
+    }
+    fn online_cpu_mem(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::OnlineCPUMemRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("online_cpu_mem");
+        let _timer = metrics::rpc_timer("online_cpu_mem");
+        let r = self.do_online_cpu_mem(req).map(|_| Empty::new());
+        ttrpc_reply(r, "online_cpu_mem")
+    }
+    fn reseed_random_dev(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ReseedRandomDevRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("reseed_random_dev");
+        let _timer = metrics::rpc_timer("reseed_random_dev");
+        let r = random::reseed_rng(req.data.as_slice()).map(|_| Empty::new());
+        ttrpc_reply(r, "reseed_random_dev")
+    }
+    fn get_guest_details(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::GuestDetailsRequest,
+    ) -> ttrpc::Result<protocols::agent::GuestDetailsResponse> {
+        let _span = trace::span("get_guest_details");
+        let _timer = metrics::rpc_timer("get_guest_details");
+        let r = self.do_get_guest_details(req);
+        ttrpc_reply(r, "get_guest_details")
+    }
+    fn mem_hotplug_by_probe(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::MemHotplugByProbeRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("mem_hotplug_by_probe");
+        let _timer = metrics::rpc_timer("mem_hotplug_by_probe");
+        let r = do_mem_hotplug_by_probe(&req.memHotplugProbeAddr).map(|_| Empty::new());
+        ttrpc_reply(r, "mem_hotplug_by_probe")
+    }
+    fn set_guest_date_time(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::SetGuestDateTimeRequest,
+    ) -> ttrpc::Result<protocols::empty::Empty> {
+        let _span = trace::span("set_guest_date_time");
+        let _timer = metrics::rpc_timer("set_guest_date_time");
+        let r = do_set_guest_date_time(req.Sec, req.Usec).map(|_| Empty::new());
+        ttrpc_reply(r, "set_guest_date_time")
+    }
+    fn copy_file(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::CopyFileRequest,
+    ) -> ttrpc::Result<protocols::agent::CopyFileResponse> {
+        let _span = trace::span("copy_file");
+        let _timer = metrics::rpc_timer("copy_file");
+        let r = files::copy_file(&req);
+        ttrpc_reply(r, "copy_file")
+    }
+    fn read_file(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::agent::ReadFileRequest,
+    ) -> ttrpc::Result<protocols::agent::ReadFileResponse> {
+        let _span = trace::span("read_file");
+        let _timer = metrics::rpc_timer("read_file");
+        let r = files::read_file(&req);
+        ttrpc_reply(r, "read_file")
+    }
+    fn get_metrics(
+        &self,
+        _ctx: &TtrpcContext,
+        _req: protocols::agent::GetMetricsRequest,
+    ) -> ttrpc::Result<Metrics> {
+        let _span = trace::span("get_metrics");
+        let _timer = metrics::rpc_timer("get_metrics");
+        let r = self.do_get_metrics();
+        ttrpc_reply(r, "get_metrics")
+    }
+}
+
+#[cfg(feature = "ttrpc")]
+impl protocols::health_ttrpc::Health for healthService {
+    fn check(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::health::CheckRequest,
+    ) -> ttrpc::Result<protocols::health::HealthCheckResponse> {
+        let _timer = metrics::rpc_timer("check");
+        ttrpc_reply(self.do_check(req), "check")
+    }
+    fn version(
+        &self,
+        _ctx: &TtrpcContext,
+        req: protocols::health::CheckRequest,
+    ) -> ttrpc::Result<protocols::health::VersionCheckResponse> {
+        let _timer = metrics::rpc_timer("version");
+        ttrpc_reply(self.do_version(req), "version")
     }
 }
 
@@ -1482,12 +1766,12 @@ fn get_memory_info(block_size: bool, hotplug: bool) -> Result<(u64, bool)> {
 		}
 	}
 
-	if hotplug {
+	// the probe is of no use when we cannot write to it
+	if hotplug && cfg!(feature = "mem-hotplug") {
 		match stat::stat(SYSFS_MEMORY_HOTPLUG_PROBE_PATH) {
 			Ok(_) => plug = true,
 			Err(e) => {
-				info!("hotplug memory error: {}",
-					e.as_errno().unwrap().desc());
+				info!("hotplug memory error: {}", e);
 				match e {
 					nix::Error::Sys(errno) => {
 						match errno {
@@ -1508,22 +1792,106 @@ fn get_agent_details() -> AgentDetails {
 	let mut detail = AgentDetails::new();
 
 	detail.set_version(AGENT_VERSION.to_string());
-	detail.set_supports_seccomp(false);
+	detail.kernel_seccomp = kernel_supports_seccomp();
+	detail.supports_seccomp = SECCOMP_PROFILES_APPLIED && detail.kernel_seccomp;
 	detail.init_daemon = {
 		unistd::getpid() == Pid::from_raw(1)
 	};
 
-	detail.device_handlers = RepeatedField::new();
-	detail.storage_handlers = RepeatedField::from_vec(
-							STORAGEHANDLERLIST
-							.keys()
-							.cloned()
-							.map(|x| x.into())
-							.collect());
-	
+	// what cannot be found out is left empty, the rest is still useful
+	match fs::read_to_string(PROC_MOUNTS) {
+		Ok(mounts) => detail.cgroup_version = cgroup_version(mounts.as_str()).to_string(),
+		Err(e) => warn!("cannot read {}: {}", PROC_MOUNTS, e),
+	}
+	let controllers = if detail.cgroup_version == "v2" {
+		fs::read_to_string(CGROUP2_CONTROLLERS)
+			.map(|c| c.split_whitespace().map(|x| x.to_string()).collect())
+	} else {
+		fs::read_to_string(PROC_CGROUPS).map(|c| cgroup_v1_controllers(c.as_str()))
+	};
+	match controllers {
+		Ok(c) => detail.cgroup_controllers = RepeatedField::from_vec(c),
+		Err(e) => warn!("cannot list the cgroup controllers: {}", e),
+	}
+
+	detail.transport = match AGENT_CONFIG.read().unwrap().server() {
+		ServerAddr::Vsock(_, _) => "vsock",
+		ServerAddr::Serial(_) => "serial",
+		ServerAddr::Unix(_) => "unix",
+		ServerAddr::Tcp(_, _) => "tcp",
+	}.to_string();
+
+	detail.kernel_version = nix::sys::utsname::uname().release().to_string();
+
+	// only what this build can handle, sorted so that it reads the same
+	// every time
+	let mut devices: Vec<String> = DEVICEHANDLERLIST.keys().map(|x| x.to_string()).collect();
+	devices.sort();
+	let mut storages: Vec<String> = STORAGEHANDLERLIST.keys().map(|x| x.to_string()).collect();
+	storages.sort();
+
+	detail.device_handlers = RepeatedField::from_vec(devices);
+	detail.storage_handlers = RepeatedField::from_vec(storages);
+
 	detail
 }
 
+// the way libseccomp probes it: a NULL filter is EFAULT when the kernel
+// has filters, EINVAL when it does not
+fn kernel_supports_seccomp() -> bool {
+	let ret = unsafe {
+		libc::prctl(libc::PR_SET_SECCOMP, SECCOMP_MODE_FILTER, 0 as libc::c_ulong)
+	};
+
+	ret < 0 && Errno::last() == Errno::EFAULT
+}
+
+// cgroup2 alone is v2, cgroup2 next to v1 controllers (on
+// /sys/fs/cgroup/unified usually) hybrid
+fn cgroup_version(mounts: &str) -> &'static str {
+	let mut v1 = false;
+	let mut v2 = false;
+
+	for line in mounts.lines() {
+		let fields: Vec<&str> = line.split_whitespace().collect();
+		if fields.len() < 3 || !fields[1].starts_with(CGROUP_ROOT) {
+			continue;
+		}
+
+		match fields[2] {
+			"cgroup" => v1 = true,
+			"cgroup2" => v2 = true,
+			_ => {}
+		}
+	}
+
+	match (v1, v2) {
+		(false, true) => "v2",
+		(true, true) => "hybrid",
+		_ => "v1",
+	}
+}
+
+// subsys_name hierarchy num_cgroups enabled
+fn cgroup_v1_controllers(cgroups: &str) -> Vec<String> {
+	cgroups.lines()
+		.filter(|l| !l.starts_with('#'))
+		.filter_map(|l| {
+			let fields: Vec<&str> = l.split_whitespace().collect();
+			if fields.len() == 4 && fields[3] == "1" {
+				Some(fields[0].to_string())
+			} else {
+				None
+			}
+		})
+		.collect()
+}
+
+// for what was left out at build time, see the features in Cargo.toml
+fn not_built_in(what: &str) -> Error {
+	ErrorKind::Unimplemented(format!("{} support is not built in", what)).into()
+}
+
 fn read_stream(fd: RawFd, cid: &str, eid: &str, l: usize) -> Result<Vec<u8>> {
 	let mut v: Vec<u8> = Vec::with_capacity(l);
 	unsafe { v.set_len(l); }
@@ -1556,32 +1924,110 @@ fn read_stream(fd: RawFd, cid: &str, eid: &str, l: usize) -> Result<Vec<u8>> {
 	Ok(v)
 }
 
-fn find_process<'a>(sandbox: &'a mut Sandbox, cid: &'a str, eid: &'a str, init: bool) -> Result<&'a mut Process> {
-	let ctr = match sandbox.get_container(cid) {
-		Some(v) => v,
-		None => return Err(ErrorKind::ErrorCode(
-			String::from("Invalid container id")).into()),
+// read from the output capture on behalf of ReadStdout/ReadStderr
+fn read_capture(c: &Capture, stderr: bool, l: usize) -> Result<Vec<u8>> {
+	let mut cursor = c.cursor(stderr).lock().unwrap();
+
+	let chunk = c.stream(stderr).read(*cursor, l);
+	if chunk.data.is_empty() && chunk.eof {
+		return Err(ErrorKind::ErrorCode("read  meet eof".to_string()).into());
+	}
+
+	*cursor = chunk.offset + chunk.data.len() as u64;
+
+	Ok(chunk.data)
+}
+
+fn read_output(c: &Capture, req: &protocols::agent::ReadOutputRequest) -> ReadOutputResponse {
+	let len = if req.len == 0 { capture::DEFAULT_BUFFER_SIZE } else { req.len as usize };
+	let chunk = c.stream(req.stderr).read(req.offset, len);
+
+	let mut resp = ReadOutputResponse::new();
+	resp.set_data(chunk.data);
+	resp.set_offset(chunk.offset);
+	resp.set_dropped(chunk.dropped);
+	resp.set_eof(chunk.eof);
+
+	resp
+}
+
+fn container_base() -> String {
+	AGENT_CONFIG.read().unwrap().container_base.clone()
+}
+
+// the user of a process: string_user when the runtime sent one, else the
+// user[:group] an image gives as username; the names are looked up in the
+// container rootfs
+fn resolve_user(rootfs: &str, ocip: &mut protocols::oci::Process, su: Option<&StringUser>) -> Result<()> {
+	let (user, group, additional) = match su {
+		Some(su) => (su.uid.clone(), su.gid.clone(), su.additionalGids.to_vec()),
+		None => {
+			let name = ocip.User.as_ref().map(|u| u.Username.clone()).unwrap_or_default();
+			let mut parts = name.splitn(2, ':');
+			let user = parts.next().unwrap_or_default().to_string();
+			let group = parts.next().unwrap_or_default().to_string();
+			(user, group, Vec::new())
+		}
 	};
 
+	let rootfs = fs::canonicalize(rootfs)
+		.chain_err(|| format!("bad container rootfs {}", rootfs))?;
+	rustjail::user::setup_user(&rootfs, ocip, user.as_str(), group.as_str(), additional.as_slice())
+		.chain_err(|| "cannot resolve the user of the process")
+}
+
+fn get_container<'a>(sandbox: &'a mut Sandbox, cid: &str) -> Result<&'a mut LinuxContainer> {
+	match sandbox.get_container(cid) {
+		Some(v) => Ok(v),
+		None => Err(ErrorKind::NotFound(
+			format!("invalid container id {}", cid)).into()),
+	}
+}
+
+fn find_process<'a>(sandbox: &'a mut Sandbox, cid: &'a str, eid: &'a str, init: bool) -> Result<&'a mut Process> {
+	let ctr = get_container(sandbox, cid)?;
+
 	if init && eid == "" {
 		let p = match ctr.processes.get_mut(&ctr.init_process_pid) {
 			Some(v) => v,
-			None =>  return Err(ErrorKind::ErrorCode(
+			None =>  return Err(ErrorKind::NotFound(
 				String::from("cannot find init process!")).into()),
 		};
 
 		return Ok(p);
 	}
 
-	let p = match ctr.get_process(eid) {
+	ctr.get_process(eid)
+}
+
+// the containers share the network namespace of the sandbox, the stats
+// are left out when netlink fails rather than failing the cgroup ones
+#[cfg(feature = "netlink")]
+fn network_stats(sandbox: &mut Sandbox) -> Vec<protocols::agent::NetworkStats> {
+	match get_rtnl(sandbox).and_then(|rtnl| rtnl.list_link_stats()) {
 		Ok(v) => v,
-		Err(_) => return Err(ErrorKind::ErrorCode(
-			"Invalid exec id".to_string()).into()),
-	};
+		Err(e) => {
+			warn!("cannot get the network stats: {}", e);
+			Vec::new()
+		}
+	}
+}
+
+#[cfg(not(feature = "netlink"))]
+fn network_stats(_sandbox: &mut Sandbox) -> Vec<protocols::agent::NetworkStats> {
+	Vec::new()
+}
+
+#[cfg(feature = "netlink")]
+fn get_rtnl(sandbox: &mut Sandbox) -> Result<&mut RtnlHandle> {
+	if sandbox.rtnl.is_none() {
+		sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0)?);
+	}
 
-	Ok(p)
+	Ok(sandbox.rtnl.as_mut().unwrap())
 }
 
+#[cfg(feature = "grpc")]
 pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16) -> Server {
     let env = Arc::new(EnvBuilder::new()
 	.cq_count(1)
@@ -1590,11 +2036,12 @@ pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16)
         .wait_thread_count_max(10)
 	.build());
     let worker = agentService {
-        sandbox: sandbox,
-        test: 1,
+        sandbox: sandbox.clone(),
     };
     let service = protocols::agent_grpc::create_agent_service(worker);
-    let hservice = protocols::health_grpc::create_health(healthService);
+    let hservice = protocols::health_grpc::create_health(healthService {
+        sandbox: sandbox,
+    });
     let mut server = ServerBuilder::new(env)
         .register_service(service)
         .register_service(hservice)
@@ -1611,6 +2058,27 @@ pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16)
     server
 }
 
+// the listener is bound by the caller, ttrpc takes any stream socket
+#[cfg(feature = "ttrpc")]
+pub fn start_ttrpc(sandbox: Arc<Mutex<Sandbox>>, listener: File) -> Result<ttrpc::Server> {
+    let worker: Box<dyn protocols::agent_ttrpc::AgentService + Send + Sync> = Box::new(agentService {
+        sandbox: sandbox.clone(),
+    });
+    let health: Box<dyn protocols::health_ttrpc::Health + Send + Sync> = Box::new(healthService {
+        sandbox: sandbox,
+    });
+
+    let mut server = ttrpc::Server::new()
+        .add_listener(listener)
+        .register_service(protocols::agent_ttrpc::create_agent_service(Arc::new(worker)))
+        .register_service(protocols::health_ttrpc::create_health(Arc::new(health)));
+    server.start()
+        .map_err(|e| ErrorKind::ErrorCode(format!("cannot start the ttrpc server: {}", e)))?;
+    info!("ttrpc server started");
+
+    Ok(server)
+}
+
 // This function updates the container namespaces configuration based on the
 // sandbox information. When the sandbox is created, it can be setup in a way
 // that all containers will share some specific namespaces. This is the agent
@@ -1634,6 +2102,10 @@ fn update_container_namespaces(sandbox: &Sandbox, spec: &mut Spec) -> Result<()>
     for namespace in namespaces.iter_mut() {
 		if namespace.Type == NSTYPEPID {
 			pidNs = true;
+			// join the namespace of the pause process
+			if sandbox.sandbox_pid_ns {
+				namespace.Path = sandbox.shared_pidns.path.clone();
+			}
 			continue
 		}
         if namespace.Type == NSTYPEIPC {
@@ -1646,15 +2118,35 @@ fn update_container_namespaces(sandbox: &Sandbox, spec: &mut Spec) -> Result<()>
         }
     };
 
-	if !pidNs && !sandbox.sandbox_pid_ns {
+	if !pidNs {
 		let mut pid_ns = LinuxNamespace::new();
 		pid_ns.set_Type(NSTYPEPID.to_string());
+		if sandbox.sandbox_pid_ns {
+			pid_ns.set_Path(sandbox.shared_pidns.path.clone());
+		}
 		linux.Namespaces.push(pid_ns);
 	}
 
     Ok(())
 }
 
+// Once the sandbox manages the dns, every container sees the shared
+// resolv.conf instead of the one from the image or the host.
+fn update_container_dns(sandbox: &Sandbox, spec: &mut Spec) {
+	if !sandbox.network.has_dns() {
+		return;
+	}
+
+	spec.Mounts.retain(|m| m.destination != RESOLV_CONF_DEST);
+
+	let mut m = oci::Mount::new();
+	m.set_destination(RESOLV_CONF_DEST.to_string());
+	m.set_source(RESOLV_CONF_PATH.to_string());
+	m.set_field_type("bind".to_string());
+	m.set_options(RepeatedField::from_vec(vec!["rbind".to_string(), "ro".to_string()]));
+	spec.Mounts.push(m);
+}
+
 // Check is the container process installed the
 // handler for specific signal.
 fn is_signal_handled(pid: pid_t, signum: u32) -> bool {
@@ -1702,6 +2194,7 @@ fn is_signal_handled(pid: pid_t, signum: u32) -> bool {
 	false
 }
 
+#[cfg(feature = "mem-hotplug")]
 fn do_mem_hotplug_by_probe(addrs: &Vec<u64>) -> Result<()> {
 	for addr in addrs.iter() {
 		fs::write(SYSFS_MEMORY_HOTPLUG_PROBE_PATH, format!("{:#X}", *addr))?;
@@ -1709,6 +2202,11 @@ fn do_mem_hotplug_by_probe(addrs: &Vec<u64>) -> Result<()> {
 	Ok(())
 }
 
+#[cfg(not(feature = "mem-hotplug"))]
+fn do_mem_hotplug_by_probe(_addrs: &Vec<u64>) -> Result<()> {
+	Err(not_built_in("memory hotplug"))
+}
+
 fn do_set_guest_date_time(sec: i64, usec: i64) -> Result<()> {
 	let tv = libc::timeval {
 		tv_sec: sec,
@@ -1723,57 +2221,6 @@ fn do_set_guest_date_time(sec: i64, usec: i64) -> Result<()> {
 	Ok(())
 }
 
-fn do_copy_file(req: &CopyFileRequest) -> Result<()> {
-	let path = fs::canonicalize(req.path.as_str())?;
-
-	if !path.starts_with(CONTAINER_BASE) {
-		return Err(nix::Error::Sys(Errno::EINVAL).into());
-	}
-
-	let parent = path.parent();
-
-	let dir = if parent.is_some() {
-		parent.unwrap().to_path_buf()
-	} else {
-		PathBuf::from("/")
-	};
-
-	if let Err(e) = fs::create_dir_all(dir.to_str().unwrap()) {
-		if e.kind() != std::io::ErrorKind::AlreadyExists {
-			return Err(e.into());
-		}
-	}
-
-	let ret = unsafe { libc::chmod(dir.to_str().unwrap().as_ptr() as *const libc::c_char, req.dir_mode) };
-
-	let _ = Errno::result(ret).map(drop)?;
-
-	let mut tmpfile = path.clone();
-	tmpfile.set_extension("tmp");
-
-	let file = OpenOptions::new()
-				.write(true)
-				.create(true)
-				.truncate(false)
-				.open(tmpfile.to_str().unwrap())?;
-	file.write_all_at(req.data.as_slice(), req.offset as u64)?;
-
-	let st = stat::stat(tmpfile.to_str().unwrap())?;
-
-	if st.st_size != req.file_size {
-		return Ok(());
-	}
-
-	let ret = unsafe { libc::chmod(tmpfile.to_str().unwrap().as_ptr() as *const libc::c_char, req.file_mode) };
-
-	let _ = Errno::result(ret).map(drop)?;
-	unistd::chown(tmpfile.to_str().unwrap(), Some(Uid::from_raw(req.uid as u32)), Some(Gid::from_raw(req.gid as u32)))?;
-
-	fs::rename(tmpfile, path)?;
-
-	Ok(())
-}
-
 fn setup_bundle(gspec: &Spec) -> Result<()>{
 	if gspec.Root.is_none() {
 		return Err(nix::Error::Sys(Errno::EINVAL).into());
@@ -1786,10 +2233,37 @@ fn setup_bundle(gspec: &Spec) -> Result<()>{
 	let config = format!("{}/{}", bundle_path, "config.json");
 
 	let oci = rustjail::grpc_to_oci(gspec);
-	info!("{:?}", oci.process.as_ref().unwrap().console_size.as_ref());
+	info!("{:?}", oci.process.as_ref().and_then(|p| p.console_size.as_ref()));
 	let _ = oci.save(config.as_str());
 
 	unistd::chdir(bundle_path)?;
 
 	Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_cgroup_version() {
+		let v1 = "sysfs /sys sysfs rw 0 0\n\
+			tmpfs /sys/fs/cgroup tmpfs rw,mode=755 0 0\n\
+			cgroup /sys/fs/cgroup/memory cgroup rw,memory 0 0\n";
+		assert_eq!(cgroup_version(v1), "v1");
+
+		let hybrid = format!("{}cgroup2 /sys/fs/cgroup/unified cgroup2 rw 0 0\n", v1);
+		assert_eq!(cgroup_version(hybrid.as_str()), "hybrid");
+
+		assert_eq!(cgroup_version("cgroup2 /sys/fs/cgroup cgroup2 rw 0 0\n"), "v2");
+	}
+
+	#[test]
+	fn test_cgroup_v1_controllers() {
+		let cgroups = "#subsys_name\thierarchy\tnum_cgroups\tenabled\n\
+			cpuset\t2\t1\t1\n\
+			memory\t3\t1\t1\n\
+			rdma\t0\t1\t0\n";
+		assert_eq!(cgroup_v1_controllers(cgroups), vec!["cpuset", "memory"]);
+	}
+}
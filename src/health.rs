@@ -0,0 +1,204 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// What the Health service answers: the sandbox as the RPCs left it, the
+// storages mounted for it, and the threads of the agent that nothing
+// would restart once gone.
+
+use protocols::health::HealthCheckResponse_ServingStatus as ServingStatus;
+use rustjail::errors::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::mount::get_mount_fs_type;
+use crate::sandbox::Sandbox;
+
+// the services a check can ask for, "" is all of them
+pub const SERVICE_SANDBOX: &'static str = "sandbox";
+pub const SERVICE_STORAGE: &'static str = "storage";
+pub const SERVICE_UEVENT: &'static str = "uevent";
+pub const SERVICE_REAPER: &'static str = "reaper";
+
+// a sandbox locked for longer is either wedged or busy with something
+// long, which of the two is not known
+const LOCK_TIMEOUT_MS: u64 = 1000;
+const LOCK_RETRY_MS: u64 = 10;
+
+const NOT_STARTED: usize = 0;
+const RUNNING: usize = 1;
+const EXITED: usize = 2;
+
+pub static UEVENT: Worker = Worker::new(SERVICE_UEVENT);
+pub static REAPER: Worker = Worker::new(SERVICE_REAPER);
+
+pub struct Worker {
+	name: &'static str,
+	state: AtomicUsize,
+}
+
+// marks the worker gone when dropped, a panic included
+pub struct WorkerGuard(&'static Worker);
+
+impl Worker {
+	const fn new(name: &'static str) -> Worker {
+		Worker {
+			name: name,
+			state: AtomicUsize::new(NOT_STARTED),
+		}
+	}
+
+	// the first thing the thread does, the guard lives as long as it
+	pub fn run(&'static self) -> WorkerGuard {
+		self.state.store(RUNNING, Ordering::SeqCst);
+		WorkerGuard(self)
+	}
+
+	fn started(&self) -> bool {
+		self.state.load(Ordering::SeqCst) != NOT_STARTED
+	}
+
+	fn status(&self) -> ServingStatus {
+		match self.state.load(Ordering::SeqCst) {
+			RUNNING => ServingStatus::SERVING,
+			EXITED => ServingStatus::NOT_SERVING,
+			_ => ServingStatus::UNKNOWN,
+		}
+	}
+}
+
+impl Drop for WorkerGuard {
+	fn drop(&mut self) {
+		error!("the {} thread is gone", self.0.name);
+		self.0.state.store(EXITED, Ordering::SeqCst);
+	}
+}
+
+pub fn check(sandbox: &Mutex<Sandbox>, service: &str) -> Result<ServingStatus> {
+	let status = match service {
+		"" => {
+			let mut statuses = match lock(sandbox) {
+				Ok(s) => vec![sandbox_status(&s), storage_status(&s)],
+				Err(status) => vec![status],
+			};
+			statuses.push(REAPER.status());
+			// not there at all for an agent running outside a VM
+			if UEVENT.started() {
+				statuses.push(UEVENT.status());
+			}
+
+			worst(statuses)
+		}
+		SERVICE_SANDBOX => lock(sandbox).map(|s| sandbox_status(&s)).unwrap_or_else(|e| e),
+		SERVICE_STORAGE => lock(sandbox).map(|s| storage_status(&s)).unwrap_or_else(|e| e),
+		SERVICE_UEVENT => UEVENT.status(),
+		SERVICE_REAPER => REAPER.status(),
+		_ => return Err(ErrorKind::NotFound(format!("unknown service {}", service)).into()),
+	};
+
+	Ok(status)
+}
+
+fn lock(sandbox: &Mutex<Sandbox>) -> std::result::Result<MutexGuard<Sandbox>, ServingStatus> {
+	let deadline = Instant::now() + Duration::from_millis(LOCK_TIMEOUT_MS);
+
+	loop {
+		match sandbox.try_lock() {
+			Ok(s) => return Ok(s),
+			Err(TryLockError::Poisoned(_)) => {
+				warn!("health: the sandbox lock is poisoned");
+				return Err(ServingStatus::NOT_SERVING);
+			}
+			Err(TryLockError::WouldBlock) => {
+				if Instant::now() >= deadline {
+					warn!("health: the sandbox is locked for over {}ms", LOCK_TIMEOUT_MS);
+					return Err(ServingStatus::UNKNOWN);
+				}
+				thread::sleep(Duration::from_millis(LOCK_RETRY_MS));
+			}
+		}
+	}
+}
+
+// from CreateSandbox until DestroySandbox
+fn sandbox_status(sandbox: &Sandbox) -> ServingStatus {
+	if sandbox.running {
+		ServingStatus::SERVING
+	} else {
+		ServingStatus::NOT_SERVING
+	}
+}
+
+fn storage_status(sandbox: &Sandbox) -> ServingStatus {
+	for m in sandbox.mounts.iter() {
+		if get_mount_fs_type(m.as_str()).is_err() {
+			warn!("health: storage {} is no longer mounted", m);
+			return ServingStatus::NOT_SERVING;
+		}
+	}
+
+	ServingStatus::SERVING
+}
+
+fn worst(statuses: Vec<ServingStatus>) -> ServingStatus {
+	let rank = |s: &ServingStatus| match s {
+		ServingStatus::SERVING => 0,
+		ServingStatus::UNKNOWN => 1,
+		ServingStatus::NOT_SERVING => 2,
+	};
+
+	statuses.into_iter().max_by_key(rank).unwrap_or(ServingStatus::SERVING)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::Arc;
+
+	static WORKER: Worker = Worker::new("test");
+
+	#[test]
+	fn test_worker() {
+		assert_eq!(WORKER.status(), ServingStatus::UNKNOWN);
+
+		let guard = WORKER.run();
+		assert_eq!(WORKER.status(), ServingStatus::SERVING);
+
+		drop(guard);
+		assert_eq!(WORKER.status(), ServingStatus::NOT_SERVING);
+	}
+
+	#[test]
+	fn test_check() {
+		let sandbox = Arc::new(Mutex::new(Sandbox::default()));
+
+		assert_eq!(check(&sandbox, SERVICE_SANDBOX).unwrap(), ServingStatus::NOT_SERVING);
+		assert_eq!(check(&sandbox, "").unwrap(), ServingStatus::NOT_SERVING);
+
+		sandbox.lock().unwrap().running = true;
+		assert_eq!(check(&sandbox, SERVICE_SANDBOX).unwrap(), ServingStatus::SERVING);
+		assert_eq!(check(&sandbox, SERVICE_STORAGE).unwrap(), ServingStatus::SERVING);
+
+		sandbox.lock().unwrap().mounts.push("/nonexistent/kata-storage".to_string());
+		assert_eq!(check(&sandbox, SERVICE_STORAGE).unwrap(), ServingStatus::NOT_SERVING);
+
+		assert!(check(&sandbox, "nonexistent").is_err());
+
+		let s = sandbox.clone();
+		let _ = thread::spawn(move || {
+			let _guard = s.lock().unwrap();
+			panic!("poisoning the sandbox lock");
+		}).join();
+		assert_eq!(check(&sandbox, SERVICE_SANDBOX).unwrap(), ServingStatus::NOT_SERVING);
+	}
+
+	#[test]
+	fn test_worst() {
+		assert_eq!(worst(vec![]), ServingStatus::SERVING);
+		assert_eq!(worst(vec![ServingStatus::SERVING, ServingStatus::UNKNOWN]), ServingStatus::UNKNOWN);
+		assert_eq!(worst(vec![ServingStatus::NOT_SERVING, ServingStatus::UNKNOWN]), ServingStatus::NOT_SERVING);
+	}
+}
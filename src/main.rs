@@ -20,7 +20,7 @@ extern crate regex;
 extern crate scan_fmt;
 extern crate oci;
 
-use futures::sync::oneshot;
+#[cfg(feature = "grpc")]
 use futures::*;
 use log::LevelFilter;
 use std::fs;
@@ -36,6 +36,14 @@ use std::sync::mpsc::{self, Sender, Receiver};
 use signal_hook::{iterator::Signals, SIGCHLD};
 use nix::sys::wait::{self, WaitStatus};
 use std::os::unix::io::AsRawFd;
+#[cfg(feature = "ttrpc")]
+use std::fs::File;
+#[cfg(feature = "ttrpc")]
+use std::os::unix::io::{FromRawFd, IntoRawFd};
+#[cfg(feature = "ttrpc")]
+use std::os::unix::net::UnixListener;
+#[cfg(feature = "ttrpc")]
+use std::net::TcpListener;
 use nix::unistd;
 use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
@@ -48,19 +56,38 @@ mod sandbox;
 mod version;
 mod uevent;
 mod device;
+mod ps;
+#[cfg(feature = "grpc")]
+mod stdio;
+mod capture;
+mod files;
+mod health;
+mod metrics;
+mod oom;
+mod pressure;
+mod rpc;
+#[cfg(feature = "tracing")]
+mod tracer;
+mod config;
+mod console;
+mod vsock;
+mod yamux;
+mod serial;
+mod pause;
 pub mod netlink;
 pub mod random;
 
 use namespace::Namespace;
 use network::Network;
 use sandbox::Sandbox;
+use config::ServerAddr;
 use uevent::watch_uevents;
 use mount::{general_mount, cgroups_mount};
 
 mod grpc;
 
-const VSOCK_ADDR: &'static str = "vsock://-1";
-const VSOCK_PORT: u16 = 1024;
+#[cfg(not(any(feature = "grpc", feature = "ttrpc")))]
+compile_error!("the agent API needs a transport, enable the grpc or the ttrpc feature");
 
 lazy_static! {
     static ref GLOBAL_DEVICE_WATCHER: Arc<Mutex<HashMap<String, Sender<String>>>> =
@@ -68,12 +95,47 @@ lazy_static! {
 }
 
 fn main() -> Result<()> {
-    simple_logging::log_to_stderr(LevelFilter::Info);
+    if env::args().nth(1).as_ref().map(|s| s.as_str()) == Some(pause::PAUSE_ARG) {
+        pause::run();
+    }
+
+    // the level is only known once the config is loaded, the logger
+    // lets everything through and the global max level does the filtering
+    simple_logging::log_to_stderr(LevelFilter::Trace);
+    log::set_max_level(LevelFilter::Info);
 	// simple_logging::log_to_file("/run/log.agent", LevelFilter::Info);
     env::set_var("RUST_BACKTRACE", "full");
 
-    if unistd::getpid() == Pid::from_raw(1) {
+    // as pid 1 the arguments are whatever the kernel did not understand
+    let pid1 = unistd::getpid() == Pid::from_raw(1);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let load_config = || config::load(&args, pid1).map_err(|e| {
+        error!("Failed to load agent config with error: {:?}", e);
+        e
+    });
+
+    let mut config = load_config()?;
+
+    // a local agent (unix or tcp) is never the init of a VM, even as pid 1
+    if pid1 && !config.server().is_local() {
         init_agent_as_init()?;
+        // /proc/cmdline is only there once init has mounted /proc
+        config = load_config()?;
+    }
+    info!("agent config: {:?}", config);
+
+    log::set_max_level(config.log_level);
+    #[cfg(feature = "tracing")]
+    {
+        if let Some(endpoint) = config.trace_endpoint.as_ref() {
+            tracer::set_endpoint(endpoint.as_str());
+        }
+    }
+    *config::AGENT_CONFIG.write().unwrap() = config.clone();
+
+    // never without being asked for, it is a root shell
+    if config.debug_console {
+        console::start(config.debug_console_vport);
     }
 
     // Initialize unique sandbox structure.
@@ -84,20 +146,27 @@ fn main() -> Result<()> {
 
     let sandbox = Arc::new(Mutex::new(s));
 
+    // trace from boot on, stopped by the StopTracing RPC like any other
+    if config.trace {
+        #[cfg(feature = "tracing")]
+        {
+            tracer::start()?;
+            sandbox.lock().unwrap().enable_grpc_trace = true;
+        }
+        #[cfg(not(feature = "tracing"))]
+        warn!("tracing asked for, but support for it is not built in");
+    }
+
     setup_signal_handler(sandbox.clone()).unwrap();
-    watch_uevents(sandbox.clone());
+    // the uevents of the host are none of our business
+    if !config.server().is_local() {
+        watch_uevents(sandbox.clone());
+    }
 
     let (tx, rx) = mpsc::channel::<i32>();
 	sandbox.lock().unwrap().sender = Some(tx);
 
-    //vsock:///dev/vsock, port
-    let mut server = grpc::start(sandbox.clone(), VSOCK_ADDR, VSOCK_PORT);
-
-/*
-    let _ = fs::remove_file("/tmp/testagent");
-	let _ = fs::remove_dir_all("/run/agent");
-    let mut server = grpc::start(sandbox.clone(), "unix:///tmp/testagent", 1);
-*/
+    let mut server = start_server(sandbox.clone(), config.server())?;
 
     let handle = thread::spawn(move || {
         // info!("Press ENTER to exit...");
@@ -113,12 +182,78 @@ fn main() -> Result<()> {
 	
 	handle.join().unwrap();
 
+    #[cfg(feature = "ttrpc")]
+    server.shutdown();
+    #[cfg(not(feature = "ttrpc"))]
     let _ = server.shutdown().wait();
-    let _ = fs::remove_file("/tmp/testagent");
+    if let ServerAddr::Unix(path) = config.server() {
+        let _ = fs::remove_file(path);
+    }
 
     Ok(())
 }
 
+// a socket left behind by a previous run would fail the bind
+fn clear_socket(path: &str) {
+    if let Some(dir) = Path::new(path).parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    let _ = fs::remove_file(path);
+}
+
+//vsock:///dev/vsock, port
+#[cfg(not(feature = "ttrpc"))]
+fn start_server(sandbox: Arc<Mutex<Sandbox>>, addr: ServerAddr) -> Result<grpcio::Server> {
+    let server = match addr {
+        ServerAddr::Vsock(host, port) => grpc::start(sandbox, host, port),
+        ServerAddr::Serial(port) => {
+            clear_socket(serial::GRPC_SOCKET);
+            let server = grpc::start(sandbox,
+                format!("unix://{}", serial::GRPC_SOCKET), 1);
+            serial::start(port);
+            server
+        }
+        ServerAddr::Unix(path) => {
+            clear_socket(path.as_str());
+            grpc::start(sandbox, format!("unix://{}", path), 1)
+        }
+        ServerAddr::Tcp(host, port) => grpc::start(sandbox, host, port),
+    };
+
+    Ok(server)
+}
+
+// ttrpc wins when both are built in, it is the one asked for explicitly
+#[cfg(feature = "ttrpc")]
+fn start_server(sandbox: Arc<Mutex<Sandbox>>, addr: ServerAddr) -> Result<protocols::ttrpc::Server> {
+    let unix_listener = |path: &str| -> Result<File> {
+        clear_socket(path);
+        let l = UnixListener::bind(path)?;
+        Ok(unsafe { File::from_raw_fd(l.into_raw_fd()) })
+    };
+
+    let listener = match addr {
+        ServerAddr::Vsock(host, port) => {
+            // vsock://-1 is any cid, as for grpc
+            let cid = host["vsock://".len()..].parse::<i32>()
+                .chain_err(|| format!("bad vsock address {}", host))?;
+            vsock::listen(cid as u32, port as u32, 1024)?
+        }
+        ServerAddr::Serial(port) => {
+            let l = unix_listener(serial::GRPC_SOCKET)?;
+            serial::start(port);
+            l
+        }
+        ServerAddr::Unix(path) => unix_listener(path.as_str())?,
+        ServerAddr::Tcp(host, port) => {
+            let l = TcpListener::bind(format!("{}:{}", host, port))?;
+            unsafe { File::from_raw_fd(l.into_raw_fd()) }
+        }
+    };
+
+    grpc::start_ttrpc(sandbox, listener)
+}
+
 fn setup_signal_handler(sandbox: Arc<Mutex<Sandbox>>) -> Result<()>{
     set_child_subreaper(true)
         .map_err(|err | format!("failed  to setup agent as a child subreaper, failed with {}", err));
@@ -128,6 +263,7 @@ fn setup_signal_handler(sandbox: Arc<Mutex<Sandbox>>) -> Result<()>{
     let mut s = sandbox.clone();
 
     thread::spawn(move || {
+        let _alive = health::REAPER.run();
         for sig in signals.forever() {
             info!("Received signal {:?}", sig);
 
@@ -150,10 +286,15 @@ fn setup_signal_handler(sandbox: Arc<Mutex<Sandbox>>) -> Result<()>{
                 }
                 let pipe_write = p.exit_pipe_w.unwrap();
                 let mut ret: i32 = 0;
+                let mut core_dumped = false;
 
+                // follow the shell convention for processes killed by signals
                 match wait_status {
                     WaitStatus::Exited(_, c) => ret = c,
-                    WaitStatus::Signaled(_, sig, _) => ret = sig as i32,
+                    WaitStatus::Signaled(_, sig, core) => {
+                        ret = 128 + sig as i32;
+                        core_dumped = core;
+                    }
                     _ => {
                         info!("got wrong status for process {}", raw_pid);
                         continue;
@@ -161,6 +302,7 @@ fn setup_signal_handler(sandbox: Arc<Mutex<Sandbox>>) -> Result<()>{
                 }
 
                 p.exit_code = ret;
+                p.core_dumped = core_dumped;
                 unistd::close(pipe_write);
             }
         }
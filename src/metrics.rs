@@ -0,0 +1,431 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// GetMetrics, in the Prometheus text format: the guest as /proc and /sys
+// show it, the agent process and its RPCs, and the cgroups of the
+// containers. A file that cannot be read leaves its metrics out.
+
+use protocols::agent::CgroupStats;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::rpc::Code;
+
+const MEMINFO: &'static str = "/proc/meminfo";
+const LOADAVG: &'static str = "/proc/loadavg";
+const STAT: &'static str = "/proc/stat";
+const DISKSTATS: &'static str = "/proc/diskstats";
+const NET_DEV: &'static str = "/proc/net/dev";
+const SYS_BLOCK: &'static str = "/sys/block";
+const SELF_STATUS: &'static str = "/proc/self/status";
+const SELF_STAT: &'static str = "/proc/self/stat";
+const SELF_FD: &'static str = "/proc/self/fd";
+
+// the sectors of /proc/diskstats, whatever the device
+const SECTOR_SIZE: f64 = 512.0;
+const NANO_PER_SECOND: f64 = 1e9;
+
+// the default buckets of the Prometheus clients, in seconds
+const RPC_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+// the columns of a cpu line of /proc/stat
+const CPU_MODES: [&'static str; 8] = ["user", "nice", "system", "idle", "iowait", "irq", "softirq", "steal"];
+
+#[derive(Default)]
+struct RpcStats {
+	// by status code
+	done: BTreeMap<String, u64>,
+	// counts of the calls up to each bound of RPC_BUCKETS
+	buckets: [u64; 11],
+	count: u64,
+	sum: f64,
+}
+
+lazy_static! {
+	static ref RPCS: Mutex<BTreeMap<&'static str, RpcStats>> = Mutex::new(BTreeMap::new());
+}
+
+// times an RPC from its creation to its drop
+pub struct RpcTimer {
+	method: &'static str,
+	start: Instant,
+}
+
+pub fn rpc_timer(method: &'static str) -> RpcTimer {
+	RpcTimer {
+		method: method,
+		start: Instant::now(),
+	}
+}
+
+impl Drop for RpcTimer {
+	fn drop(&mut self) {
+		let d = self.start.elapsed();
+		let secs = d.as_secs() as f64 + d.subsec_nanos() as f64 / NANO_PER_SECOND;
+
+		let mut rpcs = RPCS.lock().unwrap();
+		let s = rpcs.entry(self.method).or_insert_with(RpcStats::default);
+		for (i, b) in RPC_BUCKETS.iter().enumerate() {
+			if secs <= *b {
+				s.buckets[i] += 1;
+			}
+		}
+		s.count += 1;
+		s.sum += secs;
+	}
+}
+
+// the outcome of an RPC, None when it succeeded
+pub fn rpc_done(method: &'static str, code: Option<Code>) {
+	let code = code.map(|c| format!("{:?}", c)).unwrap_or_else(|| "OK".to_string());
+
+	let mut rpcs = RPCS.lock().unwrap();
+	*rpcs.entry(method).or_insert_with(RpcStats::default)
+		.done.entry(code).or_insert(0) += 1;
+}
+
+struct Exposition {
+	out: String,
+}
+
+impl Exposition {
+	fn family(&mut self, name: &str, kind: &str, help: &str) {
+		let _ = writeln!(self.out, "# HELP {} {}", name, help);
+		let _ = writeln!(self.out, "# TYPE {} {}", name, kind);
+	}
+
+	fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+		self.out.push_str(name);
+
+		if !labels.is_empty() {
+			let labels: Vec<String> = labels.iter()
+				.map(|(k, v)| format!("{}=\"{}\"", k, escape(v)))
+				.collect();
+			let _ = write!(self.out, "{{{}}}", labels.join(","));
+		}
+
+		let _ = writeln!(self.out, " {}", value);
+	}
+
+	// a family of one sample per item
+	fn samples<'a, I>(&mut self, name: &str, kind: &str, help: &str, items: I)
+	where
+		I: IntoIterator<Item = (Vec<(&'a str, &'a str)>, f64)>,
+	{
+		let mut items = items.into_iter().peekable();
+		if items.peek().is_none() {
+			return;
+		}
+
+		self.family(name, kind, help);
+		for (labels, value) in items {
+			self.sample(name, labels.as_slice(), value);
+		}
+	}
+}
+
+fn escape(v: &str) -> String {
+	v.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn read(path: &str) -> String {
+	fs::read_to_string(path).unwrap_or_default()
+}
+
+fn clock_ticks() -> f64 {
+	let t = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+	if t > 0 { t as f64 } else { 100.0 }
+}
+
+// name and value of each line, kB turned into bytes
+fn parse_meminfo(content: &str) -> Vec<(String, f64)> {
+	content.lines()
+		.filter_map(|l| {
+			let mut f = l.split_whitespace();
+			let name = f.next()?.trim_end_matches(':').to_string();
+			let value: f64 = f.next()?.parse().ok()?;
+
+			match f.next() {
+				Some("kB") => Some((name, value * 1024.0)),
+				_ => Some((name, value)),
+			}
+		})
+		.collect()
+}
+
+// the cpu number and its ticks by mode, without the "cpu" total line
+fn parse_stat(content: &str) -> Vec<(String, Vec<f64>)> {
+	content.lines()
+		.filter(|l| l.starts_with("cpu") && !l.starts_with("cpu "))
+		.filter_map(|l| {
+			let mut f = l.split_whitespace();
+			let cpu = f.next()?.trim_start_matches("cpu").to_string();
+			let ticks: Vec<f64> = f.take(CPU_MODES.len()).filter_map(|v| v.parse().ok()).collect();
+			Some((cpu, ticks))
+		})
+		.collect()
+}
+
+// /proc/net/dev after its two header lines: the interface, then 8
+// receive and 8 transmit counters
+fn parse_net_dev(content: &str) -> Vec<(String, Vec<f64>)> {
+	content.lines()
+		.skip(2)
+		.filter_map(|l| {
+			let mut parts = l.splitn(2, ':');
+			let iface = parts.next()?.trim().to_string();
+			let counters: Vec<f64> = parts.next()?.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+			if counters.len() < 16 {
+				return None;
+			}
+			Some((iface, counters))
+		})
+		.collect()
+}
+
+// the device and its counters, from reads completed on
+fn parse_diskstats(content: &str) -> Vec<(String, Vec<f64>)> {
+	content.lines()
+		.filter_map(|l| {
+			let f: Vec<&str> = l.split_whitespace().collect();
+			if f.len() < 14 {
+				return None;
+			}
+			Some((f[2].to_string(), f[3..].iter().filter_map(|v| v.parse().ok()).collect()))
+		})
+		.collect()
+}
+
+fn guest_metrics(e: &mut Exposition) {
+	let meminfo = parse_meminfo(read(MEMINFO).as_str());
+	e.samples("kata_guest_meminfo", "gauge", "The fields of /proc/meminfo, kB in bytes.",
+		meminfo.iter().map(|(k, v)| (vec![("item", k.as_str())], *v)));
+
+	let load: Vec<f64> = read(LOADAVG).split_whitespace().take(3).filter_map(|v| v.parse().ok()).collect();
+	if load.len() == 3 {
+		for (name, v) in ["kata_guest_load1", "kata_guest_load5", "kata_guest_load15"].iter().zip(load) {
+			e.family(name, "gauge", "The load average of the guest.");
+			e.sample(name, &[], v);
+		}
+	}
+
+	let ticks = clock_ticks();
+	let cpus = parse_stat(read(STAT).as_str());
+	e.samples("kata_guest_cpu_seconds_total", "counter", "The time each CPU spent in each mode.",
+		cpus.iter().flat_map(|(cpu, t)| {
+			t.iter().zip(CPU_MODES.iter())
+				.map(move |(v, mode)| (vec![("cpu", cpu.as_str()), ("mode", *mode)], *v / ticks))
+		}));
+
+	// whole disks only, partitions would count twice
+	let disks: Vec<(String, Vec<f64>)> = parse_diskstats(read(DISKSTATS).as_str()).into_iter()
+		.filter(|(d, _)| Path::new(SYS_BLOCK).join(d).exists())
+		.filter(|(d, _)| !d.starts_with("loop") && !d.starts_with("ram"))
+		.collect();
+	for (name, i, scale, help) in [
+		("kata_guest_disk_reads_completed_total", 0, 1.0, "The reads completed by the disk."),
+		("kata_guest_disk_read_bytes_total", 2, SECTOR_SIZE, "The bytes read from the disk."),
+		("kata_guest_disk_writes_completed_total", 4, 1.0, "The writes completed by the disk."),
+		("kata_guest_disk_written_bytes_total", 6, SECTOR_SIZE, "The bytes written to the disk."),
+		("kata_guest_disk_io_time_seconds_total", 9, 0.001, "The time the disk spent doing I/O."),
+	].iter() {
+		e.samples(name, "counter", help,
+			disks.iter().map(|(d, c)| (vec![("device", d.as_str())], c[*i] * scale)));
+	}
+
+	let ifaces = parse_net_dev(read(NET_DEV).as_str());
+	for (name, i, help) in [
+		("kata_guest_network_receive_bytes_total", 0, "The bytes received on the interface."),
+		("kata_guest_network_receive_packets_total", 1, "The packets received on the interface."),
+		("kata_guest_network_receive_errors_total", 2, "The receive errors of the interface."),
+		("kata_guest_network_receive_drops_total", 3, "The received packets dropped on the interface."),
+		("kata_guest_network_transmit_bytes_total", 8, "The bytes sent on the interface."),
+		("kata_guest_network_transmit_packets_total", 9, "The packets sent on the interface."),
+		("kata_guest_network_transmit_errors_total", 10, "The transmit errors of the interface."),
+		("kata_guest_network_transmit_drops_total", 11, "The packets to send dropped on the interface."),
+	].iter() {
+		e.samples(name, "counter", help,
+			ifaces.iter().map(|(n, c)| (vec![("interface", n.as_str())], c[*i])));
+	}
+}
+
+fn agent_metrics(e: &mut Exposition) {
+	let status = parse_meminfo(read(SELF_STATUS).as_str());
+	let field = |name: &str| status.iter().find(|(k, _)| k == name).map(|(_, v)| *v);
+
+	if let Some(rss) = field("VmRSS") {
+		e.family("kata_agent_resident_memory_bytes", "gauge", "The resident memory of the agent.");
+		e.sample("kata_agent_resident_memory_bytes", &[], rss);
+	}
+
+	if let Some(threads) = field("Threads") {
+		e.family("kata_agent_threads", "gauge", "The threads of the agent.");
+		e.sample("kata_agent_threads", &[], threads);
+	}
+
+	if let Ok(fds) = fs::read_dir(SELF_FD) {
+		e.family("kata_agent_open_fds", "gauge", "The open file descriptors of the agent.");
+		e.sample("kata_agent_open_fds", &[], fds.count() as f64);
+	}
+
+	// utime and stime, after the command name that may contain anything
+	let stat = read(SELF_STAT);
+	let times: Vec<f64> = match stat.rfind(')') {
+		Some(i) => stat[i + 1..].split_whitespace().skip(11).take(2).filter_map(|v| v.parse().ok()).collect(),
+		None => Vec::new(),
+	};
+	let ticks = clock_ticks();
+	e.samples("kata_agent_cpu_seconds_total", "counter", "The CPU time of the agent.",
+		times.iter().zip(["user", "system"].iter()).map(|(v, mode)| (vec![("mode", *mode)], *v / ticks)));
+
+	let rpcs = RPCS.lock().unwrap();
+
+	e.samples("kata_agent_rpc_requests_total", "counter", "The RPCs answered, by status code.",
+		rpcs.iter().flat_map(|(m, s)| {
+			s.done.iter().map(move |(code, n)| (vec![("method", *m), ("code", code.as_str())], *n as f64))
+		}));
+
+	if rpcs.is_empty() {
+		return;
+	}
+
+	let name = "kata_agent_rpc_duration_seconds";
+	e.family(name, "histogram", "The time the RPCs took.");
+	for (m, s) in rpcs.iter() {
+		for (b, n) in RPC_BUCKETS.iter().zip(s.buckets.iter()) {
+			let le = b.to_string();
+			e.sample("kata_agent_rpc_duration_seconds_bucket", &[("method", *m), ("le", le.as_str())], *n as f64);
+		}
+		e.sample("kata_agent_rpc_duration_seconds_bucket", &[("method", *m), ("le", "+Inf")], s.count as f64);
+		e.sample("kata_agent_rpc_duration_seconds_sum", &[("method", *m)], s.sum);
+		e.sample("kata_agent_rpc_duration_seconds_count", &[("method", *m)], s.count as f64);
+	}
+}
+
+fn container_metrics(e: &mut Exposition, stats: &[(String, CgroupStats)]) {
+	let cpu = |s: &CgroupStats| s.cpu_stats.as_ref().and_then(|c| c.cpu_usage.as_ref()).cloned().unwrap_or_default();
+	let throttling = |s: &CgroupStats| s.cpu_stats.as_ref().and_then(|c| c.throttling_data.as_ref()).cloned().unwrap_or_default();
+	let memory = |s: &CgroupStats| s.memory_stats.as_ref().and_then(|m| m.usage.as_ref()).cloned().unwrap_or_default();
+	let pids = |s: &CgroupStats| s.pids_stats.as_ref().cloned().unwrap_or_default();
+
+	e.samples("kata_container_cpu_usage_seconds_total", "counter", "The CPU time of the container.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), cpu(s).total_usage as f64 / NANO_PER_SECOND)));
+	e.samples("kata_container_cpu_user_seconds_total", "counter", "The user CPU time of the container.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), cpu(s).usage_in_usermode as f64 / NANO_PER_SECOND)));
+	e.samples("kata_container_cpu_kernel_seconds_total", "counter", "The kernel CPU time of the container.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), cpu(s).usage_in_kernelmode as f64 / NANO_PER_SECOND)));
+	e.samples("kata_container_cpu_throttled_periods_total", "counter", "The periods the container was throttled in.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), throttling(s).throttled_periods as f64)));
+	e.samples("kata_container_cpu_throttled_seconds_total", "counter", "The time the container was throttled.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), throttling(s).throttled_time as f64 / NANO_PER_SECOND)));
+
+	e.samples("kata_container_memory_usage_bytes", "gauge", "The memory used by the container.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), memory(s).usage as f64)));
+	e.samples("kata_container_memory_max_usage_bytes", "gauge", "The most memory the container used.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), memory(s).max_usage as f64)));
+	e.samples("kata_container_memory_limit_bytes", "gauge", "The memory limit of the container.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), memory(s).limit as f64)));
+	e.samples("kata_container_memory_failcnt_total", "counter", "The times the container hit its memory limit.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), memory(s).failcnt as f64)));
+	e.samples("kata_container_memory_cache_bytes", "gauge", "The page cache of the container.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), s.memory_stats.as_ref().map_or(0, |m| m.cache) as f64)));
+
+	e.samples("kata_container_pids", "gauge", "The tasks in the container.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), pids(s).current as f64)));
+	e.samples("kata_container_pids_limit", "gauge", "The task limit of the container, 0 for none.",
+		stats.iter().map(|(c, s)| (label(c.as_str()), pids(s).limit as f64)));
+
+	// read and write bytes by device
+	let mut blkio: Vec<(&String, String, &str, f64)> = Vec::new();
+	for (c, s) in stats.iter() {
+		if let Some(b) = s.blkio_stats.as_ref() {
+			for entry in b.io_service_bytes_recursive.iter() {
+				let op = entry.op.to_lowercase();
+				if op == "read" || op == "write" {
+					blkio.push((c, format!("{}:{}", entry.major, entry.minor),
+						if op == "read" { "read" } else { "write" }, entry.value as f64));
+				}
+			}
+		}
+	}
+	e.samples("kata_container_blkio_bytes_total", "counter", "The bytes the container read from and wrote to each device.",
+		blkio.iter().map(|(c, dev, op, v)| (vec![("container", c.as_str()), ("device", dev.as_str()), ("op", *op)], *v)));
+}
+
+fn label(cid: &str) -> Vec<(&str, &str)> {
+	vec![("container", cid)]
+}
+
+// containers are the stats of each container by id
+pub fn gather(containers: &[(String, CgroupStats)]) -> String {
+	let mut e = Exposition { out: String::new() };
+
+	guest_metrics(&mut e);
+	agent_metrics(&mut e);
+	container_metrics(&mut e, containers);
+
+	e.out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use protocols::agent::{CpuStats, CpuUsage};
+	use protobuf::SingularPtrField;
+
+	#[test]
+	fn test_parse() {
+		let meminfo = parse_meminfo("MemTotal:        2040812 kB\nHugePages_Total:       0\n");
+		assert_eq!(meminfo, vec![("MemTotal".to_string(), 2040812.0 * 1024.0), ("HugePages_Total".to_string(), 0.0)]);
+
+		let cpus = parse_stat("cpu  10 0 5 100 0 0 0 0 0 0\ncpu0 10 0 5 100 1 2 3 4 0 0\nintr 1\n");
+		assert_eq!(cpus, vec![("0".to_string(), vec![10.0, 0.0, 5.0, 100.0, 1.0, 2.0, 3.0, 4.0])]);
+
+		let net = parse_net_dev("Inter-|   Receive\n face |bytes\n  eth0: 100 2 0 0 0 0 0 0 200 3 0 0 0 0 0 0\n");
+		assert_eq!(net.len(), 1);
+		assert_eq!((net[0].0.as_str(), net[0].1[0], net[0].1[8]), ("eth0", 100.0, 200.0));
+
+		let disks = parse_diskstats(" 254 0 vda 10 0 80 5 20 0 160 7 0 12 12\n");
+		assert_eq!((disks[0].0.as_str(), disks[0].1[2], disks[0].1[9]), ("vda", 80.0, 12.0));
+	}
+
+	#[test]
+	fn test_gather() {
+		{
+			let _t = rpc_timer("test_gather");
+		}
+		rpc_done("test_gather", None);
+		rpc_done("test_gather", Some(Code::NotFound));
+
+		let mut usage = CpuUsage::new();
+		usage.total_usage = 1_500_000_000;
+		let mut cpu = CpuStats::new();
+		cpu.cpu_usage = SingularPtrField::some(usage);
+		let mut stats = CgroupStats::new();
+		stats.cpu_stats = SingularPtrField::some(cpu);
+
+		let out = gather(&[("c\"1".to_string(), stats)]);
+
+		assert!(out.contains("# TYPE kata_agent_rpc_duration_seconds histogram\n"));
+		assert!(out.contains("kata_agent_rpc_requests_total{method=\"test_gather\",code=\"NotFound\"} 1\n"));
+		assert!(out.contains("kata_agent_rpc_duration_seconds_bucket{method=\"test_gather\",le=\"+Inf\"} 1\n"));
+		assert!(out.contains("kata_container_cpu_usage_seconds_total{container=\"c\\\"1\"} 1.5\n"));
+		assert!(out.contains("kata_agent_threads "));
+
+		// every sample belongs to the family declared last
+		let mut family = String::new();
+		for l in out.lines() {
+			if l.starts_with("# TYPE ") {
+				family = l.split_whitespace().nth(2).unwrap().to_string();
+			} else if !l.starts_with('#') {
+				assert!(l.starts_with(family.as_str()), "{} outside of {}", l, family);
+			}
+		}
+	}
+}
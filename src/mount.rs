@@ -6,6 +6,7 @@
 use rustjail::cgroups::fs::Manager as FsManager;
 use rustjail::cgroups::Manager as CgroupManager;
 use rustjail::errors::*;
+use rustjail::trace;
 use std::collections::HashMap;
 use std::ffi::{CString, OsStr};
 use std::fs;
@@ -27,7 +28,9 @@ use regex::Regex;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
-use crate::device::{get_pci_device_name, get_scsi_device_name, online_device};
+use crate::device::{get_pci_device_name, online_device};
+#[cfg(feature = "scsi")]
+use crate::device::get_scsi_device_name;
 use crate::protocols::agent::Storage;
 use crate::Sandbox;
 
@@ -49,8 +52,6 @@ const ROOTBUSPATH: &'static str = "/devices/pci0000:00";
 const CGROUPPATH: &'static str = "/sys/fs/cgroup";
 const PROCCGROUPS: &'static str = "/proc/cgroups";
 
-pub const TIMEOUT_HOTPLUG: u64 = 3;
-
 #[cfg_attr(rustfmt, rustfmt_skip)]
 lazy_static! {
     pub static ref FLAGS: HashMap<&'static str, (bool, MsFlags)> = {
@@ -143,18 +144,27 @@ lazy_static! {
     	let mut m = HashMap::new();
     let blk: StorageHandler = virtio_blk_storage_handler;
         m.insert(DRIVERBLKTYPE, blk);
+    #[cfg(feature = "virtio-9p")]
+    {
 	let p9: StorageHandler= virtio9p_storage_handler;
         m.insert(DRIVER9PTYPE, p9);
+    }
+    #[cfg(feature = "virtio-fs")]
+    {
 	let virtiofs: StorageHandler = virtiofs_storage_handler;
         m.insert(DRIVERVIRTIOFSTYPE, virtiofs);
+    }
     let ephemeral: StorageHandler = ephemeral_storage_handler;
         m.insert(DRIVEREPHEMERALTYPE, ephemeral);
     let virtiommio: StorageHandler = virtiommio_blk_storage_handler;
         m.insert(DRIVERMMIOBLKTYPE, virtiommio);
     let local: StorageHandler = local_storage_handler;
         m.insert(DRIVERLOCALTYPE, local);
+    #[cfg(feature = "scsi")]
+    {
     let scsi: StorageHandler = virtio_scsi_storage_handler;
         m.insert(DRIVERSCSITYPE, scsi);
+    }
         m
     };
 }
@@ -278,6 +288,7 @@ fn local_storage_handler(
     Ok("".to_string())
 }
 
+#[cfg(feature = "virtio-9p")]
 fn virtio9p_storage_handler(storage: &Storage, sandbox: Arc<Mutex<Sandbox>>) -> Result<String> {
     common_storage_handler(storage)
 }
@@ -292,6 +303,7 @@ fn virtiommio_blk_storage_handler(
 }
 
 // virtiofs_storage_handler handles the storage for virtio-fs.
+#[cfg(feature = "virtio-fs")]
 fn virtiofs_storage_handler(storage: &Storage, sandbox: Arc<Mutex<Sandbox>>) -> Result<String> {
     common_storage_handler(storage)
 }
@@ -321,6 +333,7 @@ fn virtio_blk_storage_handler(
 }
 
 // virtio_scsi_storage_handler handles the storage for scsi driver.
+#[cfg(feature = "scsi")]
 fn virtio_scsi_storage_handler(
     storage: &Storage,
     sandbox: Arc<Mutex<Sandbox>>,
@@ -416,7 +429,8 @@ pub fn add_storages(storages: Vec<Storage>, sandbox: Arc<Mutex<Sandbox>>) -> Res
     for storage in storages {
         let handler = match STORAGEHANDLERLIST.get(storage.driver.as_str()) {
             None => {
-                return Err(ErrorKind::ErrorCode(format!(
+                // unknown, or left out of this build
+                return Err(ErrorKind::Unimplemented(format!(
                     "Failed to find the storage handler {}",
                     storage.driver
                 ))
@@ -425,6 +439,10 @@ pub fn add_storages(storages: Vec<Storage>, sandbox: Arc<Mutex<Sandbox>>) -> Res
             Some(f) => f,
         };
 
+        let mut span = trace::span("mount_storage");
+        span.tag("driver", storage.driver.as_str());
+        span.tag("mount_point", storage.mount_point.as_str());
+
         let mount_point = match handler(&storage, sandbox.clone()) {
             // Todo need to rollback the mounted storage if err met.
             Err(e) => return Err(e),
@@ -6,6 +6,7 @@
 use nix::mount::MsFlags;
 use nix::sched::{setns, unshare, CloneFlags};
 use nix::unistd::{getpid, gettid};
+use libc::pid_t;
 use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
@@ -46,7 +47,7 @@ pub fn get_current_thread_ns_path(ns_type: &str) -> String {
 }
 
 // setup_persistent_ns creates persistent namespace without switchin to it.
-// Note, pid namespaces cannot be persisted.
+// Note, pid namespaces cannot be created this way, see persist_process_ns.
 pub fn setup_persistent_ns(ns_type: &'static str) -> Result<Namespace, String> {
     if let Err(err) = fs::create_dir_all(PERSISTENT_NS_DIR) {
         return Err(err.to_string());
@@ -116,3 +117,40 @@ pub fn setup_persistent_ns(ns_type: &'static str) -> Result<Namespace, String> {
         path: new_ns_path.into_os_string().into_string().unwrap(),
     })
 }
+
+// persist_process_ns keeps a reference on a namespace of a running
+// process, which is how the sandbox pid namespace is shared since it
+// cannot be created by unshare() on a thread of ours.
+pub fn persist_process_ns(pid: pid_t, ns_type: &'static str) -> Result<Namespace, String> {
+    if let Err(err) = fs::create_dir_all(PERSISTENT_NS_DIR) {
+        return Err(err.to_string());
+    }
+
+    let new_ns_path = Path::new(PERSISTENT_NS_DIR).join(ns_type);
+
+    if let Err(err) = File::create(new_ns_path.as_path()) {
+        return Err(err.to_string());
+    }
+
+    let source = format!("/proc/{}/ns/{}", pid, ns_type);
+    let destination: &str = new_ns_path.as_path().to_str().unwrap_or("none");
+
+    let mut flags = MsFlags::empty();
+    if let Some(x) = FLAGS.get("rbind") {
+        let (_, f) = *x;
+        flags = flags | f;
+    }
+
+    let bare_mount = BareMount::new(source.as_str(), destination, "none", flags, "");
+
+    if let Err(err) = bare_mount.mount() {
+        return Err(format!(
+            "Failed to mount {} to {} with err:{:?}",
+            source, destination, err
+        ));
+    }
+
+    Ok(Namespace {
+        path: destination.to_string(),
+    })
+}
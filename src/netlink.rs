@@ -13,11 +13,17 @@ use std::default::Default;
 use std::mem;
 use rustjail::errors::*;
 use nix::errno::Errno;
+#[cfg(feature = "netlink")]
 use protocols::types::{Route, Interface, IPAddress, IPFamily};
-use protocols::agent::{UpdateRoutesRequest, UpdateInterfaceRequest, Routes};
+#[cfg(feature = "netlink")]
+use protocols::agent::{UpdateRoutesRequest, UpdateInterfaceRequest, Routes, NetworkStats};
+#[cfg(feature = "netlink")]
 use protobuf::{RepeatedField, SingularPtrField};
 use std::fmt;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::Ipv6Addr;
+#[cfg(feature = "netlink")]
+use std::net::Ipv4Addr;
+#[cfg(feature = "netlink")]
 use std::str::FromStr;
 
 // define the struct, const, etc needed by 
@@ -599,6 +605,39 @@ impl Default for rtnl_link_stats64 {
 	}
 }
 
+impl<'a> From<&'a rtnl_link_stats> for rtnl_link_stats64 {
+	fn from(s: &rtnl_link_stats) -> Self {
+		Self {
+			rx_packets: s.rx_packets as __u64,
+			tx_packets: s.tx_packets as __u64,
+			rx_bytes: s.rx_bytes as __u64,
+			tx_bytes: s.tx_bytes as __u64,
+			rx_errors: s.rx_errors as __u64,
+			tx_errors: s.tx_errors as __u64,
+			rx_dropped: s.rx_dropped as __u64,
+			tx_dropped: s.tx_dropped as __u64,
+			..Default::default()
+		}
+	}
+}
+
+#[cfg(feature = "netlink")]
+impl rtnl_link_stats64 {
+	fn network_stats(&self, name: String) -> NetworkStats {
+		let mut n = NetworkStats::new();
+		n.name = name;
+		n.rx_bytes = self.rx_bytes;
+		n.rx_packets = self.rx_packets;
+		n.rx_errors = self.rx_errors;
+		n.rx_dropped = self.rx_dropped;
+		n.tx_bytes = self.tx_bytes;
+		n.tx_packets = self.tx_packets;
+		n.tx_errors = self.tx_errors;
+		n.tx_dropped = self.tx_dropped;
+		n
+	}
+}
+
 #[repr(C)]
 #[derive(Copy)]
 pub struct rtnl_link_stats {
@@ -1329,7 +1368,11 @@ impl RtnlHandle {
 		}
 		Ok((slv, lv))
 	}
+}
 
+// interface, address and route management, uevents only need the above
+#[cfg(feature = "netlink")]
+impl RtnlHandle {
 	pub fn list_interfaces(&mut self) -> Result<Vec<Interface>> {
 		let mut ifaces: Vec<Interface> = Vec::new();
 
@@ -1438,6 +1481,61 @@ impl RtnlHandle {
 		Ok(ifaces)
 	}
 
+	// the counters of every link but lo, from IFLA_STATS64 or the 32 bit
+	// IFLA_STATS of older kernels
+	pub fn list_link_stats(&mut self) -> Result<Vec<NetworkStats>> {
+		let mut stats: Vec<NetworkStats> = Vec::new();
+
+		unsafe {
+			let (_slv, lv) = self.dump_all_links()?;
+
+			for link in &lv {
+				let nlh: *const nlmsghdr = *link;
+				let ifi: *const ifinfomsg = NLMSG_DATA!(nlh) as *const ifinfomsg;
+
+				if (*nlh).nlmsg_type != RTM_NEWLINK {
+					continue;
+				}
+
+				if (*nlh).nlmsg_len < NLMSG_SPACE!(mem::size_of::<ifinfomsg>()) {
+					info!("invalid nlmsg! nlmsg_len: {}, nlmsg_space: {}", (*nlh).nlmsg_len, NLMSG_SPACE!(mem::size_of::<ifinfomsg>()));
+					break;
+				}
+
+				if (*ifi).ifi_flags & libc::IFF_LOOPBACK as u32 != 0 {
+					continue;
+				}
+
+				let rta: *mut rtattr = IFLA_RTA!(ifi) as *mut rtattr;
+				let rtalen = IFLA_PAYLOAD!(nlh) as u32;
+				let attrs = parse_attrs(rta, rtalen, (IFLA_MAX + 1) as usize)?;
+
+				let name = if attrs[IFLA_IFNAME as usize] as i64 != 0 {
+					String::from_utf8(getattr_var(attrs[IFLA_IFNAME as usize]))?
+						.trim_end_matches('\0').to_string()
+				} else {
+					continue;
+				};
+
+				let s64 = attrs[IFLA_STATS64 as usize];
+				let s32 = attrs[IFLA_STATS as usize];
+
+				let s = if s64 as i64 != 0 && RTA_PAYLOAD!(s64) as usize >= mem::size_of::<rtnl_link_stats64>() {
+					// only 4 byte aligned in the message
+					(RTA_DATA!(s64) as *const rtnl_link_stats64).read_unaligned()
+				} else if s32 as i64 != 0 && RTA_PAYLOAD!(s32) as usize >= mem::size_of::<rtnl_link_stats>() {
+					rtnl_link_stats64::from(&*(RTA_DATA!(s32) as *const rtnl_link_stats))
+				} else {
+					continue;
+				};
+
+				stats.push(s.network_stats(name));
+			}
+		}
+
+		Ok(stats)
+	}
+
 	unsafe fn dump_all_links(&mut self) -> Result<(Vec<Vec<u8>>, Vec<*const nlmsghdr>)> {
 		let mut v: Vec<u8> = vec![0; 2048];
 		let p = v.as_mut_ptr() as *mut libc::c_char;
@@ -2561,6 +2659,7 @@ impl Drop for RtnlHandle {
 	}
 }
 
+#[cfg(feature = "netlink")]
 pub struct RtRoute {
 	pub dest: Option<Vec<u8>>,
 	pub source: Option<Vec<u8>>,
@@ -2571,6 +2670,7 @@ pub struct RtRoute {
 	pub src_len: u8,
 }
 
+#[cfg(feature = "netlink")]
 impl Default for RtRoute {
 	fn default() -> Self {
 		unsafe {
@@ -2579,6 +2679,7 @@ impl Default for RtRoute {
 	}
 }
 
+#[cfg(feature = "netlink")]
 fn parse_cidripv4(s: &str) -> Result<(Vec<u8>, u8)> {
 	let (a0, a1, a2, a3, len) = scan_fmt!(s,
 		"{}.{}.{}.{}/{}", u8, u8, u8, u8, u8)?;
@@ -2586,6 +2687,7 @@ fn parse_cidripv4(s: &str) -> Result<(Vec<u8>, u8)> {
 	Ok((ip, len))
 }
 
+#[cfg(feature = "netlink")]
 fn parse_ipv4(s: &str) -> Result<Vec<u8>> {
 	let (a0, a1, a2, a3) = scan_fmt!(s, "{}.{}.{}.{}",
 		u8, u8, u8, u8)?;
@@ -2594,6 +2696,7 @@ fn parse_ipv4(s: &str) -> Result<Vec<u8>> {
 	Ok(ip)
 }
 
+#[cfg(feature = "netlink")]
 fn parse_ipaddr(s: &str) -> Result<Vec<u8>> {
 	if let Ok(v6) = Ipv6Addr::from_str(s) {
 		return Ok(Vec::from(v6.octets().as_ref()));
@@ -2603,6 +2706,7 @@ fn parse_ipaddr(s: &str) -> Result<Vec<u8>> {
 	Ok(Vec::from(Ipv4Addr::from_str(s)?.octets().as_ref()))
 }
 
+#[cfg(feature = "netlink")]
 fn parse_cider(s: &str) -> Result<(Vec<u8>, u8)> {
 	let (addr, mask) = if s.contains("/") {
 		scan_fmt!(s, "{}/{}", String, u8)?
@@ -2613,6 +2717,7 @@ fn parse_cider(s: &str) -> Result<(Vec<u8>, u8)> {
 	Ok((parse_ipaddr(addr.as_str())?, mask))
 }
 
+#[cfg(feature = "netlink")]
 impl From<Route> for RtRoute {
 	fn from(r: Route) -> Self {
 		// only handle ipv4
@@ -2668,12 +2773,14 @@ impl From<Route> for RtRoute {
 	}
 }
 
+#[cfg(feature = "netlink")]
 pub struct RtIPAddr {
 	pub ip_family: __u8,
 	pub ip_mask: __u8,
 	pub addr: Vec<u8>,
 }
 
+#[cfg(feature = "netlink")]
 impl From<IPAddress> for RtIPAddr {
 	fn from(ipi: IPAddress) -> Self {
 		let ip_family = if ipi.family == IPFamily::v4 {
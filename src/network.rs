@@ -3,8 +3,28 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+use nix::mount::{self, MntFlags, MsFlags};
+use nix::sched::{setns, unshare, CloneFlags};
 use protocols::types::{Interface, Route};
+use rustjail::errors::*;
 use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::net::IpAddr;
+use std::os::unix::fs::{MetadataExt, OpenOptionsExt};
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::thread;
+
+// resolv.conf rendered from the sandbox dns, bind mounted into
+// every container
+pub const RESOLV_CONF_PATH: &'static str = "/run/kata-containers/sandbox/resolv.conf";
+
+// where a container has the shared resolv.conf
+pub const RESOLV_CONF_DEST: &'static str = "/etc/resolv.conf";
+
+const RESOLV_CONF_KEYWORDS: &'static [&'static str] =
+    &["nameserver", "search", "domain", "options", "sortlist"];
 
 // Network fully describes a sandbox network with its interfaces, routes and dns
 // related information.
@@ -24,7 +44,164 @@ impl Network {
         }
     }
 
-    pub fn set_dns(&mut self, dns: String) {
-        self.dns.push(dns);
+    // replace the dns entries, each one is either a resolv.conf
+    // line or a bare nameserver address
+    pub fn set_dns(&mut self, dns: Vec<String>) -> Result<()> {
+        let mut lines = Vec::new();
+
+        for d in dns.iter() {
+            lines.push(dns_line(d.as_str())?);
+        }
+
+        self.dns = lines;
+        Ok(())
+    }
+
+    pub fn has_dns(&self) -> bool {
+        !self.dns.is_empty()
+    }
+
+    pub fn setup_dns(&self) -> Result<()> {
+        write_resolv_conf(RESOLV_CONF_PATH, self.resolv_conf().as_str())
+    }
+
+    pub fn resolv_conf(&self) -> String {
+        let mut s = self.dns.join("\n");
+        s.push('\n');
+        s
+    }
+}
+
+fn dns_line(d: &str) -> Result<String> {
+    let d = d.trim();
+
+    if d.parse::<IpAddr>().is_ok() {
+        return Ok(format!("nameserver {}", d));
+    }
+
+    let keyword = d.split_whitespace().next().unwrap_or("");
+    if d.contains('\n') || !RESOLV_CONF_KEYWORDS.contains(&keyword) {
+        return Err(ErrorKind::InvalidArgument(
+            format!("invalid dns entry {:?}", d)).into());
+    }
+
+    Ok(d.to_string())
+}
+
+// Replaced whole by a rename, so that readers see either the old or the
+// new file. The containers running keep the inode they bind mounted, see
+// rebind_resolv_conf() for them.
+fn write_resolv_conf(path: &str, content: &str) -> Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let tmp = format!("{}.tmp", path);
+    let mut f = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(tmp.as_str())?;
+
+    f.write_all(content.as_bytes())?;
+    f.sync_all()?;
+    fs::rename(tmp.as_str(), path)?;
+
+    Ok(())
+}
+
+// a name the container cannot create ahead of the agent, read before
+// joining its mount namespace
+fn staged_resolv_conf() -> Result<String> {
+    let mut buf = [0u8; 8];
+    File::open("/dev/urandom")?.read_exact(&mut buf)?;
+    let name: String = buf.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok(format!("/dev/.resolv.conf.{}", name))
+}
+
+// Replaces the mount on the resolv.conf of the container by one of f,
+// staged at path, and checks that what got mounted is f indeed.
+fn mount_resolv_conf(f: &File, path: &str) -> Result<()> {
+    // the previous one goes, they would pile up otherwise
+    let _ = mount::umount2(RESOLV_CONF_DEST, MntFlags::MNT_DETACH);
+    mount::mount(Some(path), RESOLV_CONF_DEST, None::<&str>, MsFlags::MS_BIND, None::<&str>)?;
+
+    let (staged, mounted) = (f.metadata()?, fs::metadata(RESOLV_CONF_DEST)?);
+    if staged.dev() != mounted.dev() || staged.ino() != mounted.ino() {
+        let _ = mount::umount2(RESOLV_CONF_DEST, MntFlags::MNT_DETACH);
+        return Err(ErrorKind::ErrorCode(format!(
+            "{} was replaced before being mounted", path)).into());
+    }
+
+    mount::mount(None::<&str>, RESOLV_CONF_DEST, None::<&str>,
+        MsFlags::MS_BIND | MsFlags::MS_REMOUNT | MsFlags::MS_RDONLY, None::<&str>)?;
+    Ok(())
+}
+
+// Mounts content over the resolv.conf of the container of pid, from its
+// own mount namespace since a bind mount cannot come from another one.
+// The file is staged on the /dev tmpfs of the container, as a new file
+// under a random name, and unlinked once mounted.
+pub fn rebind_resolv_conf(pid: i32, content: &str) -> Result<()> {
+    let content = content.to_string();
+
+    // setns() into a mount namespace wants a thread with a fs of its own
+    let t = thread::spawn(move || -> Result<()> {
+        let staged = staged_resolv_conf()?;
+
+        unshare(CloneFlags::CLONE_FS)?;
+        let ns = File::open(format!("/proc/{}/ns/mnt", pid))?;
+        setns(ns.as_raw_fd(), CloneFlags::CLONE_NEWNS)?;
+
+        // never through a link or into a file the container left there
+        let mut f = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .custom_flags(libc::O_NOFOLLOW)
+            .mode(0o644)
+            .open(staged.as_str())?;
+
+        let r = f.write_all(content.as_bytes())
+            .map_err(Error::from)
+            .and_then(|_| mount_resolv_conf(&f, staged.as_str()));
+        let _ = fs::remove_file(staged.as_str());
+
+        r
+    });
+
+    match t.join() {
+        Ok(r) => r,
+        Err(_) => Err(ErrorKind::ErrorCode(format!(
+            "cannot update the resolv.conf of {}", pid)).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolv_conf() {
+        let mut n = Network::new();
+        n.set_dns(vec![
+            "nameserver 10.0.0.2".to_string(),
+            "8.8.8.8".to_string(),
+            "search default.svc.cluster.local svc.cluster.local".to_string(),
+            " options ndots:5".to_string(),
+        ]).unwrap();
+        assert_eq!(n.resolv_conf(), "nameserver 10.0.0.2\nnameserver 8.8.8.8\n\
+            search default.svc.cluster.local svc.cluster.local\noptions ndots:5\n");
+
+        assert!(n.set_dns(vec!["8.8.8.8\nnameserver 1.1.1.1".to_string()]).is_err());
+        assert!(n.set_dns(vec!["resolver 1.1.1.1".to_string()]).is_err());
+        // a bad update keeps the previous entries
+        assert!(n.has_dns());
+
+        let path = std::env::temp_dir().join(format!("resolv.conf.{}", std::process::id()));
+        let path = path.to_str().unwrap();
+        write_resolv_conf(path, "nameserver 10.0.0.2\nnameserver 8.8.8.8\n").unwrap();
+        write_resolv_conf(path, "nameserver 1.1.1.1\n").unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "nameserver 1.1.1.1\n");
+        let _ = fs::remove_file(path);
     }
 }
@@ -0,0 +1,203 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The OOM events of all the containers, queued for GetOOMEvent, and what
+// WaitProcess needs to tell an OOM kill from any other SIGKILL. A single
+// dispatcher hands every event to the callers in turn, an event a caller
+// gone in the meantime could not take goes to the next one.
+
+use rustjail::container::{Container, LinuxContainer};
+use rustjail::errors::*;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+// how a SIGKILLed process exits
+const SIGKILL_STATUS: i32 = 128 + libc::SIGKILL;
+// the decisions kept for late waiters, as many as exit statuses
+const MAX_EXITS: usize = 1024;
+
+#[derive(Debug, Default)]
+struct Kills {
+	// events received for the container
+	seen: u64,
+	// kills put down to one of its processes
+	accounted: u64,
+	// what was decided for the processes gone last, by exec id
+	exits: HashMap<String, bool>,
+	order: VecDeque<String>,
+}
+
+// gets the container id of an event, tells whether it reached the caller
+pub type Deliver = Box<dyn FnOnce(String) -> bool + Send>;
+
+#[derive(Default)]
+struct Queue {
+	events: VecDeque<String>,
+	waiters: VecDeque<Deliver>,
+}
+
+impl fmt::Debug for Queue {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Queue {{ events: {:?}, waiters: {} }}", self.events, self.waiters.len())
+	}
+}
+
+#[derive(Debug, Default)]
+pub struct OomEvents {
+	queue: Mutex<Queue>,
+	cond: Condvar,
+	kills: Mutex<HashMap<String, Kills>>,
+}
+
+impl OomEvents {
+	pub fn new() -> Arc<OomEvents> {
+		let events = Arc::new(OomEvents::default());
+		let dispatcher = Arc::clone(&events);
+
+		thread::spawn(move || loop {
+			dispatcher.dispatch();
+		});
+
+		events
+	}
+
+	// forwards the events of ctr until its cgroup goes away
+	pub fn watch(events: &Arc<OomEvents>, ctr: &LinuxContainer) -> Result<()> {
+		let rx = ctr.notify_oom()?;
+		let events = Arc::clone(events);
+
+		events.kills.lock().unwrap().insert(ctr.id.clone(), Kills::default());
+
+		thread::spawn(move || {
+			for cid in rx.iter() {
+				info!("OOM in container {}", cid);
+
+				if let Some(k) = events.kills.lock().unwrap().get_mut(&cid) {
+					k.seen += 1;
+				}
+				events.queue.lock().unwrap().events.push_back(cid);
+				events.cond.notify_all();
+			}
+		});
+
+		Ok(())
+	}
+
+	pub fn forget(&self, cid: &str) {
+		self.kills.lock().unwrap().remove(cid);
+	}
+
+	// deliver gets the next event no other caller took, without a thread
+	// waiting on it
+	pub fn wait(&self, deliver: Deliver) {
+		self.queue.lock().unwrap().waiters.push_back(deliver);
+		self.cond.notify_all();
+	}
+
+	// blocks until the next event, for a caller that cannot go away
+	pub fn next(&self) -> Result<String> {
+		let (tx, rx) = channel();
+		self.wait(Box::new(move |cid| tx.send(cid).is_ok()));
+		rx.recv().map_err(|_| ErrorKind::ErrorCode("the OOM event queue is closed".to_string()).into())
+	}
+
+	// blocks until there are an event and a caller for it
+	fn dispatch(&self) {
+		let (cid, deliver) = {
+			let mut q = self.queue.lock().unwrap();
+			while q.events.is_empty() || q.waiters.is_empty() {
+				q = self.cond.wait(q).unwrap();
+			}
+			(q.events.pop_front().unwrap(), q.waiters.pop_front().unwrap())
+		};
+
+		// sent without the lock, a slow caller must not hold up the others
+		if !deliver(cid.clone()) {
+			debug!("OOM event of {} kept for the next caller", cid);
+			self.queue.lock().unwrap().events.push_front(cid);
+		}
+	}
+
+	// whether the exit status of eid comes from an OOM kill; cgroup_kills
+	// is the count of the kernel, the events stand in for it on kernels
+	// that do not keep one
+	pub fn oom_killed(&self, cid: &str, eid: &str, status: i32, cgroup_kills: u64) -> bool {
+		let mut kills = self.kills.lock().unwrap();
+		let k = match kills.get_mut(cid) {
+			Some(k) => k,
+			None => return false,
+		};
+
+		// concurrent waiters get the same answer
+		if let Some(oom) = k.exits.get(eid) {
+			return *oom;
+		}
+
+		let oom = status == SIGKILL_STATUS && k.accounted < cgroup_kills.max(k.seen);
+		if oom {
+			k.accounted += 1;
+		}
+		k.exits.insert(eid.to_string(), oom);
+		k.order.push_back(eid.to_string());
+
+		while k.order.len() > MAX_EXITS {
+			if let Some(e) = k.order.pop_front() {
+				k.exits.remove(&e);
+			}
+		}
+
+		oom
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_oom_killed() {
+		let events = OomEvents::default();
+		events.kills.lock().unwrap().insert("c".to_string(), Kills::default());
+
+		assert!(!events.oom_killed("c", "a", SIGKILL_STATUS, 0));
+		assert!(!events.oom_killed("c", "b", 1, 1));
+		assert!(!events.oom_killed("x", "c", SIGKILL_STATUS, 1));
+
+		// one kill is put down to one process, the answer does not change
+		assert!(events.oom_killed("c", "c", SIGKILL_STATUS, 1));
+		assert!(!events.oom_killed("c", "d", SIGKILL_STATUS, 1));
+		assert!(!events.oom_killed("c", "a", SIGKILL_STATUS, 2));
+		assert!(events.oom_killed("c", "c", SIGKILL_STATUS, 1));
+
+		events.kills.lock().unwrap().get_mut("c").unwrap().seen = 2;
+		assert!(events.oom_killed("c", "e", SIGKILL_STATUS, 0));
+
+		// only the last decisions are kept
+		for i in 0..MAX_EXITS {
+			events.oom_killed("c", format!("x{}", i).as_str(), 0, 0);
+		}
+		let kills = events.kills.lock().unwrap();
+		assert_eq!(kills["c"].exits.len(), MAX_EXITS);
+		assert!(!kills["c"].exits.contains_key("c"));
+		drop(kills);
+
+		events.queue.lock().unwrap().events.push_back("c".to_string());
+		events.wait(Box::new(|_| false));
+		let (tx, rx) = channel();
+		events.wait(Box::new(move |cid| tx.send(cid).is_ok()));
+
+		// the caller gone does not lose the event
+		events.dispatch();
+		events.dispatch();
+		assert_eq!(rx.try_recv().unwrap(), "c");
+		assert!(events.queue.lock().unwrap().events.is_empty());
+
+		events.forget("c");
+		assert!(!events.oom_killed("c", "c", SIGKILL_STATUS, 1));
+	}
+}
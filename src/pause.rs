@@ -0,0 +1,74 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The pause process is the init of the sandbox pid namespace. It keeps
+// the namespace alive while containers come and go and reaps whatever
+// gets orphaned in there. It is the agent binary itself, started with
+// PAUSE_ARG as its only argument.
+
+use libc::{self, c_int, pid_t};
+use nix::sched::{self, CloneFlags};
+use nix::sys::signal::{self, SigAction, SigHandler, SaFlags, SigSet, Signal};
+use nix::sys::wait::{self, WaitPidFlag, WaitStatus};
+use nix::unistd;
+use rustjail::errors::*;
+use std::ffi::CString;
+use std::mem;
+
+pub const PAUSE_ARG: &'static str = "pause";
+const PAUSE_BIN: &'static str = "/proc/self/exe";
+const CLONE_STACK_SIZE: usize = 64 * 1024;
+
+extern "C" fn handle_sigchld(_: c_int) {}
+
+// main loop of the pause process, never returns
+pub fn run() -> ! {
+	let _ = prctl::set_name(PAUSE_ARG);
+
+	// SIGCHLD stays blocked but for sigsuspend(), so that no exit can
+	// slip in between the reaping and the wait
+	let mut mask = SigSet::empty();
+	mask.add(Signal::SIGCHLD);
+	let _ = mask.thread_block();
+
+	let sa = SigAction::new(SigHandler::Handler(handle_sigchld),
+		SaFlags::SA_NOCLDSTOP, SigSet::empty());
+	let _ = unsafe { signal::sigaction(Signal::SIGCHLD, &sa) };
+
+	loop {
+		loop {
+			match wait::waitpid(None, Some(WaitPidFlag::WNOHANG)) {
+				Ok(WaitStatus::StillAlive) | Err(_) => break,
+				Ok(_) => continue,
+			}
+		}
+
+		unsafe {
+			let mut empty: libc::sigset_t = mem::zeroed();
+			libc::sigemptyset(&mut empty);
+			libc::sigsuspend(&empty);
+		}
+	}
+}
+
+// start the pause process in a new pid namespace, it is a direct child
+// of the agent so the reaper collects it like any other
+pub fn start() -> Result<pid_t> {
+	let path = CString::new(PAUSE_BIN)?;
+	let args = [path.clone(), CString::new(PAUSE_ARG)?];
+	let mut stack = vec![0u8; CLONE_STACK_SIZE];
+
+	// nothing but exec in the child, the agent is multi threaded
+	let cb = Box::new(|| -> isize {
+		let _ = unistd::execv(&path, &args);
+		unsafe { libc::_exit(1) }
+	});
+
+	let pid = sched::clone(cb, stack.as_mut_slice(),
+		CloneFlags::CLONE_NEWPID, Some(libc::SIGCHLD))?;
+
+	info!("pause process started: {}", pid);
+	Ok(pid.as_raw())
+}
@@ -0,0 +1,255 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Memory pressure events, from the thresholds given for each container at
+// creation to every SubscribeMemoryPressure stream. A subscriber that falls
+// behind has at most one event pending per container and level, the ones
+// coming meanwhile are folded into it.
+
+use protocols::agent::{MemoryPressureEvent, MemoryPressureThreshold};
+use rustjail::cgroups::notify::{PsiTrigger, PRESSURE_CRITICAL, PRESSURE_LOW, PRESSURE_MEDIUM};
+use rustjail::container::{Container, LinuxContainer};
+use rustjail::errors::*;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// the bounds the kernel puts on a PSI window
+const PSI_WINDOW_MIN_US: u64 = 500_000;
+const PSI_WINDOW_MAX_US: u64 = 10_000_000;
+const PSI_WINDOW_US: u64 = 1_000_000;
+
+// the events of a subscriber not taken yet, for when it follows more
+// containers than the coalescing alone keeps in check
+const MAX_PENDING: usize = 64;
+
+#[derive(Default)]
+struct Pending {
+	// container id and level of the events in the channel
+	keys: HashSet<(String, String)>,
+	// called once the next event is there
+	wake: Option<Box<dyn Fn() + Send>>,
+}
+
+struct Subscriber {
+	// all of them when empty
+	cids: Vec<String>,
+	tx: SyncSender<MemoryPressureEvent>,
+	pending: Arc<Mutex<Pending>>,
+}
+
+impl fmt::Debug for Subscriber {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Subscriber {{ cids: {:?} }}", self.cids)
+	}
+}
+
+#[derive(Debug, Default)]
+pub struct PressureEvents {
+	subscribers: Mutex<Vec<Subscriber>>,
+}
+
+// unsubscribes when dropped
+pub struct Subscription {
+	events: Arc<PressureEvents>,
+	rx: Receiver<MemoryPressureEvent>,
+	pending: Arc<Mutex<Pending>>,
+}
+
+impl Subscription {
+	// the next event if there is one, else wake is called when it comes
+	pub fn next_or_wake(&self, wake: Box<dyn Fn() + Send>) -> Option<MemoryPressureEvent> {
+		let mut pending = self.pending.lock().unwrap();
+
+		match self.rx.try_recv() {
+			Ok(ev) => {
+				pending.keys.remove(&(ev.container_id.clone(), ev.level.clone()));
+				Some(ev)
+			}
+			Err(_) => {
+				pending.wake = Some(wake);
+				None
+			}
+		}
+	}
+}
+
+impl Drop for Subscription {
+	fn drop(&mut self) {
+		let pending = &self.pending;
+		self.events.subscribers.lock().unwrap().retain(|s| !Arc::ptr_eq(&s.pending, pending));
+	}
+}
+
+impl PressureEvents {
+	// one watch per threshold, each until the cgroup of ctr goes away;
+	// the thresholds are checked by psi_triggers() beforehand
+	pub fn watch(events: &Arc<PressureEvents>, ctr: &LinuxContainer, thresholds: &[MemoryPressureThreshold]) -> Result<()> {
+		for (t, psi) in thresholds.iter().zip(psi_triggers(thresholds)?) {
+			let rx = ctr.notify_memory_pressure(t.level.as_str(), &psi)?;
+			let level = t.level.clone();
+			let events = Arc::clone(events);
+
+			thread::spawn(move || {
+				for cid in rx.iter() {
+					debug!("memory pressure {} in container {}", level, cid);
+					events.publish(cid.as_str(), level.as_str());
+				}
+			});
+		}
+
+		Ok(())
+	}
+
+	pub fn subscribe(events: &Arc<PressureEvents>, cids: Vec<String>) -> Subscription {
+		let (tx, rx) = sync_channel(MAX_PENDING);
+		let pending = Arc::new(Mutex::new(Pending::default()));
+
+		events.subscribers.lock().unwrap().push(Subscriber {
+			cids: cids,
+			tx: tx,
+			pending: Arc::clone(&pending),
+		});
+
+		Subscription {
+			events: Arc::clone(events),
+			rx: rx,
+			pending: pending,
+		}
+	}
+
+	// never blocks, whatever the subscribers do
+	fn publish(&self, cid: &str, level: &str) {
+		let mut ev = MemoryPressureEvent::new();
+		ev.container_id = cid.to_string();
+		ev.level = level.to_string();
+		let key = (cid.to_string(), level.to_string());
+
+		for s in self.subscribers.lock().unwrap().iter() {
+			if !s.cids.is_empty() && !s.cids.iter().any(|c| c == cid) {
+				continue;
+			}
+
+			let mut pending = s.pending.lock().unwrap();
+			if pending.keys.contains(&key) {
+				continue;
+			}
+
+			match s.tx.try_send(ev.clone()) {
+				Ok(_) => {
+					pending.keys.insert(key.clone());
+					if let Some(wake) = pending.wake.take() {
+						wake();
+					}
+				}
+				Err(TrySendError::Full(_)) => debug!("memory pressure {} of {} dropped, the subscriber is behind", level, cid),
+				// the subscription goes away with its receiver
+				Err(TrySendError::Disconnected(_)) => {}
+			}
+		}
+	}
+}
+
+// what the levels stand for on cgroup v2, 0 in a threshold for these
+pub fn psi_triggers(thresholds: &[MemoryPressureThreshold]) -> Result<Vec<PsiTrigger>> {
+	thresholds.iter().map(|t| {
+		let (full, stall_us) = match t.level.as_str() {
+			PRESSURE_LOW => (false, PSI_WINDOW_US / 20),
+			PRESSURE_MEDIUM => (false, PSI_WINDOW_US * 3 / 20),
+			PRESSURE_CRITICAL => (true, PSI_WINDOW_US / 10),
+			_ => return Err(ErrorKind::InvalidArgument(format!(
+				"unknown memory pressure level {}", t.level)).into()),
+		};
+
+		let window_us = if t.window_us == 0 { PSI_WINDOW_US } else { t.window_us };
+		// the default stall is a share of the window
+		let stall_us = if t.stall_us == 0 { stall_us * window_us / PSI_WINDOW_US } else { t.stall_us };
+
+		if window_us < PSI_WINDOW_MIN_US || window_us > PSI_WINDOW_MAX_US || stall_us > window_us {
+			return Err(ErrorKind::InvalidArgument(format!(
+				"bad memory pressure threshold {}: {}us in {}us", t.level, stall_us, window_us)).into());
+		}
+
+		Ok(PsiTrigger {
+			full: full,
+			stall_us: stall_us,
+			window_us: window_us,
+		})
+	}).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn threshold(level: &str, stall_us: u64, window_us: u64) -> MemoryPressureThreshold {
+		let mut t = MemoryPressureThreshold::new();
+		t.level = level.to_string();
+		t.stall_us = stall_us;
+		t.window_us = window_us;
+		t
+	}
+
+	#[test]
+	fn test_psi_triggers() {
+		let psi = psi_triggers(&[
+			threshold("low", 0, 0),
+			threshold("critical", 0, 2_000_000),
+			threshold("medium", 300_000, 0),
+		]).unwrap();
+
+		assert_eq!(psi[0], PsiTrigger { full: false, stall_us: 50_000, window_us: 1_000_000 });
+		assert_eq!(psi[1], PsiTrigger { full: true, stall_us: 200_000, window_us: 2_000_000 });
+		assert_eq!(psi[2], PsiTrigger { full: false, stall_us: 300_000, window_us: 1_000_000 });
+
+		assert!(psi_triggers(&[threshold("high", 0, 0)]).is_err());
+		assert!(psi_triggers(&[threshold("low", 0, 100_000)]).is_err());
+		assert!(psi_triggers(&[threshold("low", 2_000_000, 1_000_000)]).is_err());
+	}
+
+	fn drain(sub: &Subscription) -> Vec<(String, String)> {
+		let mut got = Vec::new();
+		while let Some(e) = sub.next_or_wake(Box::new(|| {})) {
+			got.push((e.container_id, e.level));
+		}
+		got
+	}
+
+	fn event(cid: &str, level: &str) -> (String, String) {
+		(cid.to_string(), level.to_string())
+	}
+
+	#[test]
+	fn test_publish() {
+		let events = Arc::new(PressureEvents::default());
+		let all = PressureEvents::subscribe(&events, Vec::new());
+		let one = PressureEvents::subscribe(&events, vec!["b".to_string()]);
+		drop(PressureEvents::subscribe(&events, Vec::new()));
+		assert_eq!(events.subscribers.lock().unwrap().len(), 2);
+
+		events.publish("a", "low");
+		events.publish("b", "critical");
+		// folded into the one not taken yet
+		events.publish("a", "low");
+
+		assert_eq!(drain(&all), vec![event("a", "low"), event("b", "critical")]);
+		assert_eq!(drain(&one), vec![event("b", "critical")]);
+
+		// taken, so a new one comes, and wakes the subscriber up
+		let (tx, rx) = std::sync::mpsc::channel();
+		assert!(all.next_or_wake(Box::new(move || tx.send(()).unwrap())).is_none());
+		events.publish("a", "low");
+		assert!(rx.try_recv().is_ok());
+		assert_eq!(drain(&all), vec![event("a", "low")]);
+
+		// a stalled subscriber holds no more than MAX_PENDING
+		for i in 0..MAX_PENDING * 2 {
+			events.publish(format!("c{}", i).as_str(), "low");
+		}
+		assert_eq!(drain(&all).len(), MAX_PENDING);
+	}
+}
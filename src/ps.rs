@@ -0,0 +1,375 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// A minimal ps(1) replacement: the guest has no procps and the
+// agent must only report processes living in the container cgroup.
+
+use libc;
+use rustjail::errors::*;
+use rustjail::cgroups::fs::CLOCK_TICKS;
+use std::fs;
+use std::time::SystemTime;
+
+const PROC_PATH: &'static str = "/proc";
+const MONTHS: [&'static str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun",
+	"Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+// columns printed by "ps" and "ps -f"
+const DEFAULT_COLUMNS: [&'static str; 4] = ["pid", "tty", "time", "comm"];
+const FULL_COLUMNS: [&'static str; 8] = ["uid", "pid", "ppid", "c", "stime", "tty", "time", "args"];
+
+#[derive(Debug, Default)]
+pub struct ProcInfo {
+	pub pid: i32,
+	pub ppid: i32,
+	pub uid: u32,
+	pub state: String,
+	pub comm: String,
+	pub args: String,
+	pub tty_nr: i32,
+	pub utime: u64,
+	pub stime: u64,
+	pub start_time: u64,
+	pub vsize: u64,
+	pub rss: i64,
+}
+
+impl ProcInfo {
+	pub fn load(pid: i32) -> Result<ProcInfo> {
+		let stat = fs::read_to_string(format!("{}/{}/stat", PROC_PATH, pid))?;
+
+		// comm may contain spaces and parentheses, it ends at the last ')'
+		let (start, end) = match (stat.find('('), stat.rfind(')')) {
+			(Some(s), Some(e)) if s < e => (s, e),
+			_ => return Err(ErrorKind::ErrorCode(
+				format!("malformed stat for pid {}", pid)).into()),
+		};
+
+		let comm = stat[start + 1..end].to_string();
+		let fields: Vec<&str> = stat[end + 1..].split_whitespace().collect();
+		// fields[0] is field 3 (state) in proc(5)
+		if fields.len() < 22 {
+			return Err(ErrorKind::ErrorCode(
+				format!("malformed stat for pid {}", pid)).into());
+		}
+
+		let mut uid = 0;
+		let status = fs::read_to_string(format!("{}/{}/status", PROC_PATH, pid))?;
+		for line in status.lines() {
+			if line.starts_with("Uid:") {
+				uid = line.split_whitespace().nth(1).unwrap_or("0").parse::<u32>()?;
+				break;
+			}
+		}
+
+		let cmdline = fs::read(format!("{}/{}/cmdline", PROC_PATH, pid))?;
+		let args = cmdline.split(|c| *c == 0)
+			.filter(|a| !a.is_empty())
+			.map(|a| String::from_utf8_lossy(a).to_string())
+			.collect::<Vec<String>>()
+			.join(" ");
+
+		Ok(ProcInfo {
+			pid,
+			ppid: fields[1].parse::<i32>()?,
+			uid,
+			state: fields[0].to_string(),
+			comm,
+			args,
+			tty_nr: fields[4].parse::<i32>()?,
+			utime: fields[11].parse::<u64>()?,
+			stime: fields[12].parse::<u64>()?,
+			start_time: fields[19].parse::<u64>()?,
+			vsize: fields[20].parse::<u64>()?,
+			rss: fields[21].parse::<i64>()?,
+		})
+	}
+
+	fn cpu_secs(&self) -> u64 {
+		((self.utime + self.stime) as f64 / *CLOCK_TICKS) as u64
+	}
+}
+
+struct Column {
+	header: &'static str,
+	right: bool,
+	value: fn(&ProcInfo, &Context) -> String,
+}
+
+struct Context {
+	boot_time: u64,
+	now: u64,
+	page_kb: i64,
+}
+
+fn get_column(name: &str) -> Result<Column> {
+	let c = match name {
+		"uid" | "user" | "euid" => Column { header: "UID", right: false, value: |p, _| p.uid.to_string() },
+		"pid" => Column { header: "PID", right: true, value: |p, _| p.pid.to_string() },
+		"ppid" => Column { header: "PPID", right: true, value: |p, _| p.ppid.to_string() },
+		"c" => Column { header: "C", right: true, value: fmt_cpu },
+		"stime" | "start" => Column { header: "STIME", right: false, value: fmt_stime },
+		"tty" | "tt" => Column { header: "TTY", right: false, value: |p, _| fmt_tty(p.tty_nr) },
+		"time" => Column { header: "TIME", right: true, value: |p, _| fmt_time(p.cpu_secs()) },
+		"s" | "stat" | "state" => Column { header: "S", right: false, value: |p, _| p.state.clone() },
+		"vsz" => Column { header: "VSZ", right: true, value: |p, _| (p.vsize / 1024).to_string() },
+		"rss" => Column { header: "RSS", right: true, value: |p, c| (p.rss * c.page_kb).to_string() },
+		"comm" | "ucmd" => Column { header: "CMD", right: false, value: |p, _| p.comm.clone() },
+		"args" | "cmd" | "command" => Column { header: "CMD", right: false, value: |p, _| {
+			if p.args.is_empty() {
+				format!("[{}]", p.comm)
+			} else {
+				p.args.clone()
+			}
+		}},
+		_ => return Err(ErrorKind::ErrorCode(format!("unsupported ps column {}", name)).into()),
+	};
+
+	Ok(c)
+}
+
+// parse the subset of ps options the runtime is expected to send,
+// e.g. "-ef", "-e -o pid,comm" or "aux"
+fn parse_args(args: &[String]) -> Result<Vec<String>> {
+	let mut full = false;
+	let mut custom: Vec<String> = Vec::new();
+	let mut iter = args.iter();
+
+	while let Some(arg) = iter.next() {
+		let flags = arg.trim_start_matches('-');
+		let mut chars = flags.char_indices();
+
+		while let Some((i, c)) = chars.next() {
+			match c {
+				// process selection, we always show the whole container
+				'e' | 'A' | 'a' | 'x' => {}
+				'f' | 'u' => full = true,
+				'o' => {
+					let list = if i + 1 < flags.len() {
+						flags[i + 1..].to_string()
+					} else {
+						match iter.next() {
+							Some(v) => v.clone(),
+							None => return Err(ErrorKind::ErrorCode(
+								"ps option -o requires an argument".to_string()).into()),
+						}
+					};
+
+					for col in list.split(|c| c == ',' || c == ' ') {
+						if !col.is_empty() {
+							custom.push(col.to_lowercase());
+						}
+					}
+					break;
+				}
+				_ => return Err(ErrorKind::ErrorCode(
+					format!("unsupported ps option {}", arg)).into()),
+			}
+		}
+	}
+
+	if !custom.is_empty() {
+		return Ok(custom);
+	}
+
+	let cols: &[&str] = if full { &FULL_COLUMNS } else { &DEFAULT_COLUMNS };
+	Ok(cols.iter().map(|c| c.to_string()).collect())
+}
+
+// render a ps-compatible table for pids, honoring ps style args
+pub fn render_table(pids: &[i32], args: &[String]) -> Result<String> {
+	let mut cols = Vec::new();
+	for name in parse_args(args)? {
+		cols.push(get_column(name.as_str())?);
+	}
+
+	let ctx = Context {
+		boot_time: get_boot_time()?,
+		now: SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)
+			.map(|d| d.as_secs()).unwrap_or(0),
+		page_kb: unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as i64 / 1024,
+	};
+
+	let mut rows: Vec<Vec<String>> = Vec::new();
+	rows.push(cols.iter().map(|c| c.header.to_string()).collect());
+
+	let mut pids = pids.to_vec();
+	pids.sort();
+	for pid in pids {
+		// the process may exit while we are walking the list
+		let p = match ProcInfo::load(pid) {
+			Ok(v) => v,
+			Err(_) => continue,
+		};
+
+		rows.push(cols.iter().map(|c| (c.value)(&p, &ctx)).collect());
+	}
+
+	let mut widths = vec![0; cols.len()];
+	for row in &rows {
+		for (i, v) in row.iter().enumerate() {
+			if v.len() > widths[i] {
+				widths[i] = v.len();
+			}
+		}
+	}
+
+	let mut out = String::new();
+	for row in &rows {
+		let mut line = Vec::new();
+		for (i, v) in row.iter().enumerate() {
+			if i == cols.len() - 1 && !cols[i].right {
+				line.push(v.clone());
+			} else if cols[i].right {
+				line.push(format!("{:>1$}", v, widths[i]));
+			} else {
+				line.push(format!("{:<1$}", v, widths[i]));
+			}
+		}
+		out.push_str(line.join(" ").trim_end());
+		out.push('\n');
+	}
+
+	Ok(out)
+}
+
+fn get_boot_time() -> Result<u64> {
+	for line in fs::read_to_string(format!("{}/stat", PROC_PATH))?.lines() {
+		if line.starts_with("btime") {
+			return Ok(line.split_whitespace().nth(1).unwrap_or("0").parse::<u64>()?);
+		}
+	}
+
+	Err(ErrorKind::ErrorCode("cannot get boot time".to_string()).into())
+}
+
+fn fmt_cpu(p: &ProcInfo, ctx: &Context) -> String {
+	let start = ctx.boot_time + (p.start_time as f64 / *CLOCK_TICKS) as u64;
+	if ctx.now <= start {
+		return "0".to_string();
+	}
+
+	(p.cpu_secs() * 100 / (ctx.now - start)).to_string()
+}
+
+fn fmt_stime(p: &ProcInfo, ctx: &Context) -> String {
+	let start = (ctx.boot_time + (p.start_time as f64 / *CLOCK_TICKS) as u64) as libc::time_t;
+	let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+
+	if unsafe { libc::localtime_r(&start, &mut tm) }.is_null() {
+		return "?".to_string();
+	}
+
+	if ctx.now.saturating_sub(start as u64) < 24 * 3600 {
+		format!("{:02}:{:02}", tm.tm_hour, tm.tm_min)
+	} else {
+		format!("{}{:02}", MONTHS[tm.tm_mon as usize % 12], tm.tm_mday)
+	}
+}
+
+fn fmt_time(secs: u64) -> String {
+	let days = secs / 86400;
+	let s = format!("{:02}:{:02}:{:02}", (secs / 3600) % 24, (secs / 60) % 60, secs % 60);
+
+	if days > 0 {
+		format!("{}-{}", days, s)
+	} else {
+		s
+	}
+}
+
+fn fmt_tty(tty_nr: i32) -> String {
+	let major = (tty_nr >> 8) & 0xfff;
+	let minor = (tty_nr & 0xff) | ((tty_nr >> 12) & 0xfff00);
+
+	match major {
+		0 => "?".to_string(),
+		4 => format!("tty{}", minor),
+		136..=143 => format!("pts/{}", (major - 136) * 256 + minor),
+		_ => "?".to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn args(v: &[&str]) -> Vec<String> {
+		v.iter().map(|s| s.to_string()).collect()
+	}
+
+	#[test]
+	fn test_parse_args() {
+		assert_eq!(parse_args(&[]).unwrap(), args(&DEFAULT_COLUMNS));
+		assert_eq!(parse_args(&args(&["-e"])).unwrap(), args(&DEFAULT_COLUMNS));
+		assert_eq!(parse_args(&args(&["-ef"])).unwrap(), args(&FULL_COLUMNS));
+		assert_eq!(parse_args(&args(&["aux"])).unwrap(), args(&FULL_COLUMNS));
+
+		// -o wins over -f, its list is either glued or the next arg
+		assert_eq!(parse_args(&args(&["-f", "-o", "PID,comm"])).unwrap(), args(&["pid", "comm"]));
+		assert_eq!(parse_args(&args(&["-eopid,rss"])).unwrap(), args(&["pid", "rss"]));
+		assert_eq!(parse_args(&args(&["-o", "pid", "-o", "vsz"])).unwrap(), args(&["pid", "vsz"]));
+
+		assert!(parse_args(&args(&["-o"])).is_err());
+		assert!(parse_args(&args(&["-ej"])).is_err());
+		assert!(parse_args(&args(&["-T"])).is_err());
+		assert!(parse_args(&args(&["-e", "l"])).is_err());
+	}
+
+	#[test]
+	fn test_fmt_time() {
+		assert_eq!(fmt_time(0), "00:00:00");
+		assert_eq!(fmt_time(59), "00:00:59");
+		assert_eq!(fmt_time(3661), "01:01:01");
+		assert_eq!(fmt_time(86399), "23:59:59");
+		assert_eq!(fmt_time(86400), "1-00:00:00");
+		assert_eq!(fmt_time(2 * 86400 + 61), "2-00:01:01");
+	}
+
+	#[test]
+	fn test_fmt_tty() {
+		assert_eq!(fmt_tty(0), "?");
+		assert_eq!(fmt_tty(4 << 8 | 1), "tty1");
+		assert_eq!(fmt_tty(136 << 8), "pts/0");
+		assert_eq!(fmt_tty(136 << 8 | 255), "pts/255");
+		// minor bits above 0xff
+		assert_eq!(fmt_tty(136 << 8 | 1 << 20), "pts/256");
+		assert_eq!(fmt_tty(143 << 8), "pts/1792");
+		assert_eq!(fmt_tty(144 << 8), "?");
+	}
+
+	#[test]
+	fn test_start_after_now() {
+		// started an hour after the snapshot was taken
+		let ctx = Context { boot_time: 1_000_000, now: 1_000_000, page_kb: 4 };
+		let p = ProcInfo {
+			start_time: (3600.0 * *CLOCK_TICKS) as u64,
+			utime: 100,
+			..Default::default()
+		};
+
+		assert_eq!(fmt_cpu(&p, &ctx), "0");
+		// shown as started today, not as a date
+		let stime = fmt_stime(&p, &ctx);
+		assert_eq!(stime.len(), 5);
+		assert_eq!(&stime[2..3], ":");
+	}
+
+	#[test]
+	fn test_render_table() {
+		let pid = std::process::id() as i32;
+		let out = render_table(&[pid, -1], &args(&["-o", "pid,comm"])).unwrap();
+		let lines: Vec<&str> = out.lines().collect();
+
+		// a pid that cannot be loaded is skipped
+		assert_eq!(lines.len(), 2);
+		assert!(lines[0].trim_start().starts_with("PID CMD"));
+		assert!(lines[1].trim_start().starts_with(&format!("{} ", pid)));
+
+		assert!(render_table(&[pid], &args(&["-o", "pid,bogus"])).is_err());
+		// read as -f -o rest
+		assert!(render_table(&[pid], &args(&["--forest"])).is_err());
+	}
+}
@@ -0,0 +1,220 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// map agent errors to rpc status, so that the host can tell a
+// bad request apart from a failure inside the guest
+
+#[cfg(feature = "grpc")]
+use futures::Future;
+#[cfg(feature = "grpc")]
+use grpcio::{RpcContext, RpcStatus, RpcStatusCode, UnarySink};
+use nix::errno::Errno;
+#[cfg(feature = "ttrpc")]
+use protocols::ttrpc;
+use rustjail::errors::*;
+use std::io;
+
+use crate::metrics;
+
+// the codes agent errors map to, the same in gRPC and ttrpc
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Code {
+	NotFound,
+	InvalidArgument,
+	FailedPrecondition,
+	DeadlineExceeded,
+	Unimplemented,
+	Internal,
+}
+
+fn errno_code(errno: Errno) -> Option<Code> {
+	match errno {
+		Errno::ENOENT | Errno::ESRCH => Some(Code::NotFound),
+		Errno::EINVAL => Some(Code::InvalidArgument),
+		Errno::ETIMEDOUT => Some(Code::DeadlineExceeded),
+		_ => None,
+	}
+}
+
+fn nix_code(e: &nix::Error) -> Option<Code> {
+	match e {
+		nix::Error::Sys(errno) => errno_code(*errno),
+		nix::Error::InvalidPath | nix::Error::InvalidUtf8 => Some(Code::InvalidArgument),
+		_ => None,
+	}
+}
+
+fn io_code(e: &io::Error) -> Option<Code> {
+	match e.kind() {
+		io::ErrorKind::NotFound => Some(Code::NotFound),
+		io::ErrorKind::InvalidInput => Some(Code::InvalidArgument),
+		io::ErrorKind::TimedOut => Some(Code::DeadlineExceeded),
+		_ => e.raw_os_error().and_then(|n| errno_code(Errno::from_i32(n))),
+	}
+}
+
+fn kind_code(kind: &ErrorKind) -> Option<Code> {
+	match kind {
+		ErrorKind::NotFound(_) => Some(Code::NotFound),
+		ErrorKind::InvalidArgument(_) => Some(Code::InvalidArgument),
+		ErrorKind::FailedPrecondition(_) => Some(Code::FailedPrecondition),
+		ErrorKind::DeadlineExceeded(_) => Some(Code::DeadlineExceeded),
+		ErrorKind::Unimplemented(_) => Some(Code::Unimplemented),
+		ErrorKind::Nix(e) => nix_code(e),
+		ErrorKind::Io(e) => io_code(e),
+		_ => None,
+	}
+}
+
+// the outermost error that says something about the cause wins,
+// context added with chain_err() is skipped over
+pub fn status_code(e: &Error) -> Code {
+	if let Some(code) = kind_code(e.kind()) {
+		return code;
+	}
+
+	let mut next = e.1.next_error.as_ref();
+	while let Some(cause) = next {
+		if let Some(e) = cause.downcast_ref::<Error>() {
+			if let Some(code) = kind_code(e.kind()) {
+				return code;
+			}
+			next = e.1.next_error.as_ref();
+			continue;
+		}
+
+		let code = if let Some(e) = cause.downcast_ref::<nix::Error>() {
+			nix_code(e)
+		} else if let Some(e) = cause.downcast_ref::<io::Error>() {
+			io_code(e)
+		} else {
+			None
+		};
+
+		return code.unwrap_or(Code::Internal);
+	}
+
+	Code::Internal
+}
+
+// the whole chain, outermost first
+pub fn status_message(e: &Error) -> String {
+	e.iter()
+		.map(|e| e.to_string())
+		.collect::<Vec<String>>()
+		.join(": ")
+}
+
+#[cfg(feature = "grpc")]
+fn grpc_code(code: Code) -> RpcStatusCode {
+	match code {
+		Code::NotFound => RpcStatusCode::NotFound,
+		Code::InvalidArgument => RpcStatusCode::InvalidArgument,
+		Code::FailedPrecondition => RpcStatusCode::FailedPrecondition,
+		Code::DeadlineExceeded => RpcStatusCode::DeadlineExceeded,
+		Code::Unimplemented => RpcStatusCode::Unimplemented,
+		Code::Internal => RpcStatusCode::Internal,
+	}
+}
+
+#[cfg(feature = "grpc")]
+pub fn to_status(e: &Error) -> RpcStatus {
+	RpcStatus::new(grpc_code(status_code(e)), Some(status_message(e)))
+}
+
+// answer a unary call with either the response or the mapped error
+#[cfg(feature = "grpc")]
+pub fn reply<T>(ctx: &RpcContext, sink: UnarySink<T>, r: Result<T>, method: &'static str) {
+	let f = match r {
+		Ok(resp) => {
+			metrics::rpc_done(method, None);
+			sink.success(resp)
+		}
+		Err(e) => {
+			let status = to_status(&e);
+			warn!("{} failed: {:?} {}", method, status.status, status_message(&e));
+			metrics::rpc_done(method, Some(status_code(&e)));
+			sink.fail(status)
+		}
+	};
+
+	ctx.spawn(f.map_err(move |e| error!("failed to reply {}: {:?}", method, e)));
+}
+
+// reply() for the calls answered off the grpc workers, on a thread that
+// may block until the response is out; false when it could not be sent,
+// the caller gone
+#[cfg(feature = "grpc")]
+pub fn reply_wait<T>(sink: UnarySink<T>, r: Result<T>, method: &'static str) -> bool {
+	let f = match r {
+		Ok(resp) => {
+			metrics::rpc_done(method, None);
+			sink.success(resp)
+		}
+		Err(e) => {
+			let status = to_status(&e);
+			warn!("{} failed: {:?} {}", method, status.status, status_message(&e));
+			metrics::rpc_done(method, Some(status_code(&e)));
+			sink.fail(status)
+		}
+	};
+
+	match f.wait() {
+		Ok(_) => true,
+		Err(e) => {
+			error!("failed to reply {}: {:?}", method, e);
+			false
+		}
+	}
+}
+
+#[cfg(feature = "ttrpc")]
+fn ttrpc_code(code: Code) -> ttrpc::Code {
+	match code {
+		Code::NotFound => ttrpc::Code::NotFound,
+		Code::InvalidArgument => ttrpc::Code::InvalidArgument,
+		Code::FailedPrecondition => ttrpc::Code::FailedPrecondition,
+		Code::DeadlineExceeded => ttrpc::Code::DeadlineExceeded,
+		Code::Unimplemented => ttrpc::Code::Unimplemented,
+		Code::Internal => ttrpc::Code::Internal,
+	}
+}
+
+// the ttrpc flavour of reply(), the server sends what is returned
+#[cfg(feature = "ttrpc")]
+pub fn ttrpc_reply<T>(r: Result<T>, method: &'static str) -> ttrpc::Result<T> {
+	metrics::rpc_done(method, r.as_ref().err().map(status_code));
+
+	r.map_err(|e| {
+		let code = ttrpc_code(status_code(&e));
+		warn!("{} failed: {:?} {}", method, code, status_message(&e));
+		ttrpc::Error::status(code, status_message(&e))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_status_code() {
+		let e: Error = ErrorKind::NotFound("container a".to_string()).into();
+		assert_eq!(status_code(&e), Code::NotFound);
+
+		let e: Error = nix::Error::Sys(Errno::EINVAL).into();
+		assert_eq!(status_code(&e), Code::InvalidArgument);
+
+		let r: Result<()> = Err(ErrorKind::DeadlineExceeded("stop".to_string()).into());
+		let e = r.chain_err(|| "remove container").unwrap_err();
+		assert_eq!(status_code(&e), Code::DeadlineExceeded);
+		assert_eq!(status_message(&e), "remove container: Deadline Exceeded: 'stop'");
+
+		let e: Error = ErrorKind::Unimplemented("scsi".to_string()).into();
+		assert_eq!(status_code(&e), Code::Unimplemented);
+
+		let e: Error = ErrorKind::ErrorCode("boom".to_string()).into();
+		assert_eq!(status_code(&e), Code::Internal);
+	}
+}
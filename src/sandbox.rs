@@ -4,7 +4,7 @@
 //
 
 //use crate::container::Container;
-use crate::namespace::{setup_persistent_ns, Namespace, NSTYPEIPC, NSTYPEUTS};
+use crate::namespace::{persist_process_ns, setup_persistent_ns, Namespace, NSTYPEIPC, NSTYPEPID, NSTYPEUTS};
 use crate::network::Network;
 use rustjail::process::Process;
 use rustjail::container::LinuxContainer;
@@ -16,11 +16,22 @@ use std::sync::mpsc::{Sender, Receiver};
 use rustjail::container::BaseContainer;
 use rustjail::errors::*;
 use libc::pid_t;
+#[cfg(feature = "netlink")]
 use crate::netlink::{RtnlHandle, NETLINK_ROUTE};
 use crate::mount::{get_mount_fs_type, remove_mounts, TYPEROOTFS};
 use protocols::agent::OnlineCPUMemRequest;
 use std::fs;
+use std::os::unix::fs::MetadataExt;
 use regex::Regex;
+use std::sync::Arc;
+use crate::capture::Capture;
+use crate::oom::OomEvents;
+use crate::pressure::PressureEvents;
+use crate::pause;
+use nix::errno::Errno;
+use nix::mount::{self, MntFlags};
+use nix::sys::signal::{self, Signal};
+use nix::unistd::Pid;
 
 
 #[derive(Debug, Default)]
@@ -34,13 +45,21 @@ pub struct Sandbox {
     pub pci_device_map: HashMap<String, String>,
     pub shared_utsns: Namespace,
     pub shared_ipcns: Namespace,
+    pub shared_pidns: Namespace,
     pub storages: HashMap<String, u32>,
     pub running: bool,
     pub no_pivot_root: bool,
-    enable_grpc_trace: bool,
+    pub enable_grpc_trace: bool,
     pub sandbox_pid_ns: bool,
+	// init of the shared pid namespace, if sandbox_pid_ns
+	pub pause_pid: Option<pid_t>,
 	pub sender: Option<Sender<i32>>,
+	#[cfg(feature = "netlink")]
 	pub rtnl: Option<RtnlHandle>,
+	// output captures by container id and exec id
+	pub captures: HashMap<String, HashMap<String, Arc<Capture>>>,
+	pub oom_events: Arc<OomEvents>,
+	pub pressure_events: Arc<PressureEvents>,
 }
 
 impl Sandbox{
@@ -61,13 +80,21 @@ impl Sandbox{
             shared_ipcns: Namespace {
                 path: "".to_string(),
             },
+            shared_pidns: Namespace {
+                path: "".to_string(),
+            },
             storages: HashMap::new(),
             running: false,
             no_pivot_root: fs_type.eq(TYPEROOTFS),
             enable_grpc_trace: false,
             sandbox_pid_ns: false,
+			pause_pid: None,
 			sender: None,
+			#[cfg(feature = "netlink")]
 			rtnl: Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap()),
+			captures: HashMap::new(),
+			oom_events: OomEvents::new(),
+			pressure_events: Arc::new(PressureEvents::default()),
         })
     }
 
@@ -140,6 +167,17 @@ impl Sandbox{
             Err(err) => return Err(ErrorKind::ErrorCode(format!("Failed to setup persisten UTS namespace with error: {} ", &err)).into())
         };
 
+        // Set up shared PID namespace, held by the pause process
+        if self.sandbox_pid_ns && self.pause_pid.is_none() {
+            let pid = pause::start()?;
+            self.pause_pid = Some(pid);
+
+            self.shared_pidns = match persist_process_ns(pid, NSTYPEPID) {
+                Ok(ns) => ns,
+                Err(err) => return Err(ErrorKind::ErrorCode(format!("Failed to setup persisten PID namespace with error: {} ", &err)).into())
+            };
+        }
+
         Ok(true)
     }
 
@@ -151,6 +189,37 @@ impl Sandbox{
         self.containers.get_mut(id)
     }
 
+    pub fn add_capture(&mut self, cid: &str, eid: &str, c: Capture) {
+        self.prune_captures();
+        self.captures.entry(cid.to_string())
+            .or_insert_with(HashMap::new)
+            .insert(eid.to_string(), Arc::new(c));
+    }
+
+    pub fn get_capture(&self, cid: &str, eid: &str) -> Option<Arc<Capture>> {
+        self.captures.get(cid)
+            .and_then(|m| m.get(eid))
+            .map(|c| Arc::clone(c))
+    }
+
+    // the process of cid/eid was waited, its capture goes once the output
+    // is read to the end
+    pub fn capture_waited(&mut self, cid: &str, eid: &str) {
+        if let Some(c) = self.get_capture(cid, eid) {
+            c.set_waited();
+        }
+        self.prune_captures();
+    }
+
+    // the output read last may come after the wait, so captures are
+    // also dropped whenever a new one is added
+    fn prune_captures(&mut self) {
+        for m in self.captures.values_mut() {
+            m.retain(|_, c| !c.done());
+        }
+        self.captures.retain(|_, m| !m.is_empty());
+    }
+
     pub fn find_process<'a>(&'a mut self, pid: pid_t) -> Option<&'a mut Process> {
         for (_, c) in self.containers.iter_mut() {
            if c.processes.get(&pid).is_some() {
@@ -186,18 +255,61 @@ impl Sandbox{
 		for (_, ctr) in &mut self.containers {
 			ctr.destroy()?;
 		}
+
+		// the reaper collects it once it is gone, it may have died already
+		if let Some(pid) = self.pause_pid.take() {
+			match signal::kill(Pid::from_raw(pid), Some(Signal::SIGKILL)) {
+				Err(nix::Error::Sys(Errno::ESRCH)) => {}
+				Err(e) => return Err(e.into()),
+				Ok(_) => {}
+			}
+		}
+
+		if !self.shared_pidns.path.is_empty() {
+			let _ = mount::umount2(self.shared_pidns.path.as_str(), MntFlags::MNT_DETACH);
+			self.shared_pidns.path.clear();
+		}
+
+		self.running = false;
+
 		Ok(())
 	}
 
+	// whether the pause process still holds the shared pid namespace; its
+	// pid alone may have been reused once the reaper collected it
+	pub fn pause_alive(&self) -> bool {
+		let pid = match self.pause_pid {
+			Some(pid) => pid,
+			None => return false,
+		};
+
+		match (fs::metadata(format!("/proc/{}/ns/pid", pid)), fs::metadata(self.shared_pidns.path.as_str())) {
+			(Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+			_ => false,
+		}
+	}
+
 	pub fn online_cpu_memory(&self, req: &OnlineCPUMemRequest) -> Result<()> {
+		// refused before any cpu goes online
+		#[cfg(not(feature = "mem-hotplug"))]
+		{
+			if !req.cpu_only {
+				return Err(ErrorKind::Unimplemented(
+					"memory hotplug support is not built in".to_string()).into());
+			}
+		}
+
 		if req.nb_cpus > 0 {
 			// online cpus
 			online_cpus(req.nb_cpus as i32)?;
 		}
 
-		if !req.cpu_only {
-			// online memory
-			online_memory()?;
+		#[cfg(feature = "mem-hotplug")]
+		{
+			if !req.cpu_only {
+				// online memory
+				online_memory()?;
+			}
 		}
 
 		let cpuset = cgroups::fs::get_guest_cpuset()?;
@@ -255,6 +367,7 @@ fn online_cpus(num: i32) -> Result<i32> {
 	online_resources(CPU_ONLINE_PATH, r"cpu[0-9]+", num)
 }
 
+#[cfg(feature = "mem-hotplug")]
 fn online_memory() -> Result<()> {
 	online_resources(MEMORY_ONLINE_PATH, r"memory[0-9]+", -1)?;
 	Ok(())
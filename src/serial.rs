@@ -0,0 +1,54 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The agent API over a virtio-serial port. gRPC cannot serve a character
+// device, so it listens on GRPC_SOCKET and the yamux streams the runtime
+// opens on the port are bridged to it.
+
+use std::fs::OpenOptions;
+use std::os::unix::fs::OpenOptionsExt;
+use std::thread;
+use std::time::Duration;
+
+use crate::yamux;
+
+pub const GRPC_SOCKET: &'static str = "/run/kata-containers/agent.sock";
+// the port reads EOF while nobody is connected on the host side
+const REOPEN_INTERVAL_MS: u64 = 100;
+
+pub fn start(port: String) {
+	thread::spawn(move || {
+		info!("serving the agent API on {}", port);
+
+		// a missing port is reported once, not at every retry
+		let mut warned = false;
+
+		loop {
+			let r = OpenOptions::new()
+				.read(true)
+				.write(true)
+				.custom_flags(libc::O_CLOEXEC)
+				.open(port.as_str())
+				.and_then(|f| Ok((f.try_clone()?, f)));
+
+			match r {
+				Ok((reader, writer)) => {
+					warned = false;
+					if let Err(e) = yamux::serve(Box::new(reader), Box::new(writer), GRPC_SOCKET) {
+						warn!("yamux session on {}: {}", port, e);
+					}
+				}
+				Err(e) => {
+					if !warned {
+						warn!("cannot open {}: {}", port, e);
+						warned = true;
+					}
+				}
+			}
+
+			thread::sleep(Duration::from_millis(REOPEN_INTERVAL_MS));
+		}
+	});
+}
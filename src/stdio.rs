@@ -0,0 +1,312 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use futures::{future, Future, Sink, Stream};
+use grpcio::{DuplexSink, RequestStream, RpcStatus, RpcStatusCode, WriteFlags};
+use nix::errno::Errno;
+use nix::fcntl::{self, FcntlArg};
+use nix::unistd;
+use protocols::agent::{StreamIORequest, StreamIOResponse};
+use rustjail::errors::*;
+use rustjail::container::BaseContainer;
+use std::os::unix::io::RawFd;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::capture::{Capture, OutputStream};
+use crate::rpc;
+use crate::sandbox::Sandbox;
+
+const STREAM_BUF_SIZE: usize = 32 * 1024;
+// ^D, ends the input of a terminal in canonical mode
+const TTY_EOF: u8 = 0x04;
+
+// private copies of the process fds, they stay valid even if
+// the process is reaped and its fds closed while we are attached
+struct Attached {
+	tty: bool,
+	// output is read from the capture when the process has one
+	capture: Option<Arc<Capture>>,
+	stdin: Option<RawFd>,
+	stdout: Option<RawFd>,
+	stderr: Option<RawFd>,
+	exit_pipe: Option<RawFd>,
+}
+
+impl Drop for Attached {
+	fn drop(&mut self) {
+		for fd in &[self.stdin, self.stdout, self.stderr, self.exit_pipe] {
+			if let Some(fd) = fd {
+				let _ = unistd::close(*fd);
+			}
+		}
+	}
+}
+
+fn dup_fd(fd: Option<RawFd>) -> Result<Option<RawFd>> {
+	match fd {
+		Some(fd) => Ok(Some(fcntl::fcntl(fd, FcntlArg::F_DUPFD_CLOEXEC(0))?)),
+		None => Ok(None),
+	}
+}
+
+fn attach(sandbox: &Arc<Mutex<Sandbox>>, cid: &str, eid: &str) -> Result<Attached> {
+	let mut sandbox = sandbox.lock().unwrap();
+	let capture = sandbox.get_capture(cid, eid);
+	let ctr = match sandbox.get_container(cid) {
+		Some(v) => v,
+		None => return Err(ErrorKind::NotFound(
+			format!("invalid container id {}", cid)).into()),
+	};
+
+	let p = ctr.get_process(eid)?;
+	let mut a = Attached {
+		tty: p.term_master.is_some(),
+		capture,
+		stdin: None,
+		stdout: None,
+		stderr: None,
+		exit_pipe: None,
+	};
+
+	if a.tty {
+		a.stdin = dup_fd(p.term_master)?;
+	} else {
+		a.stdin = dup_fd(p.parent_stdin)?;
+	}
+
+	if a.capture.is_none() {
+		if a.tty {
+			a.stdout = dup_fd(p.term_master)?;
+		} else {
+			a.stdout = dup_fd(p.parent_stdout)?;
+			a.stderr = dup_fd(p.parent_stderr)?;
+		}
+	}
+	a.exit_pipe = dup_fd(p.exit_pipe_r)?;
+
+	Ok(a)
+}
+
+fn write_all(fd: RawFd, mut data: &[u8]) -> Result<()> {
+	while !data.is_empty() {
+		match unistd::write(fd, data) {
+			Ok(n) => data = &data[n..],
+			Err(nix::Error::Sys(Errno::EINTR)) => continue,
+			Err(e) => return Err(e.into()),
+		}
+	}
+
+	Ok(())
+}
+
+fn handle_input(sandbox: &Arc<Mutex<Sandbox>>, req: &StreamIORequest,
+		stdin: &mut Option<RawFd>, tty: bool) -> Result<()> {
+	let fd = match *stdin {
+		Some(fd) => fd,
+		None => return Ok(()),
+	};
+
+	if !req.data.is_empty() {
+		write_all(fd, req.data.as_slice())?;
+	}
+
+	if req.close_stdin {
+		if tty {
+			// the terminal is also our output, keep it open
+			return write_all(fd, &[TTY_EOF]);
+		}
+
+		let _ = unistd::close(fd);
+		*stdin = None;
+
+		// the process only sees EOF once every writer is gone
+		let mut sandbox = sandbox.lock().unwrap();
+		if let Some(ctr) = sandbox.get_container(req.container_id.as_str()) {
+			if let Ok(p) = ctr.get_process(req.exec_id.as_str()) {
+				if let Some(fd) = p.parent_stdin.take() {
+					let _ = unistd::close(fd);
+				}
+			}
+		}
+	}
+
+	Ok(())
+}
+
+fn spawn_reader(fd: RawFd, stderr: bool, tx: Sender<StreamIOResponse>) {
+	thread::spawn(move || {
+		let mut buf = vec![0u8; STREAM_BUF_SIZE];
+
+		loop {
+			let n = match unistd::read(fd, buf.as_mut_slice()) {
+				Ok(0) => break,
+				Ok(n) => n,
+				Err(nix::Error::Sys(Errno::EINTR)) => continue,
+				// EIO once the slave side of a terminal is closed
+				Err(_) => break,
+			};
+
+			let mut resp = StreamIOResponse::new();
+			if stderr {
+				resp.set_stderr(buf[..n].to_vec());
+			} else {
+				resp.set_stdout(buf[..n].to_vec());
+			}
+
+			if tx.send(resp).is_err() {
+				break;
+			}
+		}
+
+		let _ = unistd::close(fd);
+	});
+}
+
+// replay the captured output from its beginning, then follow it
+fn spawn_follower(out: Arc<OutputStream>, stderr: bool, tx: Sender<StreamIOResponse>) {
+	thread::spawn(move || {
+		let mut offset = 0;
+
+		loop {
+			let chunk = out.read(offset, STREAM_BUF_SIZE);
+			offset = chunk.offset + chunk.data.len() as u64;
+
+			if !chunk.data.is_empty() {
+				let mut resp = StreamIOResponse::new();
+				if stderr {
+					resp.set_stderr(chunk.data);
+				} else {
+					resp.set_stdout(chunk.data);
+				}
+
+				if tx.send(resp).is_err() {
+					break;
+				}
+			}
+
+			if chunk.eof {
+				break;
+			}
+		}
+	});
+}
+
+fn exit_status(sandbox: &Arc<Mutex<Sandbox>>, cid: &str, eid: &str) -> i32 {
+	let mut sandbox = sandbox.lock().unwrap();
+	let ctr = match sandbox.get_container(cid) {
+		Some(v) => v,
+		None => return 0,
+	};
+
+	if let Ok(p) = ctr.get_process(eid) {
+		return p.exit_code;
+	}
+
+	match ctr.exited_processes.get(eid) {
+		Some((code, _)) => *code,
+		None => 0,
+	}
+}
+
+// serve one StreamIO call, blocks until the process exits or the
+// client goes away so it must run on its own thread
+pub fn serve(sandbox: Arc<Mutex<Sandbox>>, stream: RequestStream<StreamIORequest>,
+		sink: DuplexSink<StreamIOResponse>) {
+	let mut reqs = stream.wait();
+
+	let first = match reqs.next() {
+		Some(Ok(r)) => r,
+		_ => {
+			let _ = sink.fail(RpcStatus::new(
+				RpcStatusCode::InvalidArgument,
+				Some("missing process selection".to_string()))).wait();
+			return;
+		}
+	};
+
+	let cid = first.container_id.clone();
+	let eid = first.exec_id.clone();
+	info!("stream io for {}/{}", cid, eid);
+
+	let mut fds = match attach(&sandbox, cid.as_str(), eid.as_str()) {
+		Ok(v) => v,
+		Err(e) => {
+			let _ = sink.fail(rpc::to_status(&e)).wait();
+			return;
+		}
+	};
+
+	// readers own their fds, they may outlive this function
+	let (tx, rx) = mpsc::channel();
+	if let Some(c) = fds.capture.as_ref() {
+		spawn_follower(c.stream(false), false, tx.clone());
+		spawn_follower(c.stream(true), true, tx.clone());
+	}
+	if let Some(fd) = fds.stdout.take() {
+		spawn_reader(fd, false, tx.clone());
+	}
+	if let Some(fd) = fds.stderr.take() {
+		spawn_reader(fd, true, tx.clone());
+	}
+	drop(tx);
+
+	// input keeps flowing until the client half-closes the stream
+	let tty = fds.tty;
+	let mut stdin = fds.stdin.take();
+	let s = Arc::clone(&sandbox);
+	thread::spawn(move || {
+		if let Err(e) = handle_input(&s, &first, &mut stdin, tty) {
+			warn!("stream io write stdin: {}", e);
+		}
+
+		for req in reqs {
+			let req = match req {
+				Ok(v) => v,
+				Err(_) => break,
+			};
+
+			if let Err(e) = handle_input(&s, &req, &mut stdin, tty) {
+				warn!("stream io write stdin: {}", e);
+				break;
+			}
+		}
+
+		if let Some(fd) = stdin {
+			let _ = unistd::close(fd);
+		}
+	});
+
+	let mut sink = sink;
+	for resp in rx {
+		sink = match sink.send((resp, WriteFlags::default())).wait() {
+			Ok(v) => v,
+			Err(e) => {
+				info!("stream io for {}/{} closed: {:?}", cid, eid, e);
+				return;
+			}
+		};
+	}
+
+	// all output is drained, wait for the reaper before reporting
+	if let Some(fd) = fds.exit_pipe {
+		let mut buf = [0u8; 1];
+		loop {
+			match unistd::read(fd, &mut buf) {
+				Err(nix::Error::Sys(Errno::EINTR)) => continue,
+				_ => break,
+			}
+		}
+	}
+
+	let mut resp = StreamIOResponse::new();
+	resp.set_exited(true);
+	resp.set_status(exit_status(&sandbox, cid.as_str(), eid.as_str()));
+
+	if let Ok(mut sink) = sink.send((resp, WriteFlags::default())).wait() {
+		let _ = future::poll_fn(|| sink.close()).wait();
+	}
+}
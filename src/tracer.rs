@@ -0,0 +1,324 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Span export. The spans recorded by rustjail::trace are encoded as a
+// jaeger Agent.emitBatch call (thrift compact protocol, what jaeger-agent
+// reads on its UDP port) and written to a vsock or unix stream socket,
+// each batch prefixed with its length as a big endian u64.
+
+use rustjail::errors::*;
+use rustjail::trace::{self, SpanData};
+use std::env;
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::vsock;
+
+// the host, where a trace forwarder is expected to listen
+pub const DEFAULT_ENDPOINT: &'static str = "vsock://2:10240";
+const ENDPOINT_ENV: &'static str = "KATA_AGENT_TRACE_ENDPOINT";
+const SERVICE_NAME: &'static str = "kata-agent";
+const FLUSH_INTERVAL_MS: u64 = 1000;
+
+lazy_static! {
+	static ref ENDPOINT: Mutex<String> = Mutex::new(
+		env::var(ENDPOINT_ENV).unwrap_or(DEFAULT_ENDPOINT.to_string()));
+}
+
+// bumped on every start/stop, so that a flusher from a previous
+// session knows it has to go away
+static SESSION: AtomicUsize = AtomicUsize::new(0);
+
+pub fn set_endpoint(endpoint: &str) {
+	*ENDPOINT.lock().unwrap() = endpoint.to_string();
+}
+
+pub fn start() -> Result<()> {
+	if trace::enabled() {
+		return Ok(());
+	}
+
+	// fail early on a bad endpoint rather than at the first flush
+	parse_endpoint(ENDPOINT.lock().unwrap().as_str())?;
+
+	trace::enable();
+	let session = SESSION.fetch_add(1, Ordering::SeqCst) + 1;
+
+	thread::spawn(move || {
+		loop {
+			thread::sleep(Duration::from_millis(FLUSH_INTERVAL_MS));
+			if SESSION.load(Ordering::SeqCst) != session {
+				break;
+			}
+
+			if let Err(e) = flush() {
+				warn!("cannot export spans: {}", e);
+			}
+		}
+	});
+
+	info!("tracing started");
+	Ok(())
+}
+
+// stop recording and export what is left
+pub fn stop() -> Result<()> {
+	if !trace::enabled() {
+		return Ok(());
+	}
+
+	trace::disable();
+	SESSION.fetch_add(1, Ordering::SeqCst);
+
+	info!("tracing stopped");
+	flush()
+}
+
+pub fn flush() -> Result<()> {
+	let spans = trace::take_finished();
+	if spans.is_empty() {
+		return Ok(());
+	}
+
+	let payload = encode_batch(SERVICE_NAME, &spans);
+	let endpoint = ENDPOINT.lock().unwrap().clone();
+
+	let mut conn = connect(endpoint.as_str())
+		.chain_err(|| format!("cannot connect to {}", endpoint))?;
+	conn.write_all(&(payload.len() as u64).to_be_bytes())?;
+	conn.write_all(payload.as_slice())?;
+	conn.flush()?;
+
+	Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+enum Endpoint {
+	Vsock(u32, u32),
+	Unix(String),
+}
+
+fn parse_endpoint(endpoint: &str) -> Result<Endpoint> {
+	if endpoint.starts_with("unix://") {
+		let path = &endpoint["unix://".len()..];
+		if !path.is_empty() {
+			return Ok(Endpoint::Unix(path.to_string()));
+		}
+	} else if endpoint.starts_with("vsock://") {
+		let addr: Vec<&str> = endpoint["vsock://".len()..].split(':').collect();
+		if addr.len() == 2 {
+			if let (Ok(cid), Ok(port)) = (addr[0].parse::<u32>(), addr[1].parse::<u32>()) {
+				return Ok(Endpoint::Vsock(cid, port));
+			}
+		}
+	}
+
+	Err(ErrorKind::InvalidArgument(format!(
+		"invalid trace endpoint {}, want vsock://<cid>:<port> or unix://<path>",
+		endpoint)).into())
+}
+
+fn connect(endpoint: &str) -> Result<Box<dyn Write>> {
+	match parse_endpoint(endpoint)? {
+		Endpoint::Unix(path) => Ok(Box::new(UnixStream::connect(path)?)),
+		Endpoint::Vsock(cid, port) => Ok(Box::new(vsock::connect(cid, port)?)),
+	}
+}
+
+// thrift compact protocol, only what a jaeger batch needs
+const CT_I32: u8 = 5;
+const CT_I64: u8 = 6;
+const CT_BINARY: u8 = 8;
+const CT_LIST: u8 = 9;
+const CT_STRUCT: u8 = 12;
+
+const COMPACT_PROTOCOL_ID: u8 = 0x82;
+const COMPACT_VERSION: u8 = 1;
+const MESSAGE_ONEWAY: u8 = 4;
+
+// jaeger TagType.STRING
+const TAG_STRING: i32 = 0;
+
+#[derive(Default)]
+struct Compact {
+	buf: Vec<u8>,
+	last_field: i16,
+	fields: Vec<i16>,
+}
+
+impl Compact {
+	fn varint(&mut self, mut v: u64) {
+		while v >= 0x80 {
+			self.buf.push((v as u8) | 0x80);
+			v >>= 7;
+		}
+		self.buf.push(v as u8);
+	}
+
+	fn i32(&mut self, v: i32) {
+		self.varint(((v << 1) ^ (v >> 31)) as u32 as u64);
+	}
+
+	fn i64(&mut self, v: i64) {
+		self.varint(((v << 1) ^ (v >> 63)) as u64);
+	}
+
+	fn string(&mut self, s: &str) {
+		self.varint(s.len() as u64);
+		self.buf.extend_from_slice(s.as_bytes());
+	}
+
+	fn field(&mut self, id: i16, ty: u8) {
+		let delta = id - self.last_field;
+		if delta > 0 && delta <= 15 {
+			self.buf.push(((delta as u8) << 4) | ty);
+		} else {
+			self.buf.push(ty);
+			self.i32(id as i32);
+		}
+		self.last_field = id;
+	}
+
+	fn list(&mut self, n: usize, ty: u8) {
+		if n < 15 {
+			self.buf.push(((n as u8) << 4) | ty);
+		} else {
+			self.buf.push(0xf0 | ty);
+			self.varint(n as u64);
+		}
+	}
+
+	fn begin_struct(&mut self) {
+		self.fields.push(self.last_field);
+		self.last_field = 0;
+	}
+
+	fn end_struct(&mut self) {
+		self.buf.push(0);
+		self.last_field = self.fields.pop().unwrap_or(0);
+	}
+
+	fn message(&mut self, name: &str, ty: u8, seq: i32) {
+		self.buf.push(COMPACT_PROTOCOL_ID);
+		self.buf.push((ty << 5) | COMPACT_VERSION);
+		self.varint(seq as u32 as u64);
+		self.string(name);
+	}
+}
+
+fn encode_tag(c: &mut Compact, key: &str, value: &str) {
+	c.begin_struct();
+	c.field(1, CT_BINARY);
+	c.string(key);
+	c.field(2, CT_I32);
+	c.i32(TAG_STRING);
+	c.field(3, CT_BINARY);
+	c.string(value);
+	c.end_struct();
+}
+
+fn encode_span(c: &mut Compact, s: &SpanData) {
+	c.begin_struct();
+	c.field(1, CT_I64);
+	c.i64(s.trace_id_low as i64);
+	c.field(2, CT_I64);
+	c.i64(s.trace_id_high as i64);
+	c.field(3, CT_I64);
+	c.i64(s.span_id as i64);
+	c.field(4, CT_I64);
+	c.i64(s.parent_id as i64);
+	c.field(5, CT_BINARY);
+	c.string(s.operation.as_str());
+	// sampled
+	c.field(7, CT_I32);
+	c.i32(1);
+	c.field(8, CT_I64);
+	c.i64(s.start as i64);
+	c.field(9, CT_I64);
+	c.i64(s.duration as i64);
+	if !s.tags.is_empty() {
+		c.field(10, CT_LIST);
+		c.list(s.tags.len(), CT_STRUCT);
+		for (k, v) in s.tags.iter() {
+			encode_tag(c, k.as_str(), v.as_str());
+		}
+	}
+	c.end_struct();
+}
+
+fn encode_batch(service: &str, spans: &[SpanData]) -> Vec<u8> {
+	let mut c = Compact::default();
+
+	c.message("emitBatch", MESSAGE_ONEWAY, 0);
+	// emitBatch_args
+	c.begin_struct();
+	c.field(1, CT_STRUCT);
+	// Batch
+	c.begin_struct();
+	c.field(1, CT_STRUCT);
+	// Process
+	c.begin_struct();
+	c.field(1, CT_BINARY);
+	c.string(service);
+	c.end_struct();
+	c.field(2, CT_LIST);
+	c.list(spans.len(), CT_STRUCT);
+	for s in spans {
+		encode_span(&mut c, s);
+	}
+	c.end_struct();
+	c.end_struct();
+
+	c.buf
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::io::Read;
+	use std::os::unix::net::UnixListener;
+
+	#[test]
+	fn test_parse_endpoint() {
+		assert_eq!(parse_endpoint("vsock://2:10240").unwrap(), Endpoint::Vsock(2, 10240));
+		assert_eq!(parse_endpoint("unix:///run/trace.sock").unwrap(),
+			Endpoint::Unix("/run/trace.sock".to_string()));
+		assert!(parse_endpoint("vsock://2").is_err());
+		assert!(parse_endpoint("tcp://127.0.0.1:6831").is_err());
+	}
+
+	#[test]
+	fn test_export() {
+		let path = env::temp_dir().join(format!("kata-agent-trace-{}.sock", std::process::id()));
+		let _ = std::fs::remove_file(&path);
+		let listener = UnixListener::bind(&path).unwrap();
+
+		set_endpoint(format!("unix://{}", path.display()).as_str());
+		start().unwrap();
+		{
+			let _root = trace::span("create_container");
+			let mut s = trace::span("mount_storage");
+			s.tag("driver", "9p");
+		}
+		stop().unwrap();
+
+		let (mut conn, _) = listener.accept().unwrap();
+		let mut header = [0u8; 8];
+		conn.read_exact(&mut header).unwrap();
+		let mut payload = vec![0u8; u64::from_be_bytes(header) as usize];
+		conn.read_exact(payload.as_mut_slice()).unwrap();
+		let _ = std::fs::remove_file(&path);
+
+		assert_eq!(&payload[..2], &[0x82, 0x81]);
+		let body = String::from_utf8_lossy(payload.as_slice());
+		for s in &["emitBatch", SERVICE_NAME, "create_container", "mount_storage", "9p"] {
+			assert!(body.contains(s), "{} not exported", s);
+		}
+	}
+}
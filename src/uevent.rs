@@ -7,6 +7,7 @@ use crate::device::{online_device, ROOT_BUS_PATH, SYSFS_DIR, SCSI_HOST_CHANNEL,
 use crate::grpc::SYSFS_MEMORY_ONLINE_PATH;
 use crate::netlink::{RtnlHandle, NETLINK_UEVENT};
 use crate::sandbox::Sandbox;
+use crate::health;
 use crate::GLOBAL_DEVICE_WATCHER;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -55,6 +56,7 @@ pub fn watch_uevents(sandbox: Arc<Mutex<Sandbox>>) {
     let s = sandbox.clone();
 
     thread::spawn(move || {
+        let _alive = health::UEVENT.run();
         let rtnl = RtnlHandle::new(NETLINK_UEVENT, 1).unwrap();
         loop {
             match rtnl.recv_message() {
@@ -107,7 +109,7 @@ pub fn watch_uevents(sandbox: Arc<Mutex<Sandbox>>) {
                                 for empty in empties {
                                     w.remove(&empty);
                                 }
-                            } else {
+                            } else if cfg!(feature = "mem-hotplug") {
                                 let online_path =
                                     format!("{}/{}/online", SYSFS_DIR, &event.devpath);
                                 if online_path.starts_with(SYSFS_MEMORY_ONLINE_PATH) {
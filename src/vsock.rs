@@ -0,0 +1,85 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// AF_VSOCK stream sockets, which nix does not know about.
+
+use libc::{self, sa_family_t, sockaddr, socklen_t};
+use nix::errno::Errno;
+use rustjail::errors::*;
+use std::fs::File;
+use std::mem;
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::ptr;
+
+// any local cid, for listening
+pub const VMADDR_CID_ANY: u32 = 0xffffffff;
+
+#[repr(C)]
+struct sockaddr_vm {
+	svm_family: sa_family_t,
+	svm_reserved1: u16,
+	svm_port: u32,
+	svm_cid: u32,
+	svm_zero: [u8; 4],
+}
+
+fn new_addr(cid: u32, port: u32) -> sockaddr_vm {
+	sockaddr_vm {
+		svm_family: libc::AF_VSOCK as sa_family_t,
+		svm_reserved1: 0,
+		svm_port: port,
+		svm_cid: cid,
+		svm_zero: [0; 4],
+	}
+}
+
+fn socket() -> Result<File> {
+	let fd = unsafe {
+		libc::socket(libc::AF_VSOCK, libc::SOCK_STREAM | libc::SOCK_CLOEXEC, 0)
+	};
+	Errno::result(fd)?;
+
+	// owns the fd from here on
+	Ok(unsafe { File::from_raw_fd(fd) })
+}
+
+pub fn connect(cid: u32, port: u32) -> Result<File> {
+	let sock = socket()?;
+	let addr = new_addr(cid, port);
+
+	let ret = unsafe {
+		libc::connect(sock.as_raw_fd(), &addr as *const sockaddr_vm as *const sockaddr,
+			mem::size_of::<sockaddr_vm>() as socklen_t)
+	};
+	Errno::result(ret)?;
+
+	Ok(sock)
+}
+
+pub fn listen(cid: u32, port: u32, backlog: i32) -> Result<File> {
+	let sock = socket()?;
+	let addr = new_addr(cid, port);
+
+	let ret = unsafe {
+		libc::bind(sock.as_raw_fd(), &addr as *const sockaddr_vm as *const sockaddr,
+			mem::size_of::<sockaddr_vm>() as socklen_t)
+	};
+	Errno::result(ret)?;
+
+	let ret = unsafe { libc::listen(sock.as_raw_fd(), backlog) };
+	Errno::result(ret)?;
+
+	Ok(sock)
+}
+
+pub fn accept(listener: &File) -> Result<File> {
+	let fd = unsafe {
+		libc::accept4(listener.as_raw_fd(), ptr::null_mut(), ptr::null_mut(),
+			libc::SOCK_CLOEXEC)
+	};
+	Errno::result(fd)?;
+
+	Ok(unsafe { File::from_raw_fd(fd) })
+}
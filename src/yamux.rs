@@ -0,0 +1,522 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Server side of yamux (github.com/hashicorp/yamux), the multiplexing the
+// runtime speaks over a virtio-serial port. Every stream the runtime opens
+// is bridged to a new connection to a local unix socket, where the gRPC
+// server listens, so concurrent RPCs each get their own stream. Each
+// stream writes to its backend on a thread of its own, a stalled backend
+// only holds up its stream.
+
+use rustjail::errors::*;
+use std::collections::HashMap;
+use std::io::{ErrorKind as IoErrorKind, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::UnixStream;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+const PROTO_VERSION: u8 = 0;
+const HEADER_SIZE: usize = 12;
+// what both ends assume before any window update
+const INITIAL_WINDOW: u32 = 256 * 1024;
+const STREAM_BUF_SIZE: usize = 16 * 1024;
+
+const TYPE_DATA: u8 = 0;
+const TYPE_WINDOW_UPDATE: u8 = 1;
+const TYPE_PING: u8 = 2;
+const TYPE_GO_AWAY: u8 = 3;
+
+const FLAG_SYN: u16 = 1;
+const FLAG_ACK: u16 = 2;
+const FLAG_FIN: u16 = 4;
+const FLAG_RST: u16 = 8;
+
+const GO_AWAY_PROTO_ERR: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Header {
+	ty: u8,
+	flags: u16,
+	stream_id: u32,
+	// the payload size for data, the window delta or ping value otherwise
+	length: u32,
+}
+
+impl Header {
+	fn new(ty: u8, flags: u16, stream_id: u32, length: u32) -> Header {
+		Header { ty, flags, stream_id, length }
+	}
+
+	fn encode(&self) -> [u8; HEADER_SIZE] {
+		let mut b = [0u8; HEADER_SIZE];
+		b[0] = PROTO_VERSION;
+		b[1] = self.ty;
+		b[2..4].copy_from_slice(&self.flags.to_be_bytes());
+		b[4..8].copy_from_slice(&self.stream_id.to_be_bytes());
+		b[8..12].copy_from_slice(&self.length.to_be_bytes());
+		b
+	}
+
+	fn decode(b: &[u8; HEADER_SIZE]) -> Result<Header> {
+		if b[0] != PROTO_VERSION {
+			return Err(ErrorKind::ErrorCode(
+				format!("unsupported yamux version {}", b[0])).into());
+		}
+
+		Ok(Header {
+			ty: b[1],
+			flags: u16::from_be_bytes([b[2], b[3]]),
+			stream_id: u32::from_be_bytes([b[4], b[5], b[6], b[7]]),
+			length: u32::from_be_bytes([b[8], b[9], b[10], b[11]]),
+		})
+	}
+}
+
+#[derive(Default)]
+struct StreamState {
+	// what the peer still accepts from us
+	send_window: u32,
+	// what we still accept from the peer, given back once written
+	recv_window: u32,
+	local_closed: bool,
+	remote_closed: bool,
+	reset: bool,
+}
+
+struct Stream {
+	id: u32,
+	conn: UnixStream,
+	state: Mutex<StreamState>,
+	cond: Condvar,
+	// to the writer of the backend, None for the peer's FIN; gone once
+	// the stream is closed or reset
+	input: Mutex<Option<Sender<Option<Vec<u8>>>>>,
+}
+
+impl Stream {
+	fn feed(&self, data: Option<Vec<u8>>) {
+		let mut input = self.input.lock().unwrap();
+		// nothing comes after a FIN
+		let fin = data.is_none();
+		if let Some(tx) = input.as_ref() {
+			let _ = tx.send(data);
+		}
+		if fin {
+			input.take();
+		}
+	}
+}
+
+type Writer = Arc<Mutex<Box<dyn Write + Send>>>;
+type Streams = Arc<Mutex<HashMap<u32, Arc<Stream>>>>;
+
+// header and payload go out in one go, the frames of all the streams
+// share the port
+fn send(writer: &Writer, h: Header, payload: &[u8]) -> Result<()> {
+	let mut w = writer.lock().unwrap();
+	let mut frame = Vec::with_capacity(HEADER_SIZE + payload.len());
+	frame.extend_from_slice(&h.encode());
+	frame.extend_from_slice(payload);
+	w.write_all(frame.as_slice())?;
+	w.flush()?;
+
+	Ok(())
+}
+
+fn read_full<R: Read>(r: &mut R, buf: &mut [u8]) -> Result<bool> {
+	match r.read_exact(buf) {
+		Ok(_) => Ok(true),
+		Err(ref e) if e.kind() == IoErrorKind::UnexpectedEof => Ok(false),
+		Err(e) => Err(e.into()),
+	}
+}
+
+fn forget(streams: &Streams, id: u32) {
+	streams.lock().unwrap().remove(&id);
+}
+
+// copy what the backend answers to the peer, within the window it gave us
+fn pump(stream: Arc<Stream>, writer: Writer, streams: Streams) {
+	let mut conn = match stream.conn.try_clone() {
+		Ok(c) => c,
+		Err(_) => return,
+	};
+	let mut buf = vec![0u8; STREAM_BUF_SIZE];
+
+	loop {
+		let n = match conn.read(buf.as_mut_slice()) {
+			Ok(n) => n,
+			Err(ref e) if e.kind() == IoErrorKind::Interrupted => continue,
+			Err(_) => 0,
+		};
+
+		if n == 0 {
+			break;
+		}
+
+		let mut off = 0;
+		while off < n {
+			let chunk = {
+				let mut st = stream.state.lock().unwrap();
+				while st.send_window == 0 && !st.reset {
+					st = stream.cond.wait(st).unwrap();
+				}
+				if st.reset {
+					return;
+				}
+
+				let chunk = std::cmp::min(st.send_window as usize, n - off);
+				st.send_window -= chunk as u32;
+				chunk
+			};
+
+			let h = Header::new(TYPE_DATA, 0, stream.id, chunk as u32);
+			if send(&writer, h, &buf[off..off + chunk]).is_err() {
+				return;
+			}
+			off += chunk;
+		}
+	}
+
+	let (reset, remote_closed) = {
+		let mut st = stream.state.lock().unwrap();
+		st.local_closed = true;
+		(st.reset, st.remote_closed)
+	};
+
+	if !reset {
+		let _ = send(&writer, Header::new(TYPE_DATA, FLAG_FIN, stream.id, 0), &[]);
+	}
+
+	// the peer's FIN forgets the stream if it is yet to come
+	if reset || remote_closed {
+		forget(&streams, stream.id);
+	}
+}
+
+// hand what the peer sends over to the backend, and give the window back
+// once written
+fn feed(stream: Arc<Stream>, rx: Receiver<Option<Vec<u8>>>, writer: Writer, streams: Streams) {
+	for data in rx {
+		let data = match data {
+			Some(d) => d,
+			None => {
+				// the backend sees the end of the request
+				let _ = stream.conn.shutdown(Shutdown::Write);
+				break;
+			}
+		};
+
+		if (&stream.conn).write_all(data.as_slice()).is_err() {
+			{
+				let mut st = stream.state.lock().unwrap();
+				st.reset = true;
+				stream.cond.notify_all();
+			}
+			let _ = stream.conn.shutdown(Shutdown::Both);
+			stream.input.lock().unwrap().take();
+			forget(&streams, stream.id);
+			let _ = send(&writer, Header::new(TYPE_WINDOW_UPDATE, FLAG_RST, stream.id, 0), &[]);
+			break;
+		}
+
+		stream.state.lock().unwrap().recv_window += data.len() as u32;
+		if send(&writer, Header::new(TYPE_WINDOW_UPDATE, 0, stream.id, data.len() as u32), &[]).is_err() {
+			break;
+		}
+	}
+}
+
+struct Session {
+	writer: Writer,
+	streams: Streams,
+	backend: String,
+}
+
+impl Session {
+	fn open_stream(&self, id: u32) -> Result<()> {
+		let conn = match UnixStream::connect(self.backend.as_str()) {
+			Ok(c) => c,
+			Err(e) => {
+				warn!("yamux stream {}: cannot reach {}: {}", id, self.backend, e);
+				return send(&self.writer, Header::new(TYPE_WINDOW_UPDATE, FLAG_RST, id, 0), &[]);
+			}
+		};
+
+		let (tx, rx) = channel();
+		let stream = Arc::new(Stream {
+			id,
+			conn,
+			state: Mutex::new(StreamState {
+				send_window: INITIAL_WINDOW,
+				recv_window: INITIAL_WINDOW,
+				..Default::default()
+			}),
+			cond: Condvar::new(),
+			input: Mutex::new(Some(tx)),
+		});
+
+		self.streams.lock().unwrap().insert(id, stream.clone());
+		send(&self.writer, Header::new(TYPE_WINDOW_UPDATE, FLAG_ACK, id, 0), &[])?;
+
+		let (s, writer, streams) = (stream.clone(), self.writer.clone(), self.streams.clone());
+		thread::spawn(move || feed(s, rx, writer, streams));
+
+		let writer = self.writer.clone();
+		let streams = self.streams.clone();
+		thread::spawn(move || pump(stream, writer, streams));
+
+		Ok(())
+	}
+
+	fn handle_flags(&self, stream: &Arc<Stream>, flags: u16) {
+		if flags & FLAG_RST != 0 {
+			let mut st = stream.state.lock().unwrap();
+			st.reset = true;
+			stream.cond.notify_all();
+			let _ = stream.conn.shutdown(Shutdown::Both);
+			drop(st);
+			stream.input.lock().unwrap().take();
+			forget(&self.streams, stream.id);
+			return;
+		}
+
+		if flags & FLAG_FIN != 0 {
+			let local_closed = {
+				let mut st = stream.state.lock().unwrap();
+				st.remote_closed = true;
+				st.local_closed
+			};
+
+			// after the data queued so far
+			stream.feed(None);
+			if local_closed {
+				forget(&self.streams, stream.id);
+			}
+		}
+	}
+
+	fn stream(&self, id: u32) -> Option<Arc<Stream>> {
+		self.streams.lock().unwrap().get(&id).cloned()
+	}
+
+	fn handle_data(&self, h: &Header, payload: Vec<u8>) -> Result<()> {
+		if h.flags & FLAG_SYN != 0 {
+			self.open_stream(h.stream_id)?;
+		}
+
+		let stream = match self.stream(h.stream_id) {
+			Some(s) => s,
+			// reset or never opened, the data is dropped
+			None => return Ok(()),
+		};
+
+		if !payload.is_empty() {
+			{
+				let mut st = stream.state.lock().unwrap();
+				if payload.len() as u32 > st.recv_window {
+					return Err(self.go_away(format!("yamux stream {} overran its window", h.stream_id)));
+				}
+				st.recv_window -= payload.len() as u32;
+			}
+
+			stream.feed(Some(payload));
+		}
+
+		self.handle_flags(&stream, h.flags);
+		Ok(())
+	}
+
+	fn handle_window_update(&self, h: &Header) -> Result<()> {
+		if h.flags & FLAG_SYN != 0 {
+			self.open_stream(h.stream_id)?;
+		}
+
+		if let Some(stream) = self.stream(h.stream_id) {
+			{
+				let mut st = stream.state.lock().unwrap();
+				st.send_window = st.send_window.saturating_add(h.length);
+				stream.cond.notify_all();
+			}
+			self.handle_flags(&stream, h.flags);
+		}
+
+		Ok(())
+	}
+
+	// the peer broke the protocol, tell it before hanging up
+	fn go_away(&self, msg: String) -> Error {
+		let _ = send(&self.writer, Header::new(TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTO_ERR), &[]);
+		ErrorKind::ErrorCode(msg).into()
+	}
+
+	fn close(&self) {
+		for (_, stream) in self.streams.lock().unwrap().drain() {
+			stream.state.lock().unwrap().reset = true;
+			stream.cond.notify_all();
+			let _ = stream.conn.shutdown(Shutdown::Both);
+			stream.input.lock().unwrap().take();
+		}
+	}
+}
+
+// runs a session until the peer goes away, backend is the path of the
+// unix socket the streams are bridged to
+pub fn serve(mut reader: Box<dyn Read + Send>, writer: Box<dyn Write + Send>, backend: &str) -> Result<()> {
+	let session = Session {
+		writer: Arc::new(Mutex::new(writer)),
+		streams: Arc::new(Mutex::new(HashMap::new())),
+		backend: backend.to_string(),
+	};
+
+	let r = session_loop(&session, &mut reader);
+	session.close();
+	r
+}
+
+fn session_loop(session: &Session, reader: &mut Box<dyn Read + Send>) -> Result<()> {
+	let mut hbuf = [0u8; HEADER_SIZE];
+
+	loop {
+		if !read_full(reader, &mut hbuf)? {
+			return Ok(());
+		}
+
+		let h = Header::decode(&hbuf)?;
+
+		match h.ty {
+			TYPE_DATA => {
+				// never more than a whole window, whatever the peer says
+				if h.length > INITIAL_WINDOW {
+					return Err(session.go_away(format!("yamux frame of {} bytes on stream {}", h.length, h.stream_id)));
+				}
+
+				let mut payload = vec![0u8; h.length as usize];
+				if !read_full(reader, payload.as_mut_slice())? {
+					return Ok(());
+				}
+				session.handle_data(&h, payload)?;
+			}
+			TYPE_WINDOW_UPDATE => session.handle_window_update(&h)?,
+			TYPE_PING => {
+				if h.flags & FLAG_SYN != 0 {
+					send(&session.writer, Header::new(TYPE_PING, FLAG_ACK, 0, h.length), &[])?;
+				}
+			}
+			TYPE_GO_AWAY => {
+				info!("yamux peer went away, code {}", h.length);
+				return Ok(());
+			}
+			_ => {
+				return Err(ErrorKind::ErrorCode(
+					format!("unknown yamux frame type {}", h.ty)).into());
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::os::unix::net::UnixListener;
+
+	fn read_frame(s: &mut UnixStream) -> (Header, Vec<u8>) {
+		let mut hbuf = [0u8; HEADER_SIZE];
+		s.read_exact(&mut hbuf).unwrap();
+		let h = Header::decode(&hbuf).unwrap();
+		let mut payload = vec![];
+		if h.ty == TYPE_DATA {
+			payload = vec![0u8; h.length as usize];
+			s.read_exact(payload.as_mut_slice()).unwrap();
+		}
+		(h, payload)
+	}
+
+	#[test]
+	fn test_header() {
+		let h = Header::new(TYPE_WINDOW_UPDATE, FLAG_SYN | FLAG_ACK, 3, 4096);
+		let b = h.encode();
+		assert_eq!(b, [0, 1, 0, 3, 0, 0, 0, 3, 0, 0, 16, 0]);
+		assert_eq!(Header::decode(&b).unwrap(), h);
+
+		let mut b = b;
+		b[0] = 1;
+		assert!(Header::decode(&b).is_err());
+	}
+
+	#[test]
+	fn test_session() {
+		let path = std::env::temp_dir().join(format!("yamux-backend-{}.sock", std::process::id()));
+		let _ = std::fs::remove_file(&path);
+		let listener = UnixListener::bind(&path).unwrap();
+
+		// an echo backend
+		thread::spawn(move || {
+			for conn in listener.incoming() {
+				let mut conn = conn.unwrap();
+				thread::spawn(move || {
+					let mut buf = vec![];
+					conn.read_to_end(&mut buf).unwrap();
+					conn.write_all(buf.as_slice()).unwrap();
+				});
+			}
+		});
+
+		let (mut peer, port) = UnixStream::pair().unwrap();
+		let reader = port.try_clone().unwrap();
+		let backend = path.to_str().unwrap().to_string();
+		let session = thread::spawn(move || {
+			serve(Box::new(reader), Box::new(port), backend.as_str())
+		});
+
+		// ping
+		peer.write_all(&Header::new(TYPE_PING, FLAG_SYN, 0, 42).encode()).unwrap();
+		let (h, _) = read_frame(&mut peer);
+		assert_eq!(h, Header::new(TYPE_PING, FLAG_ACK, 0, 42));
+
+		// open stream 1, send a request and close our side
+		peer.write_all(&Header::new(TYPE_WINDOW_UPDATE, FLAG_SYN, 1, 0).encode()).unwrap();
+		let (h, _) = read_frame(&mut peer);
+		assert_eq!(h, Header::new(TYPE_WINDOW_UPDATE, FLAG_ACK, 1, 0));
+
+		peer.write_all(&Header::new(TYPE_DATA, FLAG_FIN, 1, 5).encode()).unwrap();
+		peer.write_all(b"hello").unwrap();
+
+		let mut got = vec![];
+		loop {
+			let (h, payload) = read_frame(&mut peer);
+			assert_eq!(h.stream_id, 1);
+			if h.ty == TYPE_WINDOW_UPDATE {
+				assert_eq!(h.length, 5);
+				continue;
+			}
+			got.extend(payload);
+			if h.flags & FLAG_FIN != 0 {
+				break;
+			}
+		}
+		assert_eq!(got, b"hello".to_vec());
+
+		peer.write_all(&Header::new(TYPE_GO_AWAY, 0, 0, 0).encode()).unwrap();
+		session.join().unwrap().unwrap();
+		let _ = std::fs::remove_file(&path);
+	}
+	#[test]
+	fn test_oversized_frame() {
+		let (mut peer, port) = UnixStream::pair().unwrap();
+		let reader = port.try_clone().unwrap();
+		let session = thread::spawn(move || {
+			serve(Box::new(reader), Box::new(port), "/nonexistent")
+		});
+
+		// nothing is allocated for it, the session goes away
+		peer.write_all(&Header::new(TYPE_DATA, FLAG_SYN, 1, INITIAL_WINDOW + 1).encode()).unwrap();
+		let (h, _) = read_frame(&mut peer);
+		assert_eq!(h, Header::new(TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTO_ERR));
+		assert!(session.join().unwrap().is_err());
+	}
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// A blocking client, one call in flight per connection. Enough for
+// tools and tests, the runtime has its own.
+
+use protobuf::Message;
+use std::fs::File;
+use std::os::unix::io::{FromRawFd, IntoRawFd};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::error::{Code, Error, Result};
+use crate::frame::{self, MESSAGE_TYPE_REQUEST, MESSAGE_TYPE_RESPONSE};
+use crate::{Request, Response};
+
+struct Conn {
+	stream: File,
+	// client initiated streams are odd
+	next_id: u32,
+}
+
+#[derive(Clone)]
+pub struct Client {
+	conn: Arc<Mutex<Conn>>,
+}
+
+impl Client {
+	// a connected stream socket, of any family
+	pub fn new(stream: File) -> Client {
+		Client {
+			conn: Arc::new(Mutex::new(Conn {
+				stream,
+				next_id: 1,
+			})),
+		}
+	}
+
+	pub fn connect_unix<P: AsRef<Path>>(path: P) -> Result<Client> {
+		let stream = UnixStream::connect(path)?;
+		Ok(Client::new(unsafe { File::from_raw_fd(stream.into_raw_fd()) }))
+	}
+
+	pub fn request(&self, req: &Request) -> Result<Response> {
+		let buf = req.encode()?;
+		let mut conn = self.conn.lock().unwrap();
+
+		let id = conn.next_id;
+		conn.next_id = conn.next_id.wrapping_add(2);
+		frame::write_message(&mut conn.stream, id, MESSAGE_TYPE_REQUEST, buf.as_slice())?;
+
+		loop {
+			let header = frame::read_header(&mut conn.stream)?;
+			let payload = frame::read_payload(&mut conn.stream, &header);
+
+			// the answer to an earlier call that gave up
+			if header.type_ != MESSAGE_TYPE_RESPONSE || header.stream_id != id {
+				continue;
+			}
+
+			return Response::decode(payload?.as_slice());
+		}
+	}
+
+	pub fn call<Req, Resp>(&self, service: &str, method: &str, req: &Req, timeout_nano: i64) -> Result<Resp>
+	where
+		Req: Message,
+		Resp: Message,
+	{
+		let req = Request {
+			service: service.to_string(),
+			method: method.to_string(),
+			payload: req.write_to_bytes()?,
+			timeout_nano,
+		};
+
+		let resp = self.request(&req)?;
+		if resp.status.code != Code::Ok {
+			return Err(Error::RpcStatus(resp.status));
+		}
+
+		let mut r = Resp::new();
+		r.merge_from_bytes(resp.payload.as_slice())?;
+		Ok(r)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{unary, Methods, Server, Status, TtrpcContext};
+	use protobuf::well_known_types::StringValue;
+	use std::os::unix::net::UnixListener;
+
+	fn echo(_ctx: &TtrpcContext, req: StringValue) -> Result<StringValue> {
+		if req.get_value().is_empty() {
+			return Err(Error::status(Code::InvalidArgument, "nothing to echo"));
+		}
+		Ok(req)
+	}
+
+	#[test]
+	fn test_call() {
+		let dir = std::env::temp_dir().join(format!("ttrpc-test-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let path = dir.join("sock");
+		let _ = std::fs::remove_file(&path);
+
+		let listener = UnixListener::bind(&path).unwrap();
+		let mut methods = Methods::new();
+		methods.insert("/test.Echo/Echo".to_string(), unary(echo));
+
+		let mut server = Server::new()
+			.add_listener(unsafe { File::from_raw_fd(listener.into_raw_fd()) })
+			.register_service(methods);
+		server.start().unwrap();
+
+		let client = Client::connect_unix(&path).unwrap();
+
+		let mut req = StringValue::new();
+		req.set_value("hello".to_string());
+		let resp: StringValue = client.call("test.Echo", "Echo", &req, 0).unwrap();
+		assert_eq!(resp.get_value(), "hello");
+
+		match client.call::<_, StringValue>("test.Echo", "Echo", &StringValue::new(), 0) {
+			Err(Error::RpcStatus(s)) => assert_eq!(s, Status::new(Code::InvalidArgument, "nothing to echo")),
+			other => panic!("unexpected {:?}", other),
+		}
+
+		match client.call::<_, StringValue>("test.Echo", "Shout", &req, 0) {
+			Err(Error::RpcStatus(s)) => assert_eq!(s.code, Code::Unimplemented),
+			other => panic!("unexpected {:?}", other),
+		}
+
+		server.shutdown();
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+}
@@ -0,0 +1,117 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use protobuf::ProtobufError;
+use std::error;
+use std::fmt;
+use std::io;
+
+// google.rpc.Code, the same codes gRPC has
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Code {
+	Ok = 0,
+	Cancelled = 1,
+	Unknown = 2,
+	InvalidArgument = 3,
+	DeadlineExceeded = 4,
+	NotFound = 5,
+	AlreadyExists = 6,
+	PermissionDenied = 7,
+	ResourceExhausted = 8,
+	FailedPrecondition = 9,
+	Aborted = 10,
+	OutOfRange = 11,
+	Unimplemented = 12,
+	Internal = 13,
+	Unavailable = 14,
+	DataLoss = 15,
+	Unauthenticated = 16,
+}
+
+impl Code {
+	pub fn from_i32(n: i32) -> Code {
+		match n {
+			0 => Code::Ok,
+			1 => Code::Cancelled,
+			3 => Code::InvalidArgument,
+			4 => Code::DeadlineExceeded,
+			5 => Code::NotFound,
+			6 => Code::AlreadyExists,
+			7 => Code::PermissionDenied,
+			8 => Code::ResourceExhausted,
+			9 => Code::FailedPrecondition,
+			10 => Code::Aborted,
+			11 => Code::OutOfRange,
+			12 => Code::Unimplemented,
+			13 => Code::Internal,
+			14 => Code::Unavailable,
+			15 => Code::DataLoss,
+			16 => Code::Unauthenticated,
+			_ => Code::Unknown,
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Status {
+	pub code: Code,
+	pub message: String,
+}
+
+impl Status {
+	pub fn new<S: Into<String>>(code: Code, message: S) -> Status {
+		Status {
+			code,
+			message: message.into(),
+		}
+	}
+
+	pub fn ok() -> Status {
+		Status::new(Code::Ok, "")
+	}
+}
+
+#[derive(Debug)]
+pub enum Error {
+	// the call failed on the other side
+	RpcStatus(Status),
+	Io(io::Error),
+	Protobuf(ProtobufError),
+	// the peer does not speak ttrpc
+	Protocol(String),
+}
+
+impl Error {
+	pub fn status<S: Into<String>>(code: Code, message: S) -> Error {
+		Error::RpcStatus(Status::new(code, message))
+	}
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::RpcStatus(s) => write!(f, "{:?}: {}", s.code, s.message),
+			Error::Io(e) => write!(f, "{}", e),
+			Error::Protobuf(e) => write!(f, "{}", e),
+			Error::Protocol(s) => write!(f, "protocol error: {}", s),
+		}
+	}
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+	fn from(e: io::Error) -> Error {
+		Error::Io(e)
+	}
+}
+
+impl From<ProtobufError> for Error {
+	fn from(e: ProtobufError) -> Error {
+		Error::Protobuf(e)
+	}
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,246 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The ttrpc wire format: every message is a 10 byte header, big endian
+// payload length and stream id then type and flags, followed by a
+// protobuf encoded Request or Response.
+
+use protobuf::rt::read_unknown_or_skip_group;
+use protobuf::{CodedInputStream, CodedOutputStream, UnknownFields};
+use protobuf::wire_format::WireType;
+use std::io::{self, Read, Write};
+
+use crate::error::{Code, Error, Result, Status};
+
+pub const MESSAGE_HEADER_LENGTH: usize = 10;
+pub const MESSAGE_LENGTH_MAX: usize = 4 << 20;
+
+pub const MESSAGE_TYPE_REQUEST: u8 = 0x1;
+pub const MESSAGE_TYPE_RESPONSE: u8 = 0x2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MessageHeader {
+	pub length: u32,
+	pub stream_id: u32,
+	pub type_: u8,
+	pub flags: u8,
+}
+
+pub fn read_header<R: Read>(r: &mut R) -> io::Result<MessageHeader> {
+	let mut buf = [0u8; MESSAGE_HEADER_LENGTH];
+	r.read_exact(&mut buf)?;
+
+	Ok(MessageHeader {
+		length: u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]),
+		stream_id: u32::from_be_bytes([buf[4], buf[5], buf[6], buf[7]]),
+		type_: buf[8],
+		flags: buf[9],
+	})
+}
+
+// an oversized payload is skipped so that the stream stays usable,
+// only that call fails
+pub fn read_payload<R: Read>(r: &mut R, header: &MessageHeader) -> Result<Vec<u8>> {
+	let len = header.length as usize;
+
+	if len > MESSAGE_LENGTH_MAX {
+		io::copy(&mut r.take(len as u64), &mut io::sink())?;
+		return Err(Error::status(Code::ResourceExhausted, format!(
+			"message length {} exceeds the maximum of {}", len, MESSAGE_LENGTH_MAX)));
+	}
+
+	let mut buf = vec![0u8; len];
+	r.read_exact(buf.as_mut_slice())?;
+
+	Ok(buf)
+}
+
+// header and payload go out in one write, several threads share a stream
+pub fn write_message<W: Write>(w: &mut W, stream_id: u32, type_: u8, payload: &[u8]) -> Result<()> {
+	if payload.len() > MESSAGE_LENGTH_MAX {
+		return Err(Error::status(Code::ResourceExhausted, format!(
+			"message length {} exceeds the maximum of {}", payload.len(), MESSAGE_LENGTH_MAX)));
+	}
+
+	let mut buf = Vec::with_capacity(MESSAGE_HEADER_LENGTH + payload.len());
+	buf.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+	buf.extend_from_slice(&stream_id.to_be_bytes());
+	buf.push(type_);
+	buf.push(0);
+	buf.extend_from_slice(payload);
+
+	w.write_all(buf.as_slice())?;
+	Ok(())
+}
+
+fn skip(is: &mut CodedInputStream, field: u32, wire_type: WireType) -> Result<()> {
+	read_unknown_or_skip_group(field, wire_type, is, &mut UnknownFields::new())?;
+	Ok(())
+}
+
+fn encode<F>(f: F) -> Result<Vec<u8>>
+where
+	F: FnOnce(&mut CodedOutputStream) -> protobuf::ProtobufResult<()>,
+{
+	let mut buf = Vec::new();
+	{
+		let mut os = CodedOutputStream::vec(&mut buf);
+		f(&mut os)?;
+		os.flush()?;
+	}
+	Ok(buf)
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Request {
+	pub service: String,
+	pub method: String,
+	pub payload: Vec<u8>,
+	pub timeout_nano: i64,
+}
+
+impl Request {
+	pub fn encode(&self) -> Result<Vec<u8>> {
+		encode(|os| {
+			os.write_string(1, self.service.as_str())?;
+			os.write_string(2, self.method.as_str())?;
+			os.write_bytes(3, self.payload.as_slice())?;
+			if self.timeout_nano != 0 {
+				os.write_int64(4, self.timeout_nano)?;
+			}
+			Ok(())
+		})
+	}
+
+	// metadata (field 5) is not used by the agent and skipped
+	pub fn decode(buf: &[u8]) -> Result<Request> {
+		let mut is = CodedInputStream::from_bytes(buf);
+		let mut req = Request::default();
+
+		while !is.eof()? {
+			let (field, wire_type) = is.read_tag_unpack()?;
+			match (field, wire_type) {
+				(1, WireType::WireTypeLengthDelimited) => req.service = is.read_string()?,
+				(2, WireType::WireTypeLengthDelimited) => req.method = is.read_string()?,
+				(3, WireType::WireTypeLengthDelimited) => req.payload = is.read_bytes()?,
+				(4, WireType::WireTypeVarint) => req.timeout_nano = is.read_int64()?,
+				_ => skip(&mut is, field, wire_type)?,
+			}
+		}
+
+		Ok(req)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+	pub status: Status,
+	pub payload: Vec<u8>,
+}
+
+impl Response {
+	pub fn error(status: Status) -> Response {
+		Response {
+			status,
+			payload: Vec::new(),
+		}
+	}
+
+	pub fn encode(&self) -> Result<Vec<u8>> {
+		// google.rpc.Status, its details are never filled in
+		let status = encode(|os| {
+			if self.status.code != Code::Ok {
+				os.write_int32(1, self.status.code as i32)?;
+			}
+			os.write_string(2, self.status.message.as_str())
+		})?;
+
+		encode(|os| {
+			os.write_bytes(1, status.as_slice())?;
+			os.write_bytes(2, self.payload.as_slice())
+		})
+	}
+
+	pub fn decode(buf: &[u8]) -> Result<Response> {
+		let mut is = CodedInputStream::from_bytes(buf);
+		let mut resp = Response::error(Status::ok());
+
+		while !is.eof()? {
+			let (field, wire_type) = is.read_tag_unpack()?;
+			match (field, wire_type) {
+				(1, WireType::WireTypeLengthDelimited) => resp.status = decode_status(is.read_bytes()?.as_slice())?,
+				(2, WireType::WireTypeLengthDelimited) => resp.payload = is.read_bytes()?,
+				_ => skip(&mut is, field, wire_type)?,
+			}
+		}
+
+		Ok(resp)
+	}
+}
+
+fn decode_status(buf: &[u8]) -> Result<Status> {
+	let mut is = CodedInputStream::from_bytes(buf);
+	let mut status = Status::ok();
+
+	while !is.eof()? {
+		let (field, wire_type) = is.read_tag_unpack()?;
+		match (field, wire_type) {
+			(1, WireType::WireTypeVarint) => status.code = Code::from_i32(is.read_int32()?),
+			(2, WireType::WireTypeLengthDelimited) => status.message = is.read_string()?,
+			_ => skip(&mut is, field, wire_type)?,
+		}
+	}
+
+	Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_message() {
+		let mut buf = Vec::new();
+		write_message(&mut buf, 3, MESSAGE_TYPE_REQUEST, b"hello").unwrap();
+		assert_eq!(&buf[..MESSAGE_HEADER_LENGTH], &[0, 0, 0, 5, 0, 0, 0, 3, 1, 0]);
+
+		let mut r = buf.as_slice();
+		let header = read_header(&mut r).unwrap();
+		assert_eq!(header, MessageHeader { length: 5, stream_id: 3, type_: MESSAGE_TYPE_REQUEST, flags: 0 });
+		assert_eq!(read_payload(&mut r, &header).unwrap(), b"hello".to_vec());
+
+		// skipped, and what follows is still readable
+		let mut buf = Vec::new();
+		buf.extend_from_slice(&((MESSAGE_LENGTH_MAX + 1) as u32).to_be_bytes());
+		buf.extend_from_slice(&[0, 0, 0, 5, 1, 0]);
+		buf.resize(MESSAGE_HEADER_LENGTH + MESSAGE_LENGTH_MAX + 1, 0);
+		write_message(&mut buf, 7, MESSAGE_TYPE_REQUEST, b"").unwrap();
+
+		let mut r = buf.as_slice();
+		let header = read_header(&mut r).unwrap();
+		match read_payload(&mut r, &header) {
+			Err(Error::RpcStatus(s)) => assert_eq!(s.code, Code::ResourceExhausted),
+			other => panic!("unexpected {:?}", other),
+		}
+		assert_eq!(read_header(&mut r).unwrap().stream_id, 7);
+	}
+
+	#[test]
+	fn test_encoding() {
+		let req = Request {
+			service: "grpc.AgentService".to_string(),
+			method: "CreateContainer".to_string(),
+			payload: vec![1, 2, 3],
+			timeout_nano: 1000,
+		};
+		assert_eq!(Request::decode(req.encode().unwrap().as_slice()).unwrap(), req);
+
+		let resp = Response::error(Status::new(Code::NotFound, "container a"));
+		assert_eq!(Response::decode(resp.encode().unwrap().as_slice()).unwrap(), resp);
+
+		let resp = Response { status: Status::ok(), payload: vec![4, 5] };
+		assert_eq!(Response::decode(resp.encode().unwrap().as_slice()).unwrap(), resp);
+	}
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// ttrpc, the protocol containerd uses towards its shims: unary protobuf
+// calls over a plain framed stream, without http2 and the gRPC C core.
+// The services come from protocols, see agent_ttrpc.rs and
+// health_ttrpc.rs there.
+
+#[macro_use]
+extern crate log;
+extern crate libc;
+extern crate protobuf;
+
+mod error;
+mod frame;
+pub mod client;
+pub mod server;
+
+use protobuf::Message;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+pub use client::Client;
+pub use error::{Code, Error, Result, Status};
+pub use frame::{Request, Response};
+pub use server::Server;
+
+pub struct TtrpcContext {
+	// 0 when the caller set no deadline
+	pub timeout_nano: i64,
+}
+
+pub trait MethodHandler {
+	fn handle(&self, ctx: &TtrpcContext, payload: &[u8]) -> Result<Vec<u8>>;
+}
+
+// what a service registers with the server, keyed by method_name()
+pub type Methods = HashMap<String, Box<dyn MethodHandler + Send + Sync>>;
+
+pub fn method_name(service: &str, method: &str) -> String {
+	format!("/{}/{}", service, method)
+}
+
+struct Unary<Req, Resp, F> {
+	f: F,
+	_types: PhantomData<fn(Req) -> Resp>,
+}
+
+impl<Req, Resp, F> MethodHandler for Unary<Req, Resp, F>
+where
+	Req: Message,
+	Resp: Message,
+	F: Fn(&TtrpcContext, Req) -> Result<Resp>,
+{
+	fn handle(&self, ctx: &TtrpcContext, payload: &[u8]) -> Result<Vec<u8>> {
+		let mut req = Req::new();
+		req.merge_from_bytes(payload)
+			.map_err(|e| Error::status(Code::InvalidArgument, format!("cannot decode the request: {}", e)))?;
+		let resp = (self.f)(ctx, req)?;
+		Ok(resp.write_to_bytes()?)
+	}
+}
+
+pub fn unary<Req, Resp, F>(f: F) -> Box<dyn MethodHandler + Send + Sync>
+where
+	Req: Message,
+	Resp: Message,
+	F: Fn(&TtrpcContext, Req) -> Result<Resp> + Send + Sync + 'static,
+{
+	Box::new(Unary {
+		f,
+		_types: PhantomData,
+	})
+}
@@ -0,0 +1,215 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Every connection gets a reader thread and every request a thread of
+// its own, so that a call blocking for long (waiting for a process, say)
+// holds up nothing else.
+
+use std::fs::File;
+use std::io::{self, ErrorKind};
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::error::{Code, Error, Result, Status};
+use crate::frame::{self, MESSAGE_TYPE_REQUEST, MESSAGE_TYPE_RESPONSE};
+use crate::{method_name, Methods, Request, Response, TtrpcContext};
+
+// accept failing for lack of fds or memory is retried after this
+const ACCEPT_RETRY_MS: u64 = 100;
+
+pub struct Server {
+	listeners: Vec<File>,
+	methods: Methods,
+	shutdown: Arc<AtomicBool>,
+	handles: Vec<JoinHandle<()>>,
+}
+
+impl Default for Server {
+	fn default() -> Server {
+		Server::new()
+	}
+}
+
+impl Server {
+	pub fn new() -> Server {
+		Server {
+			listeners: Vec::new(),
+			methods: Methods::new(),
+			shutdown: Arc::new(AtomicBool::new(false)),
+			handles: Vec::new(),
+		}
+	}
+
+	// a bound and listening stream socket, of any family
+	pub fn add_listener(mut self, listener: File) -> Server {
+		self.listeners.push(listener);
+		self
+	}
+
+	pub fn register_service(mut self, methods: Methods) -> Server {
+		self.methods.extend(methods);
+		self
+	}
+
+	pub fn start(&mut self) -> Result<()> {
+		let methods = Arc::new(std::mem::take(&mut self.methods));
+
+		for listener in self.listeners.iter() {
+			let listener = listener.try_clone()?;
+			let methods = methods.clone();
+			let shutdown = self.shutdown.clone();
+
+			self.handles.push(thread::spawn(move || accept_loop(listener, methods, shutdown)));
+		}
+
+		Ok(())
+	}
+
+	// stops accepting, the connections already there are left to finish
+	pub fn shutdown(&mut self) {
+		self.shutdown.store(true, Ordering::SeqCst);
+
+		// wakes up the accept()
+		for listener in self.listeners.iter() {
+			unsafe { libc::shutdown(listener.as_raw_fd(), libc::SHUT_RDWR) };
+		}
+
+		for handle in self.handles.drain(..) {
+			let _ = handle.join();
+		}
+	}
+}
+
+fn accept(listener: &File) -> io::Result<File> {
+	let fd = unsafe {
+		libc::accept4(listener.as_raw_fd(), ptr::null_mut(), ptr::null_mut(),
+			libc::SOCK_CLOEXEC)
+	};
+	if fd < 0 {
+		return Err(io::Error::last_os_error());
+	}
+
+	Ok(unsafe { File::from_raw_fd(fd) })
+}
+
+fn accept_loop(listener: File, methods: Arc<Methods>, shutdown: Arc<AtomicBool>) {
+	loop {
+		let r = accept(&listener);
+
+		if shutdown.load(Ordering::SeqCst) {
+			return;
+		}
+
+		match r {
+			Ok(conn) => {
+				let methods = methods.clone();
+				thread::spawn(move || serve(conn, methods));
+			}
+			Err(ref e) if e.kind() == ErrorKind::Interrupted
+				|| e.raw_os_error() == Some(libc::ECONNABORTED) => {}
+			Err(e) => {
+				warn!("ttrpc accept: {}", e);
+				thread::sleep(Duration::from_millis(ACCEPT_RETRY_MS));
+			}
+		}
+	}
+}
+
+fn serve(mut conn: File, methods: Arc<Methods>) {
+	let writer = match conn.try_clone() {
+		Ok(w) => Arc::new(Mutex::new(w)),
+		Err(e) => {
+			warn!("ttrpc connection: {}", e);
+			return;
+		}
+	};
+
+	loop {
+		let header = match frame::read_header(&mut conn) {
+			Ok(h) => h,
+			Err(e) => {
+				if e.kind() != ErrorKind::UnexpectedEof {
+					warn!("ttrpc connection: {}", e);
+				}
+				return;
+			}
+		};
+
+		let payload = match frame::read_payload(&mut conn, &header) {
+			Ok(p) => p,
+			Err(Error::RpcStatus(status)) => {
+				send(&writer, header.stream_id, Response::error(status));
+				continue;
+			}
+			Err(e) => {
+				warn!("ttrpc connection: {}", e);
+				return;
+			}
+		};
+
+		if header.type_ != MESSAGE_TYPE_REQUEST {
+			debug!("ttrpc: ignoring message of type {} on stream {}", header.type_, header.stream_id);
+			continue;
+		}
+
+		let methods = methods.clone();
+		let writer = writer.clone();
+		thread::spawn(move || {
+			let resp = handle(&methods, payload.as_slice());
+			send(&writer, header.stream_id, resp);
+		});
+	}
+}
+
+fn handle(methods: &Methods, payload: &[u8]) -> Response {
+	let req = match Request::decode(payload) {
+		Ok(r) => r,
+		Err(e) => return Response::error(Status::new(Code::InvalidArgument,
+			format!("cannot decode the request: {}", e))),
+	};
+
+	let name = method_name(req.service.as_str(), req.method.as_str());
+	let handler = match methods.get(&name) {
+		Some(h) => h,
+		None => return Response::error(Status::new(Code::Unimplemented,
+			format!("{} is not supported", name))),
+	};
+
+	let ctx = TtrpcContext {
+		timeout_nano: req.timeout_nano,
+	};
+
+	match handler.handle(&ctx, req.payload.as_slice()) {
+		Ok(payload) => Response {
+			status: Status::ok(),
+			payload,
+		},
+		Err(Error::RpcStatus(status)) => Response::error(status),
+		Err(e) => Response::error(Status::new(Code::Internal, e.to_string())),
+	}
+}
+
+fn write_response(writer: &Mutex<File>, stream_id: u32, resp: &Response) -> Result<()> {
+	let buf = resp.encode()?;
+	let mut w = writer.lock().unwrap();
+	frame::write_message(&mut *w, stream_id, MESSAGE_TYPE_RESPONSE, buf.as_slice())
+}
+
+fn send(writer: &Mutex<File>, stream_id: u32, resp: Response) {
+	let mut r = write_response(writer, stream_id, &resp);
+
+	// too big to go out, the caller still gets an answer
+	if let Err(Error::RpcStatus(status)) = r {
+		r = write_response(writer, stream_id, &Response::error(status));
+	}
+
+	if let Err(e) = r {
+		warn!("ttrpc: cannot answer stream {}: {}", stream_id, e);
+	}
+}